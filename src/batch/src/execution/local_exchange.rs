@@ -83,7 +83,7 @@ mod tests {
     };
     use risingwave_rpc_client::{ExchangeSource, GrpcExchangeSource};
     use tokio_stream::wrappers::ReceiverStream;
-    use tonic::{Request, Response, Status};
+    use tonic::{Request, Response, Status, Streaming};
 
     struct FakeExchangeService {
         rpc_called: Arc<AtomicBool>,
@@ -113,7 +113,7 @@ mod tests {
 
         async fn get_stream(
             &self,
-            _request: Request<GetStreamRequest>,
+            _request: Request<Streaming<GetStreamRequest>>,
         ) -> Result<Response<Self::GetStreamStream>, Status> {
             unimplemented!()
         }
@@ -26,13 +26,14 @@ use crate::executor::create_source::CreateSourceExecutor;
 pub use crate::executor::create_table::CreateTableExecutor;
 use crate::executor::trace::TraceExecutor;
 use crate::executor2::executor_wrapper::ExecutorWrapper;
+use crate::executor2::monitor::TaskOperatorStatsRef;
 use crate::executor2::{
     BoxedExecutor2, BoxedExecutor2Builder, DeleteExecutor2, ExchangeExecutor2, FilterExecutor2,
     GenerateSeriesI32Executor2, GenerateSeriesTimestampExecutor2, HashAggExecutor2Builder,
     HashJoinExecutor2Builder, HopWindowExecutor2, InsertExecutor2, LimitExecutor2,
     MergeSortExchangeExecutor2, NestedLoopJoinExecutor2, OrderByExecutor2, ProjectExecutor2,
-    RowSeqScanExecutor2Builder, SortAggExecutor2, SortMergeJoinExecutor2, StreamScanExecutor2,
-    TopNExecutor2, TraceExecutor2, ValuesExecutor2,
+    RowSeqScanExecutor2Builder, SortAggExecutor2, SortMergeJoinExecutor2, SpillContext,
+    StreamScanExecutor2, TaskMemoryBudgetRef, TopNExecutor2, TraceExecutor2, ValuesExecutor2,
 };
 use crate::task::{BatchEnvironment, TaskId};
 
@@ -104,6 +105,10 @@ pub struct ExecutorBuilder<'a> {
     pub task_id: &'a TaskId,
     env: BatchEnvironment,
     epoch: u64,
+    /// Runtime statistics of the operators in the task.
+    operator_stats: TaskOperatorStatsRef,
+    /// Memory budget shared by the executors of the task.
+    memory_budget: TaskMemoryBudgetRef,
 }
 
 macro_rules! build_executor {
@@ -136,12 +141,16 @@ impl<'a> ExecutorBuilder<'a> {
         task_id: &'a TaskId,
         env: BatchEnvironment,
         epoch: u64,
+        operator_stats: TaskOperatorStatsRef,
+        memory_budget: TaskMemoryBudgetRef,
     ) -> Self {
         Self {
             plan_node,
             task_id,
             env,
             epoch,
+            operator_stats,
+            memory_budget,
         }
     }
 
@@ -169,7 +178,14 @@ impl<'a> ExecutorBuilder<'a> {
 
     #[must_use]
     pub fn clone_for_plan(&self, plan_node: &'a PlanNode) -> Self {
-        ExecutorBuilder::new(plan_node, self.task_id, self.env.clone(), self.epoch)
+        ExecutorBuilder::new(
+            plan_node,
+            self.task_id,
+            self.env.clone(),
+            self.epoch,
+            self.operator_stats.clone(),
+            self.memory_budget.clone(),
+        )
     }
 
     fn try_build(&self) -> Result<BoxedExecutor> {
@@ -200,7 +216,14 @@ impl<'a> ExecutorBuilder<'a> {
             NodeBody::HopWindow => HopWindowExecutor2,
         }?;
         let input_desc = real_executor.identity().to_string();
-        Ok(Box::new(TraceExecutor::new(real_executor, input_desc)))
+        let stats = self
+            .operator_stats
+            .register(self.plan_node.operator_id, &input_desc);
+        Ok(Box::new(TraceExecutor::new(
+            real_executor,
+            input_desc,
+            stats,
+        )))
     }
 
     fn try_build2(&self) -> Result<BoxedExecutor2> {
@@ -231,7 +254,14 @@ impl<'a> ExecutorBuilder<'a> {
             NodeBody::HopWindow => HopWindowExecutor2,
         }?;
         let input_desc = real_executor.identity().to_string();
-        Ok(Box::new(TraceExecutor2::new(real_executor, input_desc)))
+        let stats = self
+            .operator_stats
+            .register(self.plan_node.operator_id, &input_desc);
+        Ok(Box::new(TraceExecutor2::new(
+            real_executor,
+            input_desc,
+            stats,
+        )))
     }
 
     pub fn plan_node(&self) -> &PlanNode {
@@ -245,6 +275,17 @@ impl<'a> ExecutorBuilder<'a> {
     pub fn epoch(&self) -> u64 {
         self.epoch
     }
+
+    /// Returns the context for executors that spill to disk beyond the task memory budget. The
+    /// memory reserved through it is recorded in the statistics of the operator.
+    pub fn spill_context(&self) -> SpillContext {
+        let directory = &self.env.config().spill_directory;
+        SpillContext::new(
+            self.memory_budget.clone(),
+            (!directory.is_empty()).then(|| directory.into()),
+        )
+        .with_operator_stats(self.operator_stats.get(self.plan_node.operator_id))
+    }
 }
 
 #[cfg(test)]
@@ -264,8 +305,14 @@ mod tests {
             stage_id: 1,
             query_id: "test_query_id".to_string(),
         };
-        let builder =
-            ExecutorBuilder::new(&plan_node, task_id, BatchEnvironment::for_test(), u64::MAX);
+        let builder = ExecutorBuilder::new(
+            &plan_node,
+            task_id,
+            BatchEnvironment::for_test(),
+            u64::MAX,
+            Default::default(),
+            Default::default(),
+        );
         let child_plan = &PlanNode {
             ..Default::default()
         };
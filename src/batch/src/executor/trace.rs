@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+use std::time::Instant;
+
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
@@ -19,18 +22,25 @@ use tracing::event;
 use tracing_futures::Instrument;
 
 use crate::executor::{BoxedExecutor, Executor};
+use crate::executor2::monitor::OperatorStats;
 
 /// If tracing is enabled, we build a [`TraceExecutor`] on top of the underlying executor.
 /// So the duration of performance-critical operations will be traced, such as open/next/close.
+/// The runtime statistics of the underlying executor are also recorded here.
 pub(super) struct TraceExecutor {
     child: BoxedExecutor,
     /// Description of input executor
     input_desc: String,
+    stats: Arc<OperatorStats>,
 }
 
 impl TraceExecutor {
-    pub fn new(child: BoxedExecutor, input_desc: String) -> Self {
-        Self { child, input_desc }
+    pub fn new(child: BoxedExecutor, input_desc: String, stats: Arc<OperatorStats>) -> Self {
+        Self {
+            child,
+            input_desc,
+            stats,
+        }
     }
 }
 
@@ -39,6 +49,7 @@ impl Executor for TraceExecutor {
     async fn open(&mut self) -> Result<()> {
         let input_desc = self.input_desc.as_str();
         let span_name = format!("{input_desc}_open");
+        let start_time = Instant::now();
         self.child
             .open()
             .instrument(tracing::trace_span!(
@@ -47,12 +58,14 @@ impl Executor for TraceExecutor {
                 open = input_desc,
             ))
             .await?;
+        self.stats.record(start_time.elapsed(), None);
         Ok(())
     }
 
     async fn next(&mut self) -> Result<Option<DataChunk>> {
         let input_desc = self.input_desc.as_str();
         let span_name = format!("{input_desc}_next");
+        let start_time = Instant::now();
         let input_chunk = self
             .child
             .next()
@@ -64,6 +77,7 @@ impl Executor for TraceExecutor {
             .await;
         match input_chunk {
             Ok(chunk) => {
+                self.stats.record(start_time.elapsed(), chunk.as_ref());
                 match &chunk {
                     Some(chunk) => {
                         event!(tracing::Level::TRACE, prev = %input_desc, msg = "chunk", "input = \n{:#?}", chunk);
@@ -81,6 +95,7 @@ impl Executor for TraceExecutor {
     async fn close(&mut self) -> Result<()> {
         let input_desc = self.input_desc.as_str();
         let span_name = format!("{input_desc}_close");
+        let start_time = Instant::now();
         self.child
             .close()
             .instrument(tracing::trace_span!(
@@ -89,6 +104,7 @@ impl Executor for TraceExecutor {
                 close = input_desc,
             ))
             .await?;
+        self.stats.record(start_time.elapsed(), None);
         Ok(())
     }
 
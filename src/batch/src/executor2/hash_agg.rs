@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -33,9 +34,15 @@ use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::HashAggNode;
 
 use crate::executor::ExecutorBuilder;
-use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+use crate::executor2::{
+    BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2, SpillContext,
+    SpillPartitions,
+};
 use crate::task::TaskId;
 
+/// Rough size of an aggregation state, used to estimate the memory of a group.
+const ESTIMATED_AGG_STATE_SIZE: usize = 64;
+
 type AggHashMap<K> = HashMap<K, Vec<BoxedAggState>, PrecomputedBuildHasher>;
 
 struct HashAggExecutor2BuilderDispatcher;
@@ -58,6 +65,7 @@ pub struct HashAggExecutor2Builder {
     schema: Schema,
     task_id: TaskId,
     identity: String,
+    spill_context: SpillContext,
 }
 
 impl HashAggExecutor2Builder {
@@ -66,6 +74,7 @@ impl HashAggExecutor2Builder {
         child: BoxedExecutor2,
         task_id: TaskId,
         identity: String,
+        spill_context: SpillContext,
     ) -> Result<BoxedExecutor2> {
         let group_key_columns = hash_agg_node
             .get_group_keys()
@@ -103,6 +112,7 @@ impl HashAggExecutor2Builder {
             schema: Schema { fields },
             task_id,
             identity,
+            spill_context,
         };
 
         Ok(HashAggExecutor2BuilderDispatcher::dispatch_by_kind(
@@ -125,7 +135,13 @@ impl BoxedExecutor2Builder for HashAggExecutor2Builder {
         )?;
 
         let identity = source.plan_node().get_identity().clone();
-        Self::deserialize(hash_agg_node, child, source.task_id.clone(), identity)
+        Self::deserialize(
+            hash_agg_node,
+            child,
+            source.task_id.clone(),
+            identity,
+            source.spill_context(),
+        )
     }
 }
 
@@ -141,6 +157,8 @@ pub(crate) struct HashAggExecutor2<K> {
     group_key_types: Vec<DataType>,
     schema: Schema,
     identity: String,
+    /// Spills the groups that do not fit into the task memory budget
+    spill_context: SpillContext,
     _phantom: PhantomData<K>,
 }
 
@@ -153,6 +171,7 @@ impl<K> HashAggExecutor2<K> {
             group_key_types: builder.group_key_types,
             schema: builder.schema,
             identity: builder.identity,
+            spill_context: builder.spill_context,
             _phantom: PhantomData,
         }
     }
@@ -173,85 +192,143 @@ impl<K: HashKey + Send + Sync> Executor2 for HashAggExecutor2<K> {
 }
 
 impl<K: HashKey + Send + Sync> HashAggExecutor2<K> {
+    /// Estimates the memory of a new group from the key columns of `chunk`.
+    fn estimated_group_size(&self, chunk: &DataChunk) -> usize {
+        let key_size = self
+            .group_key_columns
+            .iter()
+            .map(|idx| chunk.column_at(*idx).array_ref().estimated_size())
+            .sum::<usize>()
+            / chunk.cardinality().max(1);
+        std::mem::size_of::<K>()
+            + key_size
+            + std::mem::size_of::<Vec<BoxedAggState>>()
+            + self.agg_factories.len() * ESTIMATED_AGG_STATE_SIZE
+    }
+
+    /// Aggregates the input in memory until the task memory budget is exhausted. After that, the
+    /// rows of groups not seen yet are spilled to partitions, which are aggregated one by one
+    /// after the in-memory groups are emitted. A group is either entirely in memory or entirely
+    /// spilled, so no partial states need to be merged.
     #[try_stream(boxed, ok = DataChunk, error = RwError)]
     async fn do_execute(self: Box<Self>) {
-        // hash map for each agg groups
-        let mut groups = AggHashMap::<K>::default();
-
-        // consume all chunks to compute the agg result
-        #[for_await]
-        for chunk in self.child.execute() {
-            let chunk = chunk?.compact()?;
-            let keys = K::build(self.group_key_columns.as_slice(), &chunk)?;
-            for (row_id, key) in keys.into_iter().enumerate() {
-                let mut err_flag = Ok(());
-                let states: &mut Vec<BoxedAggState> = groups.entry(key).or_insert_with(|| {
-                    self.agg_factories
-                        .iter()
-                        .map(AggStateFactory::create_agg_state)
-                        .collect::<Result<Vec<_>>>()
-                        .unwrap_or_else(|x| {
-                            err_flag = Err(x);
-                            vec![]
-                        })
-                });
-                err_flag?;
-
-                // TODO: currently not a vectorized implementation
-                states
-                    .iter_mut()
-                    .for_each(|state| state.update_with_row(&chunk, row_id).unwrap());
-            }
-        }
+        let mut reservation = self.spill_context.reservation();
+        let mut input = self.child.execute();
+        let mut level = 0;
+        let mut pending_partitions = vec![];
 
-        // generate output data chunks
-        let mut result = groups.into_iter();
-        let cardinality = DEFAULT_CHUNK_BUFFER_SIZE;
         loop {
-            let mut group_builders = self
-                .group_key_types
-                .iter()
-                .map(|datatype| datatype.create_array_builder(cardinality))
-                .collect::<Result<Vec<_>>>()?;
-
-            let mut agg_builders = self
-                .agg_factories
-                .iter()
-                .map(|agg_factory| {
-                    agg_factory
-                        .get_return_type()
-                        .create_array_builder(cardinality)
-                })
-                .collect::<Result<Vec<_>>>()?;
-
-            let mut has_next = false;
-            for (key, states) in result.by_ref().take(cardinality) {
-                has_next = true;
-                key.deserialize_to_builders(&mut group_builders[..])?;
-                states
-                    .into_iter()
-                    .zip_eq(&mut agg_builders)
-                    .try_for_each(|(aggregator, builder)| aggregator.output(builder))?;
-            }
-            if !has_next {
-                break; // exit loop
+            // hash map for each agg groups
+            let mut groups = AggHashMap::<K>::default();
+            let mut spill: Option<SpillPartitions> = None;
+
+            // consume all chunks to compute the agg result
+            #[for_await]
+            for chunk in input {
+                let chunk = chunk?.compact()?;
+                let keys = K::build(self.group_key_columns.as_slice(), &chunk)?;
+                let group_size = self.estimated_group_size(&chunk);
+                let mut spilled_rows = vec![false; chunk.cardinality()];
+                let mut has_spilled_rows = false;
+                for (row_id, key) in keys.into_iter().enumerate() {
+                    let states = match groups.entry(key) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => {
+                            if spill.is_none() && !reservation.try_grow(group_size)? {
+                                spill = Some(self.spill_context.create_partitions(level).await?);
+                            }
+                            if spill.is_some() {
+                                spilled_rows[row_id] = true;
+                                has_spilled_rows = true;
+                                continue;
+                            }
+                            entry.insert(
+                                self.agg_factories
+                                    .iter()
+                                    .map(AggStateFactory::create_agg_state)
+                                    .collect::<Result<Vec<_>>>()?,
+                            )
+                        }
+                    };
+
+                    // TODO: currently not a vectorized implementation
+                    states
+                        .iter_mut()
+                        .for_each(|state| state.update_with_row(&chunk, row_id).unwrap());
+                }
+                if has_spilled_rows {
+                    spill
+                        .as_mut()
+                        .unwrap()
+                        .write_chunk(chunk, &self.group_key_columns, Some(&spilled_rows))
+                        .await?;
+                }
             }
 
-            let columns = group_builders
-                .into_iter()
-                .chain(agg_builders)
-                .map(|b| Ok(Column::new(Arc::new(b.finish()?))))
-                .collect::<Result<Vec<_>>>()?;
+            // generate output data chunks
+            let mut result = groups.into_iter();
+            let cardinality = DEFAULT_CHUNK_BUFFER_SIZE;
+            loop {
+                let mut group_builders = self
+                    .group_key_types
+                    .iter()
+                    .map(|datatype| datatype.create_array_builder(cardinality))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let mut agg_builders = self
+                    .agg_factories
+                    .iter()
+                    .map(|agg_factory| {
+                        agg_factory
+                            .get_return_type()
+                            .create_array_builder(cardinality)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let mut has_next = false;
+                for (key, states) in result.by_ref().take(cardinality) {
+                    has_next = true;
+                    key.deserialize_to_builders(&mut group_builders[..])?;
+                    states
+                        .into_iter()
+                        .zip_eq(&mut agg_builders)
+                        .try_for_each(|(aggregator, builder)| aggregator.output(builder))?;
+                }
+                if !has_next {
+                    break; // exit loop
+                }
+
+                let columns = group_builders
+                    .into_iter()
+                    .chain(agg_builders)
+                    .map(|b| Ok(Column::new(Arc::new(b.finish()?))))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let output = DataChunk::builder().columns(columns).build();
+                yield output;
+            }
+            reservation.free();
 
-            let output = DataChunk::builder().columns(columns).build();
-            yield output;
+            // aggregate the spilled partitions with the next bits of the hash code
+            if let Some(spill) = spill {
+                pending_partitions
+                    .extend(spill.finish().await?.into_iter().map(|p| (p, level + 1)));
+            }
+            match pending_partitions.pop() {
+                Some((partition, partition_level)) => {
+                    input = partition.into_stream();
+                    level = partition_level;
+                }
+                None => break,
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use risingwave_common::array::{I32Array, I64Array};
+    use futures::TryStreamExt;
+    use risingwave_common::array::{Array, I32Array, I64Array};
     use risingwave_common::array_nonnull;
     use risingwave_common::catalog::{Field, Schema};
     use risingwave_pb::data::data_type::TypeName;
@@ -261,6 +338,7 @@ mod tests {
 
     use super::*;
     use crate::executor::test_utils::{diff_executor_output, MockExecutor};
+    use crate::executor2::TaskMemoryBudget;
 
     #[tokio::test]
     async fn execute_int32_grouped() {
@@ -314,6 +392,7 @@ mod tests {
             Box::new(src_exec),
             TaskId::default(),
             "HashAggExecutor".to_string(),
+            SpillContext::default(),
         )
         .unwrap();
 
@@ -374,6 +453,7 @@ mod tests {
             Box::new(src_exec),
             TaskId::default(),
             "HashAggExecutor".to_string(),
+            SpillContext::default(),
         )
         .unwrap();
         let schema = Schema {
@@ -387,4 +467,82 @@ mod tests {
         );
         diff_executor_output(actual_exec, Box::new(expect_exec)).await;
     }
+
+    #[tokio::test]
+    async fn execute_with_spill() {
+        // 1000 groups of 2 rows each
+        let keys = (0..2000).map(|i| Some(i % 1000)).collect_vec();
+        let values = (0..2000).map(Some).collect_vec();
+        let expected = (0..1000).map(|k| (k, 2 * k as i64 + 1000)).collect_vec();
+
+        // With a small budget part of the groups are spilled, and fit into the budget at the next
+        // levels. With a zero budget no group fits even at the last level, so the query fails.
+        for budget in [4096, 0] {
+            let src_exec = MockExecutor::with_chunk(
+                DataChunk::builder()
+                    .columns(vec![
+                        Column::new(Arc::new(I32Array::from_slice(&keys).unwrap().into())),
+                        Column::new(Arc::new(I32Array::from_slice(&values).unwrap().into())),
+                    ])
+                    .build(),
+                Schema {
+                    fields: vec![
+                        Field::unnamed(DataType::Int32),
+                        Field::unnamed(DataType::Int32),
+                    ],
+                },
+            );
+
+            let agg_call = AggCall {
+                r#type: Type::Sum as i32,
+                args: vec![Arg {
+                    input: Some(InputRefExpr { column_idx: 1 }),
+                    r#type: Some(ProstDataType {
+                        type_name: TypeName::Int32 as i32,
+                        ..Default::default()
+                    }),
+                }],
+                return_type: Some(ProstDataType {
+                    type_name: TypeName::Int64 as i32,
+                    ..Default::default()
+                }),
+                distinct: false,
+            };
+
+            let agg_prost = HashAggNode {
+                group_keys: vec![0],
+                agg_calls: vec![agg_call],
+            };
+
+            let actual_exec = HashAggExecutor2Builder::deserialize(
+                &agg_prost,
+                Box::new(src_exec),
+                TaskId::default(),
+                "HashAggExecutor".to_string(),
+                SpillContext::new(Arc::new(TaskMemoryBudget::new(budget)), None),
+            )
+            .unwrap();
+
+            let chunks = match actual_exec.execute().try_collect::<Vec<_>>().await {
+                Ok(chunks) => chunks,
+                Err(e) => {
+                    assert_eq!(budget, 0, "{}", e);
+                    continue;
+                }
+            };
+            assert_ne!(budget, 0);
+            let mut actual = vec![];
+            for chunk in chunks {
+                let keys = chunk.column_at(0).array_ref().as_int32();
+                let sums = chunk.column_at(1).array_ref().as_int64();
+                actual.extend(
+                    keys.iter()
+                        .zip_eq(sums.iter())
+                        .map(|(k, s)| (k.unwrap(), s.unwrap())),
+                );
+            }
+            actual.sort_unstable();
+            assert_eq!(actual, expected);
+        }
+    }
 }
@@ -17,6 +17,7 @@ use std::marker::PhantomData;
 
 use futures::StreamExt;
 use futures_async_stream::try_stream;
+use itertools::Itertools;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{Result, RwError};
@@ -29,7 +30,10 @@ use risingwave_pb::batch_plan::plan_node::NodeBody;
 use crate::executor::ExecutorBuilder;
 use crate::executor2::join::hash_join_state::{BuildTable, ProbeTable};
 use crate::executor2::join::JoinType;
-use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+use crate::executor2::{
+    BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2, MemoryReservation,
+    SpillContext, SpillPartitions,
+};
 use crate::task::TaskId;
 
 /// Parameters of equi-join.
@@ -71,6 +75,8 @@ pub(super) struct HashJoinExecutor2<K> {
     params: EquiJoinParams,
     schema: Schema,
     identity: String,
+    /// Switches to grace hash join once the build side exceeds the task memory budget
+    spill_context: SpillContext,
     _phantom: PhantomData<K>,
 }
 
@@ -130,99 +136,199 @@ impl<K: HashKey + Send + Sync> Executor2 for HashJoinExecutor2<K> {
     }
 }
 
-impl<K: HashKey + Send + Sync> HashJoinExecutor2<K> {
-    #[try_stream(boxed, ok = DataChunk, error = RwError)]
-    async fn do_execute(mut self: Box<Self>) {
-        let mut right_child_stream = self.right_child.take().unwrap().execute();
-        let mut build_table = BuildTable::with_params(self.params);
+/// Build side of one round of hash join.
+enum BuildSide {
+    /// The build side fits into the task memory budget.
+    InMemory(Vec<DataChunk>),
+    /// The build side exceeds the budget and has been partitioned to disk.
+    Spilled(SpillPartitions),
+}
 
-        while let Some(chunk) = right_child_stream.next().await {
+impl<K: HashKey + Send + Sync> HashJoinExecutor2<K> {
+    /// Collects the build side into memory. Once the task memory budget is exhausted, the chunks
+    /// collected so far and the rest of the build side are partitioned to disk instead.
+    async fn collect_build_side(
+        mut build_side: BoxedDataChunkStream,
+        build_key_columns: &[usize],
+        level: usize,
+        spill_context: &SpillContext,
+        reservation: &mut MemoryReservation,
+    ) -> Result<BuildSide> {
+        let mut build_chunks = vec![];
+        let mut spill: Option<SpillPartitions> = None;
+
+        while let Some(chunk) = build_side.next().await {
             let chunk = chunk?;
-            build_table.append_build_chunk(chunk)?;
-        }
-        let mut probe_table: ProbeTable<K> = build_table.try_into()?;
-
-        let mut state = HashJoinState::Probe;
-        let mut left_child_stream = self.left_child.take().unwrap().execute();
-
-        // first probe
-        match left_child_stream.next().await {
-            Some(data_chunk) => {
-                let data_chunk = data_chunk?;
-                probe_table.set_probe_data(data_chunk)?;
-            }
-            None => {
-                state = HashJoinState::Done;
+            if let Some(spill) = &mut spill {
+                spill.write_chunk(chunk, build_key_columns, None).await?;
+            } else if reservation.try_grow(chunk.estimated_size())? {
+                build_chunks.push(chunk);
+            } else {
+                let mut partitions = spill_context.create_partitions(level).await?;
+                for chunk in build_chunks.drain(..).chain(std::iter::once(chunk)) {
+                    partitions
+                        .write_chunk(chunk, build_key_columns, None)
+                        .await?;
+                }
+                reservation.free();
+                spill = Some(partitions);
             }
         }
-        // probe
-        while state == HashJoinState::Probe {
-            if let Some(ret_data_chunk) = probe_table.join()? {
-                let data_chunk = if probe_table.has_non_equi_cond() {
-                    probe_table.process_non_equi_condition(ret_data_chunk)?
-                } else {
-                    Some(ret_data_chunk)
-                };
-
-                // TODO(yuhao): Current we handle cut null columns in semi/anti join just
-                // before returning chunks. We can furthur optimize this by cut columns earlier.
-                let output_data_chunk =
-                    data_chunk.map(|chunk| probe_table.remove_null_columns_for_semi_anti(chunk));
 
-                probe_table.reset_result_index();
+        Ok(match spill {
+            Some(spill) => BuildSide::Spilled(spill),
+            None => BuildSide::InMemory(build_chunks),
+        })
+    }
 
-                if let Some(data_chunk) = output_data_chunk && data_chunk.cardinality() > 0 {
-                    yield data_chunk;
-                }
-            } else {
-                match left_child_stream.next().await {
-                    Some(data_chunk) => {
-                        let data_chunk = data_chunk?;
-                        probe_table.set_probe_data(data_chunk)?;
+    /// Runs grace hash join once the build side exceeds the task memory budget: both sides are
+    /// partitioned to disk by the hash of the join keys, and each pair of partitions is joined
+    /// separately, partitioning again with the next bits of the hash code if needed. Equal keys
+    /// always land in the same pair of partitions, so the partitions can be joined independently
+    /// for every join type.
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(mut self: Box<Self>) {
+        let mut params = self.params;
+        let build_key_columns = params.build_key_columns().to_vec();
+        let probe_key_columns = params.probe_key_columns().to_vec();
+        let mut reservation = self.spill_context.reservation();
+
+        let mut rounds = vec![(
+            self.right_child.take().unwrap().execute(),
+            self.left_child.take().unwrap().execute(),
+            0,
+        )];
+        while let Some((build_side, mut left_child_stream, level)) = rounds.pop() {
+            let build_chunks = match Self::collect_build_side(
+                build_side,
+                &build_key_columns,
+                level,
+                &self.spill_context,
+                &mut reservation,
+            )
+            .await?
+            {
+                BuildSide::InMemory(build_chunks) => build_chunks,
+                BuildSide::Spilled(build_partitions) => {
+                    let mut probe_partitions = self.spill_context.create_partitions(level).await?;
+                    #[for_await]
+                    for chunk in left_child_stream {
+                        probe_partitions
+                            .write_chunk(chunk?, &probe_key_columns, None)
+                            .await?;
                     }
-                    None => {
-                        // Consume the rest when when probe side end.
-                        let ret_data_chunk = probe_table.consume_left()?;
-                        let data_chunk = if probe_table.has_non_equi_cond() {
-                            probe_table.process_non_equi_condition(ret_data_chunk)?
-                        } else {
-                            Some(ret_data_chunk)
-                        };
+                    rounds.extend(
+                        build_partitions
+                            .finish()
+                            .await?
+                            .into_iter()
+                            .zip_eq(probe_partitions.finish().await?)
+                            .map(|(build, probe)| {
+                                (build.into_stream(), probe.into_stream(), level + 1)
+                            }),
+                    );
+                    continue;
+                }
+            };
 
-                        let output_data_chunk = data_chunk
-                            .map(|chunk| probe_table.remove_null_columns_for_semi_anti(chunk));
+            let mut build_table = BuildTable::with_params(params);
+            for chunk in build_chunks {
+                build_table.append_build_chunk(chunk)?;
+            }
+            let mut probe_table: ProbeTable<K> = build_table.try_into()?;
 
-                        probe_table.reset_result_index();
+            let mut state = HashJoinState::Probe;
 
-                        if probe_table.join_type().need_join_remaining() {
-                            state = HashJoinState::ProbeRemaining;
-                        } else {
-                            state = HashJoinState::Done;
+            // first probe
+            match left_child_stream.next().await {
+                Some(data_chunk) => {
+                    let data_chunk = data_chunk?;
+                    probe_table.set_probe_data(data_chunk)?;
+                }
+                None => {
+                    // The unmatched build rows still need to be emitted on an empty probe side.
+                    if probe_table.join_type().need_join_remaining() {
+                        state = HashJoinState::ProbeRemaining;
+                    } else {
+                        state = HashJoinState::Done;
+                    }
+                }
+            }
+            // probe
+            while state == HashJoinState::Probe {
+                if let Some(ret_data_chunk) = probe_table.join()? {
+                    let data_chunk = if probe_table.has_non_equi_cond() {
+                        probe_table.process_non_equi_condition(ret_data_chunk)?
+                    } else {
+                        Some(ret_data_chunk)
+                    };
+
+                    // TODO(yuhao): Current we handle cut null columns in semi/anti join just
+                    // before returning chunks. We can furthur optimize this by cut columns earlier.
+                    let output_data_chunk = data_chunk
+                        .map(|chunk| probe_table.remove_null_columns_for_semi_anti(chunk));
+
+                    probe_table.reset_result_index();
+
+                    if let Some(data_chunk) = output_data_chunk && data_chunk.cardinality() > 0 {
+                        yield data_chunk;
+                    }
+                } else {
+                    match left_child_stream.next().await {
+                        Some(data_chunk) => {
+                            let data_chunk = data_chunk?;
+                            probe_table.set_probe_data(data_chunk)?;
                         }
-                        if let Some(data_chunk) = output_data_chunk && data_chunk.cardinality() > 0 {
-                            yield data_chunk;
+                        None => {
+                            // Consume the rest when when probe side end.
+                            let ret_data_chunk = probe_table.consume_left()?;
+                            let data_chunk = if probe_table.has_non_equi_cond() {
+                                probe_table.process_non_equi_condition(ret_data_chunk)?
+                            } else {
+                                Some(ret_data_chunk)
+                            };
+
+                            let output_data_chunk = data_chunk
+                                .map(|chunk| probe_table.remove_null_columns_for_semi_anti(chunk));
+
+                            probe_table.reset_result_index();
+
+                            if probe_table.join_type().need_join_remaining() {
+                                state = HashJoinState::ProbeRemaining;
+                            } else {
+                                state = HashJoinState::Done;
+                            }
+                            if let Some(data_chunk) = output_data_chunk && data_chunk.cardinality() > 0 {
+                                yield data_chunk;
+                            }
                         }
                     }
                 }
             }
-        }
-        // probe_remaining
-        while state == HashJoinState::ProbeRemaining {
-            let output_data_chunk = if let Some(ret_data_chunk) = probe_table.join_remaining()? {
+            // probe_remaining
+            while state == HashJoinState::ProbeRemaining {
                 let output_data_chunk =
-                    probe_table.remove_null_columns_for_semi_anti(ret_data_chunk);
+                    if let Some(ret_data_chunk) = probe_table.join_remaining()? {
+                        let output_data_chunk =
+                            probe_table.remove_null_columns_for_semi_anti(ret_data_chunk);
 
-                probe_table.reset_result_index();
-                output_data_chunk
-            } else {
-                let ret_data_chunk = probe_table.consume_left()?;
-                let output_data_chunk =
-                    probe_table.remove_null_columns_for_semi_anti(ret_data_chunk);
+                        probe_table.reset_result_index();
+                        output_data_chunk
+                    } else {
+                        let ret_data_chunk = probe_table.consume_left()?;
+                        let output_data_chunk =
+                            probe_table.remove_null_columns_for_semi_anti(ret_data_chunk);
+
+                        state = HashJoinState::Done;
+                        output_data_chunk
+                    };
+                if output_data_chunk.cardinality() > 0 {
+                    yield output_data_chunk
+                }
+            }
 
-                state = HashJoinState::Done;
-                output_data_chunk
-            };
-            yield output_data_chunk
+            params = probe_table.into_params();
+            reservation.free();
         }
     }
 }
@@ -241,6 +347,7 @@ impl<K> HashJoinExecutor2<K> {
         params: EquiJoinParams,
         schema: Schema,
         identity: String,
+        spill_context: SpillContext,
     ) -> Self {
         HashJoinExecutor2 {
             left_child: Some(left_child),
@@ -248,6 +355,7 @@ impl<K> HashJoinExecutor2<K> {
             params,
             schema,
             identity,
+            spill_context,
             _phantom: PhantomData,
         }
     }
@@ -259,6 +367,7 @@ pub struct HashJoinExecutor2Builder {
     right_child: BoxedExecutor2,
     schema: Schema,
     task_id: TaskId,
+    spill_context: SpillContext,
 }
 
 struct HashJoinExecutor2BuilderDispatcher;
@@ -275,6 +384,7 @@ impl HashKeyDispatcher for HashJoinExecutor2BuilderDispatcher {
             input.params,
             input.schema,
             format!("HashJoinExecutor{:?}", input.task_id),
+            input.spill_context,
         ))
     }
 }
@@ -362,6 +472,7 @@ impl BoxedExecutor2Builder for HashJoinExecutor2Builder {
                 fields: schema_fields,
             },
             task_id: context.task_id.clone(),
+            spill_context: context.spill_context(),
         };
 
         Ok(HashJoinExecutor2BuilderDispatcher::dispatch_by_kind(
@@ -391,7 +502,7 @@ mod tests {
     use crate::executor::test_utils::MockExecutor;
     use crate::executor2::join::hash_join::{EquiJoinParams, HashJoinExecutor2};
     use crate::executor2::join::JoinType;
-    use crate::executor2::BoxedExecutor2;
+    use crate::executor2::{BoxedExecutor2, SpillContext, TaskMemoryBudget};
     struct DataChunkMerger {
         data_types: Vec<DataType>,
         array_builders: Vec<ArrayBuilderImpl>,
@@ -598,7 +709,11 @@ mod tests {
             )
         }
 
-        fn create_join_executor(&self, has_non_equi_cond: bool) -> BoxedExecutor2 {
+        fn create_join_executor(
+            &self,
+            has_non_equi_cond: bool,
+            spill_context: SpillContext,
+        ) -> BoxedExecutor2 {
             let join_type = self.join_type;
 
             let left_child = self.create_left_executor();
@@ -652,6 +767,7 @@ mod tests {
                 params,
                 schema,
                 "HashJoinExecutor2".to_string(),
+                spill_context,
             )) as BoxedExecutor2
         }
 
@@ -671,7 +787,8 @@ mod tests {
         }
 
         async fn do_test(&self, expected: DataChunk, has_non_equi_cond: bool) {
-            let join_executor = self.create_join_executor(has_non_equi_cond);
+            let join_executor =
+                self.create_join_executor(has_non_equi_cond, SpillContext::default());
 
             let mut data_chunk_merger = DataChunkMerger::new(self.output_data_types()).unwrap();
 
@@ -705,6 +822,39 @@ mod tests {
             // TODO: Replace this with unsorted comparison
             // assert_eq!(expected, result_chunk);
             assert!(is_data_chunk_eq(&expected, &output_chunk));
+
+            self.do_test_with_spill(expected, has_non_equi_cond).await;
+        }
+
+        /// Runs the join with half of the build side fitting into the memory budget, so that both
+        /// sides are partitioned to disk. The output order differs, so the rows are compared
+        /// sorted.
+        async fn do_test_with_spill(&self, expected: DataChunk, has_non_equi_cond: bool) {
+            let mut build_size = 0;
+            let mut build_side = self.create_right_executor().execute();
+            while let Some(chunk) = build_side.next().await {
+                build_size += chunk.unwrap().estimated_size();
+            }
+            let budget = TaskMemoryBudget::new(build_size / 2);
+            let spill_context = SpillContext::new(Arc::new(budget), None);
+            let join_executor = self.create_join_executor(has_non_equi_cond, spill_context);
+
+            let mut data_chunk_merger = DataChunkMerger::new(self.output_data_types()).unwrap();
+            let mut stream = join_executor.execute();
+            while let Some(data_chunk) = stream.next().await {
+                let data_chunk = data_chunk.unwrap().compact().unwrap();
+                data_chunk_merger.append(&data_chunk).unwrap();
+            }
+            let output_chunk = self.select_from_chunk(data_chunk_merger.finish().unwrap());
+
+            let sorted_rows = |chunk: &DataChunk| {
+                chunk
+                    .rows()
+                    .map(|row| row.to_owned_row())
+                    .sorted()
+                    .collect_vec()
+            };
+            assert_eq!(sorted_rows(&expected), sorted_rows(&output_chunk));
         }
     }
 
@@ -183,7 +183,10 @@ impl<K: HashKey> TryFrom<BuildTable> for ProbeTable<K> {
             build_matched = Some(ChunkedData::<bool>::with_chunk_sizes(
                 build_table.build_data.iter().map(|c| c.cardinality()),
             )?);
-            remaining_build_row_id = Some(RowId::default());
+            // There is no build row to visit in join remaining for an empty build side.
+            if !build_table.build_data.is_empty() {
+                remaining_build_row_id = Some(RowId::default());
+            }
         }
         if build_table.params.join_type().need_probe() && build_table.params.has_non_equi_cond() {
             probe_matched_list = Some(LinkedList::new());
@@ -1034,6 +1037,11 @@ impl<K: HashKey> ProbeTable<K> {
         self.result_offset = 0;
     }
 
+    /// Gives back the join parameters, so that they can be reused for another build side.
+    pub(super) fn into_params(self) -> EquiJoinParams {
+        self.params
+    }
+
     pub(super) fn remove_null_columns_for_semi_anti(&self, data_chunk: DataChunk) -> DataChunk {
         let join_type = self.params.join_type();
         if join_type.keep_all() {
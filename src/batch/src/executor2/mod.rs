@@ -30,6 +30,7 @@ mod order_by;
 mod project;
 mod row_seq_scan;
 mod sort_agg;
+mod spill;
 mod stream_scan;
 mod top_n;
 mod trace;
@@ -55,6 +56,7 @@ use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
 pub use row_seq_scan::*;
 pub use sort_agg::*;
+pub use spill::*;
 pub use stream_scan::*;
 pub use top_n::*;
 pub use trace::*;
@@ -12,5 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 //
+mod operator_stats;
 pub mod stats;
+pub use operator_stats::*;
 pub use stats::*;
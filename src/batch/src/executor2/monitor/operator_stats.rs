@@ -0,0 +1,146 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use risingwave_common::array::DataChunk;
+use risingwave_pb::task_service::OperatorStats as ProstOperatorStats;
+
+/// Runtime statistics of an operator in a task. They are recorded by the trace executors wrapping
+/// every operator, and reported to the frontend for `EXPLAIN ANALYZE`.
+#[derive(Debug, Default)]
+pub struct OperatorStats {
+    rows: AtomicU64,
+    chunks: AtomicU64,
+    elapsed_ns: AtomicU64,
+    /// Memory currently reserved by the operator from the task memory budget.
+    memory_bytes: AtomicU64,
+    /// The largest amount of memory ever reserved by the operator at the same time.
+    peak_memory_bytes: AtomicU64,
+}
+
+impl OperatorStats {
+    /// Records the time spent on producing the next output, and the output chunk if any.
+    pub fn record(&self, elapsed: Duration, chunk: Option<&DataChunk>) {
+        self.elapsed_ns
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+        if let Some(chunk) = chunk {
+            self.rows
+                .fetch_add(chunk.cardinality() as u64, Ordering::Relaxed);
+            self.chunks.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Records memory reserved by the operator from the task memory budget.
+    pub fn grow_memory(&self, bytes: usize) {
+        let used = self.memory_bytes.fetch_add(bytes as u64, Ordering::Relaxed) + bytes as u64;
+        self.peak_memory_bytes.fetch_max(used, Ordering::Relaxed);
+    }
+
+    /// Records memory given back by the operator to the task memory budget.
+    pub fn shrink_memory(&self, bytes: usize) {
+        self.memory_bytes.fetch_sub(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn peak_memory_bytes(&self) -> u64 {
+        self.peak_memory_bytes.load(Ordering::Relaxed)
+    }
+}
+
+/// Statistics of all operators in a task, keyed by the operator id of their plan nodes.
+#[derive(Debug, Default)]
+pub struct TaskOperatorStats {
+    operators: Mutex<BTreeMap<u64, (String, Arc<OperatorStats>)>>,
+}
+
+pub type TaskOperatorStatsRef = Arc<TaskOperatorStats>;
+
+impl TaskOperatorStats {
+    /// Returns the statistics of the given operator, creating them if not registered yet.
+    pub fn get(&self, operator_id: u64) -> Arc<OperatorStats> {
+        self.operators
+            .lock()
+            .entry(operator_id)
+            .or_default()
+            .1
+            .clone()
+    }
+
+    /// Like [`Self::get`], but also records the identity of the executor of the operator if not
+    /// recorded yet.
+    pub fn register(&self, operator_id: u64, identity: &str) -> Arc<OperatorStats> {
+        let mut operators = self.operators.lock();
+        let (registered_identity, stats) = operators.entry(operator_id).or_default();
+        if registered_identity.is_empty() {
+            *registered_identity = identity.to_string();
+        }
+        stats.clone()
+    }
+
+    pub fn to_protobuf(&self) -> Vec<ProstOperatorStats> {
+        self.operators
+            .lock()
+            .iter()
+            .map(|(operator_id, (identity, stats))| ProstOperatorStats {
+                operator_id: *operator_id,
+                identity: identity.clone(),
+                rows: stats.rows.load(Ordering::Relaxed),
+                chunks: stats.chunks.load(Ordering::Relaxed),
+                elapsed_ns: stats.elapsed_ns.load(Ordering::Relaxed),
+                peak_memory_bytes: stats.peak_memory_bytes(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::DataChunkTestExt;
+
+    use super::*;
+
+    #[test]
+    fn test_task_operator_stats() {
+        let task_stats = TaskOperatorStats::default();
+        // The memory of an operator may be tracked before its executor is built.
+        let stats = task_stats.get(1);
+        stats.grow_memory(100);
+        stats.grow_memory(50);
+        stats.shrink_memory(120);
+        stats.grow_memory(60);
+        task_stats.register(1, "FilterExecutor");
+        let chunk = DataChunk::from_pretty(
+            "I
+             1
+             2
+             3",
+        );
+        stats.record(Duration::from_millis(2), Some(&chunk));
+        stats.record(Duration::from_millis(1), Some(&chunk));
+        stats.record(Duration::from_millis(1), None);
+        task_stats.register(1, "HashAggExecutor");
+
+        let prost = task_stats.to_protobuf();
+        assert_eq!(prost.len(), 1);
+        assert_eq!(prost[0].identity, "FilterExecutor");
+        assert_eq!(prost[0].rows, 6);
+        assert_eq!(prost[0].chunks, 2);
+        assert_eq!(prost[0].elapsed_ns, 4_000_000);
+        assert_eq!(prost[0].peak_memory_bytes, 150);
+    }
+}
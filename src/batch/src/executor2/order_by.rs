@@ -31,8 +31,13 @@ use risingwave_common::util::sort_util::{compare_two_row, HeapElem, OrderPair};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 
 use crate::executor::ExecutorBuilder;
-use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+use crate::executor2::{
+    BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2, MemoryReservation,
+    SpillContext, SpillReader,
+};
 
+/// Sorts its input in memory, or with an external merge sort once the input exceeds the task
+/// memory budget: the buffered chunks are sorted into runs on disk, which are merged at the end.
 pub struct OrderByExecutor2 {
     child: Option<BoxedExecutor2>,
     sorted_indices: Vec<Vec<usize>>,
@@ -46,6 +51,13 @@ pub struct OrderByExecutor2 {
     identity: String,
     chunk_size: usize,
     schema: Schema,
+    spill_context: SpillContext,
+    /// Memory of the buffered chunks
+    reservation: MemoryReservation,
+    /// Sorted runs spilled while consuming the input
+    spilled_runs: Vec<SpillReader>,
+    /// Readers of the runs being merged, where `chunks[i]` is the current chunk of run `i`
+    run_readers: Vec<SpillReader>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -62,8 +74,10 @@ impl OrderByExecutor2 {
         disable_encoding: bool,
         identity: String,
         chunk_size: usize,
+        spill_context: SpillContext,
     ) -> Self {
         let schema = child.schema().clone();
+        let reservation = spill_context.reservation();
         Self {
             child: Some(child),
             sorted_indices,
@@ -77,6 +91,10 @@ impl OrderByExecutor2 {
             identity,
             chunk_size,
             schema,
+            spill_context,
+            reservation,
+            spilled_runs: vec![],
+            run_readers: vec![],
         }
     }
 }
@@ -108,6 +126,7 @@ impl BoxedExecutor2Builder for OrderByExecutor2 {
                 false,
                 source.plan_node().get_identity().clone(),
                 DEFAULT_CHUNK_BUFFER_SIZE,
+                source.spill_context(),
             )));
         }
         Err(InternalError("OrderBy must have one child".to_string()).into())
@@ -166,24 +185,142 @@ impl OrderByExecutor2 {
         index
     }
 
+    fn push_chunk(&mut self, chunk: DataChunk) {
+        if !self.disable_encoding && self.encodable {
+            self.encoded_keys
+                .push(encode_chunk(&chunk, self.order_pairs.clone()));
+        }
+        self.chunks.push(chunk);
+        self.sorted_indices
+            .push(self.get_order_index_from(self.chunks.len() - 1));
+        self.vis_indices.push(0);
+    }
+
+    /// Replaces the exhausted chunk of run `idx` with the next, already sorted, chunk of the run.
+    fn replace_chunk(&mut self, idx: usize, chunk: DataChunk) {
+        if !self.disable_encoding && self.encodable {
+            self.encoded_keys[idx] = encode_chunk(&chunk, self.order_pairs.clone());
+        }
+        self.sorted_indices[idx] = (0..chunk.cardinality()).collect();
+        self.vis_indices[idx] = 0;
+        self.chunks[idx] = chunk;
+    }
+
+    /// Pushes the next row of chunk `idx` to the heap, moving on to the next chunk of the run
+    /// when merging spilled runs.
+    async fn push_heap_for_next_row(&mut self, idx: usize) -> Result<()> {
+        if self.vis_indices[idx] == self.chunks[idx].cardinality()
+            && let Some(run) = self.run_readers.get_mut(idx)
+            && let Some(chunk) = run.read_chunk().await?
+        {
+            self.replace_chunk(idx, chunk);
+        }
+        self.push_heap_for_chunk(idx);
+        Ok(())
+    }
+
+    fn clear_chunks(&mut self) {
+        self.chunks.clear();
+        self.sorted_indices.clear();
+        self.encoded_keys.clear();
+        self.vis_indices.clear();
+    }
+
+    /// Sorts the buffered chunks into a run on disk and releases their memory.
+    async fn spill_sorted_run(&mut self) -> Result<()> {
+        for idx in 0..self.chunks.len() {
+            self.push_heap_for_chunk(idx);
+        }
+        let mut writer = self.spill_context.create_file().await?;
+        while let Some(chunk) = self.next_sorted_chunk().await? {
+            writer.write_chunk(chunk).await?;
+        }
+        self.spilled_runs.push(writer.finish().await?);
+        self.clear_chunks();
+        self.reservation.free();
+        Ok(())
+    }
+
     async fn collect_child_data(&mut self) -> Result<()> {
         let mut stream = self.child.take().unwrap().execute();
         while let Some(chunk) = stream.next().await {
             let chunk = chunk?;
-            if !self.disable_encoding && self.encodable {
-                self.encoded_keys
-                    .push(encode_chunk(&chunk, self.order_pairs.clone()));
+            let chunk_size = chunk.estimated_size();
+            if !self.reservation.try_grow(chunk_size)? && !self.chunks.is_empty() {
+                self.spill_sorted_run().await?;
+                // A single chunk is always kept in memory, even if it exceeds the budget.
+                self.reservation.try_grow(chunk_size)?;
+            }
+            self.push_chunk(chunk);
+        }
+
+        if !self.spilled_runs.is_empty() {
+            if !self.chunks.is_empty() {
+                self.spill_sorted_run().await?;
+            }
+            // Merge the runs, starting from the first chunk of each.
+            for mut run in std::mem::take(&mut self.spilled_runs) {
+                if let Some(chunk) = run.read_chunk().await? {
+                    self.push_chunk(chunk);
+                    self.run_readers.push(run);
+                }
             }
-            self.chunks.push(chunk);
-            self.sorted_indices
-                .push(self.get_order_index_from(self.chunks.len() - 1));
         }
-        self.vis_indices = vec![0usize; self.chunks.len()];
         for idx in 0..self.chunks.len() {
             self.push_heap_for_chunk(idx);
         }
         Ok(())
     }
+
+    /// Pops the next sorted chunk from the heap.
+    async fn next_sorted_chunk(&mut self) -> Result<Option<DataChunk>> {
+        let mut array_builders = self.schema().create_array_builders(self.chunk_size)?;
+
+        let mut chunk_size = 0usize;
+        while !self.min_heap.is_empty() && chunk_size < self.chunk_size {
+            let top = self.min_heap.pop().unwrap();
+            for (idx, builder) in array_builders.iter_mut().enumerate() {
+                let chunk_arr = self.chunks[top.chunk_idx].column_at(idx).array();
+                let chunk_arr = chunk_arr.as_ref();
+                macro_rules! gen_match {
+                    ($b: ident, $a: ident, [$( $tt: ident), *]) => {
+                        match ($b, $a) {
+                            $((ArrayBuilderImpl::$tt($b), ArrayImpl::$tt($a)) => Ok($b.append($a.value_at(top.elem_idx))),)*
+                                _ => Err(InternalError(String::from("Unmatched array and array builder types"))),
+                        }?
+                    }
+                }
+                let _ = gen_match!(
+                    builder,
+                    chunk_arr,
+                    [
+                        Int16,
+                        Int32,
+                        Int64,
+                        Float32,
+                        Float64,
+                        Utf8,
+                        Bool,
+                        Decimal,
+                        Interval,
+                        NaiveDate,
+                        NaiveTime,
+                        NaiveDateTime
+                    ]
+                );
+            }
+            chunk_size += 1;
+            self.push_heap_for_next_row(top.chunk_idx).await?;
+        }
+        if chunk_size == 0 {
+            return Ok(None);
+        }
+        let columns = array_builders
+            .into_iter()
+            .map(|b| Ok(Column::new(Arc::new(b.finish()?))))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(DataChunk::builder().columns(columns).build()))
+    }
 }
 
 impl Executor2 for OrderByExecutor2 {
@@ -214,54 +351,8 @@ impl OrderByExecutor2 {
 
         self.collect_child_data().await?;
 
-        loop {
-            let mut array_builders = self.schema().create_array_builders(self.chunk_size)?;
-
-            let mut chunk_size = 0usize;
-            while !self.min_heap.is_empty() && chunk_size < self.chunk_size {
-                let top = self.min_heap.pop().unwrap();
-                for (idx, builder) in array_builders.iter_mut().enumerate() {
-                    let chunk_arr = self.chunks[top.chunk_idx].column_at(idx).array();
-                    let chunk_arr = chunk_arr.as_ref();
-                    macro_rules! gen_match {
-                        ($b: ident, $a: ident, [$( $tt: ident), *]) => {
-                            match ($b, $a) {
-                                $((ArrayBuilderImpl::$tt($b), ArrayImpl::$tt($a)) => Ok($b.append($a.value_at(top.elem_idx))),)*
-                                    _ => Err(InternalError(String::from("Unmatched array and array builder types"))),
-                            }?
-                        }
-                    }
-                    let _ = gen_match!(
-                        builder,
-                        chunk_arr,
-                        [
-                            Int16,
-                            Int32,
-                            Int64,
-                            Float32,
-                            Float64,
-                            Utf8,
-                            Bool,
-                            Decimal,
-                            Interval,
-                            NaiveDate,
-                            NaiveTime,
-                            NaiveDateTime
-                        ]
-                    );
-                }
-                chunk_size += 1;
-                self.push_heap_for_chunk(top.chunk_idx);
-            }
-            if chunk_size == 0 {
-                break;
-            }
-            let columns = array_builders
-                .into_iter()
-                .map(|b| Ok(Column::new(Arc::new(b.finish()?))))
-                .collect::<Result<Vec<_>>>()?;
-            let chunk = DataChunk::builder().columns(columns).build();
-            yield chunk
+        while let Some(chunk) = self.next_sorted_chunk().await? {
+            yield chunk;
         }
     }
 }
@@ -279,6 +370,7 @@ mod tests {
 
     use super::*;
     use crate::executor::test_utils::MockExecutor;
+    use crate::executor2::TaskMemoryBudget;
 
     fn create_column_i32(vec: &[Option<i32>]) -> Result<Column> {
         let array = PrimitiveArray::from_slice(vec).map(|x| Arc::new(x.into()))?;
@@ -352,6 +444,7 @@ mod tests {
             false,
             "OrderByExecutor2".to_string(),
             DEFAULT_CHUNK_BUFFER_SIZE,
+            SpillContext::default(),
         ));
         let fields = &order_by_executor.schema().fields;
         assert_eq!(fields[0].data_type, DataType::Int32);
@@ -406,6 +499,7 @@ mod tests {
             false,
             "OrderByExecutor2".to_string(),
             DEFAULT_CHUNK_BUFFER_SIZE,
+            SpillContext::default(),
         ));
         let fields = &order_by_executor.schema().fields;
         assert_eq!(fields[0].data_type, DataType::Float32);
@@ -470,6 +564,7 @@ mod tests {
             false,
             "OrderByExecutor2".to_string(),
             DEFAULT_CHUNK_BUFFER_SIZE,
+            SpillContext::default(),
         ));
         let fields = &order_by_executor.schema().fields;
         assert_eq!(fields[0].data_type, DataType::Varchar);
@@ -487,6 +582,53 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_order_by_with_spill() {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int32)],
+        };
+        let mut mock_executor = MockExecutor::new(schema);
+        let values = [
+            vec![Some(5), Some(1), Some(9)],
+            vec![Some(3), Some(7), Some(2)],
+            vec![Some(8), Some(4), Some(6), Some(0)],
+        ];
+        for values in &values {
+            let column = create_column_i32(values).unwrap();
+            mock_executor.add(DataChunk::builder().columns(vec![column]).build());
+        }
+        let order_pairs = vec![OrderPair {
+            column_idx: 0,
+            order_type: OrderType::Descending,
+        }];
+
+        // With a zero budget every input chunk becomes a sorted run on disk. The small output
+        // chunk size makes the runs span multiple chunks.
+        let order_by_executor = Box::new(OrderByExecutor2::new(
+            Box::new(mock_executor),
+            vec![],
+            vec![],
+            vec![],
+            BinaryHeap::new(),
+            Arc::new(order_pairs),
+            vec![],
+            false,
+            false,
+            "OrderByExecutor2".to_string(),
+            2,
+            SpillContext::new(Arc::new(TaskMemoryBudget::new(0)), None),
+        ));
+
+        let mut stream = order_by_executor.execute();
+        let mut actual = vec![];
+        while let Some(chunk) = stream.next().await {
+            actual.extend(chunk.unwrap().column_at(0).array().as_int32().iter());
+        }
+        let mut expected = values.concat();
+        expected.sort_by(|a, b| b.cmp(a));
+        assert_eq!(actual, expected);
+    }
+
     // TODO: enable benches
 
     // fn benchmark_1e4(b: &mut Bencher, enable_encoding: bool) {
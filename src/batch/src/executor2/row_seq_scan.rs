@@ -15,9 +15,11 @@ use futures_async_stream::try_stream;
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use itertools::Itertools;
-use risingwave_common::array::DataChunk;
-use risingwave_common::catalog::{ColumnDesc, Schema, TableId};
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::catalog::{ColumnDesc, OrderedColumnDesc, Schema, TableId};
 use risingwave_common::error::{Result, RwError};
+use risingwave_common::util::ordered::{serialize_pk, OrderedRowSerializer};
+use risingwave_common::util::value_encoding::deserialize_cell;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_storage::table::cell_based_table::CellBasedTable;
 use risingwave_storage::{dispatch_state_store, Keyspace, StateStore, StateStoreImpl};
@@ -81,16 +83,34 @@ impl BoxedExecutor2Builder for RowSeqScanExecutor2Builder {
             NodeBody::RowSeqScan
         )?;
 
+        let table_desc = seq_scan_node.table_desc.as_ref().unwrap();
         let table_id = TableId {
-            table_id: seq_scan_node.table_desc.as_ref().unwrap().table_id,
+            table_id: table_desc.table_id,
         };
         let column_descs = seq_scan_node
             .column_descs
             .iter()
             .map(|column_desc| ColumnDesc::from(column_desc.clone()))
             .collect_vec();
+
+        // Keys of the table are the memcomparable-encoded primary key followed by the column id,
+        // so restricting the keyspace to the encoded prefix only scans the matching rows.
+        let pk_prefix_descs = table_desc.pk[..seq_scan_node.pk_prefix.len()]
+            .iter()
+            .map(|desc| OrderedColumnDesc::from(desc.clone()))
+            .collect_vec();
+        let pk_prefix = seq_scan_node
+            .pk_prefix
+            .iter()
+            .zip_eq(&pk_prefix_descs)
+            .map(|(value, desc)| deserialize_cell(&value[..], &desc.column_desc.data_type))
+            .collect::<Result<Vec<_>>>()?;
+        let pk_prefix_serializer =
+            OrderedRowSerializer::new(pk_prefix_descs.iter().map(|desc| desc.order).collect());
+        let pk_prefix = serialize_pk(&Row(pk_prefix), &pk_prefix_serializer)?;
+
         dispatch_state_store!(source.global_batch_env().state_store(), state_store, {
-            let keyspace = Keyspace::table_root(state_store.clone(), &table_id);
+            let keyspace = Keyspace::table_root(state_store.clone(), &table_id).append(pk_prefix);
             let storage_stats = state_store.stats();
             let batch_stats = source.global_batch_env().stats();
             let table = CellBasedTable::new_adhoc(keyspace, column_descs, storage_stats);
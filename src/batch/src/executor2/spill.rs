@@ -0,0 +1,463 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Spilling of batch executor state to local temp files.
+//!
+//! Every batch task has a [`TaskMemoryBudget`] shared by all of its executors. Memory-hungry
+//! executors track what they buffer with a [`MemoryReservation`], and once the budget is exhausted
+//! they hash partition their inputs with [`SpillPartitions`] and process the partitions one by one.
+//! The budgets of the tasks of a query may further share a [`QueryMemoryQuota`], which fails the
+//! query instead of spilling once exceeded.
+
+use std::io::{ErrorKind, SeekFrom};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures::future::try_join_all;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use prost::Message;
+use risingwave_common::array::DataChunk;
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::error::ErrorCode::{
+    InternalError, MemoryBudgetExceeded, MemoryQuotaExceeded,
+};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::util::hash_util::CRC32FastBuilder;
+use risingwave_pb::data::DataChunk as ProstDataChunk;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, BufWriter};
+
+use crate::executor2::monitor::OperatorStats;
+use crate::executor2::BoxedDataChunkStream;
+
+/// Number of hash bits consumed by each level of partitioning.
+const SPILL_PARTITION_BITS: usize = 4;
+
+/// Fan-out of each level of partitioning.
+pub const SPILL_PARTITION_NUM: usize = 1 << SPILL_PARTITION_BITS;
+
+/// Partitions that still exceed the budget are partitioned again with the next bits of the hash
+/// code. A partition still exceeding the budget at this level fails the query, as its keys are
+/// unlikely to be told apart any further.
+pub const MAX_SPILL_LEVEL: usize = 3;
+
+/// Memory quota shared by the tasks of one query on a compute node. Unlike the task budget, it is
+/// a hard limit: the query fails once it is exceeded.
+#[derive(Debug)]
+pub struct QueryMemoryQuota {
+    limit: usize,
+    used: AtomicUsize,
+}
+
+pub type QueryMemoryQuotaRef = Arc<QueryMemoryQuota>;
+
+impl QueryMemoryQuota {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    fn reserve(&self, bytes: usize) -> Result<()> {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes).filter(|used| *used <= self.limit)
+            })
+            .map_err(|_| MemoryQuotaExceeded(self.limit))?;
+        Ok(())
+    }
+
+    fn release(&self, bytes: usize) {
+        self.used.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+/// Memory budget shared by the executors of one batch task.
+#[derive(Debug)]
+pub struct TaskMemoryBudget {
+    limit: usize,
+    used: AtomicUsize,
+    /// The largest amount of memory ever reserved at the same time.
+    peak: AtomicUsize,
+    /// Quota shared with the other tasks of the same query.
+    query_quota: Option<QueryMemoryQuotaRef>,
+}
+
+pub type TaskMemoryBudgetRef = Arc<TaskMemoryBudget>;
+
+impl TaskMemoryBudget {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            used: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            query_quota: None,
+        }
+    }
+
+    pub fn with_query_quota(limit: usize, query_quota: QueryMemoryQuotaRef) -> Self {
+        Self {
+            query_quota: Some(query_quota),
+            ..Self::new(limit)
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Returns the high-water mark of the memory reserved from the budget.
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    /// Returns false if the task budget would be exceeded, and an error if the query quota would.
+    fn try_reserve(&self, bytes: usize) -> Result<bool> {
+        let previous = match self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes).filter(|used| *used <= self.limit)
+            }) {
+            Ok(previous) => previous,
+            Err(_) => return Ok(false),
+        };
+        if let Some(query_quota) = &self.query_quota && let Err(e) = query_quota.reserve(bytes) {
+            self.used.fetch_sub(bytes, Ordering::Relaxed);
+            return Err(e);
+        }
+        self.peak.fetch_max(previous + bytes, Ordering::Relaxed);
+        Ok(true)
+    }
+
+    fn release(&self, bytes: usize) {
+        self.used.fetch_sub(bytes, Ordering::Relaxed);
+        if let Some(query_quota) = &self.query_quota {
+            query_quota.release(bytes);
+        }
+    }
+}
+
+impl Default for TaskMemoryBudget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+/// Memory reserved by one executor from the task budget. It is given back on drop.
+pub struct MemoryReservation {
+    budget: TaskMemoryBudgetRef,
+    /// Statistics of the operator of the executor, where the reserved memory is also recorded.
+    stats: Option<Arc<OperatorStats>>,
+    size: usize,
+}
+
+impl MemoryReservation {
+    /// Reserves `bytes` more, returning false without reserving anything if the budget would be
+    /// exceeded. Fails if the memory quota of the query would be exceeded.
+    pub fn try_grow(&mut self, bytes: usize) -> Result<bool> {
+        let reserved = self.budget.try_reserve(bytes)?;
+        if reserved {
+            self.size += bytes;
+            if let Some(stats) = &self.stats {
+                stats.grow_memory(bytes);
+            }
+        }
+        Ok(reserved)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn free(&mut self) {
+        self.budget.release(self.size);
+        if let Some(stats) = &self.stats {
+            stats.shrink_memory(self.size);
+        }
+        self.size = 0;
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+/// Everything an executor needs to spill: the task memory budget and where to put spill files.
+#[derive(Clone, Default)]
+pub struct SpillContext {
+    budget: TaskMemoryBudgetRef,
+    /// Directory of the spill files. The system temp directory is used if `None`.
+    directory: Option<PathBuf>,
+    /// Statistics of the operator of the executor, for `EXPLAIN ANALYZE`.
+    stats: Option<Arc<OperatorStats>>,
+}
+
+impl SpillContext {
+    pub fn new(budget: TaskMemoryBudgetRef, directory: Option<PathBuf>) -> Self {
+        Self {
+            budget,
+            directory,
+            stats: None,
+        }
+    }
+
+    /// Records the memory reserved by the executor in the statistics of its operator.
+    #[must_use]
+    pub fn with_operator_stats(self, stats: Arc<OperatorStats>) -> Self {
+        Self {
+            stats: Some(stats),
+            ..self
+        }
+    }
+
+    pub fn reservation(&self) -> MemoryReservation {
+        MemoryReservation {
+            budget: self.budget.clone(),
+            stats: self.stats.clone(),
+            size: 0,
+        }
+    }
+
+    /// Creates an anonymous temp file, which is removed by the OS once closed.
+    pub async fn create_file(&self) -> Result<SpillWriter> {
+        let directory = self.directory.clone();
+        let file = tokio::task::spawn_blocking(move || match directory {
+            Some(directory) => tempfile::tempfile_in(directory),
+            None => tempfile::tempfile(),
+        })
+        .await
+        .map_err(|e| InternalError(format!("failed to create spill file: {}", e)))??;
+        Ok(SpillWriter {
+            writer: BufWriter::new(File::from_std(file)),
+        })
+    }
+
+    /// Creates [`SPILL_PARTITION_NUM`] spill files partitioned at the given `level`. Fails if
+    /// `level` has reached [`MAX_SPILL_LEVEL`].
+    pub async fn create_partitions(&self, level: usize) -> Result<SpillPartitions> {
+        if level >= MAX_SPILL_LEVEL {
+            return Err(MemoryBudgetExceeded(self.budget.limit()).into());
+        }
+        debug!(
+            "spilling at level {}, task memory used: {} of {} bytes",
+            level,
+            self.budget.used(),
+            self.budget.limit()
+        );
+        let writers = try_join_all((0..SPILL_PARTITION_NUM).map(|_| self.create_file())).await?;
+        Ok(SpillPartitions { level, writers })
+    }
+}
+
+/// Appends data chunks to a spill file.
+pub struct SpillWriter {
+    writer: BufWriter<File>,
+}
+
+impl SpillWriter {
+    pub async fn write_chunk(&mut self, chunk: DataChunk) -> Result<()> {
+        if chunk.cardinality() == 0 {
+            return Ok(());
+        }
+        let bytes = chunk.compact()?.to_protobuf().encode_to_vec();
+        self.writer.write_u32_le(bytes.len() as u32).await?;
+        self.writer.write_all(&bytes).await?;
+        Ok(())
+    }
+
+    /// Flushes the file and rewinds it for reading.
+    pub async fn finish(mut self) -> Result<SpillReader> {
+        self.writer.flush().await?;
+        let mut file = self.writer.into_inner();
+        file.seek(SeekFrom::Start(0)).await?;
+        Ok(SpillReader {
+            reader: BufReader::new(file),
+        })
+    }
+}
+
+/// Reads back the data chunks of a spill file in the order they were written.
+pub struct SpillReader {
+    reader: BufReader<File>,
+}
+
+impl SpillReader {
+    pub async fn read_chunk(&mut self) -> Result<Option<DataChunk>> {
+        let len = match self.reader.read_u32_le().await {
+            Ok(len) => len as usize,
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut bytes = vec![0; len];
+        self.reader.read_exact(&mut bytes).await?;
+        let proto = ProstDataChunk::decode(bytes.as_slice())
+            .map_err(|e| InternalError(format!("failed to decode spilled chunk: {}", e)))?;
+        DataChunk::from_protobuf(&proto).map(Some)
+    }
+
+    pub fn into_stream(self) -> BoxedDataChunkStream {
+        self.do_into_stream()
+    }
+
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_into_stream(mut self) {
+        while let Some(chunk) = self.read_chunk().await? {
+            yield chunk;
+        }
+    }
+}
+
+/// Hash partitions rows into [`SPILL_PARTITION_NUM`] spill files.
+pub struct SpillPartitions {
+    level: usize,
+    writers: Vec<SpillWriter>,
+}
+
+impl SpillPartitions {
+    /// Returns the partition of a hash code at the given level.
+    pub fn partition_of(hash_code: u64, level: usize) -> usize {
+        (hash_code >> (level * SPILL_PARTITION_BITS)) as usize & (SPILL_PARTITION_NUM - 1)
+    }
+
+    /// Writes the rows of `chunk` to the partitions of their `key_columns`. If `filter` is given,
+    /// only the rows selected by it are written.
+    pub async fn write_chunk(
+        &mut self,
+        chunk: DataChunk,
+        key_columns: &[usize],
+        filter: Option<&[bool]>,
+    ) -> Result<()> {
+        let chunk = chunk.compact()?;
+        let hash_codes = chunk.get_hash_values(key_columns, CRC32FastBuilder)?;
+        let mut visibilities = vec![vec![false; chunk.cardinality()]; SPILL_PARTITION_NUM];
+        for (row_id, hash_code) in hash_codes.into_iter().enumerate() {
+            if filter.map_or(true, |filter| filter[row_id]) {
+                visibilities[Self::partition_of(hash_code.0, self.level)][row_id] = true;
+            }
+        }
+        for (writer, visibility) in self.writers.iter_mut().zip_eq(visibilities) {
+            if visibility.iter().any(|visible| *visible) {
+                writer
+                    .write_chunk(chunk.with_visibility(Bitmap::try_from(visibility)?))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn finish(self) -> Result<Vec<SpillReader>> {
+        try_join_all(self.writers.into_iter().map(SpillWriter::finish)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::{Array, I32Array};
+
+    use super::*;
+
+    #[test]
+    fn test_memory_reservation() {
+        let budget = Arc::new(TaskMemoryBudget::new(100));
+        let stats = Arc::new(OperatorStats::default());
+        let context = SpillContext::new(budget.clone(), None).with_operator_stats(stats.clone());
+        let mut r1 = context.reservation();
+        let mut r2 = context.reservation();
+        assert!(r1.try_grow(60).unwrap());
+        assert!(!r2.try_grow(60).unwrap());
+        assert!(r2.try_grow(40).unwrap());
+        assert_eq!(budget.used(), 100);
+        r1.free();
+        assert_eq!(budget.used(), 40);
+        drop(r2);
+        assert_eq!(budget.used(), 0);
+        assert_eq!(budget.peak(), 100);
+        assert_eq!(stats.peak_memory_bytes(), 100);
+    }
+
+    #[test]
+    fn test_query_memory_quota() {
+        let quota = Arc::new(QueryMemoryQuota::new(100));
+        let budget1 = Arc::new(TaskMemoryBudget::with_query_quota(80, quota.clone()));
+        let budget2 = Arc::new(TaskMemoryBudget::with_query_quota(80, quota.clone()));
+        let mut r1 = SpillContext::new(budget1, None).reservation();
+        let mut r2 = SpillContext::new(budget2.clone(), None).reservation();
+
+        assert!(r1.try_grow(60).unwrap());
+        // The task budget is exhausted first, so the executor spills.
+        assert!(!r2.try_grow(90).unwrap());
+        // The query quota is exhausted first, so the query fails.
+        assert!(r2.try_grow(50).is_err());
+        assert_eq!(budget2.used(), 0);
+        assert_eq!(quota.used(), 60);
+
+        assert!(r2.try_grow(40).unwrap());
+        assert_eq!(quota.used(), 100);
+        drop(r1);
+        assert_eq!(quota.used(), 40);
+    }
+
+    #[tokio::test]
+    async fn test_spill_partitions() {
+        let values = (0..1000).map(Some).collect_vec();
+        let array = I32Array::from_slice(&values).unwrap();
+        let chunk = DataChunk::new(vec![Column::new(Arc::new(array.into()))], None);
+
+        let context = SpillContext::default();
+        let mut partitions = context.create_partitions(0).await.unwrap();
+        partitions
+            .write_chunk(chunk.clone(), &[0], None)
+            .await
+            .unwrap();
+        partitions.write_chunk(chunk, &[0], None).await.unwrap();
+
+        let mut total = 0;
+        for reader in partitions.finish().await.unwrap() {
+            let mut stream = reader.into_stream();
+            let mut seen = vec![];
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk.unwrap();
+                let hash_codes = chunk.get_hash_values(&[0], CRC32FastBuilder).unwrap();
+                let partition = SpillPartitions::partition_of(hash_codes[0].0, 0);
+                assert!(hash_codes
+                    .iter()
+                    .all(|h| SpillPartitions::partition_of(h.0, 0) == partition));
+                seen.extend(chunk.column_at(0).array_ref().as_int32().iter().flatten());
+            }
+            total += seen.len();
+        }
+        assert_eq!(total, 2000);
+
+        assert!(context.create_partitions(MAX_SPILL_LEVEL).await.is_err());
+    }
+}
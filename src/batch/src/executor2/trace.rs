@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+use std::time::Instant;
+
 use futures::stream::StreamExt;
 use futures_async_stream::try_stream;
 use risingwave_common::array::DataChunk;
@@ -20,19 +23,26 @@ use risingwave_common::error::RwError;
 use tracing::event;
 use tracing_futures::Instrument;
 
+use crate::executor2::monitor::OperatorStats;
 use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, Executor2};
 
 /// If tracing is enabled, we build a [`TraceExecutor2`] on top of the underlying executor.
 /// So the duration of performance-critical operations will be traced, such as open/next/close.
+/// The runtime statistics of the underlying executor are also recorded here.
 pub struct TraceExecutor2 {
     child: BoxedExecutor2,
     /// Description of input executor
     input_desc: String,
+    stats: Arc<OperatorStats>,
 }
 
 impl TraceExecutor2 {
-    pub fn new(child: BoxedExecutor2, input_desc: String) -> Self {
-        Self { child, input_desc }
+    pub fn new(child: BoxedExecutor2, input_desc: String, stats: Arc<OperatorStats>) -> Self {
+        Self {
+            child,
+            input_desc,
+            stats,
+        }
     }
 }
 
@@ -56,16 +66,22 @@ impl TraceExecutor2 {
         let input_desc = self.input_desc.as_str();
         let span_name = format!("{input_desc}_next");
         let mut child_stream = self.child.execute();
-        while let Some(chunk) = child_stream
-            .next()
-            .instrument(tracing::trace_span!(
-                "next",
-                otel.name = span_name.as_str(),
-                next = input_desc,
-            ))
-            .await
-        {
-            let chunk = chunk?;
+        loop {
+            let start_time = Instant::now();
+            let chunk = child_stream
+                .next()
+                .instrument(tracing::trace_span!(
+                    "next",
+                    otel.name = span_name.as_str(),
+                    next = input_desc,
+                ))
+                .await
+                .transpose()?;
+            self.stats.record(start_time.elapsed(), chunk.as_ref());
+            let chunk = match chunk {
+                Some(chunk) => chunk,
+                None => break,
+            };
             event!(tracing::Level::TRACE, prev = %input_desc, msg = "chunk", "input = \n{:#?}", 
                 chunk);
             yield chunk;
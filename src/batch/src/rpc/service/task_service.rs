@@ -49,6 +49,7 @@ impl TaskService for BatchServiceImpl {
             req.get_task_id().expect("no task id found"),
             req.get_plan().expect("no plan found").clone(),
             req.epoch,
+            req.limits.clone().unwrap_or_default(),
         );
         match res {
             Ok(_) => Ok(Response::new(CreateTaskResponse { status: None })),
@@ -62,9 +63,17 @@ impl TaskService for BatchServiceImpl {
     #[cfg_attr(coverage, no_coverage)]
     async fn get_task_info(
         &self,
-        _: Request<GetTaskInfoRequest>,
+        request: Request<GetTaskInfoRequest>,
     ) -> Result<Response<GetTaskInfoResponse>, Status> {
-        todo!()
+        let req = request.into_inner();
+        let task_info = self
+            .mgr
+            .get_task_info(req.get_task_id().expect("no task id found"))
+            .map_err(|e| e.to_grpc_status())?;
+        Ok(Response::new(GetTaskInfoResponse {
+            status: None,
+            task_info: Some(task_info),
+        }))
     }
 
     #[cfg_attr(coverage, no_coverage)]
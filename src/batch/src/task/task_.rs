@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
 use parking_lot::Mutex;
 use risingwave_common::array::DataChunk;
@@ -22,13 +24,15 @@ use risingwave_pb::batch_plan::{
     PlanFragment, TaskId as ProstTaskId, TaskOutputId as ProstOutputId,
 };
 use risingwave_pb::task_service::task_info::TaskStatus;
-use risingwave_pb::task_service::GetDataResponse;
+use risingwave_pb::task_service::{GetDataResponse, TaskInfo};
 use tracing_futures::Instrument;
 
 use crate::executor::{BoxedExecutor, ExecutorBuilder};
+use crate::executor2::monitor::TaskOperatorStatsRef;
+use crate::executor2::{QueryMemoryQuotaRef, TaskMemoryBudget, TaskMemoryBudgetRef};
 use crate::rpc::service::exchange::ExchangeWriter;
 use crate::task::channel::{create_output_channel, ChanReceiverImpl, ChanSenderImpl};
-use crate::task::{BatchEnvironment, BatchManager};
+use crate::task::{BatchEnvironment, BatchManager, TaskAdmission};
 
 #[derive(PartialEq, Eq, Hash, Clone, Debug, Default)]
 pub struct TaskId {
@@ -181,6 +185,20 @@ pub struct BatchTaskExecution {
     /// The execution failure.
     failure: Arc<Mutex<Option<RwError>>>,
 
+    /// Whether the execution has ended, successfully or not. This is tracked apart from `state`,
+    /// which stays `Running` so that the outputs can still be taken after the execution ends.
+    finished: Arc<AtomicBool>,
+
+    /// Runtime statistics of the operators.
+    operator_stats: TaskOperatorStatsRef,
+
+    /// Memory budget shared by the executors of the task.
+    memory_budget: TaskMemoryBudgetRef,
+
+    /// The task fails once the deadline of its query passes, including the time queued for
+    /// admission.
+    deadline: Option<Instant>,
+
     epoch: u64,
 }
 
@@ -190,7 +208,16 @@ impl BatchTaskExecution {
         plan: PlanFragment,
         env: BatchEnvironment,
         epoch: u64,
+        deadline: Option<Instant>,
+        query_quota: Option<QueryMemoryQuotaRef>,
     ) -> Result<Self> {
+        let task_memory_budget = env.config().task_memory_budget;
+        let memory_budget = Arc::new(match query_quota {
+            Some(query_quota) => {
+                TaskMemoryBudget::with_query_quota(task_memory_budget, query_quota)
+            }
+            None => TaskMemoryBudget::new(task_memory_budget),
+        });
         Ok(BatchTaskExecution {
             task_id: TaskId::from(prost_tid),
             plan,
@@ -198,6 +225,10 @@ impl BatchTaskExecution {
             receivers: Mutex::new(Vec::new()),
             env,
             failure: Arc::new(Mutex::new(None)),
+            finished: Arc::new(AtomicBool::new(false)),
+            operator_stats: Default::default(),
+            memory_budget,
+            deadline,
             epoch,
         })
     }
@@ -212,7 +243,10 @@ impl BatchTaskExecution {
     /// hash partitioned across multiple channels.
     /// To obtain the result, one must pick one of the channels to consume via [`TaskOutputId`]. As
     /// such, parallel consumers are able to consume the result idependently.
-    pub fn async_execute(&self) -> Result<()> {
+    ///
+    /// If `admission` is given, the execution waits for a slot before starting, and holds the slot
+    /// until it ends.
+    pub fn async_execute(&self, admission: Option<TaskAdmission>) -> Result<()> {
         trace!(
             "Prepare executing plan [{:?}]: {}",
             self.task_id,
@@ -224,6 +258,8 @@ impl BatchTaskExecution {
             &self.task_id.clone(),
             self.env.clone(),
             self.epoch,
+            self.operator_stats.clone(),
+            self.memory_budget.clone(),
         )
         .build()?;
 
@@ -232,7 +268,9 @@ impl BatchTaskExecution {
             .lock()
             .extend(receivers.into_iter().map(Some));
         let failure = self.failure.clone();
+        let finished = self.finished.clone();
         let task_id = self.task_id.clone();
+        let deadline = self.deadline;
         tokio::spawn(async move {
             trace!("Executing plan [{:?}]", task_id);
             let mut sender = sender;
@@ -242,19 +280,25 @@ impl BatchTaskExecution {
             let join_handle = tokio::spawn(async move {
                 // We should only pass a reference of sender to execution because we should only
                 // close it after task error has been set.
-                if let Err(e) = BatchTaskExecution::try_execute(exec, &mut sender)
+                let execution = BatchTaskExecution::try_execute(exec, &mut sender, admission)
                     .instrument(tracing::trace_span!(
                         "batch_execute",
                         task_id = ?task_id.task_id,
                         stage_id = ?task_id.stage_id,
                         query_id = ?task_id.query_id,
-                    ))
-                    .await
-                {
+                    ));
+                let result = match deadline {
+                    Some(deadline) => tokio::time::timeout_at(deadline.into(), execution)
+                        .await
+                        .unwrap_or_else(|_| Err(ErrorCode::StatementTimeout.into())),
+                    None => execution.await,
+                };
+                if let Err(e) = result {
                     // Prints the entire backtrace of error.
                     error!("Execution failed [{:?}]: {:?}", &task_id, &e);
                     *failure.lock() = Some(e);
                 }
+                finished.store(true, Ordering::Release);
             });
 
             if let Err(join_error) = join_handle.await && join_error.is_panic() {
@@ -264,7 +308,16 @@ impl BatchTaskExecution {
         Ok(())
     }
 
-    async fn try_execute(mut root: BoxedExecutor, sender: &mut ChanSenderImpl) -> Result<()> {
+    async fn try_execute(
+        mut root: BoxedExecutor,
+        sender: &mut ChanSenderImpl,
+        admission: Option<TaskAdmission>,
+    ) -> Result<()> {
+        // The slot is released when the execution ends.
+        let _slot = match &admission {
+            Some(admission) => Some(admission.acquire().await?),
+            None => None,
+        };
         root.open().await?;
         while let Some(chunk) = root.next().await? {
             if chunk.cardinality() > 0 {
@@ -299,6 +352,23 @@ impl BatchTaskExecution {
         self.failure.lock().clone()
     }
 
+    /// Returns the status of the task, together with the runtime statistics of its operators.
+    pub fn get_task_info(&self) -> TaskInfo {
+        let task_status = if self.failure.lock().is_some() {
+            TaskStatus::Failed
+        } else if self.finished.load(Ordering::Acquire) {
+            TaskStatus::Finished
+        } else {
+            *self.state.lock()
+        };
+        TaskInfo {
+            task_id: Some(self.task_id.to_prost()),
+            task_status: task_status as i32,
+            operator_stats: self.operator_stats.to_protobuf(),
+            peak_memory_bytes: self.memory_budget.peak() as u64,
+        }
+    }
+
     pub fn check_if_running(&self) -> Result<()> {
         if *self.state.lock() != TaskStatus::Running {
             return Err(ErrorCode::InternalError(format!(
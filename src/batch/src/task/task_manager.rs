@@ -13,7 +13,8 @@
 // limitations under the License.
 
 use std::collections::{hash_map, HashMap};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
 use risingwave_common::error::ErrorCode::{self, TaskNotFound};
@@ -21,7 +22,10 @@ use risingwave_common::error::{Result, RwError};
 use risingwave_pb::batch_plan::{
     PlanFragment, TaskId as ProstTaskId, TaskOutputId as ProstOutputId,
 };
+use risingwave_pb::task_service::{TaskInfo, TaskLimits};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
+use crate::executor2::{QueryMemoryQuota, QueryMemoryQuotaRef};
 use crate::task::env::BatchEnvironment;
 use crate::task::{BatchTaskExecution, TaskId, TaskOutput};
 
@@ -30,12 +34,32 @@ use crate::task::{BatchTaskExecution, TaskId, TaskOutput};
 pub struct BatchManager {
     /// Every task id has a corresponding task execution.
     tasks: Arc<Mutex<HashMap<TaskId, Box<BatchTaskExecution>>>>,
+
+    /// Caps the number of tasks executing at the same time. `None` means no cap.
+    admission: Option<TaskAdmission>,
+
+    /// Memory quotas of the queries with tasks on this compute node.
+    query_quotas: Arc<Mutex<HashMap<String, Weak<QueryMemoryQuota>>>>,
 }
 
 impl BatchManager {
     pub fn new() -> Self {
         BatchManager {
             tasks: Arc::new(Mutex::new(HashMap::new())),
+            admission: None,
+            query_quotas: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Creates a manager executing at most `max_concurrent_tasks` tasks at the same time, where
+    /// a task fails once queued for longer than `queue_timeout`. 0 means no limit.
+    pub fn with_max_concurrent_tasks(max_concurrent_tasks: usize, queue_timeout: Duration) -> Self {
+        BatchManager {
+            admission: (max_concurrent_tasks > 0).then(|| TaskAdmission {
+                slots: Arc::new(Semaphore::new(max_concurrent_tasks)),
+                queue_timeout,
+            }),
+            ..Self::new()
         }
     }
 
@@ -45,12 +69,19 @@ impl BatchManager {
         tid: &ProstTaskId,
         plan: PlanFragment,
         epoch: u64,
+        limits: TaskLimits,
     ) -> Result<()> {
         trace!("Received task id: {:?}, plan: {:?}", tid, plan);
-        let task = BatchTaskExecution::new(tid, plan, env, epoch)?;
+        // The timeout is measured from the arrival of the task, so that the deadline does not
+        // depend on the clock of the frontend.
+        let deadline = (limits.timeout_ms > 0)
+            .then(|| Instant::now() + Duration::from_millis(limits.timeout_ms));
+        let query_quota = (limits.memory_quota > 0)
+            .then(|| self.query_memory_quota(&tid.query_id, limits.memory_quota as usize));
+        let task = BatchTaskExecution::new(tid, plan, env, epoch, deadline, query_quota)?;
         let task_id = task.get_task_id().clone();
 
-        task.async_execute()?;
+        task.async_execute(self.admission.clone())?;
         if let hash_map::Entry::Vacant(e) = self.tasks.lock().entry(task_id.clone()) {
             e.insert(Box::new(task));
             Ok(())
@@ -63,6 +94,13 @@ impl BatchManager {
         }
     }
 
+    /// Returns the memory quota shared by the tasks of the query on this compute node.
+    fn query_memory_quota(&self, query_id: &str, limit: usize) -> QueryMemoryQuotaRef {
+        get_or_insert_shared(&self.query_quotas, query_id, || {
+            QueryMemoryQuota::new(limit)
+        })
+    }
+
     pub fn take_output(&self, output_id: &ProstOutputId) -> Result<TaskOutput> {
         let task_id = TaskId::from(output_id.get_task_id()?);
         debug!("Trying to take output of: {:?}", output_id);
@@ -90,6 +128,15 @@ impl BatchManager {
         }
     }
 
+    pub fn get_task_info(&self, task_id: &ProstTaskId) -> Result<TaskInfo> {
+        Ok(self
+            .tasks
+            .lock()
+            .get(&TaskId::from(task_id))
+            .ok_or(TaskNotFound)?
+            .get_task_info())
+    }
+
     pub fn get_error(&self, task_id: &TaskId) -> Result<Option<RwError>> {
         Ok(self
             .tasks
@@ -100,6 +147,47 @@ impl BatchManager {
     }
 }
 
+/// Returns the value of `query_id` in `values`, or inserts a new one created by `f`. The values
+/// are dropped with the last tasks of their queries.
+fn get_or_insert_shared<T>(
+    values: &Mutex<HashMap<String, Weak<T>>>,
+    query_id: &str,
+    f: impl FnOnce() -> T,
+) -> Arc<T> {
+    let mut values = values.lock();
+    if let Some(value) = values.get(query_id).and_then(Weak::upgrade) {
+        return value;
+    }
+    values.retain(|_, value| value.strong_count() > 0);
+    let value = Arc::new(f());
+    values.insert(query_id.to_string(), Arc::downgrade(&value));
+    value
+}
+
+/// Caps the number of batch tasks executing at the same time on a compute node. Tasks beyond the
+/// cap are queued in arrival order.
+///
+/// Tasks of different queries may wait for each other: a task holding a slot on one node may be
+/// waiting for the output of a task queued on another node, whose slots are held by tasks waiting
+/// in turn. Tasks queued for longer than the timeout fail, so that such a cycle ends with one of
+/// the queries failing, even if the queries have no `statement_timeout`.
+#[derive(Clone)]
+pub struct TaskAdmission {
+    slots: Arc<Semaphore>,
+    queue_timeout: Duration,
+}
+
+impl TaskAdmission {
+    /// Waits for a slot, which is released when the returned permit is dropped. Fails if no slot
+    /// is available within the queue timeout.
+    pub async fn acquire(&self) -> Result<OwnedSemaphorePermit> {
+        tokio::time::timeout(self.queue_timeout, self.slots.clone().acquire_owned())
+            .await
+            .map_err(|_| ErrorCode::TaskQueueTimeout(self.queue_timeout.as_millis() as u64))?
+            .map_err(|e| ErrorCode::InternalError(e.to_string()).into())
+    }
+}
+
 impl Default for BatchManager {
     fn default() -> Self {
         BatchManager::new()
@@ -108,9 +196,15 @@ impl Default for BatchManager {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use itertools::Itertools;
+    use risingwave_common::error::ErrorCode;
     use risingwave_pb::batch_plan::exchange_info::DistributionMode;
     use risingwave_pb::batch_plan::plan_node::NodeBody;
     use risingwave_pb::batch_plan::TaskOutputId as ProstTaskOutputId;
+    use risingwave_pb::task_service::TaskLimits;
     use tonic::Code;
 
     use crate::task::{BatchEnvironment, BatchManager, TaskId};
@@ -156,6 +250,7 @@ mod tests {
             root: Some(PlanNode {
                 children: vec![],
                 identity: "".to_string(),
+                operator_id: 0,
                 node_body: Some(NodeBody::Values(ValuesNode {
                     tuples: vec![],
                     fields: vec![],
@@ -171,11 +266,214 @@ mod tests {
             ..Default::default()
         };
         manager
-            .fire_task(env.clone(), &task_id, plan.clone(), 0)
+            .fire_task(env.clone(), &task_id, plan.clone(), 0, Default::default())
             .unwrap();
-        let err = manager.fire_task(env, &task_id, plan, 0).unwrap_err();
+        let err = manager
+            .fire_task(env, &task_id, plan, 0, Default::default())
+            .unwrap_err();
         assert!(err
             .to_string()
             .contains("can not create duplicate task with the same id"));
     }
+
+    #[test]
+    fn test_query_memory_quota() {
+        let manager = BatchManager::new();
+        let quota = manager.query_memory_quota("q1", 100);
+        assert!(Arc::ptr_eq(&quota, &manager.query_memory_quota("q1", 100)));
+        assert!(!Arc::ptr_eq(&quota, &manager.query_memory_quota("q2", 100)));
+
+        drop(quota);
+        manager.query_memory_quota("q3", 100);
+        assert!(!manager.query_quotas.lock().contains_key("q1"));
+    }
+
+    #[tokio::test]
+    async fn test_queued_tasks() {
+        use risingwave_pb::batch_plan::*;
+        use risingwave_pb::task_service::task_info::TaskStatus;
+
+        let manager = BatchManager::with_max_concurrent_tasks(1, Duration::from_secs(10));
+        let plan = PlanFragment {
+            root: Some(PlanNode {
+                children: vec![],
+                identity: "".to_string(),
+                operator_id: 0,
+                node_body: Some(NodeBody::Values(ValuesNode {
+                    tuples: vec![],
+                    fields: vec![],
+                })),
+            }),
+            exchange_info: Some(ExchangeInfo {
+                mode: DistributionMode::Single as i32,
+                distribution: None,
+            }),
+        };
+        // The tasks are queued and executed one by one.
+        let task_ids = [("q1", 0), ("q1", 1), ("q2", 0)]
+            .into_iter()
+            .map(|(query_id, task_id)| TaskId {
+                task_id,
+                query_id: query_id.to_string(),
+                ..Default::default()
+            })
+            .collect_vec();
+        for task_id in &task_ids {
+            manager
+                .fire_task(
+                    BatchEnvironment::for_test(),
+                    task_id,
+                    plan.clone(),
+                    0,
+                    Default::default(),
+                )
+                .unwrap();
+        }
+
+        for task_id in &task_ids {
+            let mut task_info = manager.get_task_info(task_id).unwrap();
+            for _ in 0..100 {
+                if task_info.task_status == TaskStatus::Finished as i32 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                task_info = manager.get_task_info(task_id).unwrap();
+            }
+            assert_eq!(task_info.task_status, TaskStatus::Finished as i32);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_admission() {
+        let manager = BatchManager::with_max_concurrent_tasks(1, Duration::from_millis(10));
+        let admission = manager.admission.clone().unwrap();
+
+        let slot = admission.acquire().await.unwrap();
+        assert!(matches!(
+            admission.acquire().await.unwrap_err().inner(),
+            ErrorCode::TaskQueueTimeout(10)
+        ));
+        drop(slot);
+        admission.acquire().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_timeout_while_queued() {
+        use risingwave_pb::batch_plan::*;
+        use risingwave_pb::task_service::task_info::TaskStatus;
+
+        let manager = BatchManager::with_max_concurrent_tasks(1, Duration::from_millis(100));
+        let _slot = manager.admission.clone().unwrap().acquire().await.unwrap();
+
+        let plan = PlanFragment {
+            root: Some(PlanNode {
+                children: vec![],
+                identity: "".to_string(),
+                operator_id: 0,
+                node_body: Some(NodeBody::Values(ValuesNode {
+                    tuples: vec![],
+                    fields: vec![],
+                })),
+            }),
+            exchange_info: Some(ExchangeInfo {
+                mode: DistributionMode::Single as i32,
+                distribution: None,
+            }),
+        };
+        // The first task reaches the deadline of its query first, the second has no deadline
+        // and waits until the queue timeout.
+        let q1 = TaskId {
+            query_id: "q1".to_string(),
+            ..Default::default()
+        };
+        let q2 = TaskId {
+            query_id: "q2".to_string(),
+            ..Default::default()
+        };
+        for (task_id, timeout_ms) in [(&q1, 10), (&q2, 0)] {
+            manager
+                .fire_task(
+                    BatchEnvironment::for_test(),
+                    task_id,
+                    plan.clone(),
+                    0,
+                    TaskLimits {
+                        timeout_ms,
+                        memory_quota: 0,
+                    },
+                )
+                .unwrap();
+        }
+
+        for task_id in [&q1, &q2] {
+            let mut task_info = manager.get_task_info(task_id).unwrap();
+            for _ in 0..100 {
+                if task_info.task_status == TaskStatus::Failed as i32 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                task_info = manager.get_task_info(task_id).unwrap();
+            }
+            assert_eq!(task_info.task_status, TaskStatus::Failed as i32);
+        }
+        let error_of = |task_id: &TaskId| {
+            manager
+                .get_error(&crate::task::TaskId::from(task_id))
+                .unwrap()
+                .unwrap()
+        };
+        assert!(matches!(error_of(&q1).inner(), ErrorCode::StatementTimeout));
+        assert!(matches!(
+            error_of(&q2).inner(),
+            ErrorCode::TaskQueueTimeout(100)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_task_info() {
+        use risingwave_pb::batch_plan::*;
+        use risingwave_pb::task_service::task_info::TaskStatus;
+
+        let manager = BatchManager::new();
+        let plan = PlanFragment {
+            root: Some(PlanNode {
+                children: vec![],
+                identity: "".to_string(),
+                operator_id: 1,
+                node_body: Some(NodeBody::Values(ValuesNode {
+                    tuples: vec![],
+                    fields: vec![],
+                })),
+            }),
+            exchange_info: Some(ExchangeInfo {
+                mode: DistributionMode::Single as i32,
+                distribution: None,
+            }),
+        };
+        let task_id = TaskId {
+            ..Default::default()
+        };
+        manager
+            .fire_task(
+                BatchEnvironment::for_test(),
+                &task_id,
+                plan,
+                0,
+                Default::default(),
+            )
+            .unwrap();
+
+        let mut task_info = manager.get_task_info(&task_id).unwrap();
+        for _ in 0..100 {
+            if task_info.task_status == TaskStatus::Finished as i32 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            task_info = manager.get_task_info(&task_id).unwrap();
+        }
+        assert_eq!(task_info.task_status, TaskStatus::Finished as i32);
+        assert_eq!(task_info.operator_stats.len(), 1);
+        assert_eq!(task_info.operator_stats[0].operator_id, 1);
+        assert_eq!(task_info.operator_stats[0].rows, 0);
+    }
 }
@@ -96,7 +96,13 @@ impl TestRunner {
 
     pub fn run_task(&mut self, plan: &PlanFragment) -> Result<()> {
         let task_manager = self.env.task_manager();
-        task_manager.fire_task(self.env.clone(), &self.tid, plan.clone(), u64::MAX)
+        task_manager.fire_task(
+            self.env.clone(),
+            &self.tid,
+            plan.clone(),
+            u64::MAX,
+            Default::default(),
+        )
     }
 
     pub async fn collect_task_output(
@@ -224,6 +230,7 @@ impl<'a> TableBuilder<'a> {
                 children: vec![],
                 node_body: Some(NodeBody::CreateTable(create)),
                 identity: "CreateTableExecutor".to_string(),
+                operator_id: 0,
             }),
 
             exchange_info: Some(ExchangeInfo {
@@ -263,9 +270,11 @@ impl<'a> TableBuilder<'a> {
                     children: vec![],
                     node_body: Some(NodeBody::Values(ValuesNode { tuples, fields })),
                     identity: "ValuesExecutor".to_string(),
+                    operator_id: 0,
                 }],
                 node_body: Some(NodeBody::Insert(insert)),
                 identity: "InsertExecutor".to_string(),
+                operator_id: 0,
             }),
 
             exchange_info: Some(ExchangeInfo {
@@ -340,6 +349,7 @@ impl<'a> SelectBuilder<'a> {
                     children: vec![],
                     node_body: None,
                     identity: "PlaceHolderExecutor".to_string(),
+                    operator_id: 0,
                 }),
                 exchange_info: Some(ExchangeInfo {
                     mode: 0,
@@ -0,0 +1,342 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter;
+use std::mem::size_of;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use risingwave_pb::data::buffer::CompressionType;
+use risingwave_pb::data::{Array as ProstArray, ArrayType, Buffer};
+
+use super::{Array, ArrayBuilder, ArrayIterator, ArrayMeta, NULL_VAL_FOR_HASH};
+use crate::array::ArrayBuilderImpl;
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::error::{ErrorCode, Result, RwError};
+
+/// An owned `BYTEA` value.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteaValue(Box<[u8]>);
+
+impl ByteaValue {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Box<[u8]> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for ByteaValue {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes.into_boxed_slice())
+    }
+}
+
+impl From<&[u8]> for ByteaValue {
+    fn from(bytes: &[u8]) -> Self {
+        Self(bytes.into())
+    }
+}
+
+fn invalid_bytea(s: &str) -> RwError {
+    ErrorCode::InvalidInputSyntax(format!("invalid input syntax for type bytea: \"{}\"", s)).into()
+}
+
+impl ByteaValue {
+    /// Parses the escape format, where `\\` stands for a backslash and `\nnn` for an octal
+    /// byte. All other characters stand for their own UTF-8 bytes.
+    pub fn from_escape_format(s: &str) -> Result<Self> {
+        let bytes = s.as_bytes();
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] != b'\\' {
+                out.push(bytes[i]);
+                i += 1;
+            } else if bytes.get(i + 1) == Some(&b'\\') {
+                out.push(b'\\');
+                i += 2;
+            } else {
+                let octal = bytes.get(i + 1..i + 4).ok_or_else(|| invalid_bytea(s))?;
+                if !(b'0'..=b'3').contains(&octal[0])
+                    || !octal[1..].iter().all(|b| (b'0'..=b'7').contains(b))
+                {
+                    return Err(invalid_bytea(s));
+                }
+                out.push(octal.iter().fold(0, |acc, b| acc * 8 + (b - b'0')));
+                i += 4;
+            }
+        }
+        Ok(out.into())
+    }
+
+    /// Parses pairs of hex digits, optionally separated by whitespace.
+    pub fn from_hex_format(s: &str) -> Result<Self> {
+        let digits: String = s.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+        hex::decode(digits)
+            .map(Into::into)
+            .map_err(|_| invalid_bytea(s))
+    }
+}
+
+/// Parses the Postgres input formats of `bytea`: the hex format (`\x` followed by pairs of hex
+/// digits) and the traditional escape format.
+impl FromStr for ByteaValue {
+    type Err = RwError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.strip_prefix("\\x") {
+            Some(hex) => Self::from_hex_format(hex),
+            None => Self::from_escape_format(s),
+        }
+    }
+}
+
+impl Display for ByteaValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&ByteaRef::from(self), f)
+    }
+}
+
+/// A reference to a [`ByteaValue`], or to an element stored in a [`ByteaArray`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteaRef<'a>(&'a [u8]);
+
+impl<'a> ByteaRef<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    pub fn to_owned_value(&self) -> ByteaValue {
+        self.0.into()
+    }
+}
+
+impl<'a> From<&'a ByteaValue> for ByteaRef<'a> {
+    fn from(value: &'a ByteaValue) -> Self {
+        Self(&value.0)
+    }
+}
+
+/// Formats the value in the Postgres hex output format, e.g. `\xdeadbeef`.
+impl Display for ByteaRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\\x{}", hex::encode(self.0))
+    }
+}
+
+/// `ByteaArray` is a collection of binary strings.
+#[derive(Debug)]
+pub struct ByteaArray {
+    offset: Vec<usize>,
+    bitmap: Bitmap,
+    data: Vec<u8>,
+}
+
+impl Array for ByteaArray {
+    type Builder = ByteaArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = ByteaValue;
+    type RefItem<'a> = ByteaRef<'a>;
+
+    fn value_at(&self, idx: usize) -> Option<ByteaRef<'_>> {
+        if !self.is_null(idx) {
+            Some(ByteaRef(&self.data[self.offset[idx]..self.offset[idx + 1]]))
+        } else {
+            None
+        }
+    }
+
+    unsafe fn value_at_unchecked(&self, idx: usize) -> Option<ByteaRef<'_>> {
+        if !self.is_null_unchecked(idx) {
+            Some(ByteaRef(self.data.get_unchecked(
+                *self.offset.get_unchecked(idx)..*self.offset.get_unchecked(idx + 1),
+            )))
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.offset.len() - 1
+    }
+
+    fn iter(&self) -> ArrayIterator<'_, Self> {
+        ArrayIterator::new(self)
+    }
+
+    fn to_protobuf(&self) -> ProstArray {
+        // Same layout as `Utf8Array`: an offset buffer with one entry per non-null value plus the
+        // end offset, followed by the data buffer.
+        let offset_buffer = self
+            .offset
+            .iter()
+            .zip_eq(self.null_bitmap().iter().chain(iter::once(true)))
+            .fold(
+                Vec::<u8>::with_capacity(self.offset.len() * size_of::<usize>()),
+                |mut buffer, (offset, not_null)| {
+                    if not_null {
+                        let offset = *offset as u64;
+                        buffer.extend_from_slice(&offset.to_be_bytes());
+                    }
+                    buffer
+                },
+            );
+
+        let values = vec![
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: offset_buffer,
+            },
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: self.data.clone(),
+            },
+        ];
+        let null_bitmap = self.null_bitmap().to_protobuf();
+        ProstArray {
+            null_bitmap: Some(null_bitmap),
+            values,
+            array_type: ArrayType::Bytea as i32,
+            struct_array_data: None,
+            list_array_data: None,
+        }
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn estimated_size(&self) -> usize {
+        self.offset.len() * std::mem::size_of::<usize>() + self.data.len() + self.len() / 8
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    #[inline(always)]
+    fn hash_at<H: Hasher>(&self, idx: usize, state: &mut H) {
+        if !self.is_null(idx) {
+            state.write(&self.data[self.offset[idx]..self.offset[idx + 1]]);
+        } else {
+            NULL_VAL_FOR_HASH.hash(state);
+        }
+    }
+
+    fn create_builder(&self, capacity: usize) -> Result<ArrayBuilderImpl> {
+        let array_builder = ByteaArrayBuilder::new(capacity)?;
+        Ok(ArrayBuilderImpl::Bytea(array_builder))
+    }
+}
+
+/// `ByteaArrayBuilder` use `ByteaRef` to build a `ByteaArray`.
+#[derive(Debug)]
+pub struct ByteaArrayBuilder {
+    offset: Vec<usize>,
+    bitmap: BitmapBuilder,
+    data: Vec<u8>,
+}
+
+impl ArrayBuilder for ByteaArrayBuilder {
+    type ArrayType = ByteaArray;
+
+    fn with_meta(capacity: usize, _meta: ArrayMeta) -> Result<Self> {
+        let mut offset = Vec::with_capacity(capacity + 1);
+        offset.push(0);
+        Ok(Self {
+            offset,
+            data: Vec::with_capacity(capacity),
+            bitmap: BitmapBuilder::with_capacity(capacity),
+        })
+    }
+
+    fn append(&mut self, value: Option<ByteaRef<'_>>) -> Result<()> {
+        match value {
+            Some(x) => {
+                self.bitmap.append(true);
+                self.data.extend_from_slice(x.0);
+            }
+            None => {
+                self.bitmap.append(false);
+            }
+        }
+        self.offset.push(self.data.len());
+        Ok(())
+    }
+
+    fn append_array(&mut self, other: &ByteaArray) -> Result<()> {
+        for bit in other.bitmap.iter() {
+            self.bitmap.append(bit);
+        }
+        self.data.extend_from_slice(&other.data);
+        let start = *self.offset.last().unwrap();
+        for other_offset in &other.offset[1..] {
+            self.offset.push(*other_offset + start);
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<ByteaArray> {
+        Ok(ByteaArray {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+            offset: self.offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytea_text_forms() {
+        let hex: ByteaValue = "\\xDEAD beef".parse().unwrap();
+        assert_eq!(hex.as_bytes(), &[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(hex.to_string(), "\\xdeadbeef");
+
+        let escaped: ByteaValue = "a\\\\b\\001".parse().unwrap();
+        assert_eq!(escaped.as_bytes(), b"a\\b\x01");
+
+        assert!("\\x1".parse::<ByteaValue>().is_err());
+        assert!("\\9".parse::<ByteaValue>().is_err());
+    }
+
+    #[test]
+    fn test_bytea_builder() {
+        let values = [
+            Some(ByteaValue::from(vec![0u8, 1])),
+            None,
+            Some(vec![].into()),
+        ];
+        let mut builder = ByteaArrayBuilder::new(values.len()).unwrap();
+        for v in &values {
+            builder.append(v.as_ref().map(ByteaRef::from)).unwrap();
+        }
+        let array = builder.finish().unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.value_at(0).unwrap().as_bytes(), &[0, 1]);
+        assert!(array.value_at(1).is_none());
+        assert_eq!(array.value_at(2).unwrap().as_bytes(), &[] as &[u8]);
+    }
+}
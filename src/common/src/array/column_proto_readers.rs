@@ -22,13 +22,13 @@ use crate::array::value_reader::{PrimitiveValueReader, VarSizedValueReader};
 use crate::array::{
     ArrayBuilder, ArrayImpl, ArrayMeta, BoolArrayBuilder, IntervalArrayBuilder,
     NaiveDateArrayBuilder, NaiveDateTimeArrayBuilder, NaiveTimeArrayBuilder, PrimitiveArrayBuilder,
-    PrimitiveArrayItemType,
+    PrimitiveArrayItemType, UuidArrayBuilder,
 };
 use crate::buffer::Bitmap;
 use crate::error::ErrorCode::InternalError;
 use crate::error::{Result, RwError};
 use crate::types::interval::IntervalUnit;
-use crate::types::{NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper};
+use crate::types::{NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, UuidWrapper};
 
 // TODO: Use techniques like apache arrow flight RPC to eliminate deserialization.
 // https://arrow.apache.org/docs/format/Flight.html
@@ -101,6 +101,16 @@ fn read_naive_date_time(cursor: &mut Cursor<&[u8]>) -> Result<NaiveDateTimeWrapp
     }
 }
 
+fn read_uuid(cursor: &mut Cursor<&[u8]>) -> Result<UuidWrapper> {
+    match cursor.read_u128::<BigEndian>() {
+        Ok(v) => UuidWrapper::from_protobuf(v),
+        Err(e) => Err(RwError::from(InternalError(format!(
+            "Failed to read u128 from Uuid buffer: {}",
+            e
+        )))),
+    }
+}
+
 pub fn read_interval_unit(cursor: &mut Cursor<&[u8]>) -> Result<IntervalUnit> {
     {
         let months = cursor.read_i32::<BigEndian>()?;
@@ -152,7 +162,8 @@ read_one_value_array! {
     { bool, BoolArrayBuilder },
     { NaiveDate, NaiveDateArrayBuilder },
     { NaiveTime, NaiveTimeArrayBuilder },
-    { NaiveDateTime, NaiveDateTimeArrayBuilder }
+    { NaiveDateTime, NaiveDateTimeArrayBuilder },
+    { Uuid, UuidArrayBuilder }
 }
 
 fn read_offset(offset_cursor: &mut Cursor<&[u8]>) -> Result<i64> {
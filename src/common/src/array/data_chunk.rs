@@ -201,6 +201,14 @@ impl DataChunk {
         &self.columns
     }
 
+    /// Estimated memory usage of the columns in bytes, including invisible rows.
+    pub fn estimated_size(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|c| c.array_ref().estimated_size())
+            .sum()
+    }
+
     pub fn to_protobuf(&self) -> ProstDataChunk {
         assert!(
             self.visibility.is_none(),
@@ -666,6 +674,21 @@ mod tests {
     use crate::array::*;
     use crate::{column, column_nonnull};
 
+    #[test]
+    fn test_estimated_size() {
+        let chunk = DataChunk::from_pretty(
+            "I T
+             1 abc
+             2 defgh",
+        );
+        let int_size = chunk.column_at(0).array_ref().estimated_size();
+        let str_size = chunk.column_at(1).array_ref().estimated_size();
+        assert_eq!(int_size, 2 * std::mem::size_of::<i64>());
+        // Offsets and the 8 bytes of string data.
+        assert!(str_size >= 8 + 2 * std::mem::size_of::<usize>());
+        assert_eq!(chunk.estimated_size(), int_size + str_size);
+    }
+
     #[test]
     fn test_rechunk() {
         let test_case = |num_chunks: usize, chunk_size: usize, new_chunk_size: usize| {
@@ -0,0 +1,225 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::hash::Hasher;
+use std::str::FromStr;
+
+use risingwave_pb::data::{Array as ProstArray, ArrayType};
+use serde_json::Value;
+
+use super::{
+    Array, ArrayBuilder, ArrayBuilderImpl, ArrayIterator, ArrayMeta, Utf8Array, Utf8ArrayBuilder,
+};
+use crate::buffer::Bitmap;
+use crate::error::{ErrorCode, Result, RwError};
+
+/// An owned JSONB value.
+///
+/// The document is kept in its canonical text form: object keys are sorted and no whitespace is
+/// emitted. Two semantically equal documents therefore compare, hash and encode identically, which
+/// lets `JsonbValue` reuse the string encodings for both memcomparable and value encoding.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JsonbValue(String);
+
+impl JsonbValue {
+    pub fn from_serde(value: Value) -> Self {
+        Self(value.to_string())
+    }
+
+    /// Wraps text that is already known to be in canonical form, e.g. decoded from storage.
+    pub(crate) fn from_canonical(s: String) -> Self {
+        Self(s)
+    }
+
+    pub fn null() -> Self {
+        Self::from_serde(Value::Null)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for JsonbValue {
+    type Err = RwError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(s).map_err(|e| {
+            ErrorCode::InvalidInputSyntax(format!("invalid input syntax for type jsonb: {}", e))
+        })?;
+        Ok(Self::from_serde(value))
+    }
+}
+
+impl Display for JsonbValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A reference to a [`JsonbValue`], or to a JSONB element stored in a [`JsonbArray`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JsonbRef<'a>(&'a str);
+
+impl<'a> JsonbRef<'a> {
+    pub(crate) fn from_canonical(s: &'a str) -> Self {
+        Self(s)
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Parses the document into a `serde_json::Value`. The stored text is always produced by
+    /// `serde_json`, so this never fails.
+    pub fn to_serde(&self) -> Value {
+        serde_json::from_str(self.0).expect("jsonb is always stored in its canonical form")
+    }
+
+    pub fn to_owned_value(&self) -> JsonbValue {
+        JsonbValue(self.0.to_string())
+    }
+}
+
+impl<'a> From<&'a JsonbValue> for JsonbRef<'a> {
+    fn from(value: &'a JsonbValue) -> Self {
+        Self(&value.0)
+    }
+}
+
+impl Display for JsonbRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// `JsonbArray` stores the canonical text of each document in an [`Utf8Array`].
+#[derive(Debug)]
+pub struct JsonbArray {
+    data: Utf8Array,
+}
+
+impl Array for JsonbArray {
+    type Builder = JsonbArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = JsonbValue;
+    type RefItem<'a> = JsonbRef<'a>;
+
+    fn value_at(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        self.data.value_at(idx).map(JsonbRef)
+    }
+
+    unsafe fn value_at_unchecked(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        self.data.value_at_unchecked(idx).map(JsonbRef)
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn iter(&self) -> ArrayIterator<'_, Self> {
+        ArrayIterator::new(self)
+    }
+
+    fn to_protobuf(&self) -> ProstArray {
+        ProstArray {
+            array_type: ArrayType::Jsonb as i32,
+            ..self.data.to_protobuf()
+        }
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        self.data.null_bitmap()
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.data.set_bitmap(bitmap);
+    }
+
+    #[inline(always)]
+    fn hash_at<H: Hasher>(&self, idx: usize, state: &mut H) {
+        self.data.hash_at(idx, state)
+    }
+
+    fn create_builder(&self, capacity: usize) -> Result<ArrayBuilderImpl> {
+        let array_builder = JsonbArrayBuilder::new(capacity)?;
+        Ok(ArrayBuilderImpl::Jsonb(array_builder))
+    }
+}
+
+/// `JsonbArrayBuilder` use `JsonbRef` to build a `JsonbArray`.
+#[derive(Debug)]
+pub struct JsonbArrayBuilder {
+    builder: Utf8ArrayBuilder,
+}
+
+impl ArrayBuilder for JsonbArrayBuilder {
+    type ArrayType = JsonbArray;
+
+    fn with_meta(capacity: usize, meta: ArrayMeta) -> Result<Self> {
+        Ok(Self {
+            builder: Utf8ArrayBuilder::with_meta(capacity, meta)?,
+        })
+    }
+
+    fn append(&mut self, value: Option<JsonbRef<'_>>) -> Result<()> {
+        self.builder.append(value.map(|v| v.0))
+    }
+
+    fn append_array(&mut self, other: &JsonbArray) -> Result<()> {
+        self.builder.append_array(&other.data)
+    }
+
+    fn finish(self) -> Result<JsonbArray> {
+        Ok(JsonbArray {
+            data: self.builder.finish()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonb_canonical_form() {
+        let a: JsonbValue = r#"{"b": [1, 2], "a": null}"#.parse().unwrap();
+        let b: JsonbValue = r#"{ "a":null,"b":[1,2] }"#.parse().unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.to_string(), r#"{"a":null,"b":[1,2]}"#);
+        assert!("{".parse::<JsonbValue>().is_err());
+    }
+
+    #[test]
+    fn test_jsonb_builder() {
+        let values = [
+            Some(JsonbValue::null()),
+            None,
+            Some("[true, \"x\"]".parse::<JsonbValue>().unwrap()),
+        ];
+        let mut builder = JsonbArrayBuilder::new(values.len()).unwrap();
+        for v in &values {
+            builder.append(v.as_ref().map(JsonbRef::from)).unwrap();
+        }
+        let array = builder.finish().unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.value_at(0).unwrap().as_str(), "null");
+        assert!(array.value_at(1).is_none());
+        assert_eq!(
+            array.value_at(2).unwrap().to_serde(),
+            serde_json::json!([true, "x"])
+        );
+    }
+}
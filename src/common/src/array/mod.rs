@@ -15,6 +15,7 @@
 //! `Array` defines all in-memory representations of vectorized execution framework.
 
 mod bool_array;
+mod bytea_array;
 mod chrono_array;
 pub mod column;
 mod column_proto_readers;
@@ -23,6 +24,7 @@ pub mod data_chunk_iter;
 mod decimal_array;
 pub mod interval_array;
 mod iterator;
+mod jsonb_array;
 pub mod list_array;
 mod macros;
 mod primitive_array;
@@ -30,6 +32,7 @@ pub mod stream_chunk;
 mod stream_chunk_iter;
 pub mod struct_array;
 mod utf8_array;
+mod uuid_array;
 mod value_reader;
 
 use std::convert::From;
@@ -37,6 +40,7 @@ use std::hash::Hasher;
 use std::sync::Arc;
 
 pub use bool_array::{BoolArray, BoolArrayBuilder};
+pub use bytea_array::{ByteaArray, ByteaArrayBuilder, ByteaRef, ByteaValue};
 pub use chrono_array::{
     NaiveDateArray, NaiveDateArrayBuilder, NaiveDateTimeArray, NaiveDateTimeArrayBuilder,
     NaiveTimeArray, NaiveTimeArrayBuilder,
@@ -47,6 +51,7 @@ pub use data_chunk_iter::{Row, RowDeserializer, RowRef};
 pub use decimal_array::{DecimalArray, DecimalArrayBuilder};
 pub use interval_array::{IntervalArray, IntervalArrayBuilder};
 pub use iterator::ArrayIterator;
+pub use jsonb_array::{JsonbArray, JsonbArrayBuilder, JsonbRef, JsonbValue};
 pub use list_array::{ListArray, ListArrayBuilder, ListRef, ListValue};
 use paste::paste;
 pub use primitive_array::{PrimitiveArray, PrimitiveArrayBuilder, PrimitiveArrayItemType};
@@ -54,6 +59,7 @@ use risingwave_pb::data::{Array as ProstArray, ArrayType as ProstArrayType};
 pub use stream_chunk::{Op, StreamChunk, StreamChunkTestExt};
 pub use struct_array::{StructArray, StructArrayBuilder, StructRef, StructValue};
 pub use utf8_array::*;
+pub use uuid_array::{UuidArray, UuidArrayBuilder};
 
 use crate::array::iterator::ArrayImplIterator;
 use crate::buffer::Bitmap;
@@ -173,6 +179,12 @@ pub trait Array: std::fmt::Debug + Send + Sync + Sized + 'static + Into<ArrayImp
     /// Get the null `Bitmap` from `Array`.
     fn null_bitmap(&self) -> &Bitmap;
 
+    /// Estimated memory usage of the array in bytes. By default every item is counted with the
+    /// size of its owned type, which is only accurate for fixed-size types.
+    fn estimated_size(&self) -> usize {
+        self.len() * std::mem::size_of::<Self::OwnedItem>() + self.len() / 8
+    }
+
     /// Check if an element is `null` or not.
     fn is_null(&self, idx: usize) -> bool {
         self.null_bitmap().is_set(idx).map(|v| !v).unwrap()
@@ -261,7 +273,10 @@ macro_rules! for_all_variants {
             { NaiveDateTime, naivedatetime, NaiveDateTimeArray, NaiveDateTimeArrayBuilder },
             { NaiveTime, naivetime, NaiveTimeArray, NaiveTimeArrayBuilder },
             { Struct, struct, StructArray, StructArrayBuilder },
-            { List, list, ListArray, ListArrayBuilder }
+            { List, list, ListArray, ListArrayBuilder },
+            { Jsonb, jsonb, JsonbArray, JsonbArrayBuilder },
+            { Bytea, bytea, ByteaArray, ByteaArrayBuilder },
+            { Uuid, uuid, UuidArray, UuidArrayBuilder }
         }
     };
 }
@@ -313,6 +328,18 @@ impl From<ListArray> for ArrayImpl {
     }
 }
 
+impl From<JsonbArray> for ArrayImpl {
+    fn from(arr: JsonbArray) -> Self {
+        Self::Jsonb(arr)
+    }
+}
+
+impl From<ByteaArray> for ArrayImpl {
+    fn from(arr: ByteaArray) -> Self {
+        Self::Bytea(arr)
+    }
+}
+
 for_all_variants! { array_impl_enum }
 
 /// `impl_convert` implements several conversions for `Array` and `ArrayBuilder`.
@@ -468,6 +495,13 @@ macro_rules! impl_array {
                 }
             }
 
+            /// Estimated memory usage of the array in bytes.
+            pub fn estimated_size(&self) -> usize {
+                match self {
+                    $( Self::$variant_name(inner) => inner.estimated_size(), )*
+                }
+            }
+
             pub fn hash_at<H: Hasher>(&self, idx: usize, state: &mut H) {
                 match self {
                     $( Self::$variant_name(inner) => inner.hash_at(idx, state), )*
@@ -575,6 +609,13 @@ impl ArrayImpl {
             ProstArrayType::Interval => read_interval_unit_array(array, cardinality)?,
             ProstArrayType::Struct => StructArray::from_protobuf(array)?,
             ProstArrayType::List => ListArray::from_protobuf(array)?,
+            ProstArrayType::Jsonb => {
+                read_string_array::<JsonbArrayBuilder, JsonbValueReader>(array, cardinality)?
+            }
+            ProstArrayType::Bytea => {
+                read_string_array::<ByteaArrayBuilder, ByteaValueReader>(array, cardinality)?
+            }
+            ProstArrayType::Uuid => read_uuid_array(array, cardinality)?,
         };
         Ok(array)
     }
@@ -113,6 +113,10 @@ impl Array for Utf8Array {
         &self.bitmap
     }
 
+    fn estimated_size(&self) -> usize {
+        self.offset.len() * std::mem::size_of::<usize>() + self.data.len() + self.len() / 8
+    }
+
     fn set_bitmap(&mut self, bitmap: Bitmap) {
         self.bitmap = bitmap;
     }
@@ -18,7 +18,8 @@ use std::str::{from_utf8, FromStr};
 use byteorder::{BigEndian, ReadBytesExt};
 
 use crate::array::{
-    Array, ArrayBuilder, DecimalArrayBuilder, PrimitiveArrayItemType, Utf8ArrayBuilder,
+    Array, ArrayBuilder, ByteaArrayBuilder, ByteaRef, DecimalArrayBuilder, JsonbArrayBuilder,
+    JsonbRef, PrimitiveArrayItemType, Utf8ArrayBuilder,
 };
 use crate::error::ErrorCode::InternalError;
 use crate::error::{ErrorCode, Result, RwError};
@@ -85,3 +86,19 @@ impl VarSizedValueReader<DecimalArrayBuilder> for DecimalValueReader {
         })
     }
 }
+
+pub struct JsonbValueReader {}
+
+impl VarSizedValueReader<JsonbArrayBuilder> for JsonbValueReader {
+    fn read(buf: &[u8]) -> Result<JsonbRef<'_>> {
+        Utf8ValueReader::read(buf).map(JsonbRef::from_canonical)
+    }
+}
+
+pub struct ByteaValueReader {}
+
+impl VarSizedValueReader<ByteaArrayBuilder> for ByteaValueReader {
+    fn read(buf: &[u8]) -> Result<ByteaRef<'_>> {
+        Ok(ByteaRef::new(buf))
+    }
+}
@@ -24,6 +24,7 @@ pub use schema::{test_utils as schema_test_utils, Field, Schema};
 
 pub const DEFAULT_DATABASE_NAME: &str = "dev";
 pub const DEFAULT_SCHEMA_NAME: &str = "dev";
+pub const DEFAULT_SUPER_USER: &str = "root";
 
 pub type CatalogVersion = u64;
 
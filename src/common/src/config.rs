@@ -67,6 +67,25 @@ impl Default for ServerConfig {
 pub struct BatchConfig {
     #[serde(default = "default::chunk_size")]
     pub chunk_size: u32,
+
+    /// Memory budget of a single batch task in bytes. Hash aggregation, hash join and sort spill
+    /// to local temp files once the task exceeds it.
+    #[serde(default = "default::task_memory_budget")]
+    pub task_memory_budget: usize,
+
+    /// Local directory for spill files. The system temp directory is used if empty.
+    #[serde(default)]
+    pub spill_directory: String,
+
+    /// Maximum number of batch tasks running at the same time on a compute node. More tasks are
+    /// queued in arrival order. 0 means no limit.
+    #[serde(default = "default::max_concurrent_tasks")]
+    pub max_concurrent_tasks: usize,
+
+    /// A task queued for longer than this fails. Tasks of different queries may wait for each
+    /// other across compute nodes, which the timeout resolves by failing one of the queries.
+    #[serde(default = "default::task_queue_timeout_ms")]
+    pub task_queue_timeout_ms: u64,
 }
 
 impl Default for BatchConfig {
@@ -80,6 +99,11 @@ impl Default for BatchConfig {
 pub struct StreamingConfig {
     #[serde(default = "default::chunk_size")]
     pub chunk_size: u32,
+
+    /// A source actor that reads no rows for this long reports itself idle, and stops holding back
+    /// the watermarks downstream until it reads rows again.
+    #[serde(default = "default::source_idle_timeout_ms")]
+    pub source_idle_timeout_ms: u64,
 }
 
 impl Default for StreamingConfig {
@@ -184,6 +208,23 @@ mod default {
         1024
     }
 
+    pub fn source_idle_timeout_ms() -> u64 {
+        5000
+    }
+
+    pub fn task_memory_budget() -> usize {
+        // 512 MB
+        536870912
+    }
+
+    pub fn max_concurrent_tasks() -> usize {
+        256
+    }
+
+    pub fn task_queue_timeout_ms() -> u64 {
+        10000
+    }
+
     pub fn sst_size() -> u32 {
         // 256MB
         268435456
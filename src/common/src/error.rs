@@ -133,6 +133,18 @@ pub enum ErrorCode {
     #[error("End of the stream")]
     Eof,
 
+    #[error("canceling statement due to statement timeout")]
+    StatementTimeout,
+
+    #[error("query exceeded its memory quota of {0} bytes")]
+    MemoryQuotaExceeded(usize),
+
+    #[error("task exceeded its memory budget of {0} bytes at the maximum spill level")]
+    MemoryBudgetExceeded(usize),
+
+    #[error("task was queued for more than {0} ms on the compute node")]
+    TaskQueueTimeout(u64),
+
     #[error("Unknown error: {0}")]
     UnknownError(String),
 }
@@ -295,6 +307,10 @@ impl ErrorCode {
             ErrorCode::Eof => 22,
             ErrorCode::BindError(_) => 23,
             ErrorCode::UnknownWorker => 24,
+            ErrorCode::StatementTimeout => 25,
+            ErrorCode::MemoryQuotaExceeded(_) => 26,
+            ErrorCode::MemoryBudgetExceeded(_) => 27,
+            ErrorCode::TaskQueueTimeout(_) => 28,
             ErrorCode::UnknownError(_) => 101,
         }
     }
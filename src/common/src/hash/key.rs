@@ -23,12 +23,13 @@ use itertools::Itertools;
 
 use super::{VirtualNode, VIRTUAL_NODE_COUNT};
 use crate::array::{
-    Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, DataChunk, ListRef, Row, StructRef,
+    Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, ByteaRef, DataChunk, JsonbRef, ListRef, Row,
+    StructRef,
 };
 use crate::error::Result;
 use crate::types::{
     DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
-    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarRef, ToOwnedDatum,
+    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarRef, ToOwnedDatum, UuidWrapper,
 };
 use crate::util::hash_util::CRC32FastBuilder;
 
@@ -429,6 +430,47 @@ impl<'a> HashKeySerDe<'a> for StructRef<'a> {
     }
 }
 
+impl<'a> HashKeySerDe<'a> for JsonbRef<'a> {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        panic!("Should not serialize jsonb for hash!")
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        panic!("Should not serialize jsonb for hash!")
+    }
+}
+
+impl<'a> HashKeySerDe<'a> for ByteaRef<'a> {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        panic!("Should not serialize bytea for hash!")
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        panic!("Should not serialize bytea for hash!")
+    }
+}
+
+impl HashKeySerDe<'_> for UuidWrapper {
+    type S = [u8; 16];
+
+    fn serialize(self) -> Self::S {
+        *self.as_bytes()
+    }
+
+    fn deserialize<R: Read>(source: &mut R) -> Self {
+        let value = Self::read_fixed_size_bytes::<R, 16>(source);
+        UuidWrapper::from_u128(u128::from_be_bytes(value))
+    }
+}
+
 impl<'a> HashKeySerDe<'a> for ListRef<'a> {
     type S = Vec<u8>;
 
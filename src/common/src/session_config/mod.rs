@@ -0,0 +1,521 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Session variables settable by `SET` and inspected by `SHOW`.
+//!
+//! Every variable is declared in [`SESSION_VARIABLES`] with its type and default value. Values
+//! are validated and normalized when set, so the typed getters of [`ConfigMap`] never fail.
+
+mod search_path;
+mod time_zone;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub use search_path::{SearchPath, USER_NAME_WILD_CARD};
+
+use crate::error::{ErrorCode, Result, RwError};
+
+/// If `RW_IMPLICIT_FLUSH` is on, then every INSERT/UPDATE/DELETE statement will block until the
+/// entire dataflow is refreshed.
+pub const IMPLICIT_FLUSH: &str = "RW_IMPLICIT_FLUSH";
+/// If `RW_FORCE_DELTA_JOIN` is on, streaming joins are planned as delta joins when possible.
+pub const FORCE_DELTA_JOIN: &str = "RW_FORCE_DELTA_JOIN";
+/// `query_mode` chooses between distributed execution and local execution of batch queries. See
+/// [`QueryMode`].
+pub const QUERY_MODE: &str = "query_mode";
+/// `statement_timeout` aborts batch queries running longer than the given milliseconds. 0 turns
+/// the timeout off.
+pub const STATEMENT_TIMEOUT: &str = "statement_timeout";
+/// `query_memory_quota` fails batch queries once their tasks on a compute node buffer more than the
+/// given megabytes. 0 turns the quota off.
+pub const QUERY_MEMORY_QUOTA: &str = "query_memory_quota";
+pub const SEARCH_PATH: &str = "search_path";
+pub const TIME_ZONE: &str = "TimeZone";
+pub const APPLICATION_NAME: &str = "application_name";
+pub const EXTRA_FLOAT_DIGITS: &str = "extra_float_digits";
+pub const CLIENT_ENCODING: &str = "client_encoding";
+pub const CLIENT_MIN_MESSAGES: &str = "client_min_messages";
+pub const DATE_STYLE: &str = "DateStyle";
+pub const INTERVAL_STYLE: &str = "IntervalStyle";
+pub const SERVER_ENCODING: &str = "server_encoding";
+pub const SERVER_VERSION: &str = "server_version";
+pub const INTEGER_DATETIMES: &str = "integer_datetimes";
+pub const STANDARD_CONFORMING_STRINGS: &str = "standard_conforming_strings";
+
+const DEFAULT_DATE_STYLE: &str = "ISO, MDY";
+
+/// How batch queries are executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryMode {
+    /// Local execution for point queries, and distributed execution for the others.
+    Auto,
+    /// Run the whole plan as a single task on one compute node, without stage scheduling.
+    Local,
+    /// Split the plan into stages and schedule their tasks across compute nodes.
+    Distributed,
+}
+
+#[derive(Debug)]
+enum VariableType {
+    Bool,
+    Int {
+        min: i32,
+        max: i32,
+    },
+    /// One of the listed values, matched case-insensitively.
+    Enum(&'static [&'static str]),
+    Str,
+    SearchPath,
+    /// An IANA time zone name or an offset from UTC.
+    TimeZone,
+    /// The output format and the field order of dates. Only `ISO` output and `MDY` order are
+    /// supported, so the accepted forms are `ISO`, `MDY` and their combinations.
+    DateStyle,
+}
+
+/// The declaration of a session variable.
+#[derive(Debug)]
+pub struct SessionVariable {
+    /// The canonical name, as shown by `SHOW ALL`. Names are matched case-insensitively.
+    pub name: &'static str,
+    pub default: &'static str,
+    pub description: &'static str,
+    /// Whether the client is notified with a `ParameterStatus` message of the value at
+    /// connection time and whenever it changes.
+    pub report: bool,
+    /// Read-only variables can be shown but not set.
+    pub read_only: bool,
+    ty: VariableType,
+}
+
+impl SessionVariable {
+    /// Validate `value` and return its normalized form.
+    fn normalize(&self, value: &str) -> Result<String> {
+        let invalid = || {
+            ErrorCode::InvalidInputSyntax(format!(
+                "invalid value for parameter \"{}\": \"{}\"",
+                self.name, value
+            ))
+        };
+        let normalized = match &self.ty {
+            VariableType::Bool => match value.to_ascii_lowercase().as_str() {
+                "on" | "true" | "yes" | "1" => "on".to_string(),
+                "off" | "false" | "no" | "0" => "off".to_string(),
+                _ => return Err(invalid().into()),
+            },
+            VariableType::Int { min, max } => {
+                let v: i32 = value.trim().parse().map_err(|_| invalid())?;
+                if v < *min || v > *max {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "{} is outside the valid range for parameter \"{}\" ({} .. {})",
+                        v, self.name, min, max
+                    ))
+                    .into());
+                }
+                v.to_string()
+            }
+            VariableType::Enum(candidates) => candidates
+                .iter()
+                .find(|c| c.eq_ignore_ascii_case(value))
+                .ok_or_else(invalid)?
+                .to_string(),
+            VariableType::Str => value.to_string(),
+            VariableType::SearchPath => value
+                .parse::<SearchPath>()
+                .map_err(|_: RwError| invalid())?
+                .to_string(),
+            VariableType::TimeZone => time_zone::normalize_time_zone(value).ok_or_else(invalid)?,
+            VariableType::DateStyle => {
+                let valid = value.split(',').all(|part| {
+                    let part = part.trim();
+                    part.eq_ignore_ascii_case("ISO") || part.eq_ignore_ascii_case("MDY")
+                });
+                if !valid {
+                    return Err(invalid().into());
+                }
+                DEFAULT_DATE_STYLE.to_string()
+            }
+        };
+        Ok(normalized)
+    }
+}
+
+pub static SESSION_VARIABLES: &[SessionVariable] = &[
+    SessionVariable {
+        name: IMPLICIT_FLUSH,
+        default: "off",
+        description: "Wait for the dataflow to be refreshed after every INSERT, UPDATE and DELETE.",
+        report: false,
+        read_only: false,
+        ty: VariableType::Bool,
+    },
+    SessionVariable {
+        name: FORCE_DELTA_JOIN,
+        default: "off",
+        description: "Plan streaming joins as delta joins when indexes are available.",
+        report: false,
+        read_only: false,
+        ty: VariableType::Bool,
+    },
+    SessionVariable {
+        name: QUERY_MODE,
+        default: "auto",
+        description: "Sets whether batch queries are executed locally on one compute node or \
+                      distributed across compute nodes.",
+        report: false,
+        read_only: false,
+        ty: VariableType::Enum(&["auto", "local", "distributed"]),
+    },
+    SessionVariable {
+        name: STATEMENT_TIMEOUT,
+        default: "0",
+        description: "Sets the maximum allowed duration of any batch query in milliseconds.",
+        report: false,
+        read_only: false,
+        ty: VariableType::Int {
+            min: 0,
+            max: i32::MAX,
+        },
+    },
+    SessionVariable {
+        name: QUERY_MEMORY_QUOTA,
+        default: "0",
+        description: "Sets the maximum memory in megabytes a batch query may use on each compute \
+                      node.",
+        report: false,
+        read_only: false,
+        ty: VariableType::Int {
+            min: 0,
+            max: i32::MAX,
+        },
+    },
+    SessionVariable {
+        name: SEARCH_PATH,
+        default: search_path::DEFAULT_SEARCH_PATH,
+        description: "Sets the schema search order for names that are not schema-qualified.",
+        report: false,
+        read_only: false,
+        ty: VariableType::SearchPath,
+    },
+    SessionVariable {
+        name: TIME_ZONE,
+        default: "UTC",
+        description: "Sets the time zone for displaying and interpreting time stamps.",
+        report: true,
+        read_only: false,
+        ty: VariableType::TimeZone,
+    },
+    SessionVariable {
+        name: APPLICATION_NAME,
+        default: "",
+        description: "Sets the application name to be reported in statistics and logs.",
+        report: true,
+        read_only: false,
+        ty: VariableType::Str,
+    },
+    SessionVariable {
+        name: EXTRA_FLOAT_DIGITS,
+        default: "1",
+        description: "Sets the number of digits displayed for floating-point values.",
+        report: false,
+        read_only: false,
+        ty: VariableType::Int { min: -15, max: 3 },
+    },
+    SessionVariable {
+        name: CLIENT_ENCODING,
+        default: "UTF8",
+        description: "Sets the client's character set encoding.",
+        report: true,
+        read_only: false,
+        ty: VariableType::Enum(&["UTF8"]),
+    },
+    SessionVariable {
+        name: CLIENT_MIN_MESSAGES,
+        default: "notice",
+        description: "Sets the message levels that are sent to the client.",
+        report: false,
+        read_only: false,
+        ty: VariableType::Enum(&[
+            "debug5", "debug4", "debug3", "debug2", "debug1", "log", "notice", "warning", "error",
+        ]),
+    },
+    SessionVariable {
+        name: DATE_STYLE,
+        default: DEFAULT_DATE_STYLE,
+        description: "Sets the display format for date and time values.",
+        report: true,
+        read_only: false,
+        ty: VariableType::DateStyle,
+    },
+    SessionVariable {
+        name: INTERVAL_STYLE,
+        default: "postgres",
+        description: "Sets the display format for interval values.",
+        report: true,
+        read_only: false,
+        ty: VariableType::Enum(&["postgres"]),
+    },
+    SessionVariable {
+        name: SERVER_ENCODING,
+        default: "UTF8",
+        description: "Shows the server (database) character set encoding.",
+        report: true,
+        read_only: true,
+        ty: VariableType::Str,
+    },
+    SessionVariable {
+        name: SERVER_VERSION,
+        default: "9.5.0",
+        description: "Shows the server version.",
+        report: true,
+        read_only: true,
+        ty: VariableType::Str,
+    },
+    SessionVariable {
+        name: INTEGER_DATETIMES,
+        default: "on",
+        description: "Shows whether datetimes are integer based.",
+        report: true,
+        read_only: true,
+        ty: VariableType::Bool,
+    },
+    SessionVariable {
+        name: STANDARD_CONFORMING_STRINGS,
+        default: "on",
+        description: "Causes '...' strings to treat backslashes literally.",
+        report: true,
+        read_only: true,
+        ty: VariableType::Bool,
+    },
+];
+
+/// Look up a session variable by name, case-insensitively.
+pub fn get_session_variable(name: &str) -> Result<&'static SessionVariable> {
+    SESSION_VARIABLES
+        .iter()
+        .find(|v| v.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            ErrorCode::InvalidInputSyntax(format!(
+                "unrecognized configuration parameter \"{}\"",
+                name
+            ))
+            .into()
+        })
+}
+
+/// The values of the session variables of a session.
+#[derive(Debug, Clone)]
+pub struct ConfigMap {
+    values: HashMap<&'static str, String>,
+}
+
+impl Default for ConfigMap {
+    fn default() -> Self {
+        Self {
+            values: SESSION_VARIABLES
+                .iter()
+                .map(|v| (v.name, v.default.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl ConfigMap {
+    /// Set a variable after validating the value. Returns the declaration of the variable.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<&'static SessionVariable> {
+        let var = get_session_variable(name)?;
+        if var.read_only {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "parameter \"{}\" cannot be changed",
+                var.name
+            ))
+            .into());
+        }
+        let value = var.normalize(value)?;
+        self.values.insert(var.name, value);
+        Ok(var)
+    }
+
+    /// Reset a variable to its default value. Returns the declaration of the variable.
+    pub fn reset(&mut self, name: &str) -> Result<&'static SessionVariable> {
+        let var = get_session_variable(name)?;
+        self.values.insert(var.name, var.default.to_string());
+        Ok(var)
+    }
+
+    /// Reset all variables to their default values.
+    pub fn reset_all(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn get(&self, name: &str) -> Result<&str> {
+        let var = get_session_variable(name)?;
+        Ok(self.get_by_var(var))
+    }
+
+    fn get_by_var(&self, var: &SessionVariable) -> &str {
+        self.values[var.name].as_str()
+    }
+
+    /// Iterate all variables with their current values, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static SessionVariable, &str)> {
+        SESSION_VARIABLES.iter().map(|v| (v, self.get_by_var(v)))
+    }
+
+    /// The variables reported to the client with `ParameterStatus` messages.
+    pub fn reported(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.iter()
+            .filter(|(v, _)| v.report)
+            .map(|(v, s)| (v.name, s))
+    }
+
+    fn get_bool(&self, name: &str) -> bool {
+        self.values[name] == "on"
+    }
+
+    /// Parse the value of a variable. Values are validated when set, but fall back to the default
+    /// instead of panicking if the validation ever misses something.
+    fn parse<T: FromStr + Default>(&self, name: &str) -> T {
+        self.values[name].parse().unwrap_or_else(|_| {
+            get_session_variable(name)
+                .ok()
+                .and_then(|var| var.default.parse().ok())
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn get_implicit_flush(&self) -> bool {
+        self.get_bool(IMPLICIT_FLUSH)
+    }
+
+    pub fn get_force_delta_join(&self) -> bool {
+        self.get_bool(FORCE_DELTA_JOIN)
+    }
+
+    pub fn get_query_mode(&self) -> QueryMode {
+        match self.values[QUERY_MODE].as_str() {
+            "local" => QueryMode::Local,
+            "distributed" => QueryMode::Distributed,
+            _ => QueryMode::Auto,
+        }
+    }
+
+    pub fn get_statement_timeout(&self) -> Option<Duration> {
+        let millis: u64 = self.parse(STATEMENT_TIMEOUT);
+        (millis > 0).then(|| Duration::from_millis(millis))
+    }
+
+    /// Returns the memory quota of batch queries in bytes.
+    pub fn get_query_memory_quota(&self) -> Option<u64> {
+        let megabytes: u64 = self.parse(QUERY_MEMORY_QUOTA);
+        (megabytes > 0).then(|| megabytes << 20)
+    }
+
+    pub fn get_search_path(&self) -> SearchPath {
+        self.parse(SEARCH_PATH)
+    }
+
+    pub fn get_time_zone(&self) -> &str {
+        &self.values[TIME_ZONE]
+    }
+
+    pub fn get_application_name(&self) -> &str {
+        &self.values[APPLICATION_NAME]
+    }
+
+    pub fn get_extra_float_digits(&self) -> i32 {
+        self.parse(EXTRA_FLOAT_DIGITS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_reset() {
+        let mut config = ConfigMap::default();
+        assert!(!config.get_implicit_flush());
+
+        config.set("rw_implicit_flush", "TRUE").unwrap();
+        assert!(config.get_implicit_flush());
+        assert_eq!(config.get(IMPLICIT_FLUSH).unwrap(), "on");
+
+        config.set("timezone", "Asia/Shanghai").unwrap();
+        assert_eq!(config.get_time_zone(), "Asia/Shanghai");
+        config.set("client_encoding", "utf8").unwrap();
+        assert_eq!(config.get(CLIENT_ENCODING).unwrap(), "UTF8");
+
+        config.reset("TimeZone").unwrap();
+        assert_eq!(config.get_time_zone(), "UTC");
+        config.reset_all();
+        assert!(!config.get_implicit_flush());
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut config = ConfigMap::default();
+        assert!(config.set("no_such_variable", "1").is_err());
+        assert!(config.set(EXTRA_FLOAT_DIGITS, "4").is_err());
+        assert!(config.set(EXTRA_FLOAT_DIGITS, "abc").is_err());
+        assert!(config.set(IMPLICIT_FLUSH, "maybe").is_err());
+        assert!(config.set(SERVER_VERSION, "14.0").is_err());
+        assert!(config.set(CLIENT_ENCODING, "LATIN1").is_err());
+
+        config.set(EXTRA_FLOAT_DIGITS, "3").unwrap();
+        assert_eq!(config.get_extra_float_digits(), 3);
+
+        assert_eq!(config.get_query_mode(), QueryMode::Auto);
+        assert!(config.set(QUERY_MODE, "remote").is_err());
+        config.set(QUERY_MODE, "LOCAL").unwrap();
+        assert_eq!(config.get_query_mode(), QueryMode::Local);
+
+        assert_eq!(config.get_statement_timeout(), None);
+        assert!(config.set(STATEMENT_TIMEOUT, "-1").is_err());
+        config.set(STATEMENT_TIMEOUT, "1500").unwrap();
+        assert_eq!(
+            config.get_statement_timeout(),
+            Some(Duration::from_millis(1500))
+        );
+
+        assert_eq!(config.get_query_memory_quota(), None);
+        config.set(QUERY_MEMORY_QUOTA, "64").unwrap();
+        assert_eq!(config.get_query_memory_quota(), Some(64 << 20));
+
+        assert!(config.set(TIME_ZONE, "garbage").is_err());
+        config.set(TIME_ZONE, "+08:00").unwrap();
+        assert_eq!(config.get_time_zone(), "+08:00");
+
+        for date_style in ["ISO", "iso, mdy", "MDY, ISO", "MDY"] {
+            config.set(DATE_STYLE, date_style).unwrap();
+            assert_eq!(config.get(DATE_STYLE).unwrap(), "ISO, MDY");
+        }
+        assert!(config.set(DATE_STYLE, "German").is_err());
+        assert!(config.set(DATE_STYLE, "ISO, DMY").is_err());
+    }
+
+    #[test]
+    fn test_defaults_are_valid() {
+        for var in SESSION_VARIABLES {
+            assert_eq!(
+                var.normalize(var.default).unwrap(),
+                var.default,
+                "{}",
+                var.name
+            );
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::catalog::DEFAULT_SCHEMA_NAME;
+use crate::error::{ErrorCode, Result, RwError};
+
+/// The entry of `search_path` standing for the schema named after the session user.
+pub const USER_NAME_WILD_CARD: &str = "$user";
+
+/// The default `search_path`. It must list [`DEFAULT_SCHEMA_NAME`].
+pub(super) const DEFAULT_SEARCH_PATH: &str = "\"$user\", dev";
+
+/// The schemas searched, in order, for names that are not schema-qualified.
+///
+/// Entries that name nonexistent schemas are allowed and skipped during resolution. The system
+/// schema `pg_catalog` is searched first unless it is listed explicitly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchPath {
+    path: Vec<String>,
+}
+
+impl SearchPath {
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+}
+
+impl Default for SearchPath {
+    fn default() -> Self {
+        let path: SearchPath = DEFAULT_SEARCH_PATH.parse().unwrap();
+        debug_assert!(path.path.iter().any(|s| s == DEFAULT_SCHEMA_NAME));
+        path
+    }
+}
+
+impl FromStr for SearchPath {
+    type Err = RwError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let path = s
+            .split(',')
+            .map(|entry| {
+                let entry = entry.trim();
+                let entry = entry
+                    .strip_prefix('"')
+                    .and_then(|e| e.strip_suffix('"'))
+                    .unwrap_or(entry);
+                if entry.is_empty() {
+                    Err(ErrorCode::InvalidInputSyntax(format!(
+                        "invalid search path: \"{}\"",
+                        s
+                    )))
+                } else {
+                    Ok(entry.to_string())
+                }
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(Self { path })
+    }
+}
+
+impl fmt::Display for SearchPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, entry) in self.path.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            if entry == USER_NAME_WILD_CARD {
+                write!(f, "\"{}\"", entry)?;
+            } else {
+                write!(f, "{}", entry)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_search_path() {
+        let path: SearchPath = "\"$user\", dev".parse().unwrap();
+        assert_eq!(path.path(), &["$user".to_string(), "dev".to_string()]);
+        assert_eq!(path.to_string(), "\"$user\", dev");
+
+        let path: SearchPath = "$user,a ,  b".parse().unwrap();
+        assert_eq!(path.to_string(), "\"$user\", a, b");
+
+        assert!("a,,b".parse::<SearchPath>().is_err());
+        assert_eq!(SearchPath::default().to_string(), DEFAULT_SEARCH_PATH);
+    }
+}
@@ -25,10 +25,10 @@ use rand::prelude::Distribution;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 
-use crate::array::{Array, ArrayBuilder, ArrayRef, ListValue, StructValue};
+use crate::array::{Array, ArrayBuilder, ArrayRef, ByteaValue, JsonbValue, ListValue, StructValue};
 use crate::types::{
     Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, NativeType,
-    Scalar,
+    Scalar, UuidWrapper,
 };
 
 pub trait RandValue {
@@ -109,6 +109,28 @@ impl RandValue for StructValue {
     }
 }
 
+impl RandValue for JsonbValue {
+    fn rand_value<R: rand::Rng>(rand: &mut R) -> Self {
+        JsonbValue::from_serde(rand.gen::<i32>().into())
+    }
+}
+
+impl RandValue for ByteaValue {
+    fn rand_value<R: rand::Rng>(rand: &mut R) -> Self {
+        let len = rand.gen_range(0..16);
+        (0..len)
+            .map(|_| rand.gen::<u8>())
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+impl RandValue for UuidWrapper {
+    fn rand_value<R: rand::Rng>(rand: &mut R) -> Self {
+        UuidWrapper::from_u128(rand.gen::<u128>())
+    }
+}
+
 impl RandValue for ListValue {
     fn rand_value<R: rand::Rng>(_rand: &mut R) -> Self {
         ListValue::new(vec![])
@@ -35,6 +35,7 @@ mod decimal;
 pub mod interval;
 
 mod ordered_float;
+mod uuid_wrapper;
 
 use chrono::{Datelike, Timelike};
 pub use chrono_wrapper::{
@@ -45,9 +46,11 @@ pub use interval::*;
 use itertools::Itertools;
 pub use ordered_float::IntoOrdered;
 use paste::paste;
+pub use uuid_wrapper::UuidWrapper;
 
 use crate::array::{
-    ArrayBuilderImpl, ListRef, ListValue, PrimitiveArrayItemType, StructRef, StructValue,
+    ArrayBuilderImpl, ByteaRef, ByteaValue, JsonbRef, JsonbValue, ListRef, ListValue,
+    PrimitiveArrayItemType, StructRef, StructValue,
 };
 
 pub type OrderedF32 = ordered_float::OrderedFloat<f32>;
@@ -70,6 +73,9 @@ pub enum DataType {
     Interval,
     Struct { fields: Arc<[DataType]> },
     List { datatype: Box<DataType> },
+    Jsonb,
+    Bytea,
+    Uuid,
 }
 
 const DECIMAL_DEFAULT_PRECISION: u32 = 20;
@@ -112,6 +118,9 @@ impl From<&ProstDataType> for DataType {
             TypeName::List => DataType::List {
                 datatype: Box::new((&proto.field_type[0]).into()),
             },
+            TypeName::Jsonb => DataType::Jsonb,
+            TypeName::Bytea => DataType::Bytea,
+            TypeName::Uuid => DataType::Uuid,
         }
     }
 }
@@ -147,6 +156,9 @@ impl DataType {
                 },
             )?
             .into(),
+            DataType::Jsonb => JsonbArrayBuilder::new(capacity)?.into(),
+            DataType::Bytea => ByteaArrayBuilder::new(capacity)?.into(),
+            DataType::Uuid => UuidArrayBuilder::new(capacity)?.into(),
         })
     }
 
@@ -167,6 +179,9 @@ impl DataType {
             DataType::Interval => TypeName::Interval,
             DataType::Struct { .. } => TypeName::Struct,
             DataType::List { .. } => TypeName::List,
+            DataType::Jsonb => TypeName::Jsonb,
+            DataType::Bytea => TypeName::Bytea,
+            DataType::Uuid => TypeName::Uuid,
         }
     }
 
@@ -208,6 +223,9 @@ impl DataType {
             DataType::Interval => DataSize::Variable,
             DataType::Struct { .. } => DataSize::Variable,
             DataType::List { .. } => DataSize::Variable,
+            DataType::Jsonb => DataSize::Variable,
+            DataType::Bytea => DataSize::Variable,
+            DataType::Uuid => DataSize::Fixed(size_of::<UuidWrapper>()),
         }
     }
 
@@ -298,7 +316,10 @@ macro_rules! for_all_scalar_variants {
             { NaiveDateTime, naivedatetime, NaiveDateTimeWrapper, NaiveDateTimeWrapper },
             { NaiveTime, naivetime, NaiveTimeWrapper, NaiveTimeWrapper },
             { Struct, struct, StructValue, StructRef<'scalar> },
-            { List, list, ListValue, ListRef<'scalar> }
+            { List, list, ListValue, ListRef<'scalar> },
+            { Jsonb, jsonb, JsonbValue, JsonbRef<'scalar> },
+            { Bytea, bytea, ByteaValue, ByteaRef<'scalar> },
+            { Uuid, uuid, UuidWrapper, UuidWrapper }
         }
     };
 }
@@ -581,6 +602,9 @@ impl std::hash::Hash for ScalarImpl {
                     Self::NaiveTime(naivetime) => naivetime.hash(state),
                     Self::Struct(v) => v.hash(state),
                     Self::List(v) => v.hash(state),
+                    Self::Jsonb(v) => v.hash(state),
+                    Self::Bytea(v) => v.hash(state),
+                    Self::Uuid(v) => v.hash(state),
                 }
             };
         }
@@ -632,6 +656,9 @@ impl ScalarRefImpl<'_> {
             &Self::Float32(v) => v.serialize(ser)?,
             &Self::Float64(v) => v.serialize(ser)?,
             &Self::Utf8(v) => v.serialize(ser)?,
+            &Self::Jsonb(v) => v.as_str().serialize(ser)?,
+            &Self::Bytea(v) => serde::Serializer::serialize_bytes(&mut *ser, v.as_bytes())?,
+            &Self::Uuid(v) => serde::Serializer::serialize_bytes(&mut *ser, v.as_bytes())?,
             &Self::Bool(v) => v.serialize(ser)?,
             &Self::Decimal(v) => {
                 let (mantissa, scale) = v.mantissa_scale_for_serialization();
@@ -675,6 +702,13 @@ impl ScalarImpl {
             Ty::Float32 => Self::Float32(f32::deserialize(de)?.into()),
             Ty::Float64 => Self::Float64(f64::deserialize(de)?.into()),
             Ty::Varchar => Self::Utf8(String::deserialize(de)?),
+            Ty::Jsonb => Self::Jsonb(JsonbValue::from_canonical(String::deserialize(de)?)),
+            Ty::Bytea => Self::Bytea(de.read_bytes()?.into()),
+            Ty::Uuid => Self::Uuid({
+                let bytes = de.read_bytes()?;
+                UuidWrapper::from_slice(&bytes)
+                    .map_err(|_| memcomparable::Error::InvalidBytesEncoding(bytes.len() as u8))?
+            }),
             Ty::Boolean => Self::Bool(bool::deserialize(de)?),
             Ty::Decimal => Self::Decimal({
                 let (mantissa, scale) = de.deserialize_decimal()?;
@@ -15,6 +15,7 @@
 use super::*;
 use crate::array::list_array::{ListRef, ListValue};
 use crate::array::struct_array::{StructRef, StructValue};
+use crate::array::{ByteaRef, ByteaValue, JsonbRef, JsonbValue};
 use crate::{for_all_native_types, for_all_scalar_variants};
 
 /// `ScalarPartialOrd` allows comparison between `Scalar` and `ScalarRef`.
@@ -94,6 +95,32 @@ impl Scalar for ListValue {
     }
 }
 
+/// Implement `Scalar` for `JsonbValue`.
+impl Scalar for JsonbValue {
+    type ScalarRefType<'a> = JsonbRef<'a>;
+
+    fn as_scalar_ref(&self) -> JsonbRef<'_> {
+        self.into()
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Jsonb(self)
+    }
+}
+
+/// Implement `Scalar` for `ByteaValue`.
+impl Scalar for ByteaValue {
+    type ScalarRefType<'a> = ByteaRef<'a>;
+
+    fn as_scalar_ref(&self) -> ByteaRef<'_> {
+        self.into()
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Bytea(self)
+    }
+}
+
 /// Implement `ScalarRef` for `String`.
 /// `String` could be converted to `&str`.
 impl<'a> ScalarRef<'a> for &'a str {
@@ -122,6 +149,18 @@ impl<T: PrimitiveArrayItemType + Scalar> ScalarPartialOrd for T {
     }
 }
 
+impl ScalarPartialOrd for JsonbValue {
+    fn scalar_cmp(&self, other: JsonbRef<'_>) -> Option<std::cmp::Ordering> {
+        self.as_scalar_ref().partial_cmp(&other)
+    }
+}
+
+impl ScalarPartialOrd for ByteaValue {
+    fn scalar_cmp(&self, other: ByteaRef<'_>) -> Option<std::cmp::Ordering> {
+        self.as_scalar_ref().partial_cmp(&other)
+    }
+}
+
 impl ScalarPartialOrd for bool {
     fn scalar_cmp(&self, other: Self) -> Option<std::cmp::Ordering> {
         self.partial_cmp(&other)
@@ -261,6 +300,28 @@ impl<'a> ScalarRef<'a> for NaiveTimeWrapper {
     }
 }
 
+/// Implement `Scalar` for `UuidWrapper`.
+impl Scalar for UuidWrapper {
+    type ScalarRefType<'a> = UuidWrapper;
+
+    fn as_scalar_ref(&self) -> UuidWrapper {
+        *self
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Uuid(self)
+    }
+}
+
+/// Implement `ScalarRef` for `UuidWrapper`.
+impl<'a> ScalarRef<'a> for UuidWrapper {
+    type ScalarType = UuidWrapper;
+
+    fn to_owned_scalar(&self) -> UuidWrapper {
+        *self
+    }
+}
+
 /// Implement `Scalar` for `StructValue`.
 impl<'a> ScalarRef<'a> for StructRef<'a> {
     type ScalarType = StructValue;
@@ -289,6 +350,24 @@ impl<'a> ScalarRef<'a> for ListRef<'a> {
     }
 }
 
+/// Implement `ScalarRef` for `JsonbRef`.
+impl<'a> ScalarRef<'a> for JsonbRef<'a> {
+    type ScalarType = JsonbValue;
+
+    fn to_owned_scalar(&self) -> JsonbValue {
+        self.to_owned_value()
+    }
+}
+
+/// Implement `ScalarRef` for `ByteaRef`.
+impl<'a> ScalarRef<'a> for ByteaRef<'a> {
+    type ScalarType = ByteaValue;
+
+    fn to_owned_scalar(&self) -> ByteaValue {
+        self.to_owned_value()
+    }
+}
+
 impl ScalarImpl {
     pub fn get_ident(&self) -> &'static str {
         macro_rules! impl_all_get_ident {
@@ -331,6 +410,9 @@ impl ScalarImpl {
                 })?;
                 get_data_type_from_datum(data)?
             }
+            ScalarImpl::Jsonb(_) => DataType::Jsonb,
+            ScalarImpl::Bytea(_) => DataType::Bytea,
+            ScalarImpl::Uuid(_) => DataType::Uuid,
         };
         Ok(data_type)
     }
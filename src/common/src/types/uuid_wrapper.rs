@@ -0,0 +1,107 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::error::ErrorCode::{InvalidInputSyntax, IoError};
+use crate::error::{Result, RwError};
+
+/// A wrapper around [`Uuid`], so that it can implement the traits required by our arrays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct UuidWrapper(pub Uuid);
+
+impl UuidWrapper {
+    pub fn new(data: Uuid) -> Self {
+        UuidWrapper(data)
+    }
+
+    /// Generates a random (version 4) UUID.
+    pub fn new_v4() -> Self {
+        UuidWrapper(Uuid::new_v4())
+    }
+
+    pub fn from_u128(v: u128) -> Self {
+        UuidWrapper(Uuid::from_u128(v))
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        Uuid::from_slice(bytes)
+            .map(UuidWrapper)
+            .map_err(|e| RwError::from(InvalidInputSyntax(format!("invalid uuid bytes: {}", e))))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        self.0.as_bytes()
+    }
+
+    /// Writes the 16 bytes of the UUID in big-endian (network) order.
+    pub fn to_protobuf<T: Write>(self, output: &mut T) -> Result<usize> {
+        output
+            .write(self.0.as_bytes())
+            .map_err(|e| RwError::from(IoError(e)))
+    }
+
+    pub fn from_protobuf(v: u128) -> Result<Self> {
+        Ok(Self::from_u128(v))
+    }
+}
+
+impl Default for UuidWrapper {
+    fn default() -> Self {
+        UuidWrapper(Uuid::nil())
+    }
+}
+
+impl FromStr for UuidWrapper {
+    type Err = RwError;
+
+    /// Accepts the same spellings as Postgres: the canonical hyphenated form, optionally wrapped
+    /// in braces, and the plain 32-digit hex form.
+    fn from_str(s: &str) -> Result<Self> {
+        Uuid::parse_str(s.trim()).map(UuidWrapper).map_err(|_| {
+            InvalidInputSyntax(format!("invalid input syntax for type uuid: \"{}\"", s)).into()
+        })
+    }
+}
+
+impl Display for UuidWrapper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0.hyphenated(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_text_forms() {
+        let expected = "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11";
+        for input in [
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+            "A0EEBC99-9C0B-4EF8-BB6D-6BB9BD380A11",
+            "{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11}",
+            "a0eebc999c0b4ef8bb6d6bb9bd380a11",
+        ] {
+            let uuid: UuidWrapper = input.parse().unwrap();
+            assert_eq!(uuid.to_string(), expected);
+        }
+        assert!("a0eebc99-9c0b".parse::<UuidWrapper>().is_err());
+    }
+}
@@ -55,10 +55,20 @@ impl Epoch {
         }
     }
 
+    pub fn from_physical_time(time: u64) -> Self {
+        Epoch(time << EPOCH_PHYSICAL_SHIFT_BITS)
+    }
+
     pub fn physical_time(&self) -> u64 {
         self.0 >> EPOCH_PHYSICAL_SHIFT_BITS
     }
 
+    /// Returns the smallest epoch generated no earlier than `ms` milliseconds before this one.
+    #[must_use]
+    pub fn subtract_ms(&self, ms: u64) -> Self {
+        Self::from_physical_time(self.physical_time().saturating_sub(ms))
+    }
+
     fn physical_now() -> u64 {
         UNIX_SINGULARITY_DATE_EPOCH
             .elapsed()
@@ -189,7 +189,10 @@ pub fn compare_two_row(
                 Interval,
                 NaiveDate,
                 NaiveDateTime,
-                NaiveTime
+                NaiveTime,
+                Jsonb,
+                Bytea,
+                Uuid
             ]
         );
         if res != Ordering::Equal {
@@ -18,10 +18,11 @@
 use bytes::{Buf, BufMut};
 use chrono::{Datelike, Timelike};
 
+use crate::array::JsonbValue;
 use crate::error::{Result, RwError};
 use crate::types::{
     DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
-    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarImpl, ScalarRefImpl,
+    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarImpl, ScalarRefImpl, UuidWrapper,
 };
 
 pub mod error;
@@ -52,6 +53,9 @@ fn serialize_value(value: ScalarRefImpl, mut buf: impl BufMut) {
         ScalarRefImpl::Float32(v) => buf.put_f32_le(v.into_inner()),
         ScalarRefImpl::Float64(v) => buf.put_f64_le(v.into_inner()),
         ScalarRefImpl::Utf8(v) => serialize_str(v.as_bytes(), buf),
+        ScalarRefImpl::Jsonb(v) => serialize_str(v.as_str().as_bytes(), buf),
+        ScalarRefImpl::Bytea(v) => serialize_str(v.as_bytes(), buf),
+        ScalarRefImpl::Uuid(v) => buf.put_slice(v.as_bytes()),
         ScalarRefImpl::Bool(v) => buf.put_u8(v as u8),
         ScalarRefImpl::Decimal(v) => serialize_decimal(&v, buf),
         ScalarRefImpl::Interval(v) => serialize_interval(&v, buf),
@@ -105,6 +109,9 @@ fn deserialize_value(ty: &DataType, mut data: impl Buf) -> Result<Datum> {
         DataType::Float32 => ScalarImpl::Float32(OrderedF32::from(data.get_f32_le())),
         DataType::Float64 => ScalarImpl::Float64(OrderedF64::from(data.get_f64_le())),
         DataType::Varchar => ScalarImpl::Utf8(deserialize_str(data)?),
+        DataType::Jsonb => ScalarImpl::Jsonb(JsonbValue::from_canonical(deserialize_str(data)?)),
+        DataType::Bytea => ScalarImpl::Bytea(deserialize_bytes(data).into()),
+        DataType::Uuid => ScalarImpl::Uuid(UuidWrapper::from_u128(data.get_u128())),
         DataType::Boolean => ScalarImpl::Bool(deserialize_bool(data)?),
         DataType::Decimal => ScalarImpl::Decimal(deserialize_decimal(data)?),
         DataType::Interval => ScalarImpl::Interval(deserialize_interval(data)?),
@@ -118,11 +125,15 @@ fn deserialize_value(ty: &DataType, mut data: impl Buf) -> Result<Datum> {
     }))
 }
 
-fn deserialize_str(mut data: impl Buf) -> Result<String> {
+fn deserialize_bytes(mut data: impl Buf) -> Vec<u8> {
     let len = data.get_u32_le();
     let mut bytes = vec![0; len as usize];
     data.copy_to_slice(&mut bytes);
-    Ok(String::from_utf8(bytes).map_err(ValueEncodingError::InvalidUtf8)?)
+    bytes
+}
+
+fn deserialize_str(data: impl Buf) -> Result<String> {
+    Ok(String::from_utf8(deserialize_bytes(data)).map_err(ValueEncodingError::InvalidUtf8)?)
 }
 
 fn deserialize_bool(mut data: impl Buf) -> Result<bool> {
@@ -14,6 +14,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Instant;
 
 use futures::channel::mpsc::Receiver;
 use futures::StreamExt;
@@ -23,12 +24,14 @@ use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::batch_plan::TaskOutputId as ProtoTaskOutputId;
 use risingwave_pb::task_service::exchange_service_server::ExchangeService;
 use risingwave_pb::task_service::{
-    GetDataRequest, GetDataResponse, GetStreamRequest, GetStreamResponse,
+    get_stream_request, GetDataRequest, GetDataResponse, GetStreamRequest, GetStreamResponse,
 };
+use risingwave_stream::executor::monitor::StreamingMetrics;
 use risingwave_stream::executor::Message;
-use risingwave_stream::task::LocalStreamManager;
+use risingwave_stream::task::{LocalStreamManager, UpDownActorIds};
+use tokio::sync::Semaphore;
 use tokio_stream::wrappers::ReceiverStream;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 
 /// Buffer size of the receiver of the remote channel.
 const EXCHANGE_BUFFER_SIZE: usize = 1024;
@@ -37,6 +40,7 @@ const EXCHANGE_BUFFER_SIZE: usize = 1024;
 pub struct ExchangeServiceImpl {
     batch_mgr: Arc<BatchManager>,
     stream_mgr: Arc<LocalStreamManager>,
+    metrics: Arc<StreamingMetrics>,
 }
 
 type ExchangeDataStream = ReceiverStream<std::result::Result<GetDataResponse, Status>>;
@@ -74,18 +78,38 @@ impl ExchangeService for ExchangeServiceImpl {
 
     async fn get_stream(
         &self,
-        request: Request<GetStreamRequest>,
+        request: Request<Streaming<GetStreamRequest>>,
     ) -> std::result::Result<Response<Self::GetStreamStream>, Status> {
         let peer_addr = request
             .remote_addr()
             .ok_or_else(|| Status::unavailable("get_stream connection unestablished"))?;
-        let req = request.into_inner();
-        let up_down_ids = (req.up_fragment_id, req.down_fragment_id);
+        let mut request_stream = request.into_inner();
+        let get_req = match request_stream.message().await? {
+            Some(GetStreamRequest {
+                value: Some(get_stream_request::Value::Get(get_req)),
+            }) => get_req,
+            other => {
+                return Err(Status::invalid_argument(format!(
+                    "the first request of get_stream must be `Get`, got {:?}",
+                    other
+                )))
+            }
+        };
+        let up_down_ids = (get_req.up_fragment_id, get_req.down_fragment_id);
         let receiver = self
             .stream_mgr
             .take_receiver(up_down_ids)
             .map_err(|e| e.to_grpc_status())?;
-        match self.get_stream_impl(peer_addr, receiver).await {
+        match self
+            .get_stream_impl(
+                peer_addr,
+                up_down_ids,
+                receiver,
+                get_req.permits,
+                request_stream,
+            )
+            .await
+        {
             Ok(resp) => Ok(resp),
             Err(e) => {
                 error!(
@@ -99,10 +123,15 @@ impl ExchangeService for ExchangeServiceImpl {
 }
 
 impl ExchangeServiceImpl {
-    pub fn new(mgr: Arc<BatchManager>, stream_mgr: Arc<LocalStreamManager>) -> Self {
+    pub fn new(
+        mgr: Arc<BatchManager>,
+        stream_mgr: Arc<LocalStreamManager>,
+        metrics: Arc<StreamingMetrics>,
+    ) -> Self {
         ExchangeServiceImpl {
             batch_mgr: mgr,
             stream_mgr,
+            metrics,
         }
     }
 
@@ -138,12 +167,41 @@ impl ExchangeServiceImpl {
     async fn get_stream_impl(
         &self,
         peer_addr: SocketAddr,
+        up_down_ids: UpDownActorIds,
         mut receiver: Receiver<Message>,
+        permits: u32,
+        mut request_stream: Streaming<GetStreamRequest>,
     ) -> Result<Response<<Self as ExchangeService>::GetStreamStream>> {
         let (tx, rx) = tokio::sync::mpsc::channel(EXCHANGE_BUFFER_SIZE);
         tracing::trace!(target: "events::compute::exchange", peer_addr = %peer_addr, "serve stream exchange RPC");
+
+        let permits = Arc::new(Semaphore::new(permits as usize));
+        let add_permits = permits.clone();
+        tokio::spawn(async move {
+            while let Ok(Some(req)) = request_stream.message().await {
+                if let Some(get_stream_request::Value::AddPermits(req)) = req.value {
+                    add_permits.add_permits(req.permits as usize);
+                }
+            }
+            // The downstream has gone, wake up the forwarding task waiting for permits.
+            add_permits.close();
+        });
+
+        let send_blocked_duration = self
+            .metrics
+            .exchange_send_blocked_duration
+            .with_label_values(&[&up_down_ids.0.to_string(), &up_down_ids.1.to_string()]);
         tokio::spawn(async move {
             loop {
+                // Take a permit before taking the message, so that the upstream actor is blocked
+                // by the local channel when the downstream falls behind.
+                let start_time = Instant::now();
+                match permits.acquire().await {
+                    Ok(permit) => permit.forget(),
+                    Err(_) => break,
+                }
+                send_blocked_duration.inc_by(start_time.elapsed().as_nanos() as u64);
+
                 let msg = receiver.next().await;
                 match msg {
                     // the sender is closed, we close the receiver and stop forwarding message
@@ -18,12 +18,17 @@ use itertools::Itertools;
 use risingwave_common::catalog::TableId;
 use risingwave_common::error::{tonic_err, Result as RwResult};
 use risingwave_pb::catalog::Source;
+use risingwave_pb::stream_service::inject_barrier_response::ActorCollectTime;
 use risingwave_pb::stream_service::stream_service_server::StreamService;
 use risingwave_pb::stream_service::*;
 use risingwave_stream::executor::{Barrier, Epoch};
 use risingwave_stream::task::{LocalStreamManager, StreamEnvironment};
 use tonic::{Request, Response, Status};
 
+/// Max number of actors whose collect times are reported to the meta service for each barrier, the
+/// slowest first.
+const MAX_REPORTED_ACTOR_COLLECT_TIMES: usize = 8;
+
 #[derive(Clone)]
 pub struct StreamServiceImpl {
     mgr: Arc<LocalStreamManager>,
@@ -139,21 +144,41 @@ impl StreamService for StreamServiceImpl {
         let barrier =
             Barrier::from_protobuf(req.get_barrier().map_err(tonic_err)?).map_err(tonic_err)?;
 
-        let collect_result = self
+        let result = self
             .mgr
             .send_and_collect_barrier(&barrier, req.actor_ids_to_send, req.actor_ids_to_collect)
             .await
             .map_err(|e| e.to_grpc_status())?;
+        let collect_result = result.collect_result;
 
         let finished_create_mviews = collect_result
             .finished_create_mviews
             .into_iter()
             .map(Into::into)
             .collect();
+        let finished_source_splits = collect_result
+            .finished_source_splits
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        let actor_collect_times = collect_result
+            .actor_collect_times
+            .into_iter()
+            .sorted_by_key(|(_, duration)| std::cmp::Reverse(*duration))
+            .take(MAX_REPORTED_ACTOR_COLLECT_TIMES)
+            .map(|(actor_id, duration)| ActorCollectTime {
+                actor_id,
+                duration_us: duration.as_micros() as u64,
+            })
+            .collect();
 
         Ok(Response::new(InjectBarrierResponse {
             request_id: req.request_id,
             finished_create_mviews,
+            finished_source_splits,
+            actor_collect_times,
+            collect_duration_us: result.collect_duration.as_micros() as u64,
+            sync_duration_us: result.sync_duration.as_micros() as u64,
             status: None,
         }))
     }
@@ -187,6 +212,16 @@ impl StreamService for StreamServiceImpl {
         Ok(Response::new(SyncSourcesResponse { status: None }))
     }
 
+    #[cfg_attr(coverage, no_coverage)]
+    async fn get_actor_stats(
+        &self,
+        _request: Request<GetActorStatsRequest>,
+    ) -> Result<Response<GetActorStatsResponse>, Status> {
+        Ok(Response::new(GetActorStatsResponse {
+            actor_stats: self.mgr.actor_stats(),
+        }))
+    }
+
     #[cfg_attr(coverage, no_coverage)]
     async fn drop_source(
         &self,
@@ -110,7 +110,10 @@ pub async fn compute_node_serve(
     .unwrap();
 
     // Initialize the managers.
-    let batch_mgr = Arc::new(BatchManager::new());
+    let batch_mgr = Arc::new(BatchManager::with_max_concurrent_tasks(
+        config.batch.max_concurrent_tasks,
+        Duration::from_millis(config.batch.task_queue_timeout_ms),
+    ));
     let stream_mgr = Arc::new(LocalStreamManager::new(
         client_addr.clone(),
         state_store.clone(),
@@ -142,7 +145,7 @@ pub async fn compute_node_serve(
 
     // Boot the runtime gRPC services.
     let batch_srv = BatchServiceImpl::new(batch_mgr.clone(), batch_env);
-    let exchange_srv = ExchangeServiceImpl::new(batch_mgr, stream_mgr.clone());
+    let exchange_srv = ExchangeServiceImpl::new(batch_mgr, stream_mgr.clone(), streaming_metrics);
     let stream_srv = StreamServiceImpl::new(stream_mgr, stream_env.clone());
 
     let (shutdown_send, mut shutdown_recv) = tokio::sync::mpsc::unbounded_channel();
@@ -42,6 +42,7 @@ use risingwave_stream::executor::monitor::StreamingMetrics;
 use risingwave_stream::executor::{
     Barrier, Executor, MaterializeExecutor, Message, PkIndices, SourceExecutor,
 };
+use risingwave_stream::task::FinishSourceSplitsNotifier;
 use tokio::sync::mpsc::unbounded_channel;
 
 struct SingleChunkExecutor {
@@ -150,6 +151,7 @@ async fn test_table_v2_materialize() -> Result<()> {
     let (barrier_tx, barrier_rx) = unbounded_channel();
     let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x2333);
     let stream_source = SourceExecutor::new(
+        0x3f3f3f,
         source_table_id,
         source_desc.clone(),
         keyspace,
@@ -162,6 +164,7 @@ async fn test_table_v2_materialize() -> Result<()> {
         "SourceExecutor".to_string(),
         Arc::new(StreamingMetrics::unused()),
         vec![],
+        FinishSourceSplitsNotifier::unused(0x3f3f3f),
     )?;
 
     // Create a `Materialize` to write the changes to storage
@@ -172,6 +175,7 @@ async fn test_table_v2_materialize() -> Result<()> {
         vec![OrderPair::new(1, OrderType::Ascending)],
         all_column_ids.clone(),
         2,
+        false,
     )
     .boxed()
     .execute();
@@ -258,7 +262,7 @@ async fn test_table_v2_materialize() -> Result<()> {
             col_row_ids.push(col_row_id.value_at(0).unwrap());
             col_row_ids.push(col_row_id.value_at(1).unwrap());
         }
-        Message::Barrier(_) => panic!(),
+        Message::Barrier(_) | Message::Watermark(_) | Message::WatermarkStatus(_) => panic!(),
     }
 
     // Send a barrier and poll again, should write changes to storage
@@ -328,7 +332,7 @@ async fn test_table_v2_materialize() -> Result<()> {
             let col_row_id = c.columns()[1].array_ref().as_int64();
             assert_eq!(col_row_id.value_at(0).unwrap(), col_row_ids[0]);
         }
-        Message::Barrier(_) => panic!(),
+        Message::Barrier(_) | Message::Watermark(_) | Message::WatermarkStatus(_) => panic!(),
     }
 
     // Send a barrier and poll again, should write changes to storage
@@ -51,6 +51,19 @@ pub struct SourceMessage {
     pub payload: Option<Bytes>,
     pub offset: String,
     pub split_id: String,
+    pub meta: SourceMeta,
+}
+
+/// The metadata of a message besides its payload, which can be exposed as metadata columns of
+/// the source.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SourceMeta {
+    /// The message key, e.g. Kafka message key or Kinesis and Pulsar partition key.
+    pub key: Option<Bytes>,
+    /// Milliseconds since unix epoch, e.g. Kafka message timestamp or Pulsar publish time.
+    pub timestamp: Option<i64>,
+    /// The message headers, e.g. Kafka headers or Pulsar properties.
+    pub headers: Vec<(String, Option<Bytes>)>,
 }
 
 /// The metadata of a split.
@@ -163,6 +176,15 @@ pub enum SplitReaderImpl {
 }
 
 impl SplitReaderImpl {
+    /// Returns the ids of the splits that have been read to their end, e.g. closed Kinesis
+    /// shards. Nothing more is returned by `next` for them.
+    pub fn finished_splits(&self) -> Vec<String> {
+        match self {
+            Self::Kinesis(r) => r.finished_split().into_iter().map(String::from).collect(),
+            _ => vec![],
+        }
+    }
+
     pub async fn next(&mut self) -> Result<Option<Vec<SourceMessage>>> {
         match self {
             Self::Kafka(r) => r.next().await,
@@ -216,7 +238,7 @@ pub enum SplitEnumeratorImpl {
     Nexmark(nexmark::enumerator::NexmarkSplitEnumerator),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SplitImpl {
     Kafka(kafka::KafkaSplit),
     Pulsar(pulsar::PulsarSplit),
@@ -249,6 +271,14 @@ impl SplitImpl {
         }
     }
 
+    /// Returns the ids of the splits that must be read to their end before this one.
+    pub fn parent_ids(&self) -> &[String] {
+        match self {
+            SplitImpl::Kinesis(k) => &k.parent_shard_ids,
+            _ => &[],
+        }
+    }
+
     pub fn get_type(&self) -> String {
         match self {
             SplitImpl::Kafka(_) => KAFKA_SPLIT_TYPE,
@@ -271,6 +301,18 @@ impl SplitImpl {
 }
 
 impl SplitEnumeratorImpl {
+    /// Make the enumerator list splits that start from the oldest data, whatever startup mode the
+    /// source is created with. Splits that appear after the source is created, e.g. partitions
+    /// added to a Kafka topic, must be read in full.
+    pub fn read_from_earliest(&mut self) {
+        match self {
+            SplitEnumeratorImpl::Kafka(k) => k.read_from_earliest(),
+            SplitEnumeratorImpl::Pulsar(p) => p.read_from_earliest(),
+            SplitEnumeratorImpl::Kinesis(k) => k.read_from_earliest(),
+            SplitEnumeratorImpl::Nexmark(_) => {}
+        }
+    }
+
     pub async fn list_splits(&mut self) -> Result<Vec<SplitImpl>> {
         match self {
             SplitEnumeratorImpl::Kafka(k) => k
@@ -18,7 +18,7 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use rdkafka::consumer::{BaseConsumer, Consumer, DefaultConsumerContext};
 use rdkafka::error::KafkaResult;
-use rdkafka::{Offset, TopicPartitionList};
+use rdkafka::{ClientConfig, Offset, TopicPartitionList};
 
 use crate::base::SplitEnumerator;
 use crate::kafka::split::KafkaSplit;
@@ -46,6 +46,10 @@ pub struct KafkaSplitEnumerator {
 
 impl KafkaSplitEnumerator {
     pub fn new(properties: KafkaProperties) -> anyhow::Result<KafkaSplitEnumerator> {
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", &properties.brokers);
+        properties.set_client(&mut config);
+
         let broker_address = properties.brokers;
         let topic = properties.topic;
 
@@ -69,8 +73,7 @@ impl KafkaSplitEnumerator {
             scan_start_offset = KafkaEnumeratorOffset::Timestamp(time_offset)
         }
 
-        let client: BaseConsumer = rdkafka::ClientConfig::new()
-            .set("bootstrap.servers", &broker_address)
+        let client: BaseConsumer = config
             .create_with_context(DefaultConsumerContext)
             .map_err(|e| anyhow!(e))?;
 
@@ -114,6 +117,11 @@ impl SplitEnumerator for KafkaSplitEnumerator {
 }
 
 impl KafkaSplitEnumerator {
+    /// Make the listed splits start from the low watermarks of their partitions.
+    pub fn read_from_earliest(&mut self) {
+        self.start_offset = KafkaEnumeratorOffset::Earliest;
+    }
+
     fn fetch_stop_offset(&self, partitions: &[i32]) -> KafkaResult<HashMap<i32, Option<i64>>> {
         match self.stop_offset {
             KafkaEnumeratorOffset::Earliest => unreachable!(),
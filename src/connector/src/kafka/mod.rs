@@ -14,6 +14,10 @@
 
 use std::time::Duration;
 
+use rdkafka::ClientConfig;
+
+use crate::KafkaProperties;
+
 pub(crate) mod enumerator;
 pub mod source;
 pub mod split;
@@ -22,3 +26,74 @@ pub use source::*;
 pub use split::*;
 
 const KAFKA_SYNC_CALL_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Prefix of the source properties passed to librdkafka as is.
+const KAFKA_PASSTHROUGH_PREFIX: &str = "properties.";
+
+impl KafkaProperties {
+    /// Set the security options and the `properties.*` passthrough to the librdkafka client
+    /// config, shared by the split enumerator and the split reader.
+    pub(crate) fn set_client(&self, config: &mut ClientConfig) {
+        let options = [
+            ("security.protocol", &self.security_protocol),
+            ("sasl.mechanism", &self.sasl_mechanism),
+            ("sasl.username", &self.sasl_username),
+            ("sasl.password", &self.sasl_password),
+            ("ssl.ca.location", &self.ssl_ca_location),
+            ("ssl.certificate.location", &self.ssl_certificate_location),
+            ("ssl.key.location", &self.ssl_key_location),
+            ("ssl.key.password", &self.ssl_key_password),
+        ];
+        for (key, value) in options {
+            if let Some(value) = value {
+                config.set(key, value);
+            }
+        }
+
+        for (key, value) in &self.extra_properties {
+            if let Some(key) = key.strip_prefix(KAFKA_PASSTHROUGH_PREFIX) {
+                config.set(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[test]
+    fn test_set_client() {
+        let props: HashMap<String, String> = [
+            ("kafka.brokers", "localhost:9093"),
+            ("kafka.topic", "t"),
+            ("kafka.security.protocol", "sasl_ssl"),
+            ("kafka.sasl.mechanism", "SCRAM-SHA-256"),
+            ("kafka.sasl.username", "user"),
+            ("kafka.sasl.password", "pass"),
+            ("kafka.ssl.ca.location", "/etc/kafka/ca.pem"),
+            ("properties.fetch.max.bytes", "1048576"),
+            ("row.format", "json"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let props = KafkaProperties::deserialize(serde_json::to_value(props).unwrap()).unwrap();
+
+        let mut config = ClientConfig::new();
+        props.set_client(&mut config);
+
+        assert_eq!(config.get("security.protocol"), Some("sasl_ssl"));
+        assert_eq!(config.get("sasl.mechanism"), Some("SCRAM-SHA-256"));
+        assert_eq!(config.get("sasl.username"), Some("user"));
+        assert_eq!(config.get("sasl.password"), Some("pass"));
+        assert_eq!(config.get("ssl.ca.location"), Some("/etc/kafka/ca.pem"));
+        assert_eq!(config.get("ssl.key.location"), None);
+        assert_eq!(config.get("fetch.max.bytes"), Some("1048576"));
+        assert_eq!(config.get("row.format"), None);
+    }
+}
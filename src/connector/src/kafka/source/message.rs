@@ -13,18 +13,33 @@
 // limitations under the License.
 
 use bytes::Bytes;
-use rdkafka::message::BorrowedMessage;
+use rdkafka::message::{BorrowedMessage, Headers};
 use rdkafka::Message;
 
-use crate::base::SourceMessage;
+use crate::base::{SourceMessage, SourceMeta};
 
 impl<'a> From<BorrowedMessage<'a>> for SourceMessage {
     fn from(message: BorrowedMessage<'a>) -> Self {
+        let headers = message
+            .headers()
+            .map(|headers| {
+                (0..headers.count())
+                    .filter_map(|i| headers.get(i))
+                    .map(|(key, value)| (key.to_string(), Some(Bytes::copy_from_slice(value))))
+                    .collect()
+            })
+            .unwrap_or_default();
+
         SourceMessage {
             // TODO(TaoWu): Possible performance improvement: avoid memory copying here.
             payload: message.payload().map(Bytes::copy_from_slice),
             offset: message.offset().to_string(),
             split_id: message.partition().to_string(),
+            meta: SourceMeta {
+                key: message.key().map(Bytes::copy_from_slice),
+                timestamp: message.timestamp().to_millis(),
+                headers,
+            },
         }
     }
 }
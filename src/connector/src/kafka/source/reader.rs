@@ -63,15 +63,14 @@ impl KafkaSplitReader {
     where
         Self: Sized,
     {
-        let bootstrap_servers = properties.brokers;
-
         let mut config = ClientConfig::new();
 
         // disable partition eof
         config.set("enable.partition.eof", "false");
         config.set("enable.auto.commit", "false");
         config.set("auto.offset.reset", "smallest");
-        config.set("bootstrap.servers", bootstrap_servers);
+        config.set("bootstrap.servers", &properties.brokers);
+        properties.set_client(&mut config);
 
         if config.get("group.id").is_none() {
             config.set(
@@ -23,6 +23,14 @@ use crate::kinesis::split::{KinesisOffset, KinesisSplit};
 pub struct KinesisSplitEnumerator {
     stream_name: String,
     client: kinesis_client,
+    start_position: KinesisOffset,
+}
+
+impl KinesisSplitEnumerator {
+    /// Make the listed splits start from the oldest record of their shards.
+    pub fn read_from_earliest(&mut self) {
+        self.start_position = KinesisOffset::Earliest;
+    }
 }
 
 #[async_trait]
@@ -60,8 +68,14 @@ impl SplitEnumerator for KinesisSplitEnumerator {
             .into_iter()
             .map(|x| KinesisSplit {
                 shard_id: x.shard_id().unwrap_or_default().to_string(),
-                start_position: KinesisOffset::None,
+                start_position: self.start_position.clone(),
                 end_position: KinesisOffset::None,
+                parent_shard_ids: x
+                    .parent_shard_id()
+                    .into_iter()
+                    .chain(x.adjacent_parent_shard_id())
+                    .map(String::from)
+                    .collect(),
             })
             .collect())
     }
@@ -85,6 +99,7 @@ mod tests {
         let mut enumerator = KinesisSplitEnumerator {
             stream_name,
             client,
+            start_position: KinesisOffset::None,
         };
         let list_splits_resp = enumerator.list_splits().await?;
         // println!("{:#?}", list_splits_resp);
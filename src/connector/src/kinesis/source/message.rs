@@ -16,13 +16,15 @@ use aws_sdk_kinesis::model::Record;
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
-use crate::base::SourceMessage;
+use crate::base::{SourceMessage, SourceMeta};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct KinesisMessage {
     pub shard_id: String,
     pub sequence_number: String,
     pub partition_key: String,
+    /// Approximate arrival time of the record in milliseconds since unix epoch.
+    pub arrival_timestamp: Option<i64>,
     pub payload: Option<Vec<u8>>,
 }
 
@@ -35,6 +37,11 @@ impl From<KinesisMessage> for SourceMessage {
                 .map(|payload| Bytes::copy_from_slice(payload)),
             offset: msg.sequence_number.clone(),
             split_id: msg.shard_id,
+            meta: SourceMeta {
+                key: Some(Bytes::from(msg.partition_key)),
+                timestamp: msg.arrival_timestamp,
+                headers: vec![],
+            },
         }
     }
 }
@@ -45,6 +52,9 @@ impl KinesisMessage {
             shard_id,
             sequence_number: message.sequence_number.unwrap(),
             partition_key: message.partition_key.unwrap(),
+            arrival_timestamp: message
+                .approximate_arrival_timestamp
+                .map(|t| t.secs() * 1000 + t.subsec_nanos() as i64 / 1_000_000),
             payload: Some(message.data.unwrap().into_inner()),
         }
     }
@@ -24,7 +24,7 @@ use aws_sdk_kinesis::Client as kinesis_client;
 use aws_smithy_types::DateTime;
 use http::Uri;
 
-use crate::base::{SourceMessage, SplitReader};
+use crate::base::{SourceMessage, SplitImpl, SplitReader};
 use crate::kinesis::config::AwsConfigInfo;
 use crate::kinesis::source::message::KinesisMessage;
 use crate::kinesis::source::state::KinesisSplitReaderState;
@@ -38,6 +38,8 @@ pub struct KinesisSplitReader {
     latest_sequence_num: String,
     shard_iter: Option<String>,
     assigned_split: Option<KinesisSplit>,
+    /// Whether the shard is closed and all its records have been read.
+    finished: bool,
 }
 
 #[async_trait]
@@ -51,11 +53,10 @@ impl SplitReader for KinesisSplitReader {
         loop {
             let iter = match &self.shard_iter {
                 Some(_iter) => _iter,
+                // A closed shard has no next iterator after its last record.
                 None => {
-                    return Err(anyhow::Error::msg(format!(
-                        "invalid shard iter, shard_id {}",
-                        self.shard_id
-                    )));
+                    self.finished = true;
+                    return Ok(None);
                 }
             };
             let get_record_output = match self.get_records(iter.clone()).await {
@@ -134,27 +135,39 @@ impl KinesisSplitReader {
             latest_sequence_num: "".to_string(),
             shard_iter: None,
             assigned_split: None,
+            finished: false,
         };
 
-        if let ConnectorStateV2::State(state) = state {
-            let split_id = String::from_utf8(state.identifier.to_vec())?;
+        let split = match state {
+            ConnectorStateV2::State(state) => {
+                let split_id = String::from_utf8(state.identifier.to_vec())?;
 
-            let mut start_offset = KinesisOffset::Earliest;
-            if !state.start_offset.is_empty() {
-                start_offset = KinesisOffset::SequenceNumber(state.start_offset);
-            }
-            let mut end_offset = KinesisOffset::None;
-            if !state.end_offset.is_empty() {
-                end_offset = KinesisOffset::SequenceNumber(state.end_offset);
+                let mut start_offset = KinesisOffset::Earliest;
+                if !state.start_offset.is_empty() {
+                    start_offset = KinesisOffset::SequenceNumber(state.start_offset);
+                }
+                let mut end_offset = KinesisOffset::None;
+                if !state.end_offset.is_empty() {
+                    end_offset = KinesisOffset::SequenceNumber(state.end_offset);
+                }
+                Some(KinesisSplit::new(split_id, start_offset, end_offset))
             }
-            let split = KinesisSplit {
-                shard_id: split_id.clone(),
-                start_position: start_offset.clone(),
-                end_position: end_offset.clone(),
-            };
+            ConnectorStateV2::Splits(splits) => match splits.as_slice() {
+                [SplitImpl::Kinesis(split)] => Some(split.clone()),
+                _ => {
+                    return Err(anyhow!(
+                        "a kinesis split reader reads exactly one shard, got {:?}",
+                        splits
+                    ));
+                }
+            },
+            ConnectorStateV2::None => None,
+        };
 
-            let shard_iter: Option<String> = match &start_offset {
-                KinesisOffset::Earliest => {
+        if let Some(split) = split {
+            let split_id = split.shard_id.clone();
+            let shard_iter: Option<String> = match &split.start_position {
+                KinesisOffset::Earliest | KinesisOffset::None => {
                     Self::get_kinesis_iterator(
                         &split_reader.client,
                         &split_reader.stream_name,
@@ -181,12 +194,18 @@ impl KinesisSplitReader {
                 }
             };
 
+            split_reader.shard_id = split_id;
             split_reader.assigned_split = Some(split);
             split_reader.shard_iter = shard_iter;
         }
 
         Ok(split_reader)
     }
+
+    /// Returns the shard id once the shard is closed and all its records have been read.
+    pub fn finished_split(&self) -> Option<&str> {
+        self.finished.then(|| self.shard_id.as_str())
+    }
 }
 
 impl KinesisSplitReader {
@@ -27,11 +27,15 @@ pub enum KinesisOffset {
     None,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct KinesisSplit {
     pub(crate) shard_id: String,
     pub(crate) start_position: KinesisOffset,
     pub(crate) end_position: KinesisOffset,
+    /// The shards this shard is split or merged from. They must be read to their end before this
+    /// shard, to keep the records of a partition key in order.
+    #[serde(default)]
+    pub(crate) parent_shard_ids: Vec<String>,
 }
 
 impl SplitMetaData for KinesisSplit {
@@ -58,6 +62,7 @@ impl KinesisSplit {
             shard_id,
             start_position,
             end_position,
+            parent_shard_ids: vec![],
         }
     }
 }
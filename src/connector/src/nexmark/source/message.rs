@@ -15,7 +15,7 @@
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 
-use crate::base::SourceMessage;
+use crate::base::{SourceMessage, SourceMeta};
 use crate::nexmark::source::event::Event;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -34,6 +34,7 @@ impl From<NexmarkMessage> for SourceMessage {
                 .map(|payload| Bytes::copy_from_slice(payload)),
             offset: msg.sequence_number.clone(),
             split_id: msg.shard_id,
+            meta: SourceMeta::default(),
         }
     }
 }
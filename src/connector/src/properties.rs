@@ -76,6 +76,40 @@ pub struct KafkaProperties {
 
     #[serde(rename = "kafka.consumer.group")]
     pub consumer_group: Option<String>,
+
+    /// One of `plaintext`, `ssl`, `sasl_plaintext` and `sasl_ssl`.
+    #[serde(rename = "kafka.security.protocol")]
+    pub security_protocol: Option<String>,
+
+    /// One of `PLAIN`, `SCRAM-SHA-256` and `SCRAM-SHA-512`.
+    #[serde(rename = "kafka.sasl.mechanism")]
+    pub sasl_mechanism: Option<String>,
+
+    #[serde(rename = "kafka.sasl.username")]
+    pub sasl_username: Option<String>,
+
+    #[serde(rename = "kafka.sasl.password")]
+    pub sasl_password: Option<String>,
+
+    /// Path to the CA certificate used to verify the brokers.
+    #[serde(rename = "kafka.ssl.ca.location")]
+    pub ssl_ca_location: Option<String>,
+
+    /// Path to the client certificate.
+    #[serde(rename = "kafka.ssl.certificate.location")]
+    pub ssl_certificate_location: Option<String>,
+
+    /// Path to the private key of the client certificate.
+    #[serde(rename = "kafka.ssl.key.location")]
+    pub ssl_key_location: Option<String>,
+
+    #[serde(rename = "kafka.ssl.key.password")]
+    pub ssl_key_password: Option<String>,
+
+    /// Other properties of the source. The ones prefixed with `properties.` are passed to
+    /// librdkafka with the prefix stripped, e.g. `properties.fetch.max.bytes`.
+    #[serde(flatten)]
+    pub extra_properties: HashMap<String, String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -69,6 +69,11 @@ impl PulsarSplitEnumerator {
             start_offset: scan_start_offset,
         })
     }
+
+    /// Make the listed splits start from the oldest message of their partitions.
+    pub fn read_from_earliest(&mut self) {
+        self.start_offset = PulsarEnumeratorOffset::Earliest;
+    }
 }
 
 #[async_trait]
@@ -14,11 +14,21 @@
 
 use pulsar::consumer::Message;
 
-use crate::base::SourceMessage;
+use crate::base::{SourceMessage, SourceMeta};
 
 impl From<Message<Vec<u8>>> for SourceMessage {
     fn from(msg: Message<Vec<u8>>) -> Self {
         let message_id = msg.message_id.id;
+        let metadata = msg.payload.metadata;
+        let meta = SourceMeta {
+            key: metadata.partition_key.map(bytes::Bytes::from),
+            timestamp: Some(metadata.publish_time as i64),
+            headers: metadata
+                .properties
+                .into_iter()
+                .map(|kv| (kv.key, Some(bytes::Bytes::from(kv.value))))
+                .collect(),
+        };
 
         SourceMessage {
             payload: Some(bytes::Bytes::from(msg.payload.data)),
@@ -30,6 +40,7 @@ impl From<Message<Vec<u8>>> for SourceMessage {
                 message_id.batch_index.unwrap_or(-1)
             ),
             split_id: msg.topic,
+            meta,
         }
     }
 }
@@ -22,7 +22,7 @@ const PUBLIC_TENANT: &str = "public";
 const DEFAULT_NAMESPACE: &str = "default";
 const PARTITIONED_TOPIC_SUFFIX: &str = "-partition-";
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 /// `ParsedTopic` is a parsed topic name, Generated by `parse_topic`.
 pub struct Topic {
     pub domain: String,
@@ -0,0 +1,55 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::MetaServiceOpts;
+
+fn ms(us: u64) -> f64 {
+    us as f64 / 1000.0
+}
+
+/// Print the latency breakdown of the slowest barriers collected recently.
+pub async fn slow_barriers(limit: u32) -> anyhow::Result<()> {
+    let meta_opts = MetaServiceOpts::from_env()?;
+    let meta_client = meta_opts.create_meta_client().await?;
+    let traces = meta_client.list_barrier_traces(limit).await?;
+
+    for trace in traces {
+        println!(
+            "epoch {} ({}): total {:.3}ms, collect and sync {:.3}ms, commit {:.3}ms",
+            trace.epoch,
+            trace.command,
+            ms(trace.total_duration_us),
+            ms(trace.collect_sync_duration_us),
+            ms(trace.commit_duration_us),
+        );
+        for node in trace.nodes {
+            let host = node
+                .host
+                .map(|h| format!("{}:{}", h.host, h.port))
+                .unwrap_or_default();
+            println!(
+                "  worker {} ({}): rpc {:.3}ms, collect {:.3}ms, sync {:.3}ms, slowest actor {} {:.3}ms",
+                node.worker_id,
+                host,
+                ms(node.rpc_duration_us),
+                ms(node.collect_duration_us),
+                ms(node.sync_duration_us),
+                node.slowest_actor_id,
+                ms(node.slowest_actor_duration_us),
+            );
+        }
+    }
+
+    Ok(())
+}
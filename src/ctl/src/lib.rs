@@ -31,6 +31,9 @@ enum Commands {
     /// Commands for Hummock
     #[clap(subcommand)]
     Hummock(HummockCommands),
+    /// Commands for Meta
+    #[clap(subcommand)]
+    Meta(MetaCommands),
 }
 
 #[derive(Subcommand)]
@@ -41,11 +44,24 @@ enum HummockCommands {
     ListKv,
 }
 
+#[derive(Subcommand)]
+enum MetaCommands {
+    /// list the slowest barriers collected recently, with their latency breakdown
+    SlowBarriers {
+        /// max number of barriers to list
+        #[clap(short, long, default_value_t = 10)]
+        limit: u32,
+    },
+}
+
 pub async fn start(opts: CliOpts) {
     match &opts.command {
         Commands::Hummock(HummockCommands::ListVersion) => {
             cmd_impl::hummock::list_version().await.unwrap()
         }
         Commands::Hummock(HummockCommands::ListKv) => cmd_impl::hummock::list_kv().await.unwrap(),
+        Commands::Meta(MetaCommands::SlowBarriers { limit }) => {
+            cmd_impl::meta::slow_barriers(*limit).await.unwrap()
+        }
     }
 }
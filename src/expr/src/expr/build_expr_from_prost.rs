@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::array::DataChunk;
+use risingwave_common::array::{DataChunk, I64Array, Utf8Array};
 use risingwave_common::ensure;
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::{DataType, ToOwnedDatum};
@@ -24,11 +24,16 @@ use crate::expr::expr_binary_nonnull::{new_binary_expr, new_like_default};
 use crate::expr::expr_binary_nullable::new_nullable_binary_expr;
 use crate::expr::expr_case::{CaseExpression, WhenClause};
 use crate::expr::expr_in::InExpression;
-use crate::expr::expr_ternary_bytes::{new_replace_expr, new_substr_start_end, new_translate_expr};
+use crate::expr::expr_ternary_bytes::{
+    new_lpad_expr, new_replace_expr, new_rpad_expr, new_split_part_expr, new_substr_start_end,
+    new_translate_expr,
+};
 use crate::expr::expr_unary::{
     new_length_default, new_ltrim_expr, new_rtrim_expr, new_trim_expr, new_unary_expr,
 };
+use crate::expr::template::BinaryExpression;
 use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+use crate::vector_op::to_timestamp::to_timestamp_with_pattern;
 
 fn get_return_type_and_children(prost: &ExprNode) -> Result<(Vec<ExprNode>, DataType)> {
     let ret_type = DataType::from(prost.get_return_type()?);
@@ -213,6 +218,55 @@ pub fn build_translate_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     Ok(new_translate_expr(s, match_str, replace_str, ret_type))
 }
 
+pub fn build_split_part_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3);
+    let s = expr_build_from_prost(&children[0])?;
+    let delimiter = expr_build_from_prost(&children[1])?;
+    let n = expr_build_from_prost(&children[2])?;
+    Ok(new_split_part_expr(s, delimiter, n, ret_type))
+}
+
+pub fn build_lpad_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3);
+    let s = expr_build_from_prost(&children[0])?;
+    let length = expr_build_from_prost(&children[1])?;
+    let fill = expr_build_from_prost(&children[2])?;
+    Ok(new_lpad_expr(s, length, fill, ret_type))
+}
+
+pub fn build_rpad_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3);
+    let s = expr_build_from_prost(&children[0])?;
+    let length = expr_build_from_prost(&children[1])?;
+    let fill = expr_build_from_prost(&children[2])?;
+    Ok(new_rpad_expr(s, length, fill, ret_type))
+}
+
+pub fn build_to_timestamp_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 1 || children.len() == 2);
+    let child = expr_build_from_prost(&children[0])?;
+    if children.len() == 1 {
+        new_unary_expr(prost.get_expr_type()?, ret_type, child)
+    } else {
+        let pattern = expr_build_from_prost(&children[1])?;
+        Ok(Box::new(BinaryExpression::<
+            Utf8Array,
+            Utf8Array,
+            I64Array,
+            _,
+        >::new(
+            child,
+            pattern,
+            ret_type,
+            to_timestamp_with_pattern,
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
@@ -192,6 +192,42 @@ macro_rules! interval {
 
 pub(crate) use interval;
 
+#[macro_export]
+macro_rules! jsonb {
+    ($macro:ident) => {
+        $macro! {
+            risingwave_common::types::DataType::Jsonb,
+            risingwave_common::array::JsonbArray
+        }
+    };
+}
+
+pub(crate) use jsonb;
+
+#[macro_export]
+macro_rules! bytea {
+    ($macro:ident) => {
+        $macro! {
+            risingwave_common::types::DataType::Bytea,
+            risingwave_common::array::ByteaArray
+        }
+    };
+}
+
+pub(crate) use bytea;
+
+#[macro_export]
+macro_rules! uuid {
+    ($macro:ident) => {
+        $macro! {
+            risingwave_common::types::DataType::Uuid,
+            risingwave_common::array::UuidArray
+        }
+    };
+}
+
+pub(crate) use uuid;
+
 /// Get the type match pattern out of the type macro. e.g., `DataType::Decimal { .. }`.
 #[macro_export]
 macro_rules! type_match_pattern {
@@ -14,12 +14,16 @@
 
 //! For expression that only accept two arguments + 1 bytes writer as input.
 
-use risingwave_common::array::{I32Array, Utf8Array};
+use risingwave_common::array::{ByteaArray, I32Array, NaiveDateTimeArray, Utf8Array};
 use risingwave_common::types::DataType;
 
 use crate::expr::template::BinaryBytesExpression;
 use crate::expr::BoxedExpression;
+use crate::vector_op::encode::encode;
+use crate::vector_op::left::left;
+use crate::vector_op::right::right;
 use crate::vector_op::substr::*;
+use crate::vector_op::to_char::to_char_timestamp;
 
 pub fn new_substr_start(
     expr_ia1: BoxedExpression,
@@ -48,6 +52,60 @@ pub fn new_substr_for(
     ))
 }
 
+pub fn new_left_expr(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        left,
+    ))
+}
+
+pub fn new_right_expr(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        right,
+    ))
+}
+
+pub fn new_to_char_expr(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(
+        BinaryBytesExpression::<NaiveDateTimeArray, Utf8Array, _>::new(
+            expr_ia1,
+            expr_ia2,
+            return_type,
+            to_char_timestamp,
+        ),
+    )
+}
+
+pub fn new_encode_expr(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<ByteaArray, Utf8Array, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        encode,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::DataChunk;
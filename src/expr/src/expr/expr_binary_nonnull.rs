@@ -13,19 +13,25 @@
 // limitations under the License.
 
 use risingwave_common::array::{
-    Array, BoolArray, DecimalArray, I32Array, IntervalArray, NaiveDateArray, NaiveDateTimeArray,
-    Utf8Array,
+    Array, BoolArray, ByteaArray, ByteaRef, DecimalArray, F64Array, I32Array, IntervalArray,
+    JsonbArray, JsonbRef, NaiveDateArray, NaiveDateTimeArray, Utf8Array,
 };
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
 use risingwave_common::types::*;
 use risingwave_pb::expr::expr_node::Type;
 
+use crate::expr::expr_binary_bytes::{
+    new_encode_expr, new_left_expr, new_right_expr, new_to_char_expr,
+};
 use crate::expr::template::BinaryExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::*;
 use crate::vector_op::cmp::*;
+use crate::vector_op::date_trunc::date_trunc_timestamp;
+use crate::vector_op::encode::decode;
 use crate::vector_op::extract::{extract_from_date, extract_from_timestamp};
+use crate::vector_op::jsonb::jsonb_contains;
 use crate::vector_op::like::like_default;
 use crate::vector_op::position::position;
 use crate::vector_op::round::round_digits;
@@ -124,6 +130,29 @@ macro_rules! gen_binary_expr_cmp {
                     $l, $r, $ret, $str_f,
                 ))
             }
+            // `jsonb` is stored in its canonical text form, so comparing the text is enough.
+            (DataType::Jsonb, DataType::Jsonb) => {
+                Box::new(
+                    BinaryExpression::<JsonbArray, JsonbArray, BoolArray, _>::new(
+                        $l,
+                        $r,
+                        $ret,
+                        |l: JsonbRef<'_>, r: JsonbRef<'_>| $str_f(l.as_str(), r.as_str()),
+                    ),
+                )
+            }
+            (DataType::Bytea, DataType::Bytea) => {
+                Box::new(
+                    BinaryExpression::<ByteaArray, ByteaArray, BoolArray, _>::new(
+                        $l,
+                        $r,
+                        $ret,
+                        |l: ByteaRef<'_>, r: ByteaRef<'_>| {
+                            $general_f::<_, _, &[u8]>(l.as_bytes(), r.as_bytes())
+                        },
+                    ),
+                )
+            }
             _ => {
                 $macro! {
                     [$l, $r, $ret],
@@ -167,7 +196,8 @@ macro_rules! gen_binary_expr_cmp {
                     { date, date, date, $general_f },
                     { boolean, boolean, boolean, $general_f },
                     { timestamp, date, timestamp, $general_f },
-                    { date, timestamp, timestamp, $general_f }
+                    { date, timestamp, timestamp, $general_f },
+                    { uuid, uuid, uuid, $general_f }
                 }
             }
         }
@@ -359,6 +389,30 @@ pub fn new_binary_expr(
             l, r, ret, position,
         )),
         Type::TumbleStart => new_tumble_start(l, r, ret),
+        Type::Pow => Box::new(BinaryExpression::<F64Array, F64Array, F64Array, _>::new(
+            l, r, ret, pow_f64,
+        )),
+        Type::DateTrunc => Box::new(BinaryExpression::<
+            Utf8Array,
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, date_trunc_timestamp)),
+        Type::ToChar => new_to_char_expr(l, r, ret),
+        Type::Left => new_left_expr(l, r, ret),
+        Type::Right => new_right_expr(l, r, ret),
+        Type::JsonbContains => Box::new(
+            BinaryExpression::<JsonbArray, JsonbArray, BoolArray, _>::new(
+                l,
+                r,
+                ret,
+                jsonb_contains,
+            ),
+        ),
+        Type::Encode => new_encode_expr(l, r, ret),
+        Type::Decode => Box::new(
+            BinaryExpression::<Utf8Array, Utf8Array, ByteaArray, _>::new(l, r, ret, decode),
+        ),
         tp => {
             unimplemented!(
                 "The expression {:?} using vectorized expression framework is not supported yet!",
@@ -0,0 +1,81 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayBuilder, ArrayRef, DataChunk, UuidArrayBuilder};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, UuidWrapper};
+use risingwave_common::{ensure, ensure_eq, try_match_expand};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::Expression;
+
+/// `GEN_RANDOM_UUID` takes no arguments and produces a fresh version 4 UUID for every row.
+#[derive(Debug, Default)]
+pub struct GenRandomUuidExpression {}
+
+impl Expression for GenRandomUuidExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Uuid
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let cardinality = input.cardinality();
+        let mut builder = UuidArrayBuilder::new(cardinality)?;
+        for _ in 0..cardinality {
+            builder.append(Some(UuidWrapper::new_v4()))?;
+        }
+        Ok(Arc::new(builder.finish()?.into()))
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for GenRandomUuidExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type()? == Type::GenRandomUuid);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+        ensure_eq!(func_call_node.children.len(), 0);
+        Ok(Self::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::{Array, ArrayImpl, I32Array};
+
+    use super::*;
+
+    #[test]
+    fn test_gen_random_uuid() {
+        let input = DataChunk::builder()
+            .columns(vec![Column::new(Arc::new(ArrayImpl::from(
+                I32Array::from_slice(&[Some(1), Some(2), None]).unwrap(),
+            )))])
+            .build();
+        let result = GenRandomUuidExpression::default().eval(&input).unwrap();
+        let uuids = result
+            .as_uuid()
+            .iter()
+            .map(Option::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(uuids.len(), 3);
+        assert_ne!(uuids[0], uuids[1]);
+        assert!(uuids.iter().all(|u| u.0.get_version_num() == 4));
+    }
+}
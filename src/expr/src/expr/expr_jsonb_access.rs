@@ -0,0 +1,199 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{Array, ArrayRef, DataChunk, JsonbArray, JsonbRef, JsonbValue};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum, ScalarImpl, ScalarRefImpl};
+use risingwave_common::{ensure, try_match_expand};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::vector_op::jsonb::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonbAccessKind {
+    /// `->` and `->>`, with a text key or an integer index.
+    Inner,
+    /// `#>` and `#>>`, with a path in the form of a text array literal.
+    Path,
+}
+
+/// Evaluates the `jsonb` access operators. See [`crate::vector_op::jsonb`].
+#[derive(Debug)]
+pub struct JsonbAccessExpression {
+    kind: JsonbAccessKind,
+    /// Whether the accessed value is returned as text, i.e. `->>` and `#>>`.
+    as_text: bool,
+    return_type: DataType,
+    input: BoxedExpression,
+    path: BoxedExpression,
+}
+
+impl JsonbAccessExpression {
+    fn eval_row(&self, jsonb: JsonbRef<'_>, path: ScalarRefImpl<'_>) -> Result<Datum> {
+        let value = jsonb.to_serde();
+        let target = match (self.kind, path) {
+            (JsonbAccessKind::Inner, ScalarRefImpl::Utf8(key)) => jsonb_access_field(&value, key),
+            (JsonbAccessKind::Inner, ScalarRefImpl::Int32(index)) => {
+                jsonb_access_index(&value, index)
+            }
+            (JsonbAccessKind::Path, ScalarRefImpl::Utf8(path)) => {
+                jsonb_access_path(&value, &parse_jsonb_path(path)?)
+            }
+            (_, path) => {
+                return Err(
+                    InternalError(format!("invalid jsonb path type: {}", path.get_ident())).into(),
+                )
+            }
+        };
+        Ok(target.and_then(|target| match self.as_text {
+            true => jsonb_to_text(target).map(ScalarImpl::Utf8),
+            false => Some(ScalarImpl::Jsonb(JsonbValue::from_serde(target.clone()))),
+        }))
+    }
+}
+
+impl Expression for JsonbAccessExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let jsonb_array = self.input.eval(input)?;
+        let jsonb_array: &JsonbArray = jsonb_array.as_ref().into();
+        let path_array = self.path.eval(input)?;
+
+        let mut builder = self.return_type.create_array_builder(jsonb_array.len())?;
+        for (jsonb, path) in jsonb_array.iter().zip_eq(path_array.iter()) {
+            let datum = match (jsonb, path) {
+                (Some(jsonb), Some(path)) => self.eval_row(jsonb, path)?,
+                _ => None,
+            };
+            builder.append_datum(&datum)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for JsonbAccessExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        let (kind, as_text) = match prost.get_expr_type()? {
+            Type::JsonbAccessInner => (JsonbAccessKind::Inner, false),
+            Type::JsonbAccessStr => (JsonbAccessKind::Inner, true),
+            Type::JsonbAccessPath => (JsonbAccessKind::Path, false),
+            Type::JsonbAccessPathStr => (JsonbAccessKind::Path, true),
+            tp => {
+                return Err(
+                    InternalError(format!("{:?} is not a jsonb access expression", tp)).into(),
+                )
+            }
+        };
+        let return_type = DataType::from(prost.get_return_type()?);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+        let children = &func_call_node.children;
+        ensure!(children.len() == 2);
+
+        Ok(Self {
+            kind,
+            as_text,
+            return_type,
+            input: expr_build_from_prost(&children[0])?,
+            path: expr_build_from_prost(&children[1])?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::{ArrayBuilder, JsonbArrayBuilder};
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::FunctionCall;
+
+    use super::*;
+    use crate::expr::test_utils::{make_input_ref, make_string_literal};
+
+    fn make_access_function(kind: Type, path: &str, ret: TypeName) -> ExprNode {
+        ExprNode {
+            expr_type: kind as i32,
+            return_type: Some(ProstDataType {
+                type_name: ret as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall {
+                children: vec![
+                    make_input_ref(0, TypeName::Jsonb),
+                    make_string_literal(path),
+                ],
+            })),
+        }
+    }
+
+    fn make_chunk(values: &[Option<&str>]) -> DataChunk {
+        let mut builder = JsonbArrayBuilder::new(values.len()).unwrap();
+        for v in values {
+            let v = v.map(|v| v.parse::<JsonbValue>().unwrap());
+            builder.append(v.as_ref().map(JsonbRef::from)).unwrap();
+        }
+        let array = builder.finish().unwrap();
+        DataChunk::builder()
+            .columns(vec![Column::new(Arc::new(array.into()))])
+            .build()
+    }
+
+    #[test]
+    fn test_jsonb_access() {
+        let chunk = make_chunk(&[
+            Some(r#"{"a": {"b": "x"}}"#),
+            Some(r#"{"a": null}"#),
+            Some("[1, 2]"),
+            None,
+        ]);
+
+        let expr = JsonbAccessExpression::try_from(&make_access_function(
+            Type::JsonbAccessInner,
+            "a",
+            TypeName::Jsonb,
+        ))
+        .unwrap();
+        let res = expr.eval(&chunk).unwrap();
+        assert_eq!(
+            res.datum_at(0),
+            Some(ScalarImpl::Jsonb(r#"{"b":"x"}"#.parse().unwrap()))
+        );
+        assert_eq!(res.datum_at(1), Some(ScalarImpl::Jsonb(JsonbValue::null())));
+        assert_eq!(res.datum_at(2), None);
+        assert_eq!(res.datum_at(3), None);
+
+        let expr = JsonbAccessExpression::try_from(&make_access_function(
+            Type::JsonbAccessPathStr,
+            "{a,b}",
+            TypeName::Varchar,
+        ))
+        .unwrap();
+        let res = expr.eval(&chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(ScalarImpl::Utf8("x".to_string())));
+        assert_eq!(res.datum_at(1), None);
+        assert_eq!(res.datum_at(2), None);
+    }
+}
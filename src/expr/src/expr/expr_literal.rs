@@ -19,11 +19,14 @@ use std::sync::Arc;
 
 use prost::DecodeError;
 use risingwave_common::array::{
-    read_interval_unit, Array, ArrayBuilder, ArrayBuilderImpl, ArrayRef, DataChunk,
+    read_interval_unit, Array, ArrayBuilder, ArrayBuilderImpl, ArrayRef, ByteaValue, DataChunk,
+    JsonbValue,
 };
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::{DataType, Datum, Decimal, IntervalUnit, Scalar, ScalarImpl};
+use risingwave_common::types::{
+    DataType, Datum, Decimal, IntervalUnit, Scalar, ScalarImpl, UuidWrapper,
+};
 use risingwave_common::{ensure, for_all_variants};
 use risingwave_pb::data::data_type::IntervalType::*;
 use risingwave_pb::data::data_type::{IntervalType, TypeName};
@@ -94,6 +97,7 @@ fn literal_type_match(return_type: &DataType, literal: Option<&ScalarImpl>) -> b
                     | (DataType::Int16, ScalarImpl::Int16(_))
                     | (DataType::Int32, ScalarImpl::Int32(_))
                     | (DataType::Int64, ScalarImpl::Int64(_))
+                    | (DataType::Timestampz, ScalarImpl::Int64(_))
                     | (DataType::Float32, ScalarImpl::Float32(_))
                     | (DataType::Float64, ScalarImpl::Float64(_))
                     | (DataType::Date, ScalarImpl::Int32(_))
@@ -104,6 +108,9 @@ fn literal_type_match(return_type: &DataType, literal: Option<&ScalarImpl>) -> b
                     | (DataType::Decimal, ScalarImpl::Decimal(_))
                     | (DataType::Interval, ScalarImpl::Interval(_))
                     | (DataType::Struct { .. }, ScalarImpl::Struct(_))
+                    | (DataType::Jsonb, ScalarImpl::Jsonb(_))
+                    | (DataType::Bytea, ScalarImpl::Bytea(_))
+                    | (DataType::Uuid, ScalarImpl::Uuid(_))
             )
         }
         None => true,
@@ -184,7 +191,7 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
                         InternalError(format!("Failed to deserialize i32, reason: {:?}", e))
                     })?,
                 )),
-                TypeName::Int64 => ScalarImpl::Int64(i64::from_be_bytes(
+                TypeName::Int64 | TypeName::Timestampz => ScalarImpl::Int64(i64::from_be_bytes(
                     prost_value.get_body().as_slice().try_into().map_err(|e| {
                         InternalError(format!("Failed to deserialize i64, reason: {:?}", e))
                     })?,
@@ -221,6 +228,17 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
                             InternalError(format!("Failed to deserialize decimal, reason: {:?}", e))
                         })?,
                 ),
+                TypeName::Jsonb => ScalarImpl::Jsonb(JsonbValue::from_str(
+                    std::str::from_utf8(prost_value.get_body()).map_err(|e| {
+                        InternalError(format!("Failed to deserialize jsonb, reason: {:?}", e))
+                    })?,
+                )?),
+                TypeName::Bytea => {
+                    ScalarImpl::Bytea(ByteaValue::from(prost_value.get_body().as_slice()))
+                }
+                TypeName::Uuid => {
+                    ScalarImpl::Uuid(UuidWrapper::from_slice(prost_value.get_body())?)
+                }
                 TypeName::Interval => {
                     let bytes = prost_value.get_body();
                     ScalarImpl::Interval(make_interval(
@@ -0,0 +1,296 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use regex::Regex;
+use risingwave_common::array::{Array, ArrayRef, DataChunk, Utf8Array};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_common::{ensure, try_match_expand};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::vector_op::regexp::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegexpKind {
+    IsMatch,
+    SimilarTo,
+    Match,
+    Replace,
+    SplitToArray,
+}
+
+#[derive(Debug)]
+enum RegexpPattern {
+    /// A constant pattern is compiled when the expression is built. `None` if the pattern is null.
+    Constant(Option<Regex>),
+    /// A pattern that may vary from row to row.
+    Dynamic(BoxedExpression),
+}
+
+/// Evaluates the regular expression functions and operators. See [`crate::vector_op::regexp`].
+#[derive(Debug)]
+pub struct RegexpExpression {
+    kind: RegexpKind,
+    return_type: DataType,
+    text: BoxedExpression,
+    pattern: RegexpPattern,
+    replacement: Option<BoxedExpression>,
+    options: RegexpOptions,
+}
+
+impl RegexpExpression {
+    fn compile(&self, pattern: &str) -> Result<Regex> {
+        compile_pattern(self.kind, pattern, self.options)
+    }
+
+    fn eval_row(&self, re: &Regex, text: &str, replacement: Option<&str>) -> Datum {
+        match self.kind {
+            RegexpKind::IsMatch | RegexpKind::SimilarTo => {
+                Some(ScalarImpl::Bool(regexp_is_match(re, text)))
+            }
+            RegexpKind::Match => regexp_match(re, text).map(ScalarImpl::List),
+            RegexpKind::Replace => replacement.map(|replacement| {
+                ScalarImpl::Utf8(regexp_replace(re, text, replacement, self.options.global))
+            }),
+            RegexpKind::SplitToArray => Some(ScalarImpl::List(regexp_split_to_array(re, text))),
+        }
+    }
+}
+
+fn compile_pattern(kind: RegexpKind, pattern: &str, options: RegexpOptions) -> Result<Regex> {
+    match kind {
+        RegexpKind::SimilarTo => build_regex(&similar_to_regex(pattern), options),
+        _ => build_regex(pattern, options),
+    }
+}
+
+impl Expression for RegexpExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let text_array = self.text.eval(input)?;
+        let text_array: &Utf8Array = text_array.as_ref().into();
+        let pattern_array = match &self.pattern {
+            RegexpPattern::Constant(_) => None,
+            RegexpPattern::Dynamic(pattern) => Some(pattern.eval(input)?),
+        };
+        let pattern_array: Option<&Utf8Array> = pattern_array.as_ref().map(|a| a.as_ref().into());
+        let replacement_array = self
+            .replacement
+            .as_ref()
+            .map(|replacement| replacement.eval(input))
+            .transpose()?;
+        let replacement_array: Option<&Utf8Array> =
+            replacement_array.as_ref().map(|a| a.as_ref().into());
+
+        let mut builder = self.return_type.create_array_builder(text_array.len())?;
+        // Consecutive rows often share the same pattern, so keep the last compiled one around.
+        let mut last_compiled: Option<(String, Regex)> = None;
+        for row_idx in 0..text_array.len() {
+            let re = match &self.pattern {
+                RegexpPattern::Constant(re) => re.as_ref(),
+                RegexpPattern::Dynamic(_) => match pattern_array.unwrap().value_at(row_idx) {
+                    Some(pattern) => {
+                        if !matches!(&last_compiled, Some((last, _)) if last == pattern) {
+                            last_compiled = Some((pattern.to_string(), self.compile(pattern)?));
+                        }
+                        last_compiled.as_ref().map(|(_, re)| re)
+                    }
+                    None => None,
+                },
+            };
+            let replacement = replacement_array.and_then(|a| a.value_at(row_idx));
+            let datum = match (text_array.value_at(row_idx), re) {
+                (Some(text), Some(re)) => self.eval_row(re, text, replacement),
+                _ => None,
+            };
+            builder.append_datum(&datum)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+/// Returns the string value of a constant child, or `None` if it's not a constant.
+fn constant_str(prost: &ExprNode) -> Result<Option<Option<String>>> {
+    if prost.get_expr_type()? != Type::ConstantValue {
+        return Ok(None);
+    }
+    let literal = expr_build_from_prost(prost)?.eval(&DataChunk::new_dummy(1))?;
+    Ok(Some(literal.datum_at(0).map(|datum| datum.into_utf8())))
+}
+
+impl<'a> TryFrom<&'a ExprNode> for RegexpExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        let kind = match prost.get_expr_type()? {
+            Type::RegexpIsMatch => RegexpKind::IsMatch,
+            Type::SimilarTo => RegexpKind::SimilarTo,
+            Type::RegexpMatch => RegexpKind::Match,
+            Type::RegexpReplace => RegexpKind::Replace,
+            Type::RegexpSplitToArray => RegexpKind::SplitToArray,
+            tp => return Err(InternalError(format!("{:?} is not a regexp expression", tp)).into()),
+        };
+        let return_type = DataType::from(prost.get_return_type()?);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+        let children = &func_call_node.children;
+
+        // `SIMILAR TO` doesn't take flags.
+        let num_args = if kind == RegexpKind::Replace { 3 } else { 2 };
+        let has_flags = kind != RegexpKind::SimilarTo && children.len() == num_args + 1;
+        ensure!(children.len() == num_args || has_flags);
+
+        let options = match children.get(num_args) {
+            Some(flags) => match constant_str(flags)? {
+                Some(Some(flags)) => RegexpOptions::parse(&flags)?,
+                Some(None) => RegexpOptions::default(),
+                None => {
+                    return Err(InternalError("regexp flags must be a constant".to_string()).into())
+                }
+            },
+            None => RegexpOptions::default(),
+        };
+
+        let text = expr_build_from_prost(&children[0])?;
+        let pattern = match constant_str(&children[1])? {
+            Some(pattern) => RegexpPattern::Constant(
+                pattern
+                    .map(|pattern| compile_pattern(kind, &pattern, options))
+                    .transpose()?,
+            ),
+            None => RegexpPattern::Dynamic(expr_build_from_prost(&children[1])?),
+        };
+        let replacement = match kind {
+            RegexpKind::Replace => Some(expr_build_from_prost(&children[2])?),
+            _ => None,
+        };
+
+        Ok(Self {
+            kind,
+            return_type,
+            text,
+            pattern,
+            replacement,
+            options,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::{DataChunk, Utf8Array};
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::expr_node::{RexNode, Type};
+    use risingwave_pb::expr::{ExprNode, FunctionCall};
+
+    use super::*;
+    use crate::expr::test_utils::{make_input_ref, make_string_literal};
+
+    fn make_regexp_function(kind: Type, children: Vec<ExprNode>, ret: TypeName) -> ExprNode {
+        ExprNode {
+            expr_type: kind as i32,
+            return_type: Some(ProstDataType {
+                type_name: ret as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall { children })),
+        }
+    }
+
+    fn make_chunk(columns: &[&[Option<&str>]]) -> DataChunk {
+        let columns = columns
+            .iter()
+            .map(|values| {
+                let array = Utf8Array::from_slice(values)
+                    .map(|x| Arc::new(x.into()))
+                    .unwrap();
+                Column::new(array)
+            })
+            .collect();
+        DataChunk::builder().columns(columns).build()
+    }
+
+    #[test]
+    fn test_regexp_is_match_constant_pattern() {
+        let expr = RegexpExpression::try_from(&make_regexp_function(
+            Type::RegexpIsMatch,
+            vec![
+                make_input_ref(0, TypeName::Varchar),
+                make_string_literal("^/cart"),
+                make_string_literal("i"),
+            ],
+            TypeName::Boolean,
+        ))
+        .unwrap();
+        assert!(matches!(expr.pattern, RegexpPattern::Constant(Some(_))));
+
+        let chunk = make_chunk(&[&[Some("/cart/1"), Some("/CART"), Some("/item"), None]]);
+        let res = expr.eval(&chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(ScalarImpl::Bool(true)));
+        assert_eq!(res.datum_at(1), Some(ScalarImpl::Bool(true)));
+        assert_eq!(res.datum_at(2), Some(ScalarImpl::Bool(false)));
+        assert_eq!(res.datum_at(3), None);
+    }
+
+    #[test]
+    fn test_regexp_replace_dynamic_pattern() {
+        let expr = RegexpExpression::try_from(&make_regexp_function(
+            Type::RegexpReplace,
+            vec![
+                make_input_ref(0, TypeName::Varchar),
+                make_input_ref(1, TypeName::Varchar),
+                make_string_literal("-"),
+                make_string_literal("g"),
+            ],
+            TypeName::Varchar,
+        ))
+        .unwrap();
+        assert!(matches!(expr.pattern, RegexpPattern::Dynamic(_)));
+
+        let chunk = make_chunk(&[
+            &[Some("a.b.c"), Some("a.b.c"), Some("a b")],
+            &[Some(r"\."), Some("b"), None],
+        ]);
+        let res = expr.eval(&chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(ScalarImpl::Utf8("a-b-c".to_string())));
+        assert_eq!(res.datum_at(1), Some(ScalarImpl::Utf8("a.-.c".to_string())));
+        assert_eq!(res.datum_at(2), None);
+    }
+
+    #[test]
+    fn test_invalid_constant_pattern() {
+        assert!(RegexpExpression::try_from(&make_regexp_function(
+            Type::RegexpIsMatch,
+            vec![
+                make_input_ref(0, TypeName::Varchar),
+                make_string_literal("(")
+            ],
+            TypeName::Boolean,
+        ))
+        .is_err());
+    }
+}
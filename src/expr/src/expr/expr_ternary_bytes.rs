@@ -19,7 +19,10 @@ use risingwave_common::types::DataType;
 
 use crate::expr::template::TernaryBytesExpression;
 use crate::expr::BoxedExpression;
+use crate::vector_op::lpad::lpad;
 use crate::vector_op::replace::replace;
+use crate::vector_op::rpad::rpad;
+use crate::vector_op::split_part::split_part;
 use crate::vector_op::substr::substr_start_for;
 use crate::vector_op::translate::translate;
 
@@ -74,6 +77,57 @@ pub fn new_translate_expr(
     )
 }
 
+pub fn new_split_part_expr(
+    s: BoxedExpression,
+    delimiter: BoxedExpression,
+    n: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(
+        TernaryBytesExpression::<Utf8Array, Utf8Array, I32Array, _>::new(
+            s,
+            delimiter,
+            n,
+            return_type,
+            split_part,
+        ),
+    )
+}
+
+pub fn new_lpad_expr(
+    s: BoxedExpression,
+    length: BoxedExpression,
+    fill: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(
+        TernaryBytesExpression::<Utf8Array, I32Array, Utf8Array, _>::new(
+            s,
+            length,
+            fill,
+            return_type,
+            lpad,
+        ),
+    )
+}
+
+pub fn new_rpad_expr(
+    s: BoxedExpression,
+    length: BoxedExpression,
+    fill: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(
+        TernaryBytesExpression::<Utf8Array, I32Array, Utf8Array, _>::new(
+            s,
+            length,
+            fill,
+            return_type,
+            rpad,
+        ),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::DataChunk;
@@ -24,15 +24,19 @@ use crate::expr::expr_is_null::{IsNotNullExpression, IsNullExpression};
 use crate::expr::pg_sleep::PgSleepExpression;
 use crate::expr::template::UnaryNullableExpression;
 use crate::expr::BoxedExpression;
-use crate::vector_op::arithmetic_op::general_neg;
+use crate::vector_op::arithmetic_op::{decimal_abs, general_abs, general_neg, sqrt_f64};
 use crate::vector_op::ascii::ascii;
 use crate::vector_op::cast::*;
 use crate::vector_op::cmp::{is_false, is_not_false, is_not_true, is_true};
 use crate::vector_op::conjunction;
+use crate::vector_op::jsonb::{jsonb_array_length, jsonb_typeof};
 use crate::vector_op::length::length_default;
 use crate::vector_op::lower::lower;
 use crate::vector_op::ltrim::ltrim;
+use crate::vector_op::md5::md5;
+use crate::vector_op::round::{ceil_decimal, ceil_f64, floor_decimal, floor_f64};
 use crate::vector_op::rtrim::rtrim;
+use crate::vector_op::to_timestamp::f64_sec_to_timestampz;
 use crate::vector_op::trim::trim;
 use crate::vector_op::upper::upper;
 
@@ -100,8 +104,24 @@ macro_rules! gen_cast {
             { varchar, float64, str_parse },
             { varchar, decimal, str_parse },
             { varchar, boolean, str_to_bool },
+            { varchar, jsonb, str_parse },
+            { varchar, bytea, str_parse },
+            { varchar, uuid, str_parse },
 
             { boolean, varchar, bool_to_str },
+            { int16, varchar, general_to_string },
+            { int32, varchar, general_to_string },
+            { int64, varchar, general_to_string },
+            { float32, varchar, general_to_string },
+            { float64, varchar, general_to_string },
+            { decimal, varchar, general_to_string },
+            { date, varchar, general_to_string },
+            { time, varchar, general_to_string },
+            { timestamp, varchar, general_to_string },
+            { interval, varchar, general_to_string },
+            { jsonb, varchar, general_to_string },
+            { bytea, varchar, general_to_string },
+            { uuid, varchar, general_to_string },
             { boolean, int32, general_cast },
             { int32, boolean, int32_to_bool },
 
@@ -171,6 +191,41 @@ macro_rules! gen_neg_impl {
     };
 }
 
+/// This macro helps to create abs expression.
+/// It receives all the types that impl `Signed` trait.
+/// * `$child`: child expression
+/// * `$ret`: return expression
+/// * `$input`: input type
+macro_rules! gen_abs_impl {
+    ($child:expr, $ret:expr, $($input:ident),*) => {
+        match $child.return_type() {
+            $(
+                $input! {type_match_pattern} => Box::new(
+                    UnaryExpression::<$input! {type_array}, $input! {type_array}, _>::new(
+                        $child,
+                        $ret.clone(),
+                        general_abs,
+                    )
+                ),
+            )*
+            DataType::Decimal => Box::new(
+                UnaryExpression::<DecimalArray, DecimalArray, _>::new(
+                    $child,
+                    $ret.clone(),
+                    decimal_abs,
+                )
+            ),
+            _ => {
+                return Err(ErrorCode::NotImplemented(format!(
+                    "Abs is not supported on {:?}",
+                    $child.return_type()
+                ), 112.into())
+                .into());
+            }
+        }
+    };
+}
+
 macro_rules! gen_neg {
     ($child:tt, $ret:tt) => {
         gen_neg_impl! {
@@ -250,6 +305,60 @@ pub fn new_unary_expr(
         (ProstType::Neg, _, _) => {
             gen_neg! { child_expr, return_type }
         }
+        (ProstType::Abs, _, _) => {
+            gen_abs_impl! { child_expr, return_type, int16, int32, int64, float32, float64 }
+        }
+        (ProstType::Ceil, _, DataType::Float64) => Box::new(
+            UnaryExpression::<F64Array, F64Array, _>::new(child_expr, return_type, ceil_f64),
+        ),
+        (ProstType::Ceil, _, DataType::Decimal) => {
+            Box::new(UnaryExpression::<DecimalArray, DecimalArray, _>::new(
+                child_expr,
+                return_type,
+                ceil_decimal,
+            ))
+        }
+        (ProstType::Floor, _, DataType::Float64) => {
+            Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+                child_expr,
+                return_type,
+                floor_f64,
+            ))
+        }
+        (ProstType::Floor, _, DataType::Decimal) => {
+            Box::new(UnaryExpression::<DecimalArray, DecimalArray, _>::new(
+                child_expr,
+                return_type,
+                floor_decimal,
+            ))
+        }
+        (ProstType::Sqrt, _, DataType::Float64) => Box::new(
+            UnaryExpression::<F64Array, F64Array, _>::new(child_expr, return_type, sqrt_f64),
+        ),
+        (ProstType::Md5, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
+            child_expr,
+            return_type,
+            md5,
+        )),
+        (ProstType::JsonbTypeof, _, _) => Box::new(UnaryBytesExpression::<JsonbArray, _>::new(
+            child_expr,
+            return_type,
+            jsonb_typeof,
+        )),
+        (ProstType::JsonbArrayLength, _, _) => {
+            Box::new(UnaryExpression::<JsonbArray, I32Array, _>::new(
+                child_expr,
+                return_type,
+                jsonb_array_length,
+            ))
+        }
+        (ProstType::ToTimestamp, DataType::Timestampz, DataType::Float64) => {
+            Box::new(UnaryExpression::<F64Array, I64Array, _>::new(
+                child_expr,
+                return_type,
+                f64_sec_to_timestampz,
+            ))
+        }
         (ProstType::PgSleep, _, DataType::Decimal) => Box::new(PgSleepExpression::new(child_expr)),
 
         (expr, ret, child) => {
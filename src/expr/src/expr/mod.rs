@@ -20,11 +20,15 @@ pub mod expr_binary_nonnull;
 pub mod expr_binary_nullable;
 mod expr_case;
 mod expr_coalesce;
+mod expr_concat_ws;
 mod expr_field;
+mod expr_gen_random_uuid;
 mod expr_in;
 mod expr_input_ref;
 mod expr_is_null;
+mod expr_jsonb_access;
 mod expr_literal;
+mod expr_regexp;
 mod expr_ternary_bytes;
 pub mod expr_unary;
 mod pg_sleep;
@@ -45,7 +49,11 @@ use risingwave_pb::expr::ExprNode;
 
 use crate::expr::build_expr_from_prost::*;
 use crate::expr::expr_coalesce::CoalesceExpression;
+use crate::expr::expr_concat_ws::ConcatWsExpression;
 use crate::expr::expr_field::FieldExpression;
+use crate::expr::expr_gen_random_uuid::GenRandomUuidExpression;
+use crate::expr::expr_jsonb_access::JsonbAccessExpression;
+use crate::expr::expr_regexp::RegexpExpression;
 
 pub type ExpressionRef = Arc<dyn Expression>;
 
@@ -75,12 +83,26 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
 
     match prost.get_expr_type()? {
         Cast | Upper | Lower | Not | PgSleep | IsTrue | IsNotTrue | IsFalse | IsNotFalse
-        | IsNull | IsNotNull | Neg | Ascii => build_unary_expr_prost(prost),
+        | IsNull | IsNotNull | Neg | Ascii | Abs | Ceil | Floor | Sqrt | Md5 | JsonbTypeof
+        | JsonbArrayLength => build_unary_expr_prost(prost),
         Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual | Add
         | Subtract | Multiply | Divide | Modulus | Extract | RoundDigit | TumbleStart
-        | Position => build_binary_expr_prost(prost),
+        | Position | Pow | DateTrunc | ToChar | Left | Right | JsonbContains | Encode | Decode => {
+            build_binary_expr_prost(prost)
+        }
         StreamNullByRowCount | And | Or => build_nullable_binary_expr_prost(prost),
         Coalesce => CoalesceExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
+        ConcatWs => ConcatWsExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
+        SplitPart => build_split_part_expr(prost),
+        Lpad => build_lpad_expr(prost),
+        Rpad => build_rpad_expr(prost),
+        ToTimestamp => build_to_timestamp_expr(prost),
+        RegexpIsMatch | SimilarTo | RegexpMatch | RegexpReplace | RegexpSplitToArray => {
+            RegexpExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression)
+        }
+        JsonbAccessInner | JsonbAccessStr | JsonbAccessPath | JsonbAccessPathStr => {
+            JsonbAccessExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression)
+        }
         Substr => build_substr_expr(prost),
         Length => build_length_expr(prost),
         Replace => build_replace_expr(prost),
@@ -94,6 +116,9 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         Translate => build_translate_expr(prost),
         In => build_in_expr(prost),
         Field => FieldExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
+        GenRandomUuid => {
+            GenRandomUuidExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression)
+        }
         _ => Err(InternalError(format!(
             "Unsupported expression type: {:?}",
             prost.get_expr_type()
@@ -17,7 +17,7 @@ use risingwave_pb::data::data_type::TypeName;
 use risingwave_pb::data::DataType;
 use risingwave_pb::expr::expr_node::Type::InputRef;
 use risingwave_pb::expr::expr_node::{RexNode, Type};
-use risingwave_pb::expr::{ExprNode, FunctionCall, InputRefExpr};
+use risingwave_pb::expr::{ConstantValue, ExprNode, FunctionCall, InputRefExpr};
 
 pub fn make_expression(kind: Type, rets: &[TypeName], indices: &[i32]) -> ExprNode {
     let mut exprs = Vec::new();
@@ -46,3 +46,16 @@ pub fn make_input_ref(idx: i32, ret: TypeName) -> ExprNode {
         rex_node: Some(RexNode::InputRef(InputRefExpr { column_idx: idx })),
     }
 }
+
+pub fn make_string_literal(s: &str) -> ExprNode {
+    ExprNode {
+        expr_type: Type::ConstantValue as i32,
+        return_type: Some(DataType {
+            type_name: TypeName::Varchar as i32,
+            ..Default::default()
+        }),
+        rex_node: Some(RexNode::Constant(ConstantValue {
+            body: s.as_bytes().to_vec(),
+        })),
+    }
+}
@@ -18,10 +18,14 @@ use std::convert::TryInto;
 use std::fmt::Debug;
 
 use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
-use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub};
-use risingwave_common::error::ErrorCode::{InternalError, NumericValueOutOfRange};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Signed};
+use risingwave_common::error::ErrorCode::{
+    InternalError, InvalidInputSyntax, NumericValueOutOfRange,
+};
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::{IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper};
+use risingwave_common::types::{
+    Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, OrderedF64,
+};
 
 use super::cast::date_to_timestamp;
 
@@ -98,6 +102,49 @@ pub fn general_neg<T1: CheckedNeg>(expr: T1) -> Result<T1> {
     }
 }
 
+#[inline(always)]
+pub fn general_abs<T1: Signed + CheckedNeg>(expr: T1) -> Result<T1> {
+    if expr.is_negative() {
+        general_neg(expr)
+    } else {
+        Ok(expr)
+    }
+}
+
+#[inline(always)]
+pub fn decimal_abs(decimal: Decimal) -> Result<Decimal> {
+    Ok(decimal.abs())
+}
+
+#[inline(always)]
+pub fn pow_f64(l: OrderedF64, r: OrderedF64) -> Result<OrderedF64> {
+    if l.0 == 0.0 && r.0 < 0.0 {
+        return Err(RwError::from(InvalidInputSyntax(
+            "zero raised to a negative power is undefined".to_string(),
+        )));
+    }
+    if l.0 < 0.0 && r.0.fract() != 0.0 {
+        return Err(RwError::from(InvalidInputSyntax(
+            "a negative number raised to a non-integer power yields a complex result".to_string(),
+        )));
+    }
+    let res = l.0.powf(r.0);
+    if res.is_infinite() && l.0.is_finite() && r.0.is_finite() {
+        return Err(RwError::from(NumericValueOutOfRange));
+    }
+    Ok(res.into())
+}
+
+#[inline(always)]
+pub fn sqrt_f64(expr: OrderedF64) -> Result<OrderedF64> {
+    if expr.0 < 0.0 {
+        return Err(RwError::from(InvalidInputSyntax(
+            "cannot take square root of a negative number".to_string(),
+        )));
+    }
+    Ok(expr.0.sqrt().into())
+}
+
 #[inline(always)]
 pub fn general_atm<T1, T2, T3, F>(l: T1, r: T2, atm: F) -> Result<T3>
 where
@@ -260,9 +307,9 @@ where
 mod tests {
     use std::str::FromStr;
 
-    use risingwave_common::types::Decimal;
+    use risingwave_common::types::{Decimal, OrderedF64};
 
-    use crate::vector_op::arithmetic_op::general_add;
+    use crate::vector_op::arithmetic_op::*;
 
     #[test]
     fn test() {
@@ -271,4 +318,26 @@ mod tests {
             Decimal::from_str("2").unwrap()
         );
     }
+
+    #[test]
+    fn test_math_functions() {
+        assert_eq!(general_abs(-1i32).unwrap(), 1);
+        assert!(general_abs(i32::MIN).is_err());
+        assert_eq!(
+            decimal_abs(Decimal::from_str("-1.5").unwrap()).unwrap(),
+            Decimal::from_str("1.5").unwrap()
+        );
+        assert_eq!(
+            pow_f64(OrderedF64::from(2.0), OrderedF64::from(10.0)).unwrap(),
+            OrderedF64::from(1024.0)
+        );
+        assert!(pow_f64(OrderedF64::from(0.0), OrderedF64::from(-1.0)).is_err());
+        assert!(pow_f64(OrderedF64::from(-8.0), OrderedF64::from(0.5)).is_err());
+        assert!(pow_f64(OrderedF64::from(10.0), OrderedF64::from(1000.0)).is_err());
+        assert_eq!(
+            sqrt_f64(OrderedF64::from(2.25)).unwrap(),
+            OrderedF64::from(1.5)
+        );
+        assert!(sqrt_f64(OrderedF64::from(-1.0)).is_err());
+    }
 }
@@ -213,6 +213,11 @@ pub fn bool_to_str(input: bool) -> Result<String> {
     }
 }
 
+#[inline(always)]
+pub fn general_to_string<T: std::fmt::Display>(elem: T) -> Result<String> {
+    Ok(elem.to_string())
+}
+
 pub fn int32_to_bool(input: i32) -> Result<bool> {
     Ok(input != 0)
 }
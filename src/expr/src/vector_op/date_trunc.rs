@@ -0,0 +1,98 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Datelike, Duration, NaiveDate, Timelike};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// Truncates `timestamp` to the precision given by `field`, e.g. `hour` or `month`.
+#[inline(always)]
+pub fn date_trunc_timestamp(
+    field: &str,
+    timestamp: NaiveDateTimeWrapper,
+) -> Result<NaiveDateTimeWrapper> {
+    let ts = timestamp.0;
+    let date = ts.date();
+    let year = date.year();
+    let truncated = match field.to_lowercase().as_str() {
+        "microseconds" => ts.with_nanosecond(ts.nanosecond() / 1_000 * 1_000).unwrap(),
+        "milliseconds" => ts
+            .with_nanosecond(ts.nanosecond() / 1_000_000 * 1_000_000)
+            .unwrap(),
+        "second" => ts.with_nanosecond(0).unwrap(),
+        "minute" => date.and_hms(ts.hour(), ts.minute(), 0),
+        "hour" => date.and_hms(ts.hour(), 0, 0),
+        "day" => date.and_hms(0, 0, 0),
+        // Weeks start on Monday.
+        "week" => {
+            (date - Duration::days(date.weekday().num_days_from_monday() as i64)).and_hms(0, 0, 0)
+        }
+        "month" => NaiveDate::from_ymd(year, date.month(), 1).and_hms(0, 0, 0),
+        "quarter" => NaiveDate::from_ymd(year, (date.month() - 1) / 3 * 3 + 1, 1).and_hms(0, 0, 0),
+        "year" => NaiveDate::from_ymd(year, 1, 1).and_hms(0, 0, 0),
+        "decade" => NaiveDate::from_ymd(year.div_euclid(10) * 10, 1, 1).and_hms(0, 0, 0),
+        // Centuries and millenniums start from year 1, e.g. the 21st century starts from 2001.
+        "century" => {
+            NaiveDate::from_ymd((year - 1).div_euclid(100) * 100 + 1, 1, 1).and_hms(0, 0, 0)
+        }
+        "millennium" => {
+            NaiveDate::from_ymd((year - 1).div_euclid(1000) * 1000 + 1, 1, 1).and_hms(0, 0, 0)
+        }
+        _ => {
+            return Err(RwError::from(InvalidInputSyntax(format!(
+                "timestamp units \"{}\" not recognized",
+                field
+            ))))
+        }
+    };
+    Ok(NaiveDateTimeWrapper::new(truncated))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    #[test]
+    fn test_date_trunc() {
+        let ts = NaiveDateTimeWrapper::new(
+            NaiveDateTime::parse_from_str("2001-02-16 20:38:40.123456", "%Y-%m-%d %H:%M:%S%.f")
+                .unwrap(),
+        );
+        let cases = [
+            ("microseconds", "2001-02-16 20:38:40.123456"),
+            ("milliseconds", "2001-02-16 20:38:40.123"),
+            ("second", "2001-02-16 20:38:40"),
+            ("MINUTE", "2001-02-16 20:38:00"),
+            ("hour", "2001-02-16 20:00:00"),
+            ("day", "2001-02-16 00:00:00"),
+            ("week", "2001-02-12 00:00:00"),
+            ("month", "2001-02-01 00:00:00"),
+            ("quarter", "2001-01-01 00:00:00"),
+            ("year", "2001-01-01 00:00:00"),
+            ("decade", "2000-01-01 00:00:00"),
+            ("century", "2001-01-01 00:00:00"),
+            ("millennium", "2001-01-01 00:00:00"),
+        ];
+        for (field, expected) in cases {
+            assert_eq!(
+                date_trunc_timestamp(field, ts).unwrap().0.to_string(),
+                expected
+            );
+        }
+        assert!(date_trunc_timestamp("fortnight", ts).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use risingwave_common::array::{ByteaRef, ByteaValue, BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+
+/// Postgres wraps base64 output every 76 characters.
+const BASE64_LINE_LENGTH: usize = 76;
+
+fn unrecognized_encoding(format: &str) -> RwError {
+    ErrorCode::InvalidInputSyntax(format!("unrecognized encoding: \"{}\"", format)).into()
+}
+
+/// Encodes binary data into a textual representation. Supported formats are `hex`, `base64` and
+/// `escape`.
+pub fn encode(data: ByteaRef<'_>, format: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let data = data.as_bytes();
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => writer.write_ref(&hex::encode(data)),
+        "base64" => {
+            let encoded = base64::encode(data);
+            let mut writer = writer.begin();
+            for (i, chunk) in encoded.as_bytes().chunks(BASE64_LINE_LENGTH).enumerate() {
+                if i > 0 {
+                    writer.write_ref("\n")?;
+                }
+                // SAFETY: base64 output is always ASCII.
+                writer.write_ref(unsafe { std::str::from_utf8_unchecked(chunk) })?;
+            }
+            writer.finish()
+        }
+        "escape" => {
+            let mut escaped = String::with_capacity(data.len());
+            for &b in data {
+                match b {
+                    b'\\' => escaped.push_str("\\\\"),
+                    0 | 0x80..=0xff => write!(escaped, "\\{:03o}", b).unwrap(),
+                    _ => escaped.push(b as char),
+                }
+            }
+            writer.write_ref(&escaped)
+        }
+        _ => Err(unrecognized_encoding(format)),
+    }
+}
+
+/// Decodes binary data from a textual representation produced by [`encode`].
+pub fn decode(data: &str, format: &str) -> Result<ByteaValue> {
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => ByteaValue::from_hex_format(data),
+        "base64" => {
+            let stripped: String = data.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+            base64::decode(stripped).map(Into::into).map_err(|e| {
+                ErrorCode::InvalidInputSyntax(format!("invalid base64 input: {}", e)).into()
+            })
+        }
+        "escape" => ByteaValue::from_escape_format(data),
+        _ => Err(unrecognized_encoding(format)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    fn encode_to_string(data: &[u8], format: &str) -> Result<String> {
+        let builder = Utf8ArrayBuilder::new(1)?;
+        let guard = encode(ByteaRef::new(data), format, builder.writer())?;
+        let array = guard.into_inner().finish()?;
+        Ok(array.value_at(0).unwrap().to_string())
+    }
+
+    #[test]
+    fn test_encode_decode() -> Result<()> {
+        let data = b"\x00ab\\\xff";
+        for (format, expected) in [
+            ("hex", "0061625cff".to_string()),
+            ("base64", "AGFiXP8=".to_string()),
+            ("escape", "\\000ab\\\\\\377".to_string()),
+        ] {
+            let encoded = encode_to_string(data, format)?;
+            assert_eq!(encoded, expected);
+            assert_eq!(decode(&encoded, format)?.as_bytes(), data);
+        }
+
+        let long = encode_to_string(&[0; 60], "base64")?;
+        assert_eq!(long.lines().map(str::len).collect::<Vec<_>>(), vec![76, 4]);
+        assert_eq!(decode(&long, "base64")?.as_bytes(), &[0; 60]);
+
+        assert!(encode_to_string(data, "rot13").is_err());
+        assert!(decode("zz", "hex").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions and operators on `jsonb`. The semantics follow
+//! [PG](https://www.postgresql.org/docs/current/functions-json.html).
+
+use risingwave_common::array::{BytesGuard, BytesWriter, JsonbRef};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use serde_json::Value;
+
+/// Gets the field of an object with the given key, for `jsonb -> text`.
+pub fn jsonb_access_field<'a>(v: &'a Value, key: &str) -> Option<&'a Value> {
+    v.as_object()?.get(key)
+}
+
+/// Gets the element of an array at the given index, for `jsonb -> int`. Negative indexes count
+/// from the end of the array.
+pub fn jsonb_access_index(v: &Value, index: i32) -> Option<&Value> {
+    let array = v.as_array()?;
+    let index = if index < 0 {
+        array.len().checked_sub(index.unsigned_abs() as usize)?
+    } else {
+        index as usize
+    };
+    array.get(index)
+}
+
+/// Gets the value at the given path, for `jsonb #> text[]`. Each step is used as a key when the
+/// current value is an object, or as an index when it's an array.
+pub fn jsonb_access_path<'a>(v: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter().try_fold(v, |v, step| match v {
+        Value::Object(object) => object.get(step),
+        Value::Array(_) => jsonb_access_index(v, step.parse().ok()?),
+        _ => None,
+    })
+}
+
+/// Converts the value to text for the `->>` and `#>>` operators. Strings are unquoted and JSON
+/// `null` becomes SQL `NULL`.
+pub fn jsonb_to_text(v: &Value) -> Option<String> {
+    match v {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        v => Some(v.to_string()),
+    }
+}
+
+/// Parses a path in the form of a text array literal, e.g. `{a,1,"b c"}`.
+pub fn parse_jsonb_path(path: &str) -> Result<Vec<String>> {
+    let malformed = || {
+        RwError::from(InvalidInputSyntax(format!(
+            "malformed array literal: \"{}\"",
+            path
+        )))
+    };
+    let inner = path
+        .trim()
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(malformed)?;
+    if inner.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut elems = vec![];
+    let mut chars = inner.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut elem = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next().ok_or_else(malformed)? {
+                    '"' => break,
+                    '\\' => elem.push(chars.next().ok_or_else(malformed)?),
+                    c => elem.push(c),
+                }
+            }
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        } else {
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                if c == '"' || c == '{' || c == '}' {
+                    return Err(malformed());
+                }
+                elem.push(c);
+            }
+            elem.truncate(elem.trim_end().len());
+            if elem.is_empty() {
+                return Err(malformed());
+            }
+        }
+        elems.push(elem);
+        match chars.next() {
+            Some(',') => continue,
+            None => break,
+            Some(_) => return Err(malformed()),
+        }
+    }
+    Ok(elems)
+}
+
+/// Checks whether `left` contains `right`, for `jsonb @> jsonb`.
+pub fn jsonb_contains(left: JsonbRef<'_>, right: JsonbRef<'_>) -> Result<bool> {
+    let (left, right) = (left.to_serde(), right.to_serde());
+    Ok(match (&left, &right) {
+        // As a special exception, a top-level array contains a primitive value that is one of
+        // its elements.
+        (Value::Array(array), primitive) if !primitive.is_array() && !primitive.is_object() => {
+            array.contains(primitive)
+        }
+        (left, right) => value_contains(left, right),
+    })
+}
+
+fn value_contains(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => right
+            .iter()
+            .all(|(key, r)| left.get(key).map(|l| value_contains(l, r)).unwrap_or(false)),
+        (Value::Array(left), Value::Array(right)) => right
+            .iter()
+            .all(|r| left.iter().any(|l| value_contains(l, r))),
+        (left, right) => left == right,
+    }
+}
+
+/// Returns the type of the outermost value as a text string.
+pub fn jsonb_typeof(v: JsonbRef<'_>, writer: BytesWriter) -> Result<BytesGuard> {
+    let type_name = match v.to_serde() {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    };
+    writer.write_ref(type_name)
+}
+
+/// Returns the number of elements in the outermost array.
+pub fn jsonb_array_length(v: JsonbRef<'_>) -> Result<i32> {
+    match v.to_serde() {
+        Value::Array(array) => Ok(array.len() as i32),
+        Value::Object(_) => {
+            Err(InvalidInputSyntax("cannot get array length of a non-array".to_string()).into())
+        }
+        _ => Err(InvalidInputSyntax("cannot get array length of a scalar".to_string()).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::JsonbValue;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_jsonb_access() {
+        let v = json!({"a": [1, {"b": "x"}, null], "c": 2.5});
+        assert_eq!(jsonb_access_field(&v, "c"), Some(&json!(2.5)));
+        assert_eq!(jsonb_access_field(&v, "d"), None);
+        let a = jsonb_access_field(&v, "a").unwrap();
+        assert_eq!(jsonb_access_index(a, 0), Some(&json!(1)));
+        assert_eq!(jsonb_access_index(a, -1), Some(&json!(null)));
+        assert_eq!(jsonb_access_index(a, -4), None);
+        assert_eq!(jsonb_access_index(&v, 0), None);
+
+        let path = parse_jsonb_path("{a, 1, \"b\"}").unwrap();
+        assert_eq!(jsonb_access_path(&v, &path), Some(&json!("x")));
+        assert_eq!(jsonb_access_path(&v, &[]), Some(&v));
+        assert_eq!(
+            jsonb_access_path(&v, &["c".to_string(), "d".to_string()]),
+            None
+        );
+
+        assert_eq!(jsonb_to_text(&json!("x")), Some("x".to_string()));
+        assert_eq!(jsonb_to_text(&json!([1])), Some("[1]".to_string()));
+        assert_eq!(jsonb_to_text(&json!(null)), None);
+    }
+
+    #[test]
+    fn test_parse_jsonb_path() {
+        assert_eq!(parse_jsonb_path("{}").unwrap(), Vec::<String>::new());
+        assert_eq!(
+            parse_jsonb_path(r#"{"a,b", c d ,"\"e"}"#).unwrap(),
+            vec!["a,b", "c d", "\"e"]
+        );
+        for malformed in ["a", "{a,}", "{\"a}", "{a\"b}"] {
+            assert!(parse_jsonb_path(malformed).is_err(), "{}", malformed);
+        }
+    }
+
+    #[test]
+    fn test_jsonb_contains() {
+        let contains = |l: &str, r: &str| {
+            let l: JsonbValue = l.parse().unwrap();
+            let r: JsonbValue = r.parse().unwrap();
+            jsonb_contains((&l).into(), (&r).into()).unwrap()
+        };
+        assert!(contains(r#"{"a": 1, "b": [1, 2]}"#, r#"{"b": [2]}"#));
+        assert!(!contains(r#"{"a": 1}"#, r#"{"a": 2}"#));
+        assert!(contains("[1, 2, [3, 4]]", "[[3], 1]"));
+        assert!(contains(r#"["foo", "bar"]"#, r#""foo""#));
+        assert!(!contains("[[1, 2]]", "[1]"));
+        assert!(!contains(r#"{"a": [1]}"#, r#"{"a": 1}"#));
+    }
+
+    #[test]
+    fn test_jsonb_array_length() {
+        let v: JsonbValue = "[1, [2, 3]]".parse().unwrap();
+        assert_eq!(jsonb_array_length((&v).into()).unwrap(), 2);
+        let v: JsonbValue = r#"{"a": 1}"#.parse().unwrap();
+        assert!(jsonb_array_length((&v).into()).is_err());
+    }
+}
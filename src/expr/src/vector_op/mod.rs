@@ -18,16 +18,28 @@ pub mod ascii;
 pub mod cast;
 pub mod cmp;
 pub mod conjunction;
+pub mod date_trunc;
+pub mod encode;
 pub mod extract;
+pub mod jsonb;
+pub mod left;
 pub mod length;
 pub mod like;
 pub mod lower;
+pub mod lpad;
 pub mod ltrim;
+pub mod md5;
 pub mod position;
+pub mod regexp;
 pub mod replace;
+pub mod right;
 pub mod round;
+pub mod rpad;
 pub mod rtrim;
+pub mod split_part;
 pub mod substr;
+pub mod to_char;
+pub mod to_timestamp;
 pub mod translate;
 pub mod trim;
 pub mod tumble;
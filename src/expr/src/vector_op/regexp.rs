@@ -0,0 +1,262 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use regex::{Regex, RegexBuilder};
+use risingwave_common::array::ListValue;
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::ScalarImpl;
+
+/// Options given by the `flags` argument of the regexp functions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegexpOptions {
+    pub case_insensitive: bool,
+    /// Replace all matches instead of the first one. Only meaningful for `regexp_replace`.
+    pub global: bool,
+}
+
+impl RegexpOptions {
+    /// Parses flags like `'gi'`. Later flags override earlier ones, as in PG.
+    ///
+    /// See <https://www.postgresql.org/docs/current/functions-matching.html#POSIX-EMBEDDED-OPTIONS-TABLE>.
+    pub fn parse(flags: &str) -> Result<Self> {
+        let mut options = Self::default();
+        for flag in flags.chars() {
+            match flag {
+                'i' => options.case_insensitive = true,
+                'c' => options.case_insensitive = false,
+                'g' => options.global = true,
+                _ => {
+                    return Err(RwError::from(InvalidInputSyntax(format!(
+                        "invalid regular expression option: \"{}\"",
+                        flag
+                    ))))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Compiles a POSIX-style regular expression.
+pub fn build_regex(pattern: &str, options: RegexpOptions) -> Result<Regex> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(options.case_insensitive)
+        .build()
+        .map_err(|e| {
+            RwError::from(InvalidInputSyntax(format!(
+                "invalid regular expression: {}",
+                e
+            )))
+        })
+}
+
+/// Translates a `SIMILAR TO` pattern into an equivalent regular expression. `%` and `_` are the
+/// SQL wildcards, `\` escapes the next character, and `.`, `^` and `$` lose their regex meaning.
+/// The pattern must match the whole string.
+pub fn similar_to_regex(pattern: &str) -> String {
+    let mut regex = String::with_capacity(pattern.len() + 8);
+    regex.push_str("^(?:");
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '\\' => match chars.next() {
+                Some(escaped) => regex.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4]))),
+                None => regex.push_str(r"\\"),
+            },
+            '.' | '^' | '$' => {
+                regex.push('\\');
+                regex.push(c);
+            }
+            _ => regex.push(c),
+        }
+    }
+    regex.push_str(")$");
+    regex
+}
+
+#[inline(always)]
+pub fn regexp_is_match(re: &Regex, text: &str) -> bool {
+    re.is_match(text)
+}
+
+/// Returns the captured substrings of the first match, or the whole match if the pattern has no
+/// capture groups. Returns `None` if there is no match.
+pub fn regexp_match(re: &Regex, text: &str) -> Option<ListValue> {
+    let captures = re.captures(text)?;
+    let values = if captures.len() == 1 {
+        vec![captures
+            .get(0)
+            .map(|m| ScalarImpl::Utf8(m.as_str().to_string()))]
+    } else {
+        captures
+            .iter()
+            .skip(1)
+            .map(|m| m.map(|m| ScalarImpl::Utf8(m.as_str().to_string())))
+            .collect()
+    };
+    Some(ListValue::new(values))
+}
+
+/// Replaces the first match, or all of them if `global` is set. `replacement` uses the PG syntax:
+/// `\1` to `\9` refer to capture groups and `\&` to the whole match.
+pub fn regexp_replace(re: &Regex, text: &str, replacement: &str, global: bool) -> String {
+    let replacement = pg_replacement_to_rust(replacement);
+    if global {
+        re.replace_all(text, replacement.as_str()).into_owned()
+    } else {
+        re.replace(text, replacement.as_str()).into_owned()
+    }
+}
+
+fn pg_replacement_to_rust(replacement: &str) -> String {
+    let mut result = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(d @ '1'..='9') => {
+                    result.push_str("${");
+                    result.push(d);
+                    result.push('}');
+                }
+                Some('&') => result.push_str("${0}"),
+                Some('$') => result.push_str("$$"),
+                Some(other) => result.push(other),
+                None => result.push('\\'),
+            },
+            '$' => result.push_str("$$"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Splits `text` using the pattern as the delimiter.
+pub fn regexp_split_to_array(re: &Regex, text: &str) -> ListValue {
+    ListValue::new(
+        re.split(text)
+            .map(|s| Some(ScalarImpl::Utf8(s.to_string())))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf8_list(values: &[Option<&str>]) -> ListValue {
+        ListValue::new(
+            values
+                .iter()
+                .map(|v| v.map(|s| ScalarImpl::Utf8(s.to_string())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_regexp_options() {
+        assert_eq!(
+            RegexpOptions::parse("gi").unwrap(),
+            RegexpOptions {
+                case_insensitive: true,
+                global: true
+            }
+        );
+        assert!(!RegexpOptions::parse("ic").unwrap().case_insensitive);
+        assert!(RegexpOptions::parse("x").is_err());
+    }
+
+    #[test]
+    fn test_regexp_is_match() {
+        let re = build_regex("^https?://[^/]+/cart", RegexpOptions::default()).unwrap();
+        assert!(regexp_is_match(&re, "https://shop.com/cart/1"));
+        assert!(!regexp_is_match(&re, "https://shop.com/item/1"));
+
+        let options = RegexpOptions::parse("i").unwrap();
+        let re = build_regex("cart", options).unwrap();
+        assert!(regexp_is_match(&re, "/CART"));
+
+        assert!(build_regex("(", RegexpOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_similar_to() {
+        let cases = [
+            ("abc", "abc", true),
+            ("abc", "a", false),
+            ("abc", "%(b|d)%", true),
+            ("abc", "(b|c)%", false),
+            ("abc", "_b_", true),
+            ("a.c", "a.c", true),
+            ("abc", "a.c", false),
+            ("a%c", r"a\%c", true),
+            ("abbc", "a%c", true),
+        ];
+        for (text, pattern, expected) in cases {
+            let re = build_regex(&similar_to_regex(pattern), RegexpOptions::default()).unwrap();
+            assert_eq!(
+                regexp_is_match(&re, text),
+                expected,
+                "text={}, pattern={}",
+                text,
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_regexp_match() {
+        let re = build_regex("(bar)(beque)", RegexpOptions::default()).unwrap();
+        assert_eq!(
+            regexp_match(&re, "foobarbequebaz"),
+            Some(utf8_list(&[Some("bar"), Some("beque")]))
+        );
+        let re = build_regex("barbeque", RegexpOptions::default()).unwrap();
+        assert_eq!(
+            regexp_match(&re, "foobarbequebaz"),
+            Some(utf8_list(&[Some("barbeque")]))
+        );
+        assert_eq!(regexp_match(&re, "foo"), None);
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let re = build_regex("b(..)", RegexpOptions::default()).unwrap();
+        assert_eq!(
+            regexp_replace(&re, "foobarbaz", r"X\1Y", false),
+            "fooXarYbaz"
+        );
+        assert_eq!(
+            regexp_replace(&re, "foobarbaz", r"X\1Y", true),
+            "fooXarYXazY"
+        );
+        assert_eq!(
+            regexp_replace(&re, "foobarbaz", r"[\&]", false),
+            "foo[bar]baz"
+        );
+        assert_eq!(regexp_replace(&re, "foobarbaz", "$1", false), "foo$1baz");
+    }
+
+    #[test]
+    fn test_regexp_split_to_array() {
+        let re = build_regex(r"\s+", RegexpOptions::default()).unwrap();
+        assert_eq!(
+            regexp_split_to_array(&re, "hello   world x"),
+            utf8_list(&[Some("hello"), Some("world"), Some("x")])
+        );
+    }
+}
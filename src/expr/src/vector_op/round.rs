@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use risingwave_common::error::Result;
-use risingwave_common::types::Decimal;
+use risingwave_common::types::{Decimal, OrderedF64};
 
 #[inline(always)]
 pub fn round_digits<D: Into<i32>>(input: Decimal, digits: D) -> Result<Decimal> {
@@ -25,13 +25,33 @@ pub fn round_digits<D: Into<i32>>(input: Decimal, digits: D) -> Result<Decimal>
     }
 }
 
+#[inline(always)]
+pub fn ceil_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.ceil().into())
+}
+
+#[inline(always)]
+pub fn ceil_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(input.ceil())
+}
+
+#[inline(always)]
+pub fn floor_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.floor().into())
+}
+
+#[inline(always)]
+pub fn floor_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(input.floor())
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use risingwave_common::types::Decimal;
+    use risingwave_common::types::{Decimal, OrderedF64};
 
-    use crate::vector_op::round::round_digits;
+    use crate::vector_op::round::*;
 
     fn do_test(input: &str, digits: i32, expected_output: &str) {
         let v = Decimal::from_str(input).unwrap();
@@ -46,4 +66,19 @@ mod tests {
         do_test("84818.15", 1, "84818.2");
         do_test("21.372736", -1, "0");
     }
+
+    #[test]
+    fn test_ceil_floor() {
+        let v = Decimal::from_str("-21.5").unwrap();
+        assert_eq!(ceil_decimal(v).unwrap().to_string(), "-21");
+        assert_eq!(floor_decimal(v).unwrap().to_string(), "-22");
+        assert_eq!(
+            ceil_f64(OrderedF64::from(1.2)).unwrap(),
+            OrderedF64::from(2.0)
+        );
+        assert_eq!(
+            floor_f64(OrderedF64::from(1.8)).unwrap(),
+            OrderedF64::from(1.0)
+        );
+    }
 }
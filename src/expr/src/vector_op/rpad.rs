@@ -0,0 +1,60 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter;
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+
+/// Fills up `s` to `length` characters by appending `fill` (repeated as needed). If `s` is already
+/// longer than `length`, it is truncated.
+#[inline(always)]
+pub fn rpad(s: &str, length: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let length = length.max(0) as usize;
+    let s_len = s.chars().count();
+    if s_len >= length || fill.is_empty() {
+        return writer.write_from_char_iter(s.chars().take(length));
+    }
+    let padding = iter::repeat(fill.chars()).flatten().take(length - s_len);
+    writer.write_from_char_iter(s.chars().chain(padding))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_rpad() -> Result<()> {
+        let cases = [
+            ("hi", 5, "xy", "hixyx"),
+            ("hi", 5, " ", "hi   "),
+            ("hello", 2, "xy", "he"),
+            ("hi", 5, "", "hi"),
+            ("hi", -1, "xy", ""),
+            ("床前", 4, "月光", "床前月光"),
+        ];
+
+        for (s, length, fill, expected) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let writer = builder.writer();
+            let guard = rpad(s, length, fill, writer)?;
+            let array = guard.into_inner().finish()?;
+            let v = array.value_at(0).unwrap();
+            assert_eq!(v, expected);
+        }
+        Ok(())
+    }
+}
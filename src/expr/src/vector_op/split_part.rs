@@ -0,0 +1,68 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result};
+
+/// Splits `s` at occurrences of `delimiter` and returns the `n`-th field (counting from one). When
+/// `n` is negative, returns the `|n|`-th field counting from the end.
+#[inline(always)]
+pub fn split_part(s: &str, delimiter: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    if n == 0 {
+        return Err(
+            ErrorCode::InvalidInputSyntax("field position must not be zero".to_string()).into(),
+        );
+    }
+    let field = if delimiter.is_empty() {
+        // An empty delimiter leaves the string unsplit.
+        (n == 1 || n == -1).then(|| s)
+    } else if n > 0 {
+        s.split(delimiter).nth(n as usize - 1)
+    } else {
+        s.rsplit(delimiter).nth(n.unsigned_abs() as usize - 1)
+    };
+    writer.write_ref(field.unwrap_or(""))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_split_part() -> Result<()> {
+        let cases = [
+            ("abc~@~def~@~ghi", "~@~", 2, "def"),
+            ("abc~@~def~@~ghi", "~@~", -1, "ghi"),
+            ("abc~@~def~@~ghi", "~@~", 4, ""),
+            ("abc,def", "", 1, "abc,def"),
+            ("abc,def", "", 2, ""),
+            ("床前,明月,光", ",", 2, "明月"),
+        ];
+
+        for (s, delimiter, n, expected) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let writer = builder.writer();
+            let guard = split_part(s, delimiter, n, writer)?;
+            let array = guard.into_inner().finish()?;
+            let v = array.value_at(0).unwrap();
+            assert_eq!(v, expected);
+        }
+
+        let builder = Utf8ArrayBuilder::new(1)?;
+        assert!(split_part("abc", ",", 0, builder.writer()).is_err());
+        Ok(())
+    }
+}
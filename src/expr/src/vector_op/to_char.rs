@@ -0,0 +1,116 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// Template patterns of PostgreSQL and their `chrono` equivalents. Longer patterns come first so
+/// that they take precedence over their prefixes, e.g. `DDD` over `DD`.
+///
+/// See <https://www.postgresql.org/docs/current/functions-formatting.html>.
+const PATTERNS: &[(&str, &str)] = &[
+    ("HH24", "%H"),
+    ("HH12", "%I"),
+    ("HH", "%I"),
+    ("MI", "%M"),
+    ("SS", "%S"),
+    ("MS", "%3f"),
+    ("US", "%6f"),
+    ("AM", "%p"),
+    ("PM", "%p"),
+    ("am", "%P"),
+    ("pm", "%P"),
+    ("YYYY", "%Y"),
+    ("YY", "%y"),
+    ("Month", "%B"),
+    ("Mon", "%b"),
+    ("MM", "%m"),
+    ("DDD", "%j"),
+    ("DD", "%d"),
+    ("Day", "%A"),
+    ("Dy", "%a"),
+];
+
+/// Compiles a PostgreSQL template like `YYYY-MM-DD HH24:MI:SS` to a `chrono` format string. Text
+/// in double quotes and characters that are not part of a pattern are copied literally.
+pub fn compile_pattern_to_chrono(pattern: &str) -> String {
+    let mut chrono_pattern = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    'outer: while let Some(c) = rest.chars().next() {
+        if c == '"' {
+            let quoted = &rest[1..];
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            push_literal(&mut chrono_pattern, &quoted[..end]);
+            rest = quoted.get(end + 1..).unwrap_or("");
+            continue;
+        }
+        for (pg, chrono) in PATTERNS {
+            if let Some(remaining) = rest.strip_prefix(pg) {
+                chrono_pattern.push_str(chrono);
+                rest = remaining;
+                continue 'outer;
+            }
+        }
+        push_literal(&mut chrono_pattern, &rest[..c.len_utf8()]);
+        rest = &rest[c.len_utf8()..];
+    }
+    chrono_pattern
+}
+
+fn push_literal(chrono_pattern: &mut String, literal: &str) {
+    chrono_pattern.push_str(&literal.replace('%', "%%"));
+}
+
+#[inline(always)]
+pub fn to_char_timestamp(
+    data: NaiveDateTimeWrapper,
+    pattern: &str,
+    writer: BytesWriter,
+) -> Result<BytesGuard> {
+    let chrono_pattern = compile_pattern_to_chrono(pattern);
+    writer.write_ref(&data.0.format(&chrono_pattern).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_to_char() -> Result<()> {
+        let ts = NaiveDateTimeWrapper::new(
+            NaiveDateTime::parse_from_str("2002-04-20 17:31:12.66", "%Y-%m-%d %H:%M:%S%.f")
+                .unwrap(),
+        );
+        let cases = [
+            ("YYYY-MM-DD HH24:MI:SS", "2002-04-20 17:31:12"),
+            ("HH12:MI:SS.MS AM", "05:31:12.660 PM"),
+            ("Dy, DD Mon YYYY", "Sat, 20 Apr 2002"),
+            ("\"Day\" DDD, 100%", "Day 110, 100%"),
+        ];
+
+        for (pattern, expected) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let writer = builder.writer();
+            let guard = to_char_timestamp(ts, pattern, writer)?;
+            let array = guard.into_inner().finish()?;
+            let v = array.value_at(0).unwrap();
+            assert_eq!(v, expected);
+        }
+        Ok(())
+    }
+}
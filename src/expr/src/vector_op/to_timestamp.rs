@@ -0,0 +1,84 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::format::{parse, Parsed, StrftimeItems};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::OrderedF64;
+
+use super::to_char::compile_pattern_to_chrono;
+
+/// Converts seconds since the Unix epoch to a `timestamp with time zone`, i.e. microseconds since
+/// the epoch.
+#[inline(always)]
+pub fn f64_sec_to_timestampz(elem: OrderedF64) -> Result<i64> {
+    let micros = (elem.0 * 1e6).round();
+    if !micros.is_finite() || micros < i64::MIN as f64 || micros > i64::MAX as f64 {
+        return Err(RwError::from(InvalidInputSyntax(format!(
+            "timestamp out of range: \"{}\"",
+            elem
+        ))));
+    }
+    Ok(micros as i64)
+}
+
+/// Parses `s` with a PostgreSQL template like `YYYY-MM-DD HH24:MI:SS`. The time of day defaults to
+/// midnight when the template does not contain it.
+#[inline(always)]
+pub fn to_timestamp_with_pattern(s: &str, pattern: &str) -> Result<i64> {
+    let parse_error = |_| {
+        RwError::from(InvalidInputSyntax(format!(
+            "invalid value \"{}\" for template \"{}\"",
+            s, pattern
+        )))
+    };
+    let chrono_pattern = compile_pattern_to_chrono(pattern);
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, s, StrftimeItems::new(&chrono_pattern)).map_err(parse_error)?;
+    if parsed.hour_mod_12.is_none() {
+        parsed.set_hour(0).map_err(parse_error)?;
+    } else if parsed.hour_div_12.is_none() {
+        parsed.set_ampm(false).map_err(parse_error)?;
+    }
+    if parsed.minute.is_none() {
+        parsed.set_minute(0).map_err(parse_error)?;
+    }
+    let date = parsed.to_naive_date().map_err(parse_error)?;
+    let time = parsed.to_naive_time().map_err(parse_error)?;
+    let datetime = date.and_time(time);
+    Ok(datetime.timestamp() * 1_000_000 + datetime.timestamp_subsec_micros() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_timestamp() {
+        assert_eq!(
+            f64_sec_to_timestampz(OrderedF64::from(1284352323.5)).unwrap(),
+            1284352323500000
+        );
+        assert!(f64_sec_to_timestampz(OrderedF64::from(f64::INFINITY)).is_err());
+        assert_eq!(
+            to_timestamp_with_pattern("2010-09-13 04:32:03", "YYYY-MM-DD HH24:MI:SS").unwrap(),
+            1284352323000000
+        );
+        assert_eq!(
+            to_timestamp_with_pattern("13 Sep 2010", "DD Mon YYYY").unwrap(),
+            1284336000000000
+        );
+        assert!(to_timestamp_with_pattern("2010-09", "YYYY-MM-DD").is_err());
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::Display;
 
 use risingwave_common::catalog::Field;
@@ -52,6 +52,81 @@ impl Display for Clause {
     }
 }
 
+/// A set of columns merged into a single output column by `NATURAL` join or `JOIN ... USING`.
+#[derive(Debug, Clone)]
+pub struct ColumnGroup {
+    pub column_name: String,
+    /// Indices in [`BindContext::columns`] of all columns in the group.
+    pub indices: BTreeSet<usize>,
+    /// The column that always carries the merged value, i.e. the one from the preserved side of
+    /// the joins. If `None` (e.g. after a full outer join), the merged value is `COALESCE` over
+    /// all columns in the group.
+    pub non_nullable_column: Option<usize>,
+}
+
+impl ColumnGroup {
+    /// Indices of the columns an unqualified reference to the group resolves to.
+    pub fn value_indices(&self) -> Vec<usize> {
+        match self.non_nullable_column {
+            Some(index) => vec![index],
+            None => self.indices.iter().copied().collect(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ColumnGroupContext {
+    /// Mapping column index to the id of the group it belongs to.
+    pub mapping: HashMap<usize, usize>,
+    /// Mapping group id to the group. Ids are allocated in creation order.
+    pub groups: BTreeMap<usize, ColumnGroup>,
+    next_group_id: usize,
+}
+
+impl ColumnGroupContext {
+    /// Returns the group covering exactly `indices`, if there is one.
+    pub fn group_of(&self, indices: &[usize]) -> Option<&ColumnGroup> {
+        let group_id = self.mapping.get(indices.first()?)?;
+        let group = &self.groups[group_id];
+        if group.indices.len() == indices.len() && indices.iter().all(|i| group.indices.contains(i))
+        {
+            Some(group)
+        } else {
+            None
+        }
+    }
+
+    /// Merges `left` and `right` into one group named `column_name`. Each side is either a single
+    /// column or all columns of an existing group, which is replaced by the new one.
+    pub fn merge(
+        &mut self,
+        column_name: String,
+        left: &[usize],
+        right: &[usize],
+        non_nullable_column: Option<usize>,
+    ) {
+        for index in left.iter().chain(right.iter()) {
+            if let Some(group_id) = self.mapping.remove(index) {
+                self.groups.remove(&group_id);
+            }
+        }
+        let group_id = self.next_group_id;
+        self.next_group_id += 1;
+        let indices: BTreeSet<usize> = left.iter().chain(right.iter()).copied().collect();
+        for index in &indices {
+            self.mapping.insert(*index, group_id);
+        }
+        self.groups.insert(
+            group_id,
+            ColumnGroup {
+                column_name,
+                indices,
+                non_nullable_column,
+            },
+        );
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct BindContext {
     // Columns of all tables.
@@ -62,6 +137,8 @@ pub struct BindContext {
     pub range_of: HashMap<String, (usize, usize)>,
     // `clause` identifies in what clause we are binding.
     pub clause: Option<Clause>,
+    // Columns merged by `NATURAL` join or `JOIN ... USING`.
+    pub column_group_context: ColumnGroupContext,
 }
 
 impl BindContext {
@@ -76,15 +153,40 @@ impl BindContext {
         }
     }
 
+    /// Returns the indices of the columns a reference resolves to. There are several of them only
+    /// when an unqualified name refers to a [`ColumnGroup`] whose value is `COALESCE` over all its
+    /// columns.
+    pub fn get_column_binding_indices(
+        &self,
+        table_name: Option<&String>,
+        column_name: &String,
+    ) -> Result<Vec<usize>> {
+        match table_name {
+            Some(table_name) => Ok(vec![
+                self.get_index_with_table_name(column_name, table_name)?
+            ]),
+            None => self.get_indices(column_name),
+        }
+    }
+
     fn get_index(&self, column_name: &String) -> Result<usize> {
+        match self.get_indices(column_name)?[..] {
+            [index] => Ok(index),
+            _ => Err(ErrorCode::InternalError("Ambiguous column name".into()).into()),
+        }
+    }
+
+    fn get_indices(&self, column_name: &String) -> Result<Vec<usize>> {
         let columns = self
             .indexs_of
             .get(column_name)
             .ok_or_else(|| ErrorCode::ItemNotFound(format!("Invalid column: {}", column_name)))?;
-        if columns.len() > 1 {
-            Err(ErrorCode::InternalError("Ambiguous column name".into()).into())
-        } else {
-            Ok(columns[0])
+        if columns.len() == 1 {
+            return Ok(columns.clone());
+        }
+        match self.column_group_context.group_of(columns) {
+            Some(group) => Ok(group.value_indices()),
+            None => Err(ErrorCode::InternalError("Ambiguous column name".into()).into()),
         }
     }
 
@@ -119,6 +221,7 @@ impl BindContext {
             indexs_of: HashMap::new(),
             range_of: HashMap::new(),
             clause: None,
+            column_group_context: ColumnGroupContext::default(),
         }
     }
 }
@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{BinaryOperator, Expr};
 
 use crate::binder::Binder;
-use crate::expr::{ExprImpl, ExprType, FunctionCall};
+use crate::expr::{ExprImpl, ExprType, FunctionCall, Literal};
 
 impl Binder {
     pub(super) fn bind_binary_op(
@@ -43,11 +44,63 @@ impl Binder {
             BinaryOperator::Or => ExprType::Or,
             BinaryOperator::Like => ExprType::Like,
             BinaryOperator::NotLike => return self.bind_not_like(bound_left, bound_right),
+            BinaryOperator::PGRegexMatch => ExprType::RegexpIsMatch,
+            BinaryOperator::PGRegexIMatch => {
+                return Self::bind_regex_match(bound_left, bound_right, true, false)
+            }
+            BinaryOperator::PGRegexNotMatch => {
+                return Self::bind_regex_match(bound_left, bound_right, false, true)
+            }
+            BinaryOperator::PGRegexNotIMatch => {
+                return Self::bind_regex_match(bound_left, bound_right, true, true)
+            }
+            BinaryOperator::PGJsonGet => ExprType::JsonbAccessInner,
+            BinaryOperator::PGJsonGetText => ExprType::JsonbAccessStr,
+            BinaryOperator::PGJsonGetPath => ExprType::JsonbAccessPath,
+            BinaryOperator::PGJsonGetPathText => ExprType::JsonbAccessPathStr,
+            BinaryOperator::PGJsonContains => {
+                return FunctionCall::new(
+                    ExprType::JsonbContains,
+                    vec![
+                        bound_left.cast_assign(DataType::Jsonb)?,
+                        bound_right.cast_assign(DataType::Jsonb)?,
+                    ],
+                )
+            }
+            BinaryOperator::SimilarTo => ExprType::SimilarTo,
+            BinaryOperator::NotSimilarTo => {
+                return FunctionCall::new(
+                    ExprType::Not,
+                    vec![
+                        FunctionCall::new(ExprType::SimilarTo, vec![bound_left, bound_right])?
+                            .into(),
+                    ],
+                )
+            }
             _ => return Err(ErrorCode::NotImplemented(format!("{:?}", op), 112.into()).into()),
         };
         FunctionCall::new(func_type, vec![bound_left, bound_right])
     }
 
+    /// Bind `~*`, `!~` and `!~*`. Case-insensitive matching is passed as the `i` flag.
+    fn bind_regex_match(
+        left: ExprImpl,
+        right: ExprImpl,
+        case_insensitive: bool,
+        negated: bool,
+    ) -> Result<FunctionCall> {
+        let mut inputs = vec![left, right];
+        if case_insensitive {
+            inputs.push(Literal::new(Some("i".to_string().into()), DataType::Varchar).into());
+        }
+        let func_call = FunctionCall::new(ExprType::RegexpIsMatch, inputs)?;
+        if negated {
+            FunctionCall::new(ExprType::Not, vec![func_call.into()])
+        } else {
+            Ok(func_call)
+        }
+    }
+
     /// Apply a NOT on top of LIKE.
     fn bind_not_like(&mut self, left: ExprImpl, right: ExprImpl) -> Result<FunctionCall> {
         Ok(FunctionCall::new(
@@ -15,8 +15,8 @@
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_sqlparser::ast::Ident;
 
-use crate::binder::Binder;
-use crate::expr::{CorrelatedInputRef, ExprImpl, InputRef};
+use crate::binder::{BindContext, Binder};
+use crate::expr::{CorrelatedInputRef, ExprImpl, ExprType, FunctionCall, InputRef};
 
 impl Binder {
     pub fn bind_column(&mut self, idents: &[Ident]) -> Result<ExprImpl> {
@@ -32,12 +32,11 @@ impl Binder {
             }
         };
 
-        if let Ok(index) = self
+        if let Ok(indices) = self
             .context
-            .get_column_binding_index(table_name, column_name)
+            .get_column_binding_indices(table_name, column_name)
         {
-            let column = &self.context.columns[index];
-            return Ok(InputRef::new(column.index, column.field.data_type.clone()).into());
+            return Self::bind_columns_by_indices(&self.context, &indices, 0);
         }
 
         // Try to find a correlated column in `upper_contexts`, starting from the innermost context.
@@ -45,15 +44,9 @@ impl Binder {
         for (i, context) in self.upper_contexts.iter().rev().enumerate() {
             // `depth` starts from 1.
             let depth = i + 1;
-            match context.get_column_binding_index(table_name, column_name) {
-                Ok(index) => {
-                    let column = &context.columns[index];
-                    return Ok(CorrelatedInputRef::new(
-                        column.index,
-                        column.field.data_type.clone(),
-                        depth,
-                    )
-                    .into());
+            match context.get_column_binding_indices(table_name, column_name) {
+                Ok(indices) => {
+                    return Self::bind_columns_by_indices(context, &indices, depth);
                 }
                 Err(e) => {
                     err = e;
@@ -62,4 +55,31 @@ impl Binder {
         }
         Err(err)
     }
+
+    /// Binds columns of `context` at `depth` (0 for the current context). Several columns are
+    /// merged columns of a `NATURAL` join or `JOIN ... USING`, and are bound to `COALESCE` over
+    /// them.
+    pub(in crate::binder) fn bind_columns_by_indices(
+        context: &BindContext,
+        indices: &[usize],
+        depth: usize,
+    ) -> Result<ExprImpl> {
+        let mut exprs: Vec<ExprImpl> = indices
+            .iter()
+            .map(|index| {
+                let column = &context.columns[*index];
+                match depth {
+                    0 => InputRef::new(column.index, column.field.data_type.clone()).into(),
+                    _ => {
+                        CorrelatedInputRef::new(column.index, column.field.data_type.clone(), depth)
+                            .into()
+                    }
+                }
+            })
+            .collect();
+        match exprs.len() {
+            1 => Ok(exprs.pop().unwrap()),
+            _ => Ok(FunctionCall::new(ExprType::Coalesce, exprs)?.into()),
+        }
+    }
 }
@@ -20,8 +20,28 @@ use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr};
 
 use crate::binder::bind_context::Clause;
 use crate::binder::Binder;
+use crate::catalog::system_catalog::PG_CATALOG_SCHEMA_NAME;
 use crate::expr::{AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal};
 
+/// Set-returning functions, which can only be called in the FROM clause.
+const SET_RETURNING_FUNCTIONS: [&str; 2] = ["jsonb_array_elements", "jsonb_array_elements_text"];
+
+/// Reject a call to a set-returning function in an expression, with a clear error instead of
+/// treating it as an unknown function.
+fn check_set_returning_function(name: &str) -> Result<()> {
+    if SET_RETURNING_FUNCTIONS
+        .iter()
+        .any(|f| f.eq_ignore_ascii_case(name))
+    {
+        return Err(ErrorCode::NotImplemented(
+            format!("set-returning function {} outside of the FROM clause", name),
+            None.into(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
 impl Binder {
     pub(super) fn bind_function(&mut self, f: Function) -> Result<ExprImpl> {
         let mut inputs = f
@@ -31,9 +51,16 @@ impl Binder {
             .flatten_ok()
             .try_collect()?;
 
-        if f.name.0.len() == 1 {
-            let function_name = f.name.0.get(0).unwrap().value.as_str();
+        // Built-in functions live in `pg_catalog`, which is always searched first.
+        let is_builtin = match f.name.0.as_slice() {
+            [_] => true,
+            [schema, _] => schema.value == PG_CATALOG_SCHEMA_NAME,
+            _ => false,
+        };
+        if is_builtin {
+            let function_name = f.name.0.last().unwrap().value.as_str();
             let function_name = function_name.to_lowercase();
+            check_set_returning_function(&function_name)?;
             let agg_kind = match function_name.as_str() {
                 "count" => Some(AggKind::Count),
                 "sum" => Some(AggKind::Sum),
@@ -67,6 +94,68 @@ impl Binder {
                     inputs = Self::rewrite_round_args(inputs);
                     ExprType::RoundDigit
                 }
+                "concat" => {
+                    inputs = Self::rewrite_concat_to_concat_ws(inputs);
+                    ExprType::ConcatWs
+                }
+                "concat_ws" => ExprType::ConcatWs,
+                "split_part" => ExprType::SplitPart,
+                "left" => ExprType::Left,
+                "right" => ExprType::Right,
+                "lpad" => {
+                    inputs = Self::rewrite_pad_args(inputs);
+                    ExprType::Lpad
+                }
+                "rpad" => {
+                    inputs = Self::rewrite_pad_args(inputs);
+                    ExprType::Rpad
+                }
+                "md5" => ExprType::Md5,
+                "regexp_match" => ExprType::RegexpMatch,
+                "regexp_replace" => ExprType::RegexpReplace,
+                "regexp_split_to_array" => ExprType::RegexpSplitToArray,
+                "strpos" => ExprType::Position,
+                "abs" => ExprType::Abs,
+                "ceil" | "ceiling" => {
+                    inputs = Self::rewrite_ceil_floor_args(inputs);
+                    ExprType::Ceil
+                }
+                "floor" => {
+                    inputs = Self::rewrite_ceil_floor_args(inputs);
+                    ExprType::Floor
+                }
+                "power" | "pow" => {
+                    inputs = Self::cast_args_to_float64(inputs);
+                    ExprType::Pow
+                }
+                "sqrt" => {
+                    inputs = Self::cast_args_to_float64(inputs);
+                    ExprType::Sqrt
+                }
+                "mod" => ExprType::Modulus,
+                "date_trunc" => {
+                    inputs = Self::rewrite_date_trunc_args(inputs);
+                    ExprType::DateTrunc
+                }
+                "to_char" => {
+                    inputs = Self::rewrite_to_char_args(inputs);
+                    ExprType::ToChar
+                }
+                "to_timestamp" => {
+                    if inputs.len() == 1 {
+                        inputs = Self::cast_args_to_float64(inputs);
+                    }
+                    ExprType::ToTimestamp
+                }
+                "now" => {
+                    self.ensure_now_allowed()?;
+                    ExprType::Now
+                }
+                "jsonb_typeof" => ExprType::JsonbTypeof,
+                "jsonb_array_length" => ExprType::JsonbArrayLength,
+                "encode" => ExprType::Encode,
+                "decode" => ExprType::Decode,
+                "gen_random_uuid" => ExprType::GenRandomUuid,
                 _ => {
                     return Err(ErrorCode::NotImplemented(
                         format!("unsupported function: {:?}", function_name),
@@ -132,6 +221,96 @@ impl Binder {
         }
     }
 
+    /// Rewrite concat(expr1, ...) to `concat_ws('', expr1, ...)`.
+    fn rewrite_concat_to_concat_ws(mut inputs: Vec<ExprImpl>) -> Vec<ExprImpl> {
+        inputs.insert(
+            0,
+            Literal::new(Some(String::new().into()), DataType::Varchar).into(),
+        );
+        inputs
+    }
+
+    /// Rewrite lpad(str, len) and rpad(str, len) to pad with spaces, which is the default fill.
+    fn rewrite_pad_args(mut inputs: Vec<ExprImpl>) -> Vec<ExprImpl> {
+        if inputs.len() == 2 {
+            inputs.push(Literal::new(Some(" ".to_string().into()), DataType::Varchar).into());
+        }
+        inputs
+    }
+
+    /// Rewrite the arguments to be consistent with the `ceil` and `floor` signatures:
+    /// - ceil(Decimal) -> Decimal
+    /// - ceil(Float64) -> Float64
+    ///
+    /// Other numeric types are casted to Float64.
+    fn rewrite_ceil_floor_args(inputs: Vec<ExprImpl>) -> Vec<ExprImpl> {
+        inputs
+            .into_iter()
+            .map(|input| match input.return_type() {
+                DataType::Decimal => input,
+                _ => input
+                    .clone()
+                    .cast_implicit(DataType::Float64)
+                    .unwrap_or(input),
+            })
+            .collect()
+    }
+
+    fn cast_args_to_float64(inputs: Vec<ExprImpl>) -> Vec<ExprImpl> {
+        inputs
+            .into_iter()
+            .map(|input| {
+                input
+                    .clone()
+                    .cast_implicit(DataType::Float64)
+                    .unwrap_or(input)
+            })
+            .collect()
+    }
+
+    /// Rewrite date_trunc(Varchar, Date) to date_trunc(Varchar, Timestamp).
+    fn rewrite_date_trunc_args(mut inputs: Vec<ExprImpl>) -> Vec<ExprImpl> {
+        if inputs.len() == 2 {
+            let source = inputs.pop().unwrap();
+            inputs.push(
+                source
+                    .clone()
+                    .cast_implicit(DataType::Timestamp)
+                    .unwrap_or(source),
+            );
+        }
+        inputs
+    }
+
+    /// Rewrite to_char(Date, Varchar) to to_char(Timestamp, Varchar).
+    fn rewrite_to_char_args(mut inputs: Vec<ExprImpl>) -> Vec<ExprImpl> {
+        if inputs.len() == 2 {
+            let source = inputs.remove(0);
+            inputs.insert(
+                0,
+                source
+                    .clone()
+                    .cast_implicit(DataType::Timestamp)
+                    .unwrap_or(source),
+            );
+        }
+        inputs
+    }
+
+    /// `now()` is replaced with the start time of a batch query by the scheduler, see
+    /// `QueryParams::bind_plan`. In a streaming plan its value would be
+    /// frozen when the materialized view is created, so it's rejected there.
+    fn ensure_now_allowed(&self) -> Result<()> {
+        if self.in_streaming {
+            return Err(ErrorCode::NotImplemented(
+                "now() in materialized views".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
     fn ensure_aggregate_allowed(&self) -> Result<()> {
         if let Some(clause) = self.context.clause {
             if clause == Clause::Values || clause == Clause::Where {
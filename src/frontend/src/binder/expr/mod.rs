@@ -343,6 +343,9 @@ pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
         AstDataType::Timestamp(false) => DataType::Timestamp,
         AstDataType::Timestamp(true) => DataType::Timestampz,
         AstDataType::Interval => DataType::Interval,
+        AstDataType::Jsonb => DataType::Jsonb,
+        AstDataType::Bytea => DataType::Bytea,
+        AstDataType::Uuid => DataType::Uuid,
         AstDataType::Array(datatype) => DataType::List {
             datatype: Box::new(bind_data_type(datatype)?),
         },
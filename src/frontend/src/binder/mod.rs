@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risingwave_common::catalog::Field;
 use risingwave_common::error::Result;
-use risingwave_sqlparser::ast::Statement;
+use risingwave_common::session_config::SearchPath;
+use risingwave_sqlparser::ast::{Expr, Statement};
 
 pub mod bind_context;
 mod delete;
@@ -32,8 +34,9 @@ pub use delete::BoundDelete;
 pub use insert::BoundInsert;
 pub use query::BoundQuery;
 pub use relation::{
-    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundSource, BoundTableSource,
-    BoundWindowTableFunction, Relation, WindowTableFunctionKind,
+    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundJsonbArrayElementsFunction,
+    BoundSource, BoundSystemTable, BoundTableSource, BoundWindowTableFunction, Relation,
+    WindowTableFunctionKind,
 };
 pub use select::BoundSelect;
 pub use set_expr::BoundSetExpr;
@@ -41,12 +44,17 @@ pub use statement::BoundStatement;
 pub use values::BoundValues;
 
 use crate::catalog::catalog_service::CatalogReadGuard;
+use crate::expr::ExprImpl;
+use crate::session::SessionImpl;
 
 /// `Binder` binds the identifiers in AST to columns in relations
 pub struct Binder {
     // TODO: maybe we can only lock the database, but not the whole catalog.
     catalog: CatalogReadGuard,
     db_name: String,
+    /// The `search_path` and user of the session, used to resolve unqualified relation names.
+    search_path: SearchPath,
+    user_name: String,
     context: BindContext,
     /// A stack holding contexts of outer queries when binding a subquery.
     ///
@@ -54,16 +62,31 @@ pub struct Binder {
     upper_contexts: Vec<BindContext>,
 
     next_subquery_id: usize,
+
+    /// Whether the statement is bound for a streaming plan, e.g. a materialized view, where
+    /// functions evaluated at query time like `now()` are not allowed.
+    in_streaming: bool,
 }
 
 impl Binder {
-    pub fn new(catalog: CatalogReadGuard, db_name: String) -> Binder {
+    pub fn new(session: &SessionImpl) -> Binder {
         Binder {
-            catalog,
-            db_name,
+            catalog: session.env().catalog_reader().read_guard(),
+            db_name: session.database().to_string(),
+            search_path: session.config().get_search_path(),
+            user_name: session.user_name().to_string(),
             context: BindContext::new(),
             upper_contexts: vec![],
             next_subquery_id: 0,
+            in_streaming: false,
+        }
+    }
+
+    /// Create a [`Binder`] for binding the query of a streaming plan.
+    pub fn new_for_stream(session: &SessionImpl) -> Binder {
+        Binder {
+            in_streaming: true,
+            ..Self::new(session)
         }
     }
 
@@ -72,6 +95,26 @@ impl Binder {
         self.bind_statement(stmt)
     }
 
+    /// Bind a standalone expression which can only refer to `columns` of `table_name`, e.g. the
+    /// watermark expression of a source column.
+    pub fn bind_expr_on_columns(
+        &mut self,
+        columns: Vec<Field>,
+        table_name: String,
+        expr: Expr,
+    ) -> Result<ExprImpl> {
+        self.push_context();
+        let result = self
+            .bind_context(
+                columns.into_iter().map(|field| (false, field)),
+                table_name,
+                None,
+            )
+            .and_then(|_| self.bind_expr(expr));
+        self.pop_context();
+        result
+    }
+
     fn push_context(&mut self) {
         let new_context = std::mem::take(&mut self.context);
         self.upper_contexts.push(new_context);
@@ -94,8 +137,10 @@ pub mod test_utils {
     use std::sync::Arc;
 
     use parking_lot::RwLock;
+    use risingwave_common::catalog::DEFAULT_SUPER_USER;
+    use risingwave_common::session_config::SearchPath;
 
-    use super::Binder;
+    use super::{BindContext, Binder};
     use crate::catalog::catalog_service::CatalogReader;
     use crate::catalog::root_catalog::Catalog;
 
@@ -103,7 +148,15 @@ pub mod test_utils {
     pub fn mock_binder_with_catalog(catalog: Catalog, db_name: String) -> Binder {
         let catalog = Arc::new(RwLock::new(catalog));
         let catalog_reader = CatalogReader::new(catalog);
-        Binder::new(catalog_reader.read_guard(), db_name)
+        Binder {
+            catalog: catalog_reader.read_guard(),
+            db_name,
+            search_path: SearchPath::default(),
+            user_name: DEFAULT_SUPER_USER.to_string(),
+            context: BindContext::new(),
+            upper_contexts: vec![],
+            next_subquery_id: 0,
+        }
     }
     #[cfg(test)]
     pub fn mock_binder() -> Binder {
@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+use std::ops::Range;
+
+use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 use risingwave_pb::plan_common::JoinType;
 use risingwave_sqlparser::ast::{JoinConstraint, JoinOperator, TableWithJoins};
 
 use crate::binder::{Binder, Relation};
-use crate::expr::{Expr as _, ExprImpl};
+use crate::expr::{merge_expr_by_binary, Expr as _, ExprImpl, ExprType, FunctionCall};
 
 #[derive(Debug)]
 pub struct BoundJoin {
@@ -52,9 +56,12 @@ impl Binder {
     }
 
     fn bind_table_with_joins(&mut self, table: TableWithJoins) -> Result<Relation> {
+        let begin = self.context.columns.len();
         let mut root = self.bind_table_factor(table.relation)?;
         for join in table.joins {
+            let mid = self.context.columns.len();
             let right = self.bind_table_factor(join.relation)?;
+            let end = self.context.columns.len();
             let (constraint, join_type) = match join.join_operator {
                 JoinOperator::Inner(constraint) => (constraint, JoinType::Inner),
                 JoinOperator::LeftOuter(constraint) => (constraint, JoinType::LeftOuter),
@@ -63,7 +70,7 @@ impl Binder {
                 // Cross join equals to inner join with with no constraint.
                 JoinOperator::CrossJoin => (JoinConstraint::None, JoinType::Inner),
             };
-            let cond = self.bind_join_constraint(constraint)?;
+            let cond = self.bind_join_constraint(constraint, join_type, begin..mid, mid..end)?;
             let join = BoundJoin {
                 join_type,
                 left: root,
@@ -76,11 +83,24 @@ impl Binder {
         Ok(root)
     }
 
-    fn bind_join_constraint(&mut self, constraint: JoinConstraint) -> Result<ExprImpl> {
+    /// Binds the join condition. `left` and `right` are the ranges of columns in the context
+    /// belonging to the two sides of the join.
+    fn bind_join_constraint(
+        &mut self,
+        constraint: JoinConstraint,
+        join_type: JoinType,
+        left: Range<usize>,
+        right: Range<usize>,
+    ) -> Result<ExprImpl> {
         Ok(match constraint {
             JoinConstraint::None => ExprImpl::literal_bool(true),
             JoinConstraint::Natural => {
-                return Err(ErrorCode::NotImplemented("Natural join".into(), 1633.into()).into())
+                let right_names: HashSet<_> = self.visible_column_names(right.clone()).collect();
+                let names = self
+                    .visible_column_names(left.clone())
+                    .filter(|name| right_names.contains(name))
+                    .collect_vec();
+                self.bind_using_columns(names, join_type, left, right)?
             }
             JoinConstraint::On(expr) => {
                 let bound_expr = self.bind_expr(expr)?;
@@ -93,9 +113,116 @@ impl Binder {
                 }
                 bound_expr
             }
-            JoinConstraint::Using(_columns) => {
-                return Err(ErrorCode::NotImplemented("USING".into(), 1636.into()).into())
+            JoinConstraint::Using(columns) => {
+                let mut names = Vec::with_capacity(columns.len());
+                for column in columns {
+                    if names.contains(&column.value) {
+                        return Err(ErrorCode::BindError(format!(
+                            "column name \"{}\" appears more than once in USING clause",
+                            column.value
+                        ))
+                        .into());
+                    }
+                    names.push(column.value);
+                }
+                self.bind_using_columns(names, join_type, left, right)?
             }
         })
     }
+
+    /// Distinct names of the visible columns in `range`, in the order of their first appearance.
+    fn visible_column_names(&self, range: Range<usize>) -> impl Iterator<Item = String> + '_ {
+        self.context.columns[range]
+            .iter()
+            .filter(|c| !c.is_hidden)
+            .map(|c| c.field.name.clone())
+            .unique()
+    }
+
+    /// Equates each pair of same-named columns from both sides and merges them into a
+    /// [`ColumnGroup`](crate::binder::bind_context::ColumnGroup), so that an unqualified reference
+    /// to the name resolves to a single column.
+    fn bind_using_columns(
+        &mut self,
+        names: Vec<String>,
+        join_type: JoinType,
+        left: Range<usize>,
+        right: Range<usize>,
+    ) -> Result<ExprImpl> {
+        let mut conds = Vec::with_capacity(names.len());
+        for name in names {
+            let (left_indices, left_non_nullable) =
+                self.resolve_using_column(&name, left.clone(), "left")?;
+            let (right_indices, right_non_nullable) =
+                self.resolve_using_column(&name, right.clone(), "right")?;
+
+            let left_expr = Self::bind_columns_by_indices(
+                &self.context,
+                &left_non_nullable.map_or_else(|| left_indices.clone(), |i| vec![i]),
+                0,
+            )?;
+            let right_expr = Self::bind_columns_by_indices(
+                &self.context,
+                &right_non_nullable.map_or_else(|| right_indices.clone(), |i| vec![i]),
+                0,
+            )?;
+            conds.push(FunctionCall::new(ExprType::Equal, vec![left_expr, right_expr])?.into());
+
+            // The merged column takes its value from the preserved side of the join. For an inner
+            // join both sides are equal, and for a full outer join neither side is always present.
+            let non_nullable_column = match join_type {
+                JoinType::Inner => left_non_nullable.or(right_non_nullable),
+                JoinType::LeftOuter => left_non_nullable,
+                JoinType::RightOuter => right_non_nullable,
+                _ => None,
+            };
+            self.context.column_group_context.merge(
+                name,
+                &left_indices,
+                &right_indices,
+                non_nullable_column,
+            );
+        }
+        Ok(merge_expr_by_binary(
+            conds.into_iter(),
+            ExprType::And,
+            ExprImpl::literal_bool(true),
+        ))
+    }
+
+    /// Finds the visible column named `name` in `range`, which may also be a group of columns
+    /// merged by an earlier join. Returns the indices of the columns and the one carrying the
+    /// value, if any.
+    fn resolve_using_column(
+        &self,
+        name: &str,
+        range: Range<usize>,
+        side: &str,
+    ) -> Result<(Vec<usize>, Option<usize>)> {
+        let indices = self
+            .context
+            .indexs_of
+            .get(name)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|i| range.contains(i) && !self.context.columns[*i].is_hidden)
+            .collect_vec();
+        match indices[..] {
+            [] => Err(ErrorCode::ItemNotFound(format!(
+                "column \"{}\" specified in USING clause does not exist in {} table",
+                name, side
+            ))
+            .into()),
+            [index] => Ok((indices, Some(index))),
+            _ => match self.context.column_group_context.group_of(&indices) {
+                Some(group) => Ok((indices, group.non_nullable_column)),
+                None => Err(ErrorCode::BindError(format!(
+                    "common column name \"{}\" appears more than once in {} table",
+                    name, side
+                ))
+                .into()),
+            },
+        }
+    }
 }
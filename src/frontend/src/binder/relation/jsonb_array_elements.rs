@@ -0,0 +1,93 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::Field;
+use risingwave_common::error::ErrorCode;
+use risingwave_common::types::DataType;
+use risingwave_sqlparser::ast::{FunctionArg, TableAlias};
+
+use super::{Binder, Result};
+use crate::expr::ExprImpl;
+
+/// `jsonb_array_elements(jsonb)` or `jsonb_array_elements_text(jsonb)`, expanding a JSONB array
+/// to a set of its elements.
+#[derive(Debug)]
+pub struct BoundJsonbArrayElementsFunction {
+    pub(crate) arg: ExprImpl,
+    /// Whether the elements are returned as text, for `jsonb_array_elements_text`.
+    pub(crate) as_text: bool,
+}
+
+impl BoundJsonbArrayElementsFunction {
+    pub fn data_type(&self) -> DataType {
+        if self.as_text {
+            DataType::Varchar
+        } else {
+            DataType::Jsonb
+        }
+    }
+}
+
+impl Binder {
+    pub(super) fn bind_jsonb_array_elements_function(
+        &mut self,
+        func_name: &str,
+        args: Vec<FunctionArg>,
+        alias: Option<TableAlias>,
+    ) -> Result<BoundJsonbArrayElementsFunction> {
+        let func_name = func_name.to_lowercase();
+        let args: Vec<_> = args
+            .into_iter()
+            .map(|arg| self.bind_function_arg(arg))
+            .flatten_ok()
+            .try_collect()?;
+        let Ok(arg) = args.into_iter().exactly_one() else {
+            return Err(ErrorCode::BindError(format!(
+                "{} takes exactly 1 argument",
+                func_name
+            ))
+            .into());
+        };
+        // The array is expanded when the query is planned, so it can't refer to other relations.
+        if arg.has_input_ref()
+            || arg.has_correlated_input_ref()
+            || arg.has_subquery()
+            || arg.has_agg_call()
+        {
+            return Err(ErrorCode::NotImplemented(
+                format!("{} with a non-constant argument", func_name),
+                None.into(),
+            )
+            .into());
+        }
+        let function = BoundJsonbArrayElementsFunction {
+            arg: arg.cast_implicit(DataType::Jsonb)?,
+            as_text: func_name == "jsonb_array_elements_text",
+        };
+
+        let columns = [(
+            false,
+            Field {
+                data_type: function.data_type(),
+                name: "value".to_string(),
+                sub_fields: vec![],
+                type_name: "".to_string(),
+            },
+        )];
+        self.bind_context(columns, func_name, alias)?;
+
+        Ok(function)
+    }
+}
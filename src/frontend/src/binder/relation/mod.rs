@@ -15,7 +15,7 @@
 use std::collections::hash_map::Entry;
 use std::str::FromStr;
 
-use risingwave_common::catalog::{Field, DEFAULT_SCHEMA_NAME};
+use risingwave_common::catalog::Field;
 use risingwave_common::error::{internal_error, ErrorCode, Result};
 use risingwave_sqlparser::ast::{Ident, ObjectName, TableAlias, TableFactor};
 
@@ -24,13 +24,15 @@ use crate::binder::Binder;
 
 mod generate_series;
 mod join;
+mod jsonb_array_elements;
 mod subquery;
 mod table_or_source;
 mod window_table_function;
 pub use generate_series::BoundGenerateSeriesFunction;
 pub use join::BoundJoin;
+pub use jsonb_array_elements::BoundJsonbArrayElementsFunction;
 pub use subquery::BoundSubquery;
-pub use table_or_source::{BoundBaseTable, BoundSource, BoundTableSource};
+pub use table_or_source::{BoundBaseTable, BoundSource, BoundSystemTable, BoundTableSource};
 pub use window_table_function::{BoundWindowTableFunction, WindowTableFunctionKind};
 
 /// A validated item that refers to a table-like entity, including base table, subquery, join, etc.
@@ -39,10 +41,12 @@ pub use window_table_function::{BoundWindowTableFunction, WindowTableFunctionKin
 pub enum Relation {
     Source(Box<BoundSource>),
     BaseTable(Box<BoundBaseTable>),
+    SystemTable(Box<BoundSystemTable>),
     Subquery(Box<BoundSubquery>),
     Join(Box<BoundJoin>),
     WindowTableFunction(Box<BoundWindowTableFunction>),
     GenerateSeriesFunction(Box<BoundGenerateSeriesFunction>),
+    JsonbArrayElementsFunction(Box<BoundJsonbArrayElementsFunction>),
 }
 
 impl Binder {
@@ -66,9 +70,45 @@ impl Binder {
         Ok((first_name, second_name))
     }
 
-    /// return the (`schema_name`, `table_name`)
-    pub fn resolve_table_name(name: ObjectName) -> Result<(String, String)> {
-        Self::resolve_double_name(name.0, "empty table name", DEFAULT_SCHEMA_NAME)
+    /// return the (`schema_name`, `table_name`), where `schema_name` is `None` if the name is
+    /// not schema-qualified.
+    pub fn resolve_schema_qualified_name(name: ObjectName) -> Result<(Option<String>, String)> {
+        let mut identifiers = name.0;
+        if identifiers.len() > 2 {
+            return Err(internal_error(
+                "table name must contain at most 2 arguments",
+            ));
+        }
+        let table_name = identifiers
+            .pop()
+            .ok_or_else(|| ErrorCode::InternalError("empty table name".into()))?
+            .value;
+        let schema_name = identifiers.pop().map(|ident| ident.value);
+        Ok((schema_name, table_name))
+    }
+
+    /// return the (`schema_name`, `table_name`). An unqualified name is resolved to the first
+    /// schema on the `search_path` containing the relation, or the first existing schema on it if
+    /// no such schema is found.
+    pub fn resolve_table_name(&self, name: ObjectName) -> Result<(String, String)> {
+        let (schema_name, table_name) = Self::resolve_schema_qualified_name(name)?;
+        let schema_name = match schema_name {
+            Some(schema_name) => schema_name,
+            None => match self.catalog.resolve_relation_schema(
+                &self.db_name,
+                &self.search_path,
+                &self.user_name,
+                &table_name,
+            )? {
+                Some(schema_name) => schema_name.to_string(),
+                None => self
+                    .catalog
+                    .first_valid_schema(&self.db_name, &self.search_path, &self.user_name)?
+                    .name()
+                    .to_string(),
+            },
+        };
+        Ok((schema_name, table_name))
     }
 
     /// return the ( `database_name`, `schema_name`)
@@ -157,7 +197,7 @@ impl Binder {
         match table_factor {
             TableFactor::Table { name, alias, args } => {
                 if args.is_empty() {
-                    let (schema_name, table_name) = Self::resolve_table_name(name)?;
+                    let (schema_name, table_name) = self.resolve_table_name(name)?;
                     self.bind_table_or_source(&schema_name, &table_name, alias)
                 } else {
                     let func_name = &name.0[0].value;
@@ -166,6 +206,13 @@ impl Binder {
                             self.bind_generate_series_function(args)?,
                         )));
                     }
+                    if func_name.eq_ignore_ascii_case("jsonb_array_elements")
+                        || func_name.eq_ignore_ascii_case("jsonb_array_elements_text")
+                    {
+                        return Ok(Relation::JsonbArrayElementsFunction(Box::new(
+                            self.bind_jsonb_array_elements_function(func_name, args, alias)?,
+                        )));
+                    }
                     let kind = WindowTableFunctionKind::from_str(func_name).map_err(|_| {
                         ErrorCode::NotImplemented(
                             format!("unknown window function kind: {}", name.0[0].value),
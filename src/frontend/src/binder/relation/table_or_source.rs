@@ -14,12 +14,14 @@
 
 use std::sync::Arc;
 
+use risingwave_common::array::Row;
 use risingwave_common::catalog::ColumnDesc;
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_sqlparser::ast::{ObjectName, TableAlias};
 
 use crate::binder::{Binder, Relation};
 use crate::catalog::source_catalog::SourceCatalog;
+use crate::catalog::system_catalog::{get_sys_table, is_system_schema, SystemTableCatalog};
 use crate::catalog::table_catalog::TableCatalog;
 use crate::catalog::{CatalogError, TableId};
 
@@ -39,6 +41,14 @@ pub struct BoundTableSource {
     pub columns: Vec<ColumnDesc>,
 }
 
+/// A relation in `pg_catalog` or `information_schema`, with its rows generated from the catalog
+/// at binding time.
+#[derive(Debug)]
+pub struct BoundSystemTable {
+    pub table_catalog: &'static SystemTableCatalog,
+    pub rows: Vec<Row>,
+}
+
 #[derive(Debug)]
 pub struct BoundSource {
     pub catalog: SourceCatalog,
@@ -57,20 +67,8 @@ impl Binder {
         table_name: &str,
         alias: Option<TableAlias>,
     ) -> Result<Relation> {
-        if schema_name == "pg_catalog" {
-            // TODO: support pg_catalog.
-            return Err(ErrorCode::NotImplemented(
-                // TODO: We can ref the document of `SHOW` commands here if ready.
-                r###"pg_catalog is not supported, please use `SHOW` commands for now.
-`SHOW TABLES`,
-`SHOW MATERIALIZED VIEWS`,
-`DESCRIBE <table>`,
-`SHOW COLUMNS FROM [table]`
-"###
-                .into(),
-                1695.into(),
-            )
-            .into());
+        if is_system_schema(schema_name) {
+            return self.bind_system_table(schema_name, table_name, alias);
         }
 
         let (ret, columns) = {
@@ -112,6 +110,34 @@ impl Binder {
         Ok(ret)
     }
 
+    fn bind_system_table(
+        &mut self,
+        schema_name: &str,
+        table_name: &str,
+        alias: Option<TableAlias>,
+    ) -> Result<Relation> {
+        let table_catalog = get_sys_table(schema_name, table_name).ok_or_else(|| {
+            ErrorCode::NotImplemented(
+                format!(
+                    "system table {}.{} is not supported",
+                    schema_name, table_name
+                ),
+                1695.into(),
+            )
+        })?;
+        let rows = table_catalog.gen_rows(&self.catalog, &self.db_name)?;
+
+        self.bind_context(
+            table_catalog.fields().into_iter().map(|f| (false, f)),
+            table_name.to_string(),
+            alias,
+        )?;
+        Ok(Relation::SystemTable(Box::new(BoundSystemTable {
+            table_catalog,
+            rows,
+        })))
+    }
+
     fn resolve_table_indexes(
         &mut self,
         schema_name: &str,
@@ -159,7 +185,7 @@ impl Binder {
     }
 
     pub(crate) fn bind_table_source(&mut self, name: ObjectName) -> Result<BoundTableSource> {
-        let (schema_name, source_name) = Self::resolve_table_name(name)?;
+        let (schema_name, source_name) = self.resolve_table_name(name)?;
         let source = self
             .catalog
             .get_source_by_name(&self.db_name, &schema_name, &source_name)?;
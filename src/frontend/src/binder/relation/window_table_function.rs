@@ -75,7 +75,7 @@ impl Binder {
             )
             .into()),
         }?;
-        let (schema_name, table_name) = Self::resolve_table_name(table_name)?;
+        let (schema_name, table_name) = self.resolve_table_name(table_name)?;
 
         // TODO: support alias.
         let base = self.bind_table_or_source(&schema_name, &table_name, None)?;
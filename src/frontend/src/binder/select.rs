@@ -153,8 +153,14 @@ impl Binder {
                     aliases.extend(names);
                 }
                 SelectItem::Wildcard => {
+                    let (exprs, names) = self.iter_column_groups()?;
+                    select_list.extend(exprs);
+                    aliases.extend(names);
+                    let group_mapping = &self.context.column_group_context.mapping;
                     let (exprs, names) = Self::iter_bound_columns(
-                        self.context.columns[..].iter().filter(|c| !c.is_hidden),
+                        self.context.columns[..]
+                            .iter()
+                            .filter(|c| !c.is_hidden && !group_mapping.contains_key(&c.index)),
                     );
                     select_list.extend(exprs);
                     aliases.extend(names);
@@ -177,6 +183,22 @@ impl Binder {
             .unzip()
     }
 
+    /// The merged columns of `NATURAL` joins and `JOIN ... USING`, which come first in the
+    /// expansion of `*`.
+    fn iter_column_groups(&self) -> Result<(Vec<ExprImpl>, Vec<Option<String>>)> {
+        let mut exprs = vec![];
+        let mut names = vec![];
+        for group in self.context.column_group_context.groups.values() {
+            exprs.push(Self::bind_columns_by_indices(
+                &self.context,
+                &group.value_indices(),
+                0,
+            )?);
+            names.push(Some(group.column_name.clone()));
+        }
+        Ok((exprs, names))
+    }
+
     fn require_bool_clause(expr: &Option<ExprImpl>, clause: &str) -> Result<()> {
         if let Some(expr) = expr {
             let return_type = expr.return_type();
@@ -23,7 +23,9 @@ pub(crate) mod database_catalog;
 pub(crate) mod root_catalog;
 pub(crate) mod schema_catalog;
 pub(crate) mod source_catalog;
+pub(crate) mod system_catalog;
 pub(crate) mod table_catalog;
+pub(crate) mod table_statistics;
 
 pub(crate) type SourceId = u32;
 
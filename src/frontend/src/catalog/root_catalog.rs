@@ -16,7 +16,8 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 use risingwave_common::catalog::{CatalogVersion, TableId};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::session_config::{SearchPath, USER_NAME_WILD_CARD};
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
 };
@@ -25,7 +26,9 @@ use super::source_catalog::SourceCatalog;
 use super::{CatalogError, SourceId};
 use crate::catalog::database_catalog::DatabaseCatalog;
 use crate::catalog::schema_catalog::SchemaCatalog;
+use crate::catalog::system_catalog::{get_sys_table, is_system_schema, PG_CATALOG_SCHEMA_NAME};
 use crate::catalog::table_catalog::TableCatalog;
+use crate::catalog::table_statistics::TableStatistics;
 use crate::catalog::{DatabaseId, SchemaId};
 
 /// Root catalog of database catalog. Manage all database/schema/table in memory on frontend. it
@@ -90,6 +93,28 @@ impl Catalog {
             .create_table(proto);
     }
 
+    pub fn update_table(&mut self, proto: &ProstTable) {
+        self.get_database_mut(proto.database_id)
+            .unwrap()
+            .get_schema_mut(proto.schema_id)
+            .unwrap()
+            .update_table(proto);
+    }
+
+    pub fn update_table_statistics(
+        &mut self,
+        db_id: DatabaseId,
+        schema_id: SchemaId,
+        tb_id: TableId,
+        statistics: TableStatistics,
+    ) {
+        self.get_database_mut(db_id)
+            .unwrap()
+            .get_schema_mut(schema_id)
+            .unwrap()
+            .update_table_statistics(tb_id, statistics);
+    }
+
     pub fn create_source(&mut self, proto: ProstSource) {
         self.get_database_mut(proto.database_id)
             .unwrap()
@@ -133,6 +158,10 @@ impl Catalog {
         Ok(self.get_database_by_name(db_name)?.get_all_schema_names())
     }
 
+    pub fn iter_databases(&self) -> impl Iterator<Item = &DatabaseCatalog> {
+        self.database_by_name.values()
+    }
+
     pub fn get_all_database_names(&self) -> Vec<String> {
         self.database_by_name.keys().cloned().collect_vec()
     }
@@ -165,6 +194,72 @@ impl Catalog {
             .ok_or_else(|| CatalogError::NotFound("source", source_name.to_string()).into())
     }
 
+    /// Iterate the schema names on the search path, with `$user` replaced by `user_name`.
+    fn iter_search_path<'a>(
+        search_path: &'a SearchPath,
+        user_name: &'a str,
+    ) -> impl Iterator<Item = &'a str> {
+        search_path.path().iter().map(move |schema_name| {
+            if schema_name == USER_NAME_WILD_CARD {
+                user_name
+            } else {
+                schema_name.as_str()
+            }
+        })
+    }
+
+    /// Resolve the schema of an unqualified relation name, i.e. the first schema on the search
+    /// path containing a relation of that name. `pg_catalog` is searched first unless it is
+    /// listed on the path. Returns `None` if no schema contains the relation.
+    pub fn resolve_relation_schema<'a>(
+        &self,
+        db_name: &str,
+        search_path: &'a SearchPath,
+        user_name: &'a str,
+        relation_name: &str,
+    ) -> Result<Option<&'a str>> {
+        let db = self.get_database_by_name(db_name)?;
+        let explicit_pg_catalog = search_path
+            .path()
+            .iter()
+            .any(|s| s == PG_CATALOG_SCHEMA_NAME);
+        if !explicit_pg_catalog && get_sys_table(PG_CATALOG_SCHEMA_NAME, relation_name).is_some() {
+            return Ok(Some(PG_CATALOG_SCHEMA_NAME));
+        }
+
+        for schema_name in Self::iter_search_path(search_path, user_name) {
+            let found = if is_system_schema(schema_name) {
+                get_sys_table(schema_name, relation_name).is_some()
+            } else if let Some(schema) = db.get_schema_by_name(schema_name) {
+                schema.get_table_by_name(relation_name).is_some()
+                    || schema.get_source_by_name(relation_name).is_some()
+            } else {
+                false
+            };
+            if found {
+                return Ok(Some(schema_name));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get the schema in which objects with unqualified names are created, i.e. the first
+    /// existing schema on the search path.
+    pub fn first_valid_schema(
+        &self,
+        db_name: &str,
+        search_path: &SearchPath,
+        user_name: &str,
+    ) -> Result<&SchemaCatalog> {
+        let db = self.get_database_by_name(db_name)?;
+        Self::iter_search_path(search_path, user_name)
+            .filter(|schema_name| !is_system_schema(schema_name))
+            .find_map(|schema_name| db.get_schema_by_name(schema_name))
+            .ok_or_else(|| {
+                ErrorCode::BindError("no schema has been selected to create in".to_string()).into()
+            })
+    }
+
     /// Check the name if duplicated with existing table, materialized view or source.
     pub fn check_relation_name_duplicated(
         &self,
@@ -20,6 +20,7 @@ use risingwave_pb::stream_plan::source_node::SourceType;
 
 use super::source_catalog::SourceCatalog;
 use crate::catalog::table_catalog::TableCatalog;
+use crate::catalog::table_statistics::TableStatistics;
 use crate::catalog::SchemaId;
 
 pub type SourceId = u32;
@@ -27,7 +28,6 @@ pub type SourceId = u32;
 #[derive(Clone, Debug)]
 pub struct SchemaCatalog {
     id: SchemaId,
-    #[allow(dead_code)]
     name: String,
     table_by_name: HashMap<String, TableCatalog>,
     table_name_by_id: HashMap<TableId, String>,
@@ -45,6 +45,18 @@ impl SchemaCatalog {
         self.table_name_by_id.try_insert(id, name).unwrap();
     }
 
+    /// Replace the catalog of an existing table, e.g. after its statistics are updated.
+    pub fn update_table(&mut self, prost: &ProstTable) {
+        let id = prost.id.into();
+        let name = self.table_name_by_id.get(&id).unwrap();
+        self.table_by_name.insert(name.clone(), prost.into());
+    }
+
+    pub fn update_table_statistics(&mut self, id: TableId, statistics: TableStatistics) {
+        let name = self.table_name_by_id.get(&id).unwrap();
+        self.table_by_name.get_mut(name).unwrap().statistics = Some(statistics);
+    }
+
     pub fn drop_table(&mut self, id: TableId) {
         let name = self.table_name_by_id.remove(&id).unwrap();
         self.table_by_name.remove(&name).unwrap();
@@ -84,6 +96,12 @@ impl SchemaCatalog {
             .map(|(_, v)| v)
     }
 
+    /// Iterate all tables, materialized views and indexes, including the ones backing
+    /// materialized sources.
+    pub fn iter_all_table(&self) -> impl Iterator<Item = &TableCatalog> {
+        self.table_by_name.values()
+    }
+
     /// Iterate all sources, including the materialized sources.
     pub fn iter_source(&self) -> impl Iterator<Item = &SourceCatalog> {
         self.source_by_name
@@ -114,6 +132,10 @@ impl SchemaCatalog {
     pub fn id(&self) -> SchemaId {
         self.id
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 impl From<&ProstSchema> for SchemaCatalog {
@@ -27,6 +27,10 @@ pub struct SourceCatalog {
     pub columns: Vec<ColumnCatalog>,
     pub pk_col_ids: Vec<ColumnId>,
     pub source_type: SourceType,
+    /// Columns with a watermark defined by `WATERMARK FOR`.
+    pub watermark_col_ids: Vec<ColumnId>,
+    /// The normalized SQL defining the source, with secrets redacted.
+    pub definition: String,
 }
 
 impl SourceCatalog {
@@ -56,7 +60,7 @@ impl From<&ProstSource> for SourceCatalog {
     fn from(prost: &ProstSource) -> Self {
         let id = prost.id;
         let name = prost.name.clone();
-        let (source_type, prost_columns, pk_col_ids) = match &prost.info {
+        let (source_type, prost_columns, pk_col_ids, watermark_col_ids) = match &prost.info {
             Some(Info::StreamSource(source)) => (
                 SourceType::Source,
                 source.columns.clone(),
@@ -65,11 +69,28 @@ impl From<&ProstSource> for SourceCatalog {
                     .iter()
                     .map(|id| ColumnId::new(*id))
                     .collect(),
+                source
+                    .watermark_descs
+                    .iter()
+                    .map(|desc| {
+                        let column = &source.columns[desc.watermark_idx as usize];
+                        ColumnId::new(column.column_desc.as_ref().unwrap().column_id)
+                    })
+                    .collect(),
             ),
             Some(Info::TableSource(source)) => (
                 SourceType::Table,
                 source.columns.clone(),
-                vec![TABLE_SOURCE_PK_COLID],
+                if source.pk_column_ids.is_empty() {
+                    vec![TABLE_SOURCE_PK_COLID]
+                } else {
+                    source
+                        .pk_column_ids
+                        .iter()
+                        .map(|id| ColumnId::new(*id))
+                        .collect()
+                },
+                vec![],
             ),
             None => unreachable!(),
         };
@@ -80,6 +101,8 @@ impl From<&ProstSource> for SourceCatalog {
             columns,
             pk_col_ids,
             source_type,
+            watermark_col_ids,
+            definition: prost.definition.clone(),
         }
     }
 }
@@ -0,0 +1,118 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::pg_catalog::{iter_relations, iter_visible_columns, RelationKind};
+use super::{varchar, SystemTableCatalog};
+use crate::catalog::database_catalog::DatabaseCatalog;
+use crate::catalog::root_catalog::Catalog;
+
+pub(super) static INFORMATION_SCHEMA_TABLES: &[SystemTableCatalog] = &[
+    SystemTableCatalog {
+        name: "tables",
+        columns: &[
+            (DataType::Varchar, "table_catalog"),
+            (DataType::Varchar, "table_schema"),
+            (DataType::Varchar, "table_name"),
+            (DataType::Varchar, "table_type"),
+        ],
+        gen_rows: gen_tables,
+    },
+    SystemTableCatalog {
+        name: "columns",
+        columns: &[
+            (DataType::Varchar, "table_catalog"),
+            (DataType::Varchar, "table_schema"),
+            (DataType::Varchar, "table_name"),
+            (DataType::Varchar, "column_name"),
+            (DataType::Int32, "ordinal_position"),
+            (DataType::Varchar, "is_nullable"),
+            (DataType::Varchar, "data_type"),
+        ],
+        gen_rows: gen_columns,
+    },
+];
+
+/// The name of a data type as reported in `information_schema.columns.data_type`.
+fn sql_type_name(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int16 => "smallint",
+        DataType::Int32 => "integer",
+        DataType::Int64 => "bigint",
+        DataType::Float32 => "real",
+        DataType::Float64 => "double precision",
+        DataType::Decimal => "numeric",
+        DataType::Date => "date",
+        DataType::Varchar => "character varying",
+        DataType::Time => "time without time zone",
+        DataType::Timestamp => "timestamp without time zone",
+        DataType::Timestampz => "timestamp with time zone",
+        DataType::Interval => "interval",
+        DataType::Struct { .. } => "USER-DEFINED",
+        DataType::List { .. } => "ARRAY",
+        DataType::Jsonb => "jsonb",
+        DataType::Bytea => "bytea",
+        DataType::Uuid => "uuid",
+    }
+}
+
+fn gen_tables(_catalog: &Catalog, database: &DatabaseCatalog) -> Vec<Row> {
+    database
+        .iter_schemas()
+        .flat_map(move |schema| {
+            iter_relations(schema)
+                .filter(|rel| rel.kind != RelationKind::Index)
+                .map(move |rel| {
+                    let table_type = match rel.kind {
+                        RelationKind::Table => "BASE TABLE",
+                        RelationKind::MaterializedView => "VIEW",
+                        RelationKind::Source => "FOREIGN",
+                        RelationKind::Index => unreachable!(),
+                    };
+                    Row::new(vec![
+                        varchar(database.name()),
+                        varchar(schema.name()),
+                        varchar(rel.name),
+                        varchar(table_type),
+                    ])
+                })
+        })
+        .collect()
+}
+
+fn gen_columns(_catalog: &Catalog, database: &DatabaseCatalog) -> Vec<Row> {
+    database
+        .iter_schemas()
+        .flat_map(move |schema| {
+            iter_relations(schema)
+                .filter(|rel| rel.kind != RelationKind::Index)
+                .flat_map(move |rel| {
+                    iter_visible_columns(rel.columns).map(move |(position, column)| {
+                        Row::new(vec![
+                            varchar(database.name()),
+                            varchar(schema.name()),
+                            varchar(rel.name),
+                            varchar(column.name()),
+                            Some(ScalarImpl::Int32(position as i32)),
+                            varchar(if rel.is_nullable(column) { "YES" } else { "NO" }),
+                            varchar(sql_type_name(column.data_type())),
+                        ])
+                    })
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,216 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! System catalogs exposed to clients as read-only relations in the `pg_catalog` and
+//! `information_schema` schemas. Their rows are generated from the frontend [`Catalog`] every
+//! time they are bound, so they always reflect the catalog version the query is planned on.
+
+mod information_schema;
+mod pg_catalog;
+
+use risingwave_common::array::Row;
+use risingwave_common::catalog::Field;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::database_catalog::DatabaseCatalog;
+use crate::catalog::root_catalog::Catalog;
+
+pub const PG_CATALOG_SCHEMA_NAME: &str = "pg_catalog";
+pub const INFORMATION_SCHEMA_SCHEMA_NAME: &str = "information_schema";
+
+/// Reserved oids of the system schemas.
+pub const PG_CATALOG_SCHEMA_OID: i32 = 11;
+pub const INFORMATION_SCHEMA_SCHEMA_OID: i32 = 12;
+
+/// Oids below this are reserved for system objects, like `FirstNormalObjectId` of Postgres. User
+/// schemas and relations are reported with their catalog ids shifted by it, so that they never
+/// collide with the system ones.
+pub const FIRST_USER_OID: i32 = 16384;
+
+/// The oid of a user object with the given catalog id.
+pub fn user_oid(id: u32) -> i32 {
+    FIRST_USER_OID + id as i32
+}
+
+pub type SystemCatalogColumnsDef<'a> = (DataType, &'a str);
+
+/// A read-only relation in one of the system schemas.
+pub struct SystemTableCatalog {
+    pub name: &'static str,
+    pub columns: &'static [SystemCatalogColumnsDef<'static>],
+    /// Generates the rows visible from the given database.
+    gen_rows: fn(&Catalog, &DatabaseCatalog) -> Vec<Row>,
+}
+
+impl SystemTableCatalog {
+    pub fn fields(&self) -> Vec<Field> {
+        self.columns
+            .iter()
+            .map(|(data_type, name)| Field::with_name(data_type.clone(), *name))
+            .collect()
+    }
+
+    /// Generate the rows of this relation from the current catalog.
+    pub fn gen_rows(&self, catalog: &Catalog, db_name: &str) -> Result<Vec<Row>> {
+        let database = catalog.get_database_by_name(db_name)?;
+        Ok((self.gen_rows)(catalog, database))
+    }
+}
+
+impl std::fmt::Debug for SystemTableCatalog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SystemTableCatalog")
+            .field("name", &self.name)
+            .field("columns", &self.columns)
+            .finish()
+    }
+}
+
+fn varchar(s: &str) -> Option<ScalarImpl> {
+    Some(ScalarImpl::Utf8(s.to_string()))
+}
+
+pub fn is_system_schema(schema_name: &str) -> bool {
+    schema_name == PG_CATALOG_SCHEMA_NAME || schema_name == INFORMATION_SCHEMA_SCHEMA_NAME
+}
+
+/// Look up a system table by its schema and name.
+pub fn get_sys_table(schema_name: &str, table_name: &str) -> Option<&'static SystemTableCatalog> {
+    let tables = match schema_name {
+        PG_CATALOG_SCHEMA_NAME => pg_catalog::PG_CATALOG_TABLES,
+        INFORMATION_SCHEMA_SCHEMA_NAME => information_schema::INFORMATION_SCHEMA_TABLES,
+        _ => return None,
+    };
+    tables.iter().find(|t| t.name == table_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+
+    use super::*;
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_sys_table_rows() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 varchar)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create materialized view mv as select v1 from t")
+            .await
+            .unwrap();
+
+        let session = frontend.session_ref();
+        let catalog = session.env().catalog_reader().read_guard();
+        let gen_rows = |schema_name, table_name| {
+            get_sys_table(schema_name, table_name)
+                .unwrap()
+                .gen_rows(&catalog, DEFAULT_DATABASE_NAME)
+                .unwrap()
+        };
+
+        let relkinds = gen_rows(PG_CATALOG_SCHEMA_NAME, "pg_class")
+            .into_iter()
+            .map(|row| (row[1].clone().unwrap(), row[3].clone().unwrap()))
+            .collect::<std::collections::HashMap<_, _>>();
+        assert_eq!(
+            relkinds[&ScalarImpl::Utf8("t".into())],
+            ScalarImpl::Utf8("r".into())
+        );
+        assert_eq!(
+            relkinds[&ScalarImpl::Utf8("mv".into())],
+            ScalarImpl::Utf8("m".into())
+        );
+
+        // The hidden row id column is not exposed.
+        let mut columns = gen_rows(INFORMATION_SCHEMA_SCHEMA_NAME, "columns")
+            .into_iter()
+            .filter(|row| row[2] == Some(ScalarImpl::Utf8("t".into())))
+            .map(|row| {
+                (
+                    row[1].clone().unwrap(),
+                    row[3].clone().unwrap(),
+                    row[4].clone().unwrap(),
+                    row[6].clone().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        columns.sort_by_key(|c| c.2.clone());
+        assert_eq!(
+            columns,
+            vec![
+                (
+                    ScalarImpl::Utf8(DEFAULT_SCHEMA_NAME.into()),
+                    ScalarImpl::Utf8("v1".into()),
+                    ScalarImpl::Int32(1),
+                    ScalarImpl::Utf8("integer".into()),
+                ),
+                (
+                    ScalarImpl::Utf8(DEFAULT_SCHEMA_NAME.into()),
+                    ScalarImpl::Utf8("v2".into()),
+                    ScalarImpl::Int32(2),
+                    ScalarImpl::Utf8("character varying".into()),
+                ),
+            ]
+        );
+
+        assert!(get_sys_table(PG_CATALOG_SCHEMA_NAME, "pg_proc").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sys_table_oids_and_nullability() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int primary key, v2 varchar)")
+            .await
+            .unwrap();
+
+        let session = frontend.session_ref();
+        let catalog = session.env().catalog_reader().read_guard();
+        let gen_rows = |schema_name, table_name| {
+            get_sys_table(schema_name, table_name)
+                .unwrap()
+                .gen_rows(&catalog, DEFAULT_DATABASE_NAME)
+                .unwrap()
+        };
+
+        // User schemas never take the oids reserved for system schemas.
+        for row in gen_rows(PG_CATALOG_SCHEMA_NAME, "pg_namespace") {
+            let oid = match row[0] {
+                Some(ScalarImpl::Int32(oid)) => oid,
+                _ => unreachable!(),
+            };
+            let is_system = oid == PG_CATALOG_SCHEMA_OID || oid == INFORMATION_SCHEMA_SCHEMA_OID;
+            assert_eq!(is_system, oid < FIRST_USER_OID);
+        }
+
+        let nullability = gen_rows(INFORMATION_SCHEMA_SCHEMA_NAME, "columns")
+            .into_iter()
+            .filter(|row| row[2] == Some(ScalarImpl::Utf8("t".into())))
+            .map(|row| (row[3].clone().unwrap(), row[5].clone().unwrap()))
+            .collect::<std::collections::HashMap<_, _>>();
+        assert_eq!(
+            nullability[&ScalarImpl::Utf8("v1".into())],
+            ScalarImpl::Utf8("NO".into())
+        );
+        assert_eq!(
+            nullability[&ScalarImpl::Utf8("v2".into())],
+            ScalarImpl::Utf8("YES".into())
+        );
+    }
+}
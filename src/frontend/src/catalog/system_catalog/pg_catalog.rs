@@ -0,0 +1,284 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
+use risingwave_common::array::Row;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_pb::stream_plan::source_node::SourceType;
+
+use super::{
+    user_oid, varchar, SystemTableCatalog, INFORMATION_SCHEMA_SCHEMA_NAME,
+    INFORMATION_SCHEMA_SCHEMA_OID, PG_CATALOG_SCHEMA_NAME, PG_CATALOG_SCHEMA_OID,
+};
+use crate::catalog::column_catalog::ColumnCatalog;
+use crate::catalog::database_catalog::DatabaseCatalog;
+use crate::catalog::root_catalog::Catalog;
+use crate::catalog::schema_catalog::SchemaCatalog;
+use crate::handler::util::data_type_to_type_oid;
+
+pub(super) static PG_CATALOG_TABLES: &[SystemTableCatalog] = &[
+    SystemTableCatalog {
+        name: "pg_namespace",
+        columns: &[(DataType::Int32, "oid"), (DataType::Varchar, "nspname")],
+        gen_rows: gen_pg_namespace,
+    },
+    SystemTableCatalog {
+        name: "pg_class",
+        columns: &[
+            (DataType::Int32, "oid"),
+            (DataType::Varchar, "relname"),
+            (DataType::Int32, "relnamespace"),
+            (DataType::Varchar, "relkind"),
+            (DataType::Int16, "relnatts"),
+        ],
+        gen_rows: gen_pg_class,
+    },
+    SystemTableCatalog {
+        name: "pg_attribute",
+        columns: &[
+            (DataType::Int32, "attrelid"),
+            (DataType::Varchar, "attname"),
+            (DataType::Int32, "atttypid"),
+            (DataType::Int16, "attlen"),
+            (DataType::Int16, "attnum"),
+            (DataType::Boolean, "attnotnull"),
+            (DataType::Boolean, "attisdropped"),
+        ],
+        gen_rows: gen_pg_attribute,
+    },
+    SystemTableCatalog {
+        name: "pg_type",
+        columns: &[
+            (DataType::Int32, "oid"),
+            (DataType::Varchar, "typname"),
+            (DataType::Int16, "typlen"),
+            (DataType::Int32, "typnamespace"),
+            (DataType::Varchar, "typtype"),
+        ],
+        gen_rows: gen_pg_type,
+    },
+    SystemTableCatalog {
+        name: "pg_database",
+        columns: &[
+            (DataType::Int32, "oid"),
+            (DataType::Varchar, "datname"),
+            (DataType::Int32, "encoding"),
+            (DataType::Varchar, "datcollate"),
+            (DataType::Varchar, "datctype"),
+        ],
+        gen_rows: gen_pg_database,
+    },
+];
+
+/// Types reported in `pg_type`, with their names in Postgres. The oids are the ones sent to
+/// clients in row descriptions, so that `pg_attribute.atttypid` can be joined with `pg_type.oid`.
+const PG_TYPES: &[(TypeOid, &str)] = &[
+    (TypeOid::Boolean, "bool"),
+    (TypeOid::Bytea, "bytea"),
+    (TypeOid::BigInt, "int8"),
+    (TypeOid::SmallInt, "int2"),
+    (TypeOid::Int, "int4"),
+    (TypeOid::Float4, "float4"),
+    (TypeOid::Float8, "float8"),
+    (TypeOid::Varchar, "varchar"),
+    (TypeOid::Date, "date"),
+    (TypeOid::Time, "time"),
+    (TypeOid::Timestamp, "timestamp"),
+    (TypeOid::Timestampz, "timestamptz"),
+    (TypeOid::Decimal, "numeric"),
+    (TypeOid::Uuid, "uuid"),
+    (TypeOid::Jsonb, "jsonb"),
+];
+
+/// The kind of a relation listed in `pg_class`, using the `relkind` codes of Postgres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RelationKind {
+    Table,
+    MaterializedView,
+    Index,
+    Source,
+}
+
+impl RelationKind {
+    fn relkind(self) -> &'static str {
+        match self {
+            RelationKind::Table => "r",
+            RelationKind::MaterializedView => "m",
+            RelationKind::Index => "i",
+            RelationKind::Source => "f",
+        }
+    }
+}
+
+/// A relation of a schema, as seen by the system catalogs.
+pub(super) struct RelationInfo<'a> {
+    pub oid: i32,
+    pub name: &'a str,
+    pub kind: RelationKind,
+    pub columns: &'a [ColumnCatalog],
+    /// Indices of the primary key columns declared by the user, only for tables.
+    pub pk: &'a [usize],
+}
+
+impl RelationInfo<'_> {
+    /// Whether `column` may contain nulls. Only the primary key columns of tables are not.
+    pub fn is_nullable(&self, column: &ColumnCatalog) -> bool {
+        !self
+            .pk
+            .iter()
+            .any(|&i| self.columns[i].column_id() == column.column_id())
+    }
+}
+
+/// Iterate the relations in `schema`: tables, materialized views and materialized sources,
+/// indexes, and sources that are not materialized.
+pub(super) fn iter_relations(schema: &SchemaCatalog) -> impl Iterator<Item = RelationInfo<'_>> {
+    let tables = schema.iter_all_table().map(move |t| {
+        let kind = if t.is_index_on.is_some() {
+            RelationKind::Index
+        } else if t.associated_source_id().is_some()
+            && schema
+                .get_source_by_name(t.name())
+                .map_or(false, |s| s.source_type == SourceType::Table)
+        {
+            RelationKind::Table
+        } else {
+            RelationKind::MaterializedView
+        };
+        let pk = match kind {
+            RelationKind::Table => &t.pks[..],
+            _ => &[],
+        };
+        RelationInfo {
+            oid: user_oid(t.id().table_id()),
+            name: t.name(),
+            kind,
+            columns: t.columns(),
+            pk,
+        }
+    });
+    let sources = schema
+        .iter_source()
+        .filter(move |s| schema.get_table_by_name(&s.name).is_none())
+        .map(|s| RelationInfo {
+            oid: user_oid(s.id),
+            name: &s.name,
+            kind: RelationKind::Source,
+            columns: &s.columns,
+            pk: &[],
+        });
+    tables.chain(sources)
+}
+
+/// Iterate the visible columns of a relation with their 1-based attribute numbers.
+pub(super) fn iter_visible_columns(
+    columns: &[ColumnCatalog],
+) -> impl Iterator<Item = (i16, &ColumnCatalog)> {
+    columns
+        .iter()
+        .filter(|c| !c.is_hidden())
+        .enumerate()
+        .map(|(i, c)| (i as i16 + 1, c))
+}
+
+fn gen_pg_namespace(_catalog: &Catalog, database: &DatabaseCatalog) -> Vec<Row> {
+    let system_schemas = [
+        (PG_CATALOG_SCHEMA_OID, PG_CATALOG_SCHEMA_NAME),
+        (
+            INFORMATION_SCHEMA_SCHEMA_OID,
+            INFORMATION_SCHEMA_SCHEMA_NAME,
+        ),
+    ];
+    database
+        .iter_schemas()
+        .map(|schema| (user_oid(schema.id()), schema.name()))
+        .chain(system_schemas)
+        .map(|(oid, name)| Row::new(vec![Some(ScalarImpl::Int32(oid)), varchar(name)]))
+        .collect()
+}
+
+fn gen_pg_class(_catalog: &Catalog, database: &DatabaseCatalog) -> Vec<Row> {
+    database
+        .iter_schemas()
+        .flat_map(|schema| {
+            iter_relations(schema).map(move |rel| {
+                Row::new(vec![
+                    Some(ScalarImpl::Int32(rel.oid)),
+                    varchar(rel.name),
+                    Some(ScalarImpl::Int32(user_oid(schema.id()))),
+                    varchar(rel.kind.relkind()),
+                    Some(ScalarImpl::Int16(
+                        iter_visible_columns(rel.columns).count() as i16
+                    )),
+                ])
+            })
+        })
+        .collect()
+}
+
+fn gen_pg_attribute(_catalog: &Catalog, database: &DatabaseCatalog) -> Vec<Row> {
+    database
+        .iter_schemas()
+        .flat_map(iter_relations)
+        .flat_map(|rel| {
+            iter_visible_columns(rel.columns).map(move |(attnum, column)| {
+                let type_oid = data_type_to_type_oid(column.data_type().clone());
+                Row::new(vec![
+                    Some(ScalarImpl::Int32(rel.oid)),
+                    varchar(column.name()),
+                    Some(ScalarImpl::Int32(type_oid.as_number())),
+                    Some(ScalarImpl::Int16(
+                        PgFieldDescriptor::new(String::new(), type_oid).get_type_len(),
+                    )),
+                    Some(ScalarImpl::Int16(attnum)),
+                    Some(ScalarImpl::Bool(!rel.is_nullable(column))),
+                    Some(ScalarImpl::Bool(false)),
+                ])
+            })
+        })
+        .collect()
+}
+
+fn gen_pg_type(_catalog: &Catalog, _database: &DatabaseCatalog) -> Vec<Row> {
+    PG_TYPES
+        .iter()
+        .map(|(type_oid, name)| {
+            Row::new(vec![
+                Some(ScalarImpl::Int32(type_oid.as_number())),
+                varchar(name),
+                Some(ScalarImpl::Int16(
+                    PgFieldDescriptor::new(String::new(), *type_oid).get_type_len(),
+                )),
+                Some(ScalarImpl::Int32(PG_CATALOG_SCHEMA_OID)),
+                varchar("b"),
+            ])
+        })
+        .collect()
+}
+
+fn gen_pg_database(catalog: &Catalog, _database: &DatabaseCatalog) -> Vec<Row> {
+    catalog
+        .iter_databases()
+        .map(|database| {
+            Row::new(vec![
+                Some(ScalarImpl::Int32(database.id() as i32)),
+                varchar(database.name()),
+                // UTF8
+                Some(ScalarImpl::Int32(6)),
+                varchar("C"),
+                varchar("C"),
+            ])
+        })
+        .collect()
+}
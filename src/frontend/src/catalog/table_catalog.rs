@@ -22,6 +22,7 @@ use risingwave_pb::catalog::Table as ProstTable;
 use risingwave_pb::plan_common::OrderType as ProstOrderType;
 
 use super::column_catalog::ColumnCatalog;
+use super::table_statistics::TableStatistics;
 use super::{DatabaseId, SchemaId};
 use crate::catalog::TableId;
 
@@ -47,6 +48,13 @@ pub struct TableCatalog {
 
     /// If set to Some(TableId), then this table is an index on another table.
     pub is_index_on: Option<TableId>,
+
+    /// The normalized SQL defining the table, materialized view or index, with secrets
+    /// redacted. Used by `SHOW CREATE`.
+    pub definition: String,
+
+    /// Statistics collected by `ANALYZE`, or `None` if the table has never been analyzed.
+    pub statistics: Option<TableStatistics>,
 }
 
 impl TableCatalog {
@@ -91,6 +99,16 @@ impl TableCatalog {
         self.distribution_keys.as_ref()
     }
 
+    /// Get a reference to the table catalog's definition.
+    pub fn definition(&self) -> &str {
+        self.definition.as_ref()
+    }
+
+    /// Get a reference to the table catalog's statistics.
+    pub fn statistics(&self) -> Option<&TableStatistics> {
+        self.statistics.as_ref()
+    }
+
     pub fn to_prost(&self, schema_id: SchemaId, database_id: DatabaseId) -> ProstTable {
         let (order_column_ids, orders) = self
             .order_desc()
@@ -123,6 +141,8 @@ impl TableCatalog {
                 .iter()
                 .map(|k| *k as i32)
                 .collect_vec(),
+            definition: self.definition.clone(),
+            statistics: self.statistics.as_ref().map(TableStatistics::to_prost),
         }
     }
 }
@@ -180,6 +200,8 @@ impl From<ProstTable> for TableCatalog {
                 .map(|k| *k as usize)
                 .collect_vec(),
             pks: tb.pk.iter().map(|x| *x as _).collect(),
+            definition: tb.definition,
+            statistics: tb.statistics.as_ref().map(Into::into),
         }
     }
 }
@@ -248,6 +270,8 @@ mod tests {
             distribution_keys: vec![],
             optional_associated_source_id: OptionalAssociatedSourceId::AssociatedSourceId(233)
                 .into(),
+            definition: "".to_string(),
+            statistics: None,
         }
         .into();
 
@@ -294,6 +318,8 @@ mod tests {
                     order: OrderType::Ascending
                 }],
                 distribution_keys: vec![],
+                definition: "".to_string(),
+                statistics: None,
             }
         );
     }
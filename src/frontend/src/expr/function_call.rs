@@ -115,6 +115,29 @@ impl FunctionCall {
                 }
                 align_types(inputs.iter_mut())
             }
+            ExprType::ConcatWs => {
+                if inputs.is_empty() {
+                    return Err(ErrorCode::BindError(
+                        "ConcatWs function must contain at least 1 argument".into(),
+                    )
+                    .into());
+                }
+                // Like PG, arguments of any type are accepted and converted to their textual
+                // representations.
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| input.cast_assign(DataType::Varchar))
+                    .collect::<Result<_>>()?;
+                Ok(DataType::Varchar)
+            }
+            // The return type name inferred is `List` without the element type.
+            ExprType::RegexpMatch | ExprType::RegexpSplitToArray => infer_type(
+                func_type,
+                inputs.iter().map(|expr| expr.return_type()).collect(),
+            )
+            .map(|_| DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            }),
             _ => infer_type(
                 func_type,
                 inputs.iter().map(|expr| expr.return_type()).collect(),
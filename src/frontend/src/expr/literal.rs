@@ -95,6 +95,9 @@ fn literal_to_protobuf(d: &Datum) -> Option<RexNode> {
         ScalarImpl::NaiveTime(_) => todo!(),
         ScalarImpl::Struct(_) => todo!(),
         ScalarImpl::List(_) => todo!(),
+        ScalarImpl::Jsonb(v) => v.as_str().as_bytes().to_vec(),
+        ScalarImpl::Bytea(v) => v.as_bytes().to_vec(),
+        ScalarImpl::Uuid(v) => v.as_bytes().to_vec(),
     };
     Some(RexNode::Constant(ConstantValue { body }))
 }
@@ -43,6 +43,9 @@ enum DataTypeName {
     Interval,
     Struct,
     List,
+    Jsonb,
+    Bytea,
+    Uuid,
 }
 
 fn name_of(ty: &DataType) -> DataTypeName {
@@ -62,6 +65,9 @@ fn name_of(ty: &DataType) -> DataTypeName {
         DataType::Interval => DataTypeName::Interval,
         DataType::Struct { .. } => DataTypeName::Struct,
         DataType::List { .. } => DataTypeName::List,
+        DataType::Jsonb => DataTypeName::Jsonb,
+        DataType::Bytea => DataTypeName::Bytea,
+        DataType::Uuid => DataTypeName::Uuid,
     }
 }
 
@@ -91,6 +97,9 @@ pub fn infer_type(func_type: ExprType, inputs_type: Vec<DataType>) -> Result<Dat
         DataTypeName::List => DataType::List {
             datatype: Box::new(DataType::Int32),
         },
+        DataTypeName::Jsonb => DataType::Jsonb,
+        DataTypeName::Bytea => DataType::Bytea,
+        DataTypeName::Uuid => DataType::Uuid,
     })
 }
 
@@ -173,6 +182,9 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
+        T::Bytea,
+        T::Uuid,
     ];
     let num_types = [
         T::Int16,
@@ -210,10 +222,13 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Date, T::Timestamp, T::Timestampz]);
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Time, T::Interval]);
     for e in cmp_exprs {
-        for t in [T::Boolean, T::Varchar] {
+        for t in [T::Boolean, T::Varchar, T::Bytea, T::Uuid] {
             map.insert(FuncSign::new(*e, vec![t, t]), T::Boolean);
         }
     }
+    for e in [E::Equal, E::NotEqual] {
+        map.insert(FuncSign::new(e, vec![T::Jsonb, T::Jsonb]), T::Boolean);
+    }
 
     // arithmetic expressions
     for t in num_types {
@@ -233,6 +248,19 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         FuncSign::new(E::RoundDigit, vec![T::Decimal, T::Int32]),
         T::Decimal,
     );
+    for t in num_types {
+        map.insert(FuncSign::new(E::Abs, vec![t]), t);
+    }
+    for e in [E::Ceil, E::Floor] {
+        for t in [T::Float64, T::Decimal] {
+            map.insert(FuncSign::new(e, vec![t]), t);
+        }
+    }
+    map.insert(
+        FuncSign::new(E::Pow, vec![T::Float64, T::Float64]),
+        T::Float64,
+    );
+    map.insert(FuncSign::new(E::Sqrt, vec![T::Float64]), T::Float64);
 
     // temporal expressions
     for (base, delta) in [
@@ -270,9 +298,25 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
             T::Timestamp,
         );
     }
+    map.insert(
+        FuncSign::new(E::DateTrunc, vec![T::Varchar, T::Timestamp]),
+        T::Timestamp,
+    );
+    map.insert(
+        FuncSign::new(E::ToChar, vec![T::Timestamp, T::Varchar]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::ToTimestamp, vec![T::Float64]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::ToTimestamp, vec![T::Varchar, T::Varchar]),
+        T::Timestampz,
+    );
 
     // string expressions
-    for e in [E::Trim, E::Ltrim, E::Rtrim, E::Lower, E::Upper] {
+    for e in [E::Trim, E::Ltrim, E::Rtrim, E::Lower, E::Upper, E::Md5] {
         map.insert(FuncSign::new(e, vec![T::Varchar]), T::Varchar);
     }
     for e in [E::Trim, E::Ltrim, E::Rtrim] {
@@ -292,6 +336,19 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
             T::Varchar,
         );
     }
+    map.insert(
+        FuncSign::new(E::SplitPart, vec![T::Varchar, T::Varchar, T::Int32]),
+        T::Varchar,
+    );
+    for e in [E::Left, E::Right] {
+        map.insert(FuncSign::new(e, vec![T::Varchar, T::Int32]), T::Varchar);
+    }
+    for e in [E::Lpad, E::Rpad] {
+        map.insert(
+            FuncSign::new(e, vec![T::Varchar, T::Int32, T::Varchar]),
+            T::Varchar,
+        );
+    }
     for e in [E::Length, E::Ascii] {
         map.insert(FuncSign::new(e, vec![T::Varchar]), T::Int32);
     }
@@ -303,6 +360,62 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         FuncSign::new(E::Like, vec![T::Varchar, T::Varchar]),
         T::Boolean,
     );
+    map.insert(
+        FuncSign::new(E::SimilarTo, vec![T::Varchar, T::Varchar]),
+        T::Boolean,
+    );
+    // The trailing argument of regexp functions is the optional flags.
+    for (e, args, ret) in [
+        (E::RegexpIsMatch, vec![T::Varchar, T::Varchar], T::Boolean),
+        (E::RegexpMatch, vec![T::Varchar, T::Varchar], T::List),
+        (
+            E::RegexpReplace,
+            vec![T::Varchar, T::Varchar, T::Varchar],
+            T::Varchar,
+        ),
+        (E::RegexpSplitToArray, vec![T::Varchar, T::Varchar], T::List),
+    ] {
+        let mut args_with_flags = args.clone();
+        args_with_flags.push(T::Varchar);
+        map.insert(FuncSign::new(e, args), ret);
+        map.insert(FuncSign::new(e, args_with_flags), ret);
+    }
+
+    // jsonb expressions
+    for (e, ret) in [
+        (E::JsonbAccessInner, T::Jsonb),
+        (E::JsonbAccessStr, T::Varchar),
+    ] {
+        for key in [T::Varchar, T::Int32] {
+            map.insert(FuncSign::new(e, vec![T::Jsonb, key]), ret);
+        }
+    }
+    map.insert(
+        FuncSign::new(E::JsonbAccessPath, vec![T::Jsonb, T::Varchar]),
+        T::Jsonb,
+    );
+    map.insert(
+        FuncSign::new(E::JsonbAccessPathStr, vec![T::Jsonb, T::Varchar]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::JsonbContains, vec![T::Jsonb, T::Jsonb]),
+        T::Boolean,
+    );
+    map.insert(FuncSign::new(E::JsonbTypeof, vec![T::Jsonb]), T::Varchar);
+    map.insert(FuncSign::new(E::JsonbArrayLength, vec![T::Jsonb]), T::Int32);
+
+    // binary string and uuid expressions
+    map.insert(
+        FuncSign::new(E::Encode, vec![T::Bytea, T::Varchar]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::Decode, vec![T::Varchar, T::Varchar]),
+        T::Bytea,
+    );
+    map.insert(FuncSign::new(E::GenRandomUuid, vec![]), T::Uuid);
+    map.insert(FuncSign::new(E::Now, vec![]), T::Timestampz);
 
     map
 }
@@ -420,6 +533,9 @@ fn build_cast_map() -> HashMap<(DataTypeName, DataTypeName), CastContext> {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
+        T::Bytea,
+        T::Uuid,
     ] {
         m.insert((t, T::Varchar), CastContext::Assign);
         // Casting from string is explicit-only in PG.
@@ -0,0 +1,148 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use futures_async_stream::for_await;
+use itertools::Itertools;
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::session_config::QueryMode;
+use risingwave_common::types::DataType;
+use risingwave_sqlparser::ast::{Ident, ObjectName};
+use risingwave_sqlparser::parser::Parser;
+
+use super::query::execute;
+use crate::binder::Binder;
+use crate::catalog::table_statistics::{ColumnStatistics, DistinctCountSketch, TableStatistics};
+use crate::session::OptimizerContext;
+
+/// Collects the row count of a table and the distinct counts and null fractions of its visible
+/// columns by scanning it, and stores them in the catalog for the optimizer. Distinct counts are
+/// estimated with [`DistinctCountSketch`].
+pub(super) async fn handle_analyze(
+    context: OptimizerContext,
+    table_name: ObjectName,
+) -> Result<PgResponse> {
+    let session = context.session_ctx.clone();
+    let (schema_name, table_name) = session.resolve_relation_name(table_name)?;
+
+    let (table_id, columns) = {
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let table =
+            catalog_reader.get_table_by_name(session.database(), &schema_name, &table_name)?;
+        let columns = table
+            .columns()
+            .iter()
+            .filter(|c| {
+                !c.is_hidden()
+                    && !matches!(
+                        c.data_type(),
+                        DataType::Struct { .. } | DataType::List { .. }
+                    )
+            })
+            .map(|c| (c.column_id(), c.name().to_string()))
+            .collect_vec();
+        (table.id(), columns)
+    };
+
+    // Scan the visible columns and summarize them on the fly, so that the memory used does not
+    // grow with the size of the table. Distinct values are estimated with a sketch per column.
+    // A constant is selected if there is no column to analyze, so that the rows are still counted.
+    let select_items = if columns.is_empty() {
+        "1".to_string()
+    } else {
+        columns
+            .iter()
+            .map(|(_, name)| Ident::with_quote('"', name).to_string())
+            .join(", ")
+    };
+    let sql = format!(
+        "SELECT {} FROM {}.{}",
+        select_items,
+        Ident::with_quote('"', &schema_name),
+        Ident::with_quote('"', &table_name)
+    );
+    let stmt = Parser::parse_sql(&sql)
+        .map_err(|e| ErrorCode::InternalError(format!("failed to parse {}: {}", sql, e)))?
+        .remove(0);
+
+    let bound = {
+        let mut binder = Binder::new(&session);
+        binder.bind(stmt)?
+    };
+    let (data_stream, _) = execute(context, bound, QueryMode::Distributed).await?;
+
+    let mut row_count = 0;
+    let mut null_counts = vec![0u64; columns.len()];
+    let mut sketches = columns
+        .iter()
+        .map(|_| DistinctCountSketch::default())
+        .collect_vec();
+    #[for_await]
+    for chunk in data_stream {
+        let chunk = chunk?.compact()?;
+        row_count += chunk.cardinality() as u64;
+        for ((column, null_count), sketch) in chunk.columns()[..columns.len()]
+            .iter()
+            .zip_eq(null_counts.iter_mut())
+            .zip_eq(sketches.iter_mut())
+        {
+            let array = column.array_ref();
+            for idx in 0..array.len() {
+                if array.value_at(idx).is_none() {
+                    *null_count += 1;
+                } else {
+                    let mut hasher = DefaultHasher::new();
+                    array.hash_at(idx, &mut hasher);
+                    sketch.add(hasher.finish());
+                }
+            }
+        }
+    }
+
+    let statistics = TableStatistics {
+        row_count,
+        columns: columns
+            .iter()
+            .zip_eq(null_counts)
+            .zip_eq(sketches)
+            .map(|(((column_id, _), null_count), sketch)| {
+                let null_fraction = if row_count == 0 {
+                    0.0
+                } else {
+                    null_count as f64 / row_count as f64
+                };
+                // The estimate may slightly exceed the number of non-null values.
+                let distinct_count = sketch.estimate().min(row_count - null_count);
+                (
+                    *column_id,
+                    ColumnStatistics {
+                        distinct_count,
+                        null_fraction,
+                    },
+                )
+            })
+            .collect(),
+    };
+
+    session
+        .env()
+        .catalog_writer()
+        .update_table_statistics(table_id, statistics.to_prost())
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ANALYZE))
+}
@@ -66,7 +66,7 @@ pub(crate) fn gen_create_index_plan(
         })
         .try_collect::<_, Vec<_>, _>()?;
 
-    let (schema_name, table_name) = Binder::resolve_table_name(table_name)?;
+    let (schema_name, table_name) = session.resolve_relation_name(table_name)?;
     let catalog_reader = session.env().catalog_reader();
     let table = catalog_reader
         .read_guard()
@@ -122,7 +122,9 @@ pub(crate) fn gen_create_index_plan(
         .gen_create_index_plan(index_name.to_string(), table.id())?
     };
 
-    let (index_schema_name, index_table_name) = Binder::resolve_table_name(index_name)?;
+    // An index is created in the schema of its table unless qualified otherwise.
+    let (index_schema_name, index_table_name) = Binder::resolve_schema_qualified_name(index_name)?;
+    let index_schema_name = index_schema_name.unwrap_or(schema_name);
     let (index_database_id, index_schema_id) = session
         .env()
         .catalog_reader()
@@ -145,11 +147,12 @@ pub async fn handle_create_index(
     name: ObjectName,
     table_name: ObjectName,
     columns: Vec<OrderByExpr>,
+    definition: String,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
 
     let (plan, table) = {
-        let (plan, table) = gen_create_index_plan(
+        let (plan, mut table) = gen_create_index_plan(
             &session,
             context.into(),
             name.clone(),
@@ -157,6 +160,7 @@ pub async fn handle_create_index(
             columns,
         )?;
         let plan = plan.to_stream_prost();
+        table.definition = definition;
 
         (plan, table)
     };
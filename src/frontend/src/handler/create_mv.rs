@@ -15,9 +15,10 @@
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::catalog::Table as ProstTable;
-use risingwave_sqlparser::ast::{ObjectName, Query};
+use risingwave_sqlparser::ast::{ObjectName, Query, SqlOption, Value};
 
 use crate::binder::{Binder, BoundSetExpr};
+use crate::optimizer::plan_node::PlanTreeNode;
 use crate::optimizer::property::Distribution;
 use crate::optimizer::PlanRef;
 use crate::planner::Planner;
@@ -30,7 +31,7 @@ pub fn gen_create_mv_plan(
     query: Box<Query>,
     name: ObjectName,
 ) -> Result<(PlanRef, ProstTable)> {
-    let (schema_name, table_name) = Binder::resolve_table_name(name)?;
+    let (schema_name, table_name) = session.resolve_name_for_create(name)?;
     let (database_id, schema_id) = session
         .env()
         .catalog_reader()
@@ -38,10 +39,7 @@ pub fn gen_create_mv_plan(
         .check_relation_name_duplicated(session.database(), &schema_name, &table_name)?;
 
     let bound = {
-        let mut binder = Binder::new(
-            session.env().catalog_reader().read_guard(),
-            session.database().to_string(),
-        );
+        let mut binder = Binder::new_for_stream(&session);
         binder.bind_query(*query)?
     };
 
@@ -56,25 +54,90 @@ pub fn gen_create_mv_plan(
         }
     }
 
+    let emit_on_window_close = context.inner().emit_on_window_close;
     let mut plan_root = Planner::new(context).plan_query(bound)?;
     plan_root.set_required_dist(Distribution::any().clone());
     let materialize = plan_root.gen_create_mv_plan(table_name)?;
     let table = materialize.table().to_prost(schema_id, database_id);
     let plan: PlanRef = materialize.into();
 
+    if emit_on_window_close && !has_emit_on_window_close_agg(&plan) {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "emit_on_window_close requires a GROUP BY on a column with watermark".to_string(),
+        )
+        .into());
+    }
+
     Ok((plan, table))
 }
 
+fn has_emit_on_window_close_agg(plan: &PlanRef) -> bool {
+    plan.as_stream_hash_agg()
+        .map_or(false, |agg| agg.emit_on_window_close())
+        || plan.inputs().iter().any(has_emit_on_window_close_agg)
+}
+
+/// Extract `emit_on_window_close` from the `WITH` options of `CREATE MATERIALIZED VIEW`.
+pub(crate) fn emit_on_window_close_option(with_options: &[SqlOption]) -> Result<bool> {
+    let Some(option) = with_options
+        .iter()
+        .find(|option| option.name.value.eq_ignore_ascii_case("emit_on_window_close"))
+    else {
+        return Ok(false);
+    };
+    match &option.value {
+        Value::Boolean(b) => Ok(*b),
+        Value::SingleQuotedString(s) if s.eq_ignore_ascii_case("true") => Ok(true),
+        Value::SingleQuotedString(s) if s.eq_ignore_ascii_case("false") => Ok(false),
+        value => Err(ErrorCode::InvalidInputSyntax(format!(
+            "emit_on_window_close must be a boolean, got {}",
+            value
+        ))
+        .into()),
+    }
+}
+
+/// Extract `state_ttl_seconds` from the `WITH` options of `CREATE MATERIALIZED VIEW`, in
+/// milliseconds.
+///
+/// The TTL applies to the states of hash joins and hash aggregations: join rows and groups that
+/// haven't been updated within it are forgotten, while the results derived from them are kept.
+pub(crate) fn state_ttl_option(with_options: &[SqlOption]) -> Result<Option<u64>> {
+    let Some(option) = with_options
+        .iter()
+        .find(|option| option.name.value.eq_ignore_ascii_case("state_ttl_seconds"))
+    else {
+        return Ok(None);
+    };
+    let seconds = match &option.value {
+        Value::Number(n, _) | Value::SingleQuotedString(n) => n.parse::<u64>().ok(),
+        _ => None,
+    };
+    match seconds {
+        Some(seconds) if seconds > 0 => Ok(Some(seconds * 1000)),
+        _ => Err(ErrorCode::InvalidInputSyntax(format!(
+            "state_ttl_seconds must be a positive integer, got {}",
+            option.value
+        ))
+        .into()),
+    }
+}
+
 pub async fn handle_create_mv(
-    context: OptimizerContext,
+    mut context: OptimizerContext,
     name: ObjectName,
     query: Box<Query>,
+    with_options: Vec<SqlOption>,
+    definition: String,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
+    context.emit_on_window_close = emit_on_window_close_option(&with_options)?;
+    context.state_ttl_ms = state_ttl_option(&with_options)?;
 
     let (table, stream_plan) = {
-        let (plan, table) = gen_create_mv_plan(&session, context.into(), query, name)?;
+        let (plan, mut table) = gen_create_mv_plan(&session, context.into(), query, name)?;
         let stream_plan = plan.to_stream_prost();
+        table.definition = definition;
         (table, stream_plan)
     };
 
@@ -193,4 +256,70 @@ pub mod tests {
             "Bind error: An alias must be specified for an expression"
         );
     }
+
+    #[tokio::test]
+    async fn test_emit_on_window_close() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql =
+            "create source s (v int, ts timestamp, watermark for ts as ts - interval '5' second) \
+            with ('kafka.topic' = 'abc') row format json";
+        frontend.run_sql(sql).await.unwrap();
+
+        let sql = "create materialized view mv1 with (emit_on_window_close = true) as \
+            select ts, count(*) as cnt from s group by ts";
+        frontend.run_sql(sql).await.unwrap();
+
+        // The group key has no watermark, so the windows can never be closed.
+        let sql = "create materialized view mv2 with (emit_on_window_close = true) as \
+            select v, count(*) as cnt from s group by v";
+        let err = frontend.run_sql(sql).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid input syntax: emit_on_window_close requires a GROUP BY on a column with \
+            watermark"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_state_ttl() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = "create table t1 (v1 int, v2 int)";
+        frontend.run_sql(sql).await.unwrap();
+        let sql = "create table t2 (v1 int, v2 int)";
+        frontend.run_sql(sql).await.unwrap();
+
+        let sql = "create materialized view mv1 with (state_ttl_seconds = 86400) as \
+            select t1.v2 as l, t2.v2 as r from t1 join t2 on t1.v1 = t2.v1";
+        frontend.run_sql(sql).await.unwrap();
+
+        let sql = "create materialized view mv2 with (state_ttl_seconds = 'forever') as \
+            select t1.v2 as l, t2.v2 as r from t1 join t2 on t1.v1 = t2.v1";
+        let err = frontend.run_sql(sql).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid input syntax: state_ttl_seconds must be a positive integer, got 'forever'"
+        );
+
+        // The groups of the aggregation expire as well.
+        let sql = "create materialized view mv3 with (state_ttl_seconds = 86400) as \
+            select t1.v1 as k, count(*) as cnt from t1 join t2 on t1.v1 = t2.v1 group by t1.v1";
+        frontend.run_sql(sql).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_now_in_mv() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = "create table t (v1 int, ts timestamp)";
+        frontend.run_sql(sql).await.unwrap();
+
+        let sql = "create materialized view mv as select v1 from t where ts > now()";
+        let err = frontend.run_sql(sql).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Feature is not yet implemented: now() in materialized views, No tracking issue"
+        );
+    }
 }
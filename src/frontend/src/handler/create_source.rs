@@ -16,19 +16,25 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::ErrorCode::ProtocolError;
+use risingwave_common::catalog::Field;
+use risingwave_common::error::ErrorCode::{self, ProtocolError};
 use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
 use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::{Source as ProstSource, StreamSourceInfo};
-use risingwave_pb::plan_common::{ColumnCatalog as ProstColumnCatalog, RowFormatType};
-use risingwave_source::ProtobufParser;
+use risingwave_pb::plan_common::{
+    ColumnCatalog as ProstColumnCatalog, RowFormatType, WatermarkDesc,
+};
+use risingwave_source::{ProtobufParser, SourceMetaColumn};
 use risingwave_sqlparser::ast::{
-    CreateSourceStatement, ObjectName, ProtobufSchema, SourceSchema, SqlOption, Value,
+    CreateSourceStatement, ObjectName, ProtobufSchema, SourceSchema, SourceWatermark, SqlOption,
+    Value,
 };
 
-use super::create_table::{bind_sql_columns, gen_materialized_source_plan};
+use super::create_table::{bind_sql_columns, bind_sql_pk_column_ids, gen_materialized_source_plan};
 use crate::binder::Binder;
 use crate::catalog::column_catalog::ColumnCatalog;
+use crate::expr::Expr;
 use crate::session::{OptimizerContext, SessionImpl};
 
 pub(crate) fn make_prost_source(
@@ -36,7 +42,7 @@ pub(crate) fn make_prost_source(
     name: ObjectName,
     source_info: Info,
 ) -> Result<ProstSource> {
-    let (schema_name, name) = Binder::resolve_table_name(name)?;
+    let (schema_name, name) = session.resolve_name_for_create(name)?;
 
     let (database_id, schema_id) = session
         .env()
@@ -50,6 +56,7 @@ pub(crate) fn make_prost_source(
         database_id,
         name,
         info: Some(source_info),
+        definition: "".to_string(),
     })
 }
 
@@ -79,41 +86,124 @@ fn handle_source_with_properties(options: Vec<SqlOption>) -> Result<HashMap<Stri
         .collect()
 }
 
+/// Bind the `WATERMARK FOR` definitions of a source. The expression of each watermark is bound
+/// against the watermark column alone, which is the only input when it is evaluated.
+fn bind_source_watermarks(
+    session: &SessionImpl,
+    source_name: &str,
+    source_watermarks: Vec<SourceWatermark>,
+    columns: &[ProstColumnCatalog],
+) -> Result<Vec<WatermarkDesc>> {
+    let mut binder = Binder::new(session);
+    source_watermarks
+        .into_iter()
+        .map(|SourceWatermark { column, expr }| {
+            let (watermark_idx, column_desc) = columns
+                .iter()
+                .map(|c| c.column_desc.as_ref().unwrap())
+                .find_position(|c| c.name == column.value)
+                .ok_or_else(|| {
+                    ErrorCode::ItemNotFound(format!("Invalid watermark column: {}", column.value))
+                })?;
+            let data_type = DataType::from(column_desc.get_column_type()?);
+            let expr = binder.bind_expr_on_columns(
+                vec![Field::with_name(
+                    data_type.clone(),
+                    column_desc.name.clone(),
+                )],
+                source_name.to_string(),
+                expr,
+            )?;
+            if expr.return_type() != data_type {
+                return Err(ErrorCode::BindError(format!(
+                    "The return type of the watermark expression must be {:?}, but got {:?}",
+                    data_type,
+                    expr.return_type()
+                ))
+                .into());
+            }
+            Ok(WatermarkDesc {
+                watermark_idx: watermark_idx as u32,
+                expr: Some(expr.to_expr_proto()),
+            })
+        })
+        .collect()
+}
+
 pub async fn handle_create_source(
     context: OptimizerContext,
     is_materialized: bool,
     stmt: CreateSourceStatement,
+    definition: String,
 ) -> Result<PgResponse> {
-    let source = match &stmt.source_schema {
+    let columns = match &stmt.source_schema {
         SourceSchema::Protobuf(protobuf_schema) => {
             let mut columns = vec![ColumnCatalog::row_id_column().to_protobuf()];
             columns.extend(extract_protobuf_table_schema(protobuf_schema)?.into_iter());
-            StreamSourceInfo {
-                properties: handle_source_with_properties(stmt.with_properties.0)?,
-                row_format: RowFormatType::Protobuf as i32,
-                row_schema_location: protobuf_schema.row_schema_location.0.clone(),
-                row_id_index: 0,
-                columns,
-                pk_column_ids: vec![0],
-            }
+            columns
         }
-        SourceSchema::Json => StreamSourceInfo {
-            properties: handle_source_with_properties(stmt.with_properties.0)?,
-            row_format: RowFormatType::Json as i32,
-            row_schema_location: "".to_string(),
-            row_id_index: 0,
-            columns: bind_sql_columns(stmt.columns)?,
-            pk_column_ids: vec![0],
-        },
+        SourceSchema::Json
+        | SourceSchema::DebeziumJson
+        | SourceSchema::DebeziumAvro(_)
+        | SourceSchema::Maxwell
+        | SourceSchema::CanalJson => bind_sql_columns(&stmt.columns)?,
+    };
+    for column in &columns {
+        let column_desc = column.column_desc.as_ref().unwrap();
+        if let Some(meta_column) = SourceMetaColumn::from_name(&column_desc.name) {
+            meta_column.check_data_type(&DataType::from(column_desc.get_column_type()?))?;
+        }
+    }
+    let mut pk_column_ids = bind_sql_pk_column_ids(&stmt.columns, &stmt.constraints, &columns)?;
+    if pk_column_ids.is_empty() {
+        pk_column_ids = vec![0];
+    } else if !is_materialized {
+        // The source itself keeps every message, so a declared primary key can only be enforced by
+        // materializing it.
+        return Err(ErrorCode::InvalidInputSyntax(
+            "PRIMARY KEY is only supported on materialized sources".to_string(),
+        )
+        .into());
+    }
+    let (row_format, row_schema_location) = match &stmt.source_schema {
+        SourceSchema::Protobuf(protobuf_schema) => (
+            RowFormatType::Protobuf,
+            protobuf_schema.row_schema_location.0.clone(),
+        ),
+        SourceSchema::Json => (RowFormatType::Json, "".to_string()),
+        SourceSchema::DebeziumJson => (RowFormatType::DebeziumJson, "".to_string()),
+        SourceSchema::DebeziumAvro(debezium_avro_schema) => (
+            RowFormatType::DebeziumAvro,
+            debezium_avro_schema.row_schema_location.0.clone(),
+        ),
+        SourceSchema::Maxwell => (RowFormatType::Maxwell, "".to_string()),
+        SourceSchema::CanalJson => (RowFormatType::CanalJson, "".to_string()),
     };
-
     let session = context.session_ctx.clone();
-    let source = make_prost_source(&session, stmt.source_name, Info::StreamSource(source))?;
+    let watermark_descs = bind_source_watermarks(
+        &session,
+        &stmt.source_name.to_string(),
+        stmt.source_watermarks,
+        &columns,
+    )?;
+    let source = StreamSourceInfo {
+        properties: handle_source_with_properties(stmt.with_properties.0)?,
+        row_format: row_format as i32,
+        row_schema_location,
+        row_id_index: 0,
+        columns,
+        pk_column_ids,
+        watermark_descs,
+    };
+
+    let mut source = make_prost_source(&session, stmt.source_name, Info::StreamSource(source))?;
+    source.definition = definition;
     let catalog_writer = session.env().catalog_writer();
     if is_materialized {
         let (plan, table) = {
-            let (plan, table) = gen_materialized_source_plan(context.into(), source.clone())?;
+            let (plan, mut table) = gen_materialized_source_plan(context.into(), source.clone())?;
             let plan = plan.to_stream_prost();
+            table.definition = source.definition.clone();
             (plan, table)
         };
         catalog_writer
@@ -18,11 +18,11 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{ColumnDesc, ColumnId};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::{Source as ProstSource, Table as ProstTable, TableSourceInfo};
 use risingwave_pb::plan_common::ColumnCatalog;
-use risingwave_sqlparser::ast::{ColumnDef, ObjectName};
+use risingwave_sqlparser::ast::{ColumnDef, ColumnOption, ObjectName, TableConstraint};
 
 use super::create_source::make_prost_source;
 use crate::binder::expr::bind_data_type;
@@ -32,21 +32,20 @@ use crate::optimizer::property::{Distribution, Order};
 use crate::optimizer::{PlanRef, PlanRoot};
 use crate::session::{OptimizerContext, OptimizerContextRef, SessionImpl};
 
-// FIXME: store PK columns in ProstTableSourceInfo as Catalog information, and then remove this
-
 /// Binds the column schemas declared in CREATE statement into `ColumnCatalog`.
-pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<Vec<ColumnCatalog>> {
+pub fn bind_sql_columns(columns: &[ColumnDef]) -> Result<Vec<ColumnCatalog>> {
     let column_descs = {
         let mut column_descs = Vec::with_capacity(columns.len() + 1);
-        // Put the hidden row id column in the first column. This is used for PK.
+        // Put the hidden row id column in the first column. This is used for PK, unless the user
+        // declares one.
         column_descs.push(row_id_column_desc());
         // Then user columns.
-        for (i, column) in columns.into_iter().enumerate() {
+        for (i, column) in columns.iter().enumerate() {
             check_valid_column_name(&column.name.value)?;
             column_descs.push(ColumnDesc {
                 data_type: bind_data_type(&column.data_type)?,
                 column_id: ColumnId::new((i + 1) as i32),
-                name: column.name.value,
+                name: column.name.value.clone(),
                 field_descs: vec![],
                 type_name: "".to_string(),
             });
@@ -65,17 +64,81 @@ pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<Vec<ColumnCatalog>> {
     Ok(columns_catalog)
 }
 
+/// Binds the `PRIMARY KEY` declared on a column or as a table constraint into the ids of the
+/// bound `columns`. Returns an empty vector if there is none.
+pub fn bind_sql_pk_column_ids(
+    column_defs: &[ColumnDef],
+    constraints: &[TableConstraint],
+    columns: &[ColumnCatalog],
+) -> Result<Vec<i32>> {
+    let mut pk_names = vec![];
+    let column_pks = column_defs.iter().filter(|column| {
+        column
+            .options
+            .iter()
+            .any(|def| matches!(def.option, ColumnOption::Unique { is_primary: true }))
+    });
+    for column in column_pks {
+        pk_names.push(vec![column.name.value.clone()]);
+    }
+    for constraint in constraints {
+        if let TableConstraint::Unique {
+            columns,
+            is_primary: true,
+            ..
+        } = constraint
+        {
+            pk_names.push(columns.iter().map(|c| c.value.clone()).collect_vec());
+        }
+    }
+    if pk_names.len() > 1 {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "multiple primary keys are not allowed".to_string(),
+        )
+        .into());
+    }
+
+    let mut pk_column_ids = vec![];
+    for name in pk_names.into_iter().flatten() {
+        let column_id = columns
+            .iter()
+            .filter(|c| !c.is_hidden)
+            .filter_map(|c| c.column_desc.as_ref())
+            .find(|desc| desc.name == name)
+            .ok_or_else(|| {
+                ErrorCode::InvalidInputSyntax(format!(
+                    "column \"{}\" named in key does not exist",
+                    name
+                ))
+            })?
+            .column_id;
+        if pk_column_ids.contains(&column_id) {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "column \"{}\" appears twice in primary key constraint",
+                name
+            ))
+            .into());
+        }
+        pk_column_ids.push(column_id);
+    }
+    Ok(pk_column_ids)
+}
+
 pub(crate) fn gen_create_table_plan(
     session: &SessionImpl,
     context: OptimizerContextRef,
     table_name: ObjectName,
     columns: Vec<ColumnDef>,
+    constraints: Vec<TableConstraint>,
 ) -> Result<(PlanRef, ProstSource, ProstTable)> {
+    let column_catalogs = bind_sql_columns(&columns)?;
+    let pk_column_ids = bind_sql_pk_column_ids(&columns, &constraints, &column_catalogs)?;
     let source = make_prost_source(
         session,
         table_name,
         Info::TableSource(TableSourceInfo {
-            columns: bind_sql_columns(columns)?,
+            columns: column_catalogs,
+            pk_column_ids,
         }),
     )?;
     let (plan, table) = gen_materialized_source_plan(context, source.clone())?;
@@ -84,6 +147,8 @@ pub(crate) fn gen_create_table_plan(
 
 /// Generate a stream plan with `StreamSource` + `StreamMaterialize`, it ressembles a
 /// `CREATE MATERIALIZED VIEW AS SELECT * FROM <source>`.
+///
+/// If the source has a user-declared primary key, the materialized table upserts on it.
 pub(crate) fn gen_materialized_source_plan(
     context: OptimizerContextRef,
     source: ProstSource,
@@ -92,20 +157,27 @@ pub(crate) fn gen_materialized_source_plan(
         // Manually assemble the materialization plan for the table.
         let source_node: PlanRef =
             StreamSource::new(LogicalSource::new(Rc::new((&source).into()), context)).into();
+        let pk_indices = source_node.pk_indices().to_vec();
         let mut required_cols = FixedBitSet::with_capacity(source_node.schema().len());
         required_cols.toggle_range(..);
         required_cols.toggle(0);
         let mut out_names = source_node.schema().names();
         out_names.remove(0);
 
-        PlanRoot::new(
+        let materialize = PlanRoot::new(
             source_node,
-            Distribution::HashShard(vec![0]),
+            Distribution::HashShard(pk_indices.clone()),
             Order::any().clone(),
             required_cols,
             out_names,
         )
-        .gen_create_mv_plan(source.name.clone())?
+        .gen_create_mv_plan(source.name.clone())?;
+        // The hidden row id column in the first column is the pk, unless the user declares one.
+        if pk_indices == [0] {
+            materialize
+        } else {
+            materialize.with_handle_pk_conflict()
+        }
     };
     let table = materialize
         .table()
@@ -118,13 +190,22 @@ pub async fn handle_create_table(
     context: OptimizerContext,
     table_name: ObjectName,
     columns: Vec<ColumnDef>,
+    constraints: Vec<TableConstraint>,
+    definition: String,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
 
     let (plan, source, table) = {
-        let (plan, source, table) =
-            gen_create_table_plan(&session, context.into(), table_name.clone(), columns)?;
+        let (plan, mut source, mut table) = gen_create_table_plan(
+            &session,
+            context.into(),
+            table_name.clone(),
+            columns,
+            constraints,
+        )?;
         let plan = plan.to_stream_prost();
+        source.definition = definition.clone();
+        table.definition = definition;
 
         (plan, source, table)
     };
@@ -196,4 +277,42 @@ mod tests {
 
         assert_eq!(columns, expected_columns);
     }
+
+    #[tokio::test]
+    async fn test_create_table_with_pk() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t1 (v1 int, v2 int primary key, v3 int)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create table t2 (v1 int, v2 int, v3 int, primary key (v3, v1))")
+            .await
+            .unwrap();
+
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+        let pk_names = |name: &str| {
+            let reader = catalog_reader.read_guard();
+            let table = reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, name)
+                .unwrap();
+            table
+                .pks
+                .iter()
+                .map(|&idx| table.columns()[idx].name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(pk_names("t1"), vec!["v2"]);
+        assert_eq!(pk_names("t2"), vec!["v3", "v1"]);
+
+        for sql in [
+            "create table t3 (v1 int primary key, v2 int primary key)",
+            "create table t3 (v1 int primary key, primary key (v1))",
+            "create table t3 (v1 int, primary key (v2))",
+            "create table t3 (v1 int, primary key (v1, v1))",
+        ] {
+            assert!(frontend.run_sql(sql).await.is_err(), "{}", sql);
+        }
+    }
 }
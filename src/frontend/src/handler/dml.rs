@@ -17,7 +17,6 @@ use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::Result;
 use risingwave_sqlparser::ast::Statement;
 
-use super::query::IMPLICIT_FLUSH;
 use crate::binder::Binder;
 use crate::handler::util::{to_pg_field, to_pg_rows};
 use crate::planner::Planner;
@@ -29,10 +28,7 @@ pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<Pg
     let session = context.session_ctx.clone();
 
     let bound = {
-        let mut binder = Binder::new(
-            session.env().catalog_reader().read_guard(),
-            session.database().to_string(),
-        );
+        let mut binder = Binder::new(&session);
         binder.bind(stmt)?
     };
 
@@ -71,10 +67,9 @@ pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<Pg
     };
 
     // Implicitly flush the writes.
-    if let Some(flag) = session.get_config(IMPLICIT_FLUSH) {
-        if flag.is_set(false) {
-            flush_for_write(&session, stmt_type).await?;
-        }
+    let implicit_flush = session.config().get_implicit_flush();
+    if implicit_flush {
+        flush_for_write(&session, stmt_type).await?;
     }
 
     Ok(PgResponse::new(stmt_type, rows_count, rows, pg_descs))
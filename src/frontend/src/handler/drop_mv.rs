@@ -17,7 +17,6 @@ use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::stream_plan::source_node::SourceType;
 use risingwave_sqlparser::ast::ObjectName;
 
-use crate::binder::Binder;
 use crate::session::OptimizerContext;
 
 pub async fn handle_drop_mv(
@@ -25,7 +24,7 @@ pub async fn handle_drop_mv(
     table_name: ObjectName,
 ) -> Result<PgResponse> {
     let session = context.session_ctx;
-    let (schema_name, table_name) = Binder::resolve_table_name(table_name)?;
+    let (schema_name, table_name) = session.resolve_relation_name(table_name)?;
 
     let catalog_reader = session.env().catalog_reader();
 
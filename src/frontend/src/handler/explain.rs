@@ -15,21 +15,33 @@
 use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_sqlparser::ast::Statement;
 
-use super::create_mv::gen_create_mv_plan;
+use super::create_mv::{emit_on_window_close_option, gen_create_mv_plan, state_ttl_option};
 use super::create_table::gen_create_table_plan;
 use crate::binder::Binder;
+use crate::optimizer::PlanRef;
 use crate::planner::Planner;
+use crate::scheduler::plan_fragmenter::BatchPlanFragmenter;
+use crate::scheduler::{ExecutionContext, ExecutionContextRef};
 use crate::session::OptimizerContext;
 
-pub(super) fn handle_explain(
-    context: OptimizerContext,
+pub(super) async fn handle_explain(
+    mut context: OptimizerContext,
     stmt: Statement,
-    _verbose: bool,
+    verbose: bool,
+    analyze: bool,
 ) -> Result<PgResponse> {
+    if analyze {
+        return handle_explain_analyze(context, stmt, verbose).await;
+    }
+
     let session = context.session_ctx.clone();
+    if let Statement::CreateView { with_options, .. } = &stmt {
+        context.emit_on_window_close = emit_on_window_close_option(with_options)?;
+        context.state_ttl_ms = state_ttl_option(with_options)?;
+    }
     // bind, plan, optimize, and serialize here
     let mut planner = Planner::new(context.into());
 
@@ -42,16 +54,16 @@ pub(super) fn handle_explain(
             ..
         } => gen_create_mv_plan(&*session, planner.ctx(), query, name)?.0,
 
-        Statement::CreateTable { name, columns, .. } => {
-            gen_create_table_plan(&*session, planner.ctx(), name, columns)?.0
-        }
+        Statement::CreateTable {
+            name,
+            columns,
+            constraints,
+            ..
+        } => gen_create_table_plan(&*session, planner.ctx(), name, columns, constraints)?.0,
 
         stmt => {
             let bound = {
-                let mut binder = Binder::new(
-                    session.env().catalog_reader().read_guard(),
-                    session.database().to_string(),
-                );
+                let mut binder = Binder::new(&session);
                 binder.bind(stmt)?
             };
             let logical = planner.plan(bound)?;
@@ -66,7 +78,93 @@ pub(super) fn handle_explain(
         .map(|s| Row::new(vec![Some(s.into())]))
         .collect::<Vec<_>>();
 
-    Ok(PgResponse::new(
+    Ok(explain_response(rows))
+}
+
+/// Runs the query on the distributed scheduler, and explains its plan with the runtime
+/// statistics of every operator, aggregated over all tasks of its stage. With `verbose`, more
+/// statistics of the operators and the peak memory of the tasks are shown.
+async fn handle_explain_analyze(
+    context: OptimizerContext,
+    stmt: Statement,
+    verbose: bool,
+) -> Result<PgResponse> {
+    if !matches!(stmt, Statement::Query(_)) {
+        return Err(ErrorCode::NotImplemented(
+            format!("EXPLAIN ANALYZE of statement: {}", stmt),
+            None.into(),
+        )
+        .into());
+    }
+    let session = context.session_ctx.clone();
+
+    // Subblock to make sure PlanRef (an Rc) is dropped before `await` below.
+    let (query, plan_lines) = {
+        let bound = {
+            let mut binder = Binder::new(&session);
+            binder.bind(stmt)?
+        };
+        let plan = Planner::new(context.into())
+            .plan(bound)?
+            .gen_batch_query_plan()?;
+
+        let mut plan_lines = vec![];
+        explain_with_ids(&plan, 0, &mut plan_lines);
+
+        let plan_fragmenter = BatchPlanFragmenter::new(session.env().worker_node_manager_ref());
+        (plan_fragmenter.split(plan)?, plan_lines)
+    };
+
+    let execution_context: ExecutionContextRef = ExecutionContext::new(session.clone()).into();
+    let query_manager = execution_context.session().env().query_manager().clone();
+    let stats = query_manager
+        .schedule_with_stats(execution_context, query)
+        .await?;
+
+    let mut rows = plan_lines
+        .into_iter()
+        .map(|(operator_id, line)| {
+            let line = match stats.get_operator(operator_id) {
+                Some(operator_stats) => format!("{} ({})", line, operator_stats.explain(verbose)),
+                None => format!("{} (never executed)", line),
+            };
+            Row::new(vec![Some(line)])
+        })
+        .collect::<Vec<_>>();
+    rows.push(Row::new(vec![Some(format!(
+        "Execution time: {:.3}ms",
+        stats.elapsed.as_secs_f64() * 1000.0
+    ))]));
+    if verbose {
+        rows.push(Row::new(vec![Some(format!(
+            "Peak task memory: {}B",
+            stats.peak_task_memory_bytes
+        ))]));
+    }
+    if stats.unfinished_tasks > 0 {
+        rows.push(Row::new(vec![Some(format!(
+            "Warning: statistics are incomplete, {} tasks had not finished",
+            stats.unfinished_tasks
+        ))]));
+    }
+
+    Ok(explain_response(rows))
+}
+
+/// Explains each plan node in a line, together with its id which is also the operator id of the
+/// executors running it.
+fn explain_with_ids(plan: &PlanRef, level: usize, lines: &mut Vec<(u64, String)>) {
+    lines.push((
+        plan.id().0 as u64,
+        format!("{}{}", " ".repeat(level * 2), plan),
+    ));
+    for input in plan.inputs() {
+        explain_with_ids(&input, level + 1, lines);
+    }
+}
+
+fn explain_response(rows: Vec<Row>) -> PgResponse {
+    PgResponse::new(
         StatementType::EXPLAIN,
         rows.len() as i32,
         rows,
@@ -74,5 +172,5 @@ pub(super) fn handle_explain(
             "QUERY PLAN".to_owned(),
             TypeOid::Varchar,
         )],
-    ))
+    )
 }
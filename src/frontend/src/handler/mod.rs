@@ -20,6 +20,7 @@ use risingwave_sqlparser::ast::{DropStatement, ObjectType, Statement};
 
 use crate::session::{OptimizerContext, SessionImpl};
 
+mod analyze;
 mod create_database;
 pub mod create_index;
 pub mod create_mv;
@@ -37,22 +38,37 @@ mod explain;
 mod flush;
 #[allow(dead_code)]
 pub mod query;
-mod set;
 mod show;
 pub mod util;
+mod variable;
 
 pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result<PgResponse> {
     let context = OptimizerContext::new(session.clone());
+    let definition = match &stmt {
+        Statement::CreateSource { .. }
+        | Statement::CreateTable { .. }
+        | Statement::CreateView { .. }
+        | Statement::CreateIndex { .. } => util::normalized_definition(&stmt),
+        _ => String::new(),
+    };
     match stmt {
         Statement::Explain {
-            statement, verbose, ..
-        } => explain::handle_explain(context, *statement, verbose),
+            statement,
+            verbose,
+            analyze,
+            ..
+        } => explain::handle_explain(context, *statement, verbose, analyze).await,
         Statement::CreateSource {
             is_materialized,
             stmt,
-        } => create_source::handle_create_source(context, is_materialized, stmt).await,
-        Statement::CreateTable { name, columns, .. } => {
-            create_table::handle_create_table(context, name, columns).await
+        } => create_source::handle_create_source(context, is_materialized, stmt, definition).await,
+        Statement::CreateTable {
+            name,
+            columns,
+            constraints,
+            ..
+        } => {
+            create_table::handle_create_table(context, name, columns, constraints, definition).await
         }
         Statement::CreateDatabase {
             db_name,
@@ -68,6 +84,9 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
         // TODO: support complex sql for `show columns from <table>`
         Statement::ShowColumn { name } => describe::handle_describe(context, name).await,
         Statement::ShowObjects(show_object) => show::handle_show_object(context, show_object).await,
+        Statement::ShowCreateObject { create_type, name } => {
+            show::handle_show_create_object(context, create_type, name)
+        }
         Statement::Drop(DropStatement {
             object_type,
             object_name,
@@ -102,14 +121,18 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             or_replace: false,
             name,
             query,
+            with_options,
             ..
-        } => create_mv::handle_create_mv(context, name, query).await,
+        } => create_mv::handle_create_mv(context, name, query, with_options, definition).await,
         Statement::Flush => flush::handle_flush(context).await,
+        Statement::Analyze { table_name } => analyze::handle_analyze(context, table_name).await,
         Statement::SetVariable {
             local: _,
             variable,
             value,
-        } => set::handle_set(context, variable, value),
+        } => variable::handle_set(context, variable, value),
+        Statement::ShowVariable { variable } => variable::handle_show(context, variable),
+        Statement::ResetVariable { variable } => variable::handle_reset(context, variable),
         Statement::CreateIndex {
             name,
             table_name,
@@ -129,7 +152,7 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
                 )
                 .into());
             }
-            create_index::handle_create_index(context, name, table_name, columns).await
+            create_index::handle_create_index(context, name, table_name, columns, definition).await
         }
         _ => {
             Err(ErrorCode::NotImplemented(format!("Unhandled ast: {:?}", stmt), None.into()).into())
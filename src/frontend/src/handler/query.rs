@@ -12,45 +12,52 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use futures::future::Either;
 use futures_async_stream::for_await;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::session_config::QueryMode;
 use risingwave_sqlparser::ast::Statement;
 use tracing::info;
 
 use crate::binder::{Binder, BoundStatement};
 use crate::handler::util::{to_pg_field, to_pg_rows};
+use crate::optimizer::PlanRef;
 use crate::planner::Planner;
 use crate::scheduler::plan_fragmenter::BatchPlanFragmenter;
 use crate::scheduler::{DataChunkStream, ExecutionContext, ExecutionContextRef};
 use crate::session::{OptimizerContext, SessionImpl};
 
-/// If `RW_IMPLICIT_FLUSH` is on, then every INSERT/UPDATE/DELETE statement will block
-/// until the entire dataflow is refreshed. In other words, every related table & MV will
-/// be able to see the write.
-/// TODO: Use session config to set this.
-pub static IMPLICIT_FLUSH: &str = "RW_IMPLICIT_FLUSH";
-
 pub async fn handle_query(context: OptimizerContext, stmt: Statement) -> Result<PgResponse> {
     let stmt_type = to_statement_type(&stmt);
     let session = context.session_ctx.clone();
 
     let bound = {
-        let mut binder = Binder::new(
-            session.env().catalog_reader().read_guard(),
-            session.database().to_string(),
-        );
+        let mut binder = Binder::new(&session);
         binder.bind(stmt)?
     };
 
-    let (data_stream, pg_descs) = distribute_execute(context, bound).await?;
+    let query_mode = session.config().get_query_mode();
+    let statement_timeout = session.config().get_statement_timeout();
+    let run = async {
+        let (data_stream, pg_descs) = execute(context, bound, query_mode).await?;
 
-    let mut rows = vec![];
-    #[for_await]
-    for chunk in data_stream {
-        rows.extend(to_pg_rows(chunk?));
-    }
+        let mut rows = vec![];
+        #[for_await]
+        for chunk in data_stream {
+            rows.extend(to_pg_rows(chunk?));
+        }
+        Ok::<_, RwError>((rows, pg_descs))
+    };
+    // The tasks on the compute nodes are also given the deadline of the query, so they stop by
+    // themselves.
+    let (rows, pg_descs) = match statement_timeout {
+        Some(statement_timeout) => tokio::time::timeout(statement_timeout, run)
+            .await
+            .map_err(|_| ErrorCode::StatementTimeout)??,
+        None => run.await?,
+    };
 
     let rows_count = match stmt_type {
         StatementType::SELECT => rows.len() as i32,
@@ -67,10 +74,9 @@ pub async fn handle_query(context: OptimizerContext, stmt: Statement) -> Result<
         _ => unreachable!(),
     };
 
-    if let Some(flag) = session.get_config(IMPLICIT_FLUSH) {
-        if flag.is_set(false) {
-            flush_for_write(&session, stmt_type).await?;
-        }
+    let implicit_flush = session.config().get_implicit_flush();
+    if implicit_flush {
+        flush_for_write(&session, stmt_type).await?;
     }
 
     Ok(PgResponse::new(stmt_type, rows_count, rows, pg_descs))
@@ -98,13 +104,16 @@ fn to_statement_type(stmt: &Statement) -> StatementType {
     }
 }
 
-async fn distribute_execute(
+/// Plans the statement and executes it, locally on one compute node or distributed across compute
+/// nodes according to `query_mode`.
+pub(super) async fn execute(
     context: OptimizerContext,
     stmt: BoundStatement,
+    query_mode: QueryMode,
 ) -> Result<(impl DataChunkStream, Vec<PgFieldDescriptor>)> {
     let session = context.session_ctx.clone();
     // Subblock to make sure PlanRef (an Rc) is dropped before `await` below.
-    let (query, pg_descs) = {
+    let (plan, pg_descs) = {
         let root = Planner::new(context.into()).plan(stmt)?;
 
         let pg_descs = root
@@ -114,23 +123,109 @@ async fn distribute_execute(
             .map(to_pg_field)
             .collect::<Vec<PgFieldDescriptor>>();
 
-        let plan = root.gen_batch_query_plan()?;
-
-        info!(
-            "Generated distributed plan: {:?}",
-            plan.explain_to_string()?
-        );
-
-        let plan_fragmenter = BatchPlanFragmenter::new(session.env().worker_node_manager_ref());
-        let query = plan_fragmenter.split(plan)?;
-        info!("Generated query after plan fragmenter: {:?}", &query);
-        (query, pg_descs)
+        let local_plan = match query_mode {
+            QueryMode::Local => Some(root.gen_batch_local_plan()?),
+            QueryMode::Auto => {
+                let plan = root.gen_batch_local_plan()?;
+                is_point_query(&plan).then(|| plan)
+            }
+            QueryMode::Distributed => None,
+        };
+
+        let plan = match local_plan {
+            Some(plan) => {
+                info!("Generated local plan: {:?}", plan.explain_to_string()?);
+                Either::Left(plan.to_batch_prost())
+            }
+            None => {
+                let plan = root.gen_batch_query_plan()?;
+
+                info!(
+                    "Generated distributed plan: {:?}",
+                    plan.explain_to_string()?
+                );
+
+                let plan_fragmenter =
+                    BatchPlanFragmenter::new(session.env().worker_node_manager_ref());
+                let query = plan_fragmenter.split(plan)?;
+                info!("Generated query after plan fragmenter: {:?}", &query);
+                Either::Right(query)
+            }
+        };
+        (plan, pg_descs)
     };
 
     let execution_context: ExecutionContextRef = ExecutionContext::new(session.clone()).into();
     let query_manager = execution_context.session().env().query_manager().clone();
-    Ok((
-        query_manager.schedule(execution_context, query).await?,
-        pg_descs,
-    ))
+    let data_stream = match plan {
+        Either::Left(plan) => Either::Left(
+            query_manager
+                .schedule_local(execution_context, plan)
+                .await?,
+        ),
+        Either::Right(query) => {
+            Either::Right(query_manager.schedule(execution_context, query).await?)
+        }
+    };
+    Ok((data_stream, pg_descs))
+}
+
+/// Whether every table scanned by the local plan is looked up by its whole primary key, so that
+/// the query reads at most one row from each table.
+fn is_point_query(plan: &PlanRef) -> bool {
+    if let Some(scan) = plan.as_batch_seq_scan() {
+        return scan.is_point_get();
+    }
+    plan.inputs().iter().all(is_point_query)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_sqlparser::parser::Parser;
+
+    use super::*;
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_is_point_query() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 int)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create materialized view mv as select v1, count(*) as cnt from t group by v1")
+            .await
+            .unwrap();
+
+        let session = frontend.session_ref();
+        let local_plan = |sql: &str| {
+            let stmt = Parser::parse_sql(sql).unwrap().remove(0);
+            let bound = Binder::new(&session).bind(stmt).unwrap();
+            Planner::new(OptimizerContext::new(session.clone()).into())
+                .plan(bound)
+                .unwrap()
+                .gen_batch_local_plan()
+                .unwrap()
+        };
+        let is_point_query_sql = |sql: &str| is_point_query(&local_plan(sql));
+
+        assert!(is_point_query_sql("select cnt from mv where v1 = 1"));
+        assert!(is_point_query_sql(
+            "select cnt from mv where 1 = v1 and cnt > 0"
+        ));
+        assert!(is_point_query_sql("select 1"));
+        assert!(!is_point_query_sql("select cnt from mv where v1 > 1"));
+        assert!(!is_point_query_sql(
+            "select cnt from mv where v1 = 1 or v1 = 2"
+        ));
+        assert!(!is_point_query_sql("select cnt from mv"));
+        assert!(!is_point_query_sql("select v1 from t where v1 = 1"));
+
+        // The equality on the primary key is pushed into the scan.
+        let explain = local_plan("select cnt from mv where v1 = 1 and cnt > 0")
+            .explain_to_string()
+            .unwrap();
+        assert!(explain.contains("pk_prefix: [v1 = 1]"), "{}", explain);
+    }
 }
@@ -16,16 +16,28 @@ use itertools::Itertools;
 use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
-use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
 use risingwave_common::error::Result;
-use risingwave_sqlparser::ast::{Ident, ShowObject};
+use risingwave_sqlparser::ast::{Ident, ObjectName, ShowCreateType, ShowObject};
 
-use crate::session::OptimizerContext;
+use crate::catalog::catalog_service::CatalogReadGuard;
+use crate::catalog::schema_catalog::SchemaCatalog;
+use crate::catalog::CatalogError;
+use crate::session::{OptimizerContext, SessionImpl};
 
-fn schema_or_default(schema: &Option<Ident>) -> &str {
-    schema
-        .as_ref()
-        .map_or_else(|| DEFAULT_SCHEMA_NAME, |s| &s.value)
+/// Get the given schema, or the first existing schema on the `search_path` if not specified.
+fn schema_or_default<'a>(
+    catalog_reader: &'a CatalogReadGuard,
+    session: &SessionImpl,
+    schema: &Option<Ident>,
+) -> Result<&'a SchemaCatalog> {
+    match schema {
+        Some(schema) => catalog_reader.get_schema_by_name(session.database(), &schema.value),
+        None => catalog_reader.first_valid_schema(
+            session.database(),
+            &session.config().get_search_path(),
+            session.user_name(),
+        ),
+    }
 }
 
 pub async fn handle_show_object(
@@ -36,30 +48,30 @@ pub async fn handle_show_object(
     let catalog_reader = session.env().catalog_reader().read_guard();
 
     let names = match command {
-        // If not include schema name, use default schema name
-        ShowObject::Table { schema } => catalog_reader
-            .get_schema_by_name(session.database(), schema_or_default(&schema))?
+        // If not include schema name, use the first schema on the search path
+        ShowObject::Table { schema } => schema_or_default(&catalog_reader, &session, &schema)?
             .iter_table()
             .map(|t| t.name.clone())
             .collect(),
         ShowObject::Database => catalog_reader.get_all_database_names(),
         ShowObject::Schema => catalog_reader.get_all_schema_names(session.database())?,
-        // If not include schema name, use default schema name
-        ShowObject::MaterializedView { schema } => catalog_reader
-            .get_schema_by_name(session.database(), schema_or_default(&schema))?
-            .iter_mv()
-            .map(|t| t.name.clone())
-            .collect(),
-        ShowObject::Source { schema } => catalog_reader
-            .get_schema_by_name(session.database(), schema_or_default(&schema))?
+        // If not include schema name, use the first schema on the search path
+        ShowObject::MaterializedView { schema } => {
+            schema_or_default(&catalog_reader, &session, &schema)?
+                .iter_mv()
+                .map(|t| t.name.clone())
+                .collect()
+        }
+        ShowObject::Source { schema } => schema_or_default(&catalog_reader, &session, &schema)?
             .iter_source()
             .map(|t| t.name.clone())
             .collect(),
-        ShowObject::MaterializedSource { schema } => catalog_reader
-            .get_schema_by_name(session.database(), schema_or_default(&schema))?
-            .iter_materialized_source()
-            .map(|t| t.name.clone())
-            .collect(),
+        ShowObject::MaterializedSource { schema } => {
+            schema_or_default(&catalog_reader, &session, &schema)?
+                .iter_materialized_source()
+                .map(|t| t.name.clone())
+                .collect()
+        }
     };
 
     let rows = names
@@ -75,6 +87,56 @@ pub async fn handle_show_object(
     ))
 }
 
+/// Returns the SQL that created the given relation, as recorded in its catalog.
+pub fn handle_show_create_object(
+    context: OptimizerContext,
+    create_type: ShowCreateType,
+    name: ObjectName,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let (schema_name, object_name) = session.resolve_relation_name(name)?;
+    let catalog_reader = session.env().catalog_reader().read_guard();
+
+    let definition = match create_type {
+        ShowCreateType::Source => catalog_reader
+            .get_source_by_name(session.database(), &schema_name, &object_name)?
+            .definition
+            .clone(),
+        ShowCreateType::Table | ShowCreateType::MaterializedView | ShowCreateType::Index => {
+            let table =
+                catalog_reader.get_table_by_name(session.database(), &schema_name, &object_name)?;
+            let (matched, kind) = match create_type {
+                ShowCreateType::Table => (table.associated_source_id.is_some(), "table"),
+                ShowCreateType::MaterializedView => (
+                    table.associated_source_id.is_none() && table.is_index_on.is_none(),
+                    "materialized view",
+                ),
+                ShowCreateType::Index => (table.is_index_on.is_some(), "index"),
+                ShowCreateType::Source => unreachable!(),
+            };
+            if !matched {
+                return Err(CatalogError::NotFound(kind, object_name).into());
+            }
+            table.definition.clone()
+        }
+    };
+
+    let rows = vec![Row::new(vec![
+        Some(format!("{}.{}", schema_name, object_name)),
+        Some(definition),
+    ])];
+
+    Ok(PgResponse::new(
+        StatementType::SHOW_COMMAND,
+        1,
+        rows,
+        vec![
+            PgFieldDescriptor::new("Name".to_owned(), TypeOid::Varchar),
+            PgFieldDescriptor::new("Create Sql".to_owned(), TypeOid::Varchar),
+        ],
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::LocalFrontend;
@@ -108,4 +170,52 @@ mod tests {
             .await;
         assert_eq!(rows, vec!["Row([Some(\"t2\")])".to_string()]);
     }
+
+    #[tokio::test]
+    async fn test_show_create() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = r#"CREATE SOURCE s
+        WITH ('kafka.topic' = 'abc', 'properties.sasl.password' = 'hunter2')
+        ROW FORMAT JSON"#;
+        frontend.run_sql(sql).await.unwrap();
+        frontend
+            .run_sql("CREATE TABLE t (v1 INT, v2 INT)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("CREATE MATERIALIZED VIEW mv AS SELECT v1 FROM t")
+            .await
+            .unwrap();
+
+        let rows = frontend
+            .query_formatted_result("SHOW CREATE SOURCE s")
+            .await;
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].contains("public.s"));
+        assert!(rows[0].contains("'[REDACTED]'"));
+        assert!(!rows[0].contains("hunter2"));
+
+        let rows = frontend.query_formatted_result("SHOW CREATE TABLE t").await;
+        assert_eq!(
+            rows,
+            vec![
+                "Row([Some(\"public.t\"), Some(\"CREATE TABLE t (v1 INT, v2 INT)\")])".to_string()
+            ]
+        );
+
+        let rows = frontend
+            .query_formatted_result("SHOW CREATE MATERIALIZED VIEW mv")
+            .await;
+        assert_eq!(
+            rows,
+            vec![
+                "Row([Some(\"public.mv\"), Some(\"CREATE MATERIALIZED VIEW mv AS SELECT v1 FROM t\")])"
+                    .to_string()
+            ]
+        );
+
+        // Kind mismatch is reported as not found.
+        assert!(frontend.run_sql("SHOW CREATE TABLE mv").await.is_err());
+    }
 }
@@ -19,6 +19,7 @@ use pgwire::types::Row;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Field;
 use risingwave_common::types::{DataType, ScalarRefImpl};
+use risingwave_sqlparser::ast::{SqlOption, Statement, Value};
 
 /// Format scalars according to postgres convention.
 fn pg_value_format(d: ScalarRefImpl) -> String {
@@ -58,6 +59,43 @@ pub fn to_pg_rows(chunk: DataChunk) -> Vec<Row> {
         .collect_vec()
 }
 
+/// Substrings of `WITH` option names whose values must never be persisted in a definition.
+const SECRET_OPTION_PATTERNS: [&str; 6] = [
+    "password",
+    "secret",
+    "token",
+    "access_key",
+    "private_key",
+    "sasl.jaas.config",
+];
+
+const REDACTED_VALUE: &str = "[REDACTED]";
+
+fn redact_options(options: &mut [SqlOption]) {
+    for option in options {
+        let name = option.name.value.to_lowercase();
+        if SECRET_OPTION_PATTERNS
+            .iter()
+            .any(|pattern| name.contains(pattern))
+        {
+            option.value = Value::SingleQuotedString(REDACTED_VALUE.to_string());
+        }
+    }
+}
+
+/// Returns the normalized SQL of a `CREATE` statement to be stored as the definition of the
+/// created object, with the values of secret-bearing `WITH` options redacted.
+pub fn normalized_definition(stmt: &Statement) -> String {
+    let mut stmt = stmt.clone();
+    match &mut stmt {
+        Statement::CreateTable { with_options, .. }
+        | Statement::CreateView { with_options, .. } => redact_options(with_options),
+        Statement::CreateSource { stmt, .. } => redact_options(&mut stmt.with_properties.0),
+        _ => {}
+    }
+    stmt.to_string()
+}
+
 /// Convert from [`Field`] to [`PgFieldDescriptor`].
 pub fn to_pg_field(f: &Field) -> PgFieldDescriptor {
     PgFieldDescriptor::new(f.name.clone(), data_type_to_type_oid(f.data_type()))
@@ -80,6 +118,9 @@ pub fn data_type_to_type_oid(data_type: DataType) -> TypeOid {
         DataType::Interval => TypeOid::Varchar,
         DataType::Struct { .. } => TypeOid::Varchar,
         DataType::List { .. } => TypeOid::Varchar,
+        DataType::Jsonb => TypeOid::Jsonb,
+        DataType::Bytea => TypeOid::Bytea,
+        DataType::Uuid => TypeOid::Uuid,
     }
 }
 
@@ -101,6 +142,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_normalized_definition() {
+        let sql = "CREATE SOURCE s (v1 INT) WITH ('connector' = 'kafka', 'properties.sasl.password' = 'hunter2') ROW FORMAT JSON";
+        let stmt = risingwave_sqlparser::parser::Parser::parse_sql(sql)
+            .unwrap()
+            .remove(0);
+        let definition = normalized_definition(&stmt);
+        assert!(definition.contains("'connector' = 'kafka'"));
+        assert!(definition.contains("'[REDACTED]'"));
+        assert!(!definition.contains("hunter2"));
+    }
+
     #[test]
     fn test_to_pg_rows() {
         let chunk = DataChunk::new(
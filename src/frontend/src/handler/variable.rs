@@ -0,0 +1,248 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
+use pgwire::pg_response::{PgResponse, StatementType};
+use pgwire::types::Row;
+use risingwave_common::error::Result;
+use risingwave_common::session_config::get_session_variable;
+use risingwave_sqlparser::ast::{Ident, SetVariableValue};
+
+use crate::session::OptimizerContext;
+
+/// Name of the pseudo variable of `SHOW ALL` and `RESET ALL`.
+const ALL: &str = "ALL";
+
+pub(super) fn handle_set(
+    context: OptimizerContext,
+    name: Ident,
+    value: Vec<SetVariableValue>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let var = match value.as_slice() {
+        // `SET <var> TO DEFAULT` is equivalent to `RESET <var>`.
+        [SetVariableValue::Ident(ident)] if ident.value.eq_ignore_ascii_case("DEFAULT") => {
+            session.reset_config(&name.value)?
+        }
+        _ => session.set_config(&name.value, &to_string(&value))?,
+    };
+
+    let parameter_status = if var.report {
+        let value = session.config().get(var.name)?.to_string();
+        vec![(var.name.to_string(), value)]
+    } else {
+        vec![]
+    };
+
+    Ok(PgResponse::empty_result(StatementType::SET_OPTION).with_parameter_status(parameter_status))
+}
+
+pub(super) fn handle_reset(context: OptimizerContext, name: Ident) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let parameter_status = if name.value.eq_ignore_ascii_case(ALL) {
+        let before = session.config().clone();
+        session.reset_all_config();
+        let config = session.config();
+        // Only report the variables whose values are changed.
+        let changed = config
+            .reported()
+            .filter(|(var_name, value)| before.get(var_name).map_or(true, |v| v != *value))
+            .map(|(var_name, value)| (var_name.to_string(), value.to_string()))
+            .collect();
+        changed
+    } else {
+        let var = session.reset_config(&name.value)?;
+        if var.report {
+            vec![(var.name.to_string(), var.default.to_string())]
+        } else {
+            vec![]
+        }
+    };
+
+    Ok(PgResponse::empty_result(StatementType::SET_OPTION).with_parameter_status(parameter_status))
+}
+
+pub(super) fn handle_show(context: OptimizerContext, variable: Vec<Ident>) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let config = session.config();
+    let name = variable.iter().map(|v| v.value.as_str()).join(" ");
+
+    if name.eq_ignore_ascii_case(ALL) {
+        let rows = config
+            .iter()
+            .map(|(var, value)| {
+                Row::new(vec![
+                    Some(var.name.to_string()),
+                    Some(value.to_string()),
+                    Some(var.description.to_string()),
+                ])
+            })
+            .collect_vec();
+        return Ok(PgResponse::new(
+            StatementType::SHOW_COMMAND,
+            rows.len() as i32,
+            rows,
+            vec![
+                PgFieldDescriptor::new("Name".to_string(), TypeOid::Varchar),
+                PgFieldDescriptor::new("Setting".to_string(), TypeOid::Varchar),
+                PgFieldDescriptor::new("Description".to_string(), TypeOid::Varchar),
+            ],
+        ));
+    }
+
+    let var = get_session_variable(&name)?;
+    let value = config.get(var.name)?.to_string();
+    Ok(PgResponse::new(
+        StatementType::SHOW_COMMAND,
+        1,
+        vec![Row::new(vec![Some(value)])],
+        vec![PgFieldDescriptor::new(
+            var.name.to_string(),
+            TypeOid::Varchar,
+        )],
+    ))
+}
+
+/// Convert any set variable to String.
+/// For example, TRUE -> "TRUE", 1 -> "1".
+/// Multiple values are joined with comma, e.g. `SET search_path TO a, b` -> "a, b".
+fn to_string(value: &[SetVariableValue]) -> String {
+    value
+        .iter()
+        .map(|v| match v {
+            // The quotes of string literals are not part of the value.
+            SetVariableValue::Literal(risingwave_sqlparser::ast::Value::SingleQuotedString(s)) => {
+                s.clone()
+            }
+            v => v.to_string(),
+        })
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use pgwire::pg_server::Session;
+    use risingwave_common::catalog::DEFAULT_DATABASE_NAME;
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_set_show_reset() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+
+        let rsp = session
+            .clone()
+            .run_statement("SET application_name TO 'psql'")
+            .await
+            .unwrap();
+        assert_eq!(
+            rsp.get_parameter_status(),
+            &[("application_name".to_string(), "psql".to_string())]
+        );
+
+        let rsp = session
+            .clone()
+            .run_statement("SHOW APPLICATION_NAME")
+            .await
+            .unwrap();
+        let rows = rsp.iter().map(|row| row[0].clone()).collect::<Vec<_>>();
+        assert_eq!(rows, vec![Some("psql".to_string())]);
+
+        session
+            .clone()
+            .run_statement("SET extra_float_digits = 3")
+            .await
+            .unwrap();
+        assert_eq!(session.config().get_extra_float_digits(), 3);
+        assert!(session
+            .clone()
+            .run_statement("SET extra_float_digits = 10")
+            .await
+            .is_err());
+        assert!(session
+            .clone()
+            .run_statement("SET no_such_variable = 1")
+            .await
+            .is_err());
+
+        let rsp = session.clone().run_statement("RESET ALL").await.unwrap();
+        assert_eq!(
+            rsp.get_parameter_status(),
+            &[("application_name".to_string(), "".to_string())]
+        );
+        assert_eq!(session.config().get_extra_float_digits(), 1);
+
+        let rsp = session.clone().run_statement("SHOW ALL").await.unwrap();
+        assert!(rsp
+            .iter()
+            .any(|row| row[0].as_deref() == Some("search_path")));
+    }
+
+    #[tokio::test]
+    async fn test_search_path() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+
+        session
+            .clone()
+            .run_statement("CREATE SCHEMA s1")
+            .await
+            .unwrap();
+        session
+            .clone()
+            .run_statement("SET search_path TO s1, dev")
+            .await
+            .unwrap();
+        session
+            .clone()
+            .run_statement("CREATE TABLE t (v1 int)")
+            .await
+            .unwrap();
+        assert!(catalog_reader
+            .read_guard()
+            .get_table_by_name(DEFAULT_DATABASE_NAME, "s1", "t")
+            .is_ok());
+
+        let rsp = session.clone().run_statement("SHOW TABLES").await.unwrap();
+        let rows = rsp.iter().map(|row| row[0].clone()).collect::<Vec<_>>();
+        assert_eq!(rows, vec![Some("t".to_string())]);
+        session.clone().run_statement("DESCRIBE t").await.unwrap();
+
+        // No schema on the search path exists.
+        session
+            .clone()
+            .run_statement("SET search_path TO no_such_schema")
+            .await
+            .unwrap();
+        assert!(session
+            .clone()
+            .run_statement("CREATE TABLE t2 (v1 int)")
+            .await
+            .is_err());
+
+        session
+            .clone()
+            .run_statement("RESET search_path")
+            .await
+            .unwrap();
+        session
+            .clone()
+            .run_statement("DROP TABLE s1.t")
+            .await
+            .unwrap();
+    }
+}
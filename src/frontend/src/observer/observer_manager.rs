@@ -116,6 +116,7 @@ impl ObserverManager {
             },
             Some(Info::TableV2(table)) => match resp.operation() {
                 Operation::Add => catalog_guard.create_table(table),
+                Operation::Update => catalog_guard.update_table(table),
                 Operation::Delete => {
                     catalog_guard.drop_table(table.database_id, table.schema_id, table.id.into())
                 }
@@ -0,0 +1,633 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cost-based reordering of multi-way inner joins.
+//!
+//! A tree of inner joins is flattened into a set of relations and a set of predicates. If the
+//! cardinalities of all relations can be estimated from the statistics collected by `ANALYZE`,
+//! the cheapest join tree is searched exhaustively for small joins and greedily for large ones.
+//! Otherwise the syntactic order is kept.
+
+use itertools::Itertools;
+use risingwave_pb::plan_common::JoinType;
+
+use super::plan_node::{LogicalJoin, LogicalProject, PlanTreeNodeBinary, PlanTreeNodeUnary};
+use super::PlanRef;
+use crate::expr::{ExprImpl, ExprRewriter, ExprType, InputRef};
+use crate::utils::{ColIndexMapping, Condition};
+
+/// Selectivity of an equality predicate whose operands have unknown distinct counts.
+const DEFAULT_EQ_SELECTIVITY: f64 = 0.1;
+/// Selectivity of a range comparison.
+const DEFAULT_RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+/// Selectivity of any other predicate.
+const DEFAULT_SELECTIVITY: f64 = 0.25;
+/// Joins of more relations than this are ordered greedily instead of exhaustively.
+const MAX_EXHAUSTIVE_SEARCH_RELATIONS: usize = 10;
+
+/// The cost minimized by the join order search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinCostModel {
+    /// Rows produced by every join, plus rows inserted into the hash table built on its right
+    /// side.
+    Batch,
+    /// Rows kept in the state of every join, which holds both of its inputs.
+    Stream,
+}
+
+/// Estimates the number of rows produced by a logical plan. Returns `None` if the plan reads a
+/// table that has not been analyzed.
+pub fn estimate_row_count(plan: &PlanRef) -> Option<f64> {
+    if let Some(scan) = plan.as_logical_scan() {
+        scan.statistics().map(|s| s.row_count as f64)
+    } else if let Some(filter) = plan.as_logical_filter() {
+        let input = filter.input();
+        let selectivity = condition_selectivity(filter.predicate(), |idx| {
+            estimate_distinct_count(&input, idx)
+        });
+        Some(estimate_row_count(&input)? * selectivity)
+    } else if let Some(project) = plan.as_logical_project() {
+        estimate_row_count(&project.input())
+    } else if let Some(join) = plan.as_logical_join() {
+        let (left, right) = (join.left(), join.right());
+        let left_rows = estimate_row_count(&left)?;
+        let right_rows = estimate_row_count(&right)?;
+        let left_len = left.schema().len();
+        let selectivity = condition_selectivity(join.on(), |idx| {
+            if idx < left_len {
+                estimate_distinct_count(&left, idx)
+            } else {
+                estimate_distinct_count(&right, idx - left_len)
+            }
+        });
+        let inner_rows = left_rows * right_rows * selectivity;
+        Some(match join.join_type() {
+            JoinType::Inner => inner_rows,
+            JoinType::LeftOuter => inner_rows.max(left_rows),
+            JoinType::RightOuter => inner_rows.max(right_rows),
+            JoinType::FullOuter => inner_rows.max(left_rows + right_rows),
+            JoinType::LeftSemi => inner_rows.min(left_rows),
+            JoinType::RightSemi => inner_rows.min(right_rows),
+            // Anti joins are bounded by their outer side only.
+            JoinType::LeftAnti => left_rows,
+            JoinType::RightAnti => right_rows,
+        })
+    } else if let Some(agg) = plan.as_logical_agg() {
+        let input = agg.input();
+        let input_rows = estimate_row_count(&input)?;
+        let groups: f64 = agg
+            .group_keys()
+            .iter()
+            .map(|&key| estimate_distinct_count(&input, key).unwrap_or(input_rows))
+            .product();
+        Some(groups.min(input_rows).max(1.0))
+    } else if let Some(limit) = plan.as_logical_limit() {
+        Some(estimate_row_count(&limit.input())?.min(limit.limit() as f64))
+    } else if let Some(top_n) = plan.as_logical_top_n() {
+        Some(estimate_row_count(&top_n.input())?.min(top_n.limit() as f64))
+    } else {
+        plan.as_logical_values()
+            .map(|values| values.rows().len() as f64)
+    }
+}
+
+/// Estimates the number of distinct values of the `idx`-th output column of a logical plan.
+pub fn estimate_distinct_count(plan: &PlanRef, idx: usize) -> Option<f64> {
+    if let Some(scan) = plan.as_logical_scan() {
+        scan.column_statistics(idx).map(|s| s.distinct_count as f64)
+    } else if let Some(filter) = plan.as_logical_filter() {
+        let distinct_count = estimate_distinct_count(&filter.input(), idx)?;
+        Some(match estimate_row_count(plan) {
+            Some(rows) => distinct_count.min(rows),
+            None => distinct_count,
+        })
+    } else if let Some(project) = plan.as_logical_project() {
+        match &project.exprs()[idx] {
+            ExprImpl::InputRef(input_ref) => {
+                estimate_distinct_count(&project.input(), input_ref.index())
+            }
+            _ => None,
+        }
+    } else if let Some(join) = plan.as_logical_join() {
+        let left_len = join.left().schema().len();
+        match join.join_type() {
+            JoinType::LeftSemi | JoinType::LeftAnti => estimate_distinct_count(&join.left(), idx),
+            JoinType::RightSemi | JoinType::RightAnti => {
+                estimate_distinct_count(&join.right(), idx)
+            }
+            _ if idx < left_len => estimate_distinct_count(&join.left(), idx),
+            _ => estimate_distinct_count(&join.right(), idx - left_len),
+        }
+    } else if let Some(agg) = plan.as_logical_agg() {
+        let key = *agg.group_keys().get(idx)?;
+        estimate_distinct_count(&agg.input(), key)
+    } else if let Some(limit) = plan.as_logical_limit() {
+        estimate_distinct_count(&limit.input(), idx)
+    } else if let Some(top_n) = plan.as_logical_top_n() {
+        estimate_distinct_count(&top_n.input(), idx)
+    } else {
+        None
+    }
+}
+
+fn condition_selectivity(
+    condition: &Condition,
+    distinct_count: impl Fn(usize) -> Option<f64>,
+) -> f64 {
+    condition
+        .conjunctions
+        .iter()
+        .map(|expr| expr_selectivity(expr, &distinct_count))
+        .product()
+}
+
+fn expr_selectivity(expr: &ExprImpl, distinct_count: &impl Fn(usize) -> Option<f64>) -> f64 {
+    let ExprImpl::FunctionCall(call) = expr else {
+        return DEFAULT_SELECTIVITY;
+    };
+    match call.get_expr_type() {
+        ExprType::Equal => call
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                ExprImpl::InputRef(input_ref) => distinct_count(input_ref.index()),
+                _ => None,
+            })
+            .reduce(f64::max)
+            .map(|distinct_count| 1.0 / distinct_count.max(1.0))
+            .unwrap_or(DEFAULT_EQ_SELECTIVITY),
+        ExprType::LessThan
+        | ExprType::LessThanOrEqual
+        | ExprType::GreaterThan
+        | ExprType::GreaterThanOrEqual => DEFAULT_RANGE_SELECTIVITY,
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
+
+/// Reorders every tree of inner joins in the logical plan to minimize the cost given by
+/// `cost_model`.
+pub fn reorder_joins(plan: PlanRef, cost_model: JoinCostModel) -> PlanRef {
+    if is_inner_join(&plan) {
+        let mut graph = JoinGraph::default();
+        graph.flatten(plan.clone(), 0);
+        if let Some(reordered) = graph.reorder(cost_model) {
+            return reordered;
+        }
+    }
+    let inputs = plan
+        .inputs()
+        .into_iter()
+        .map(|input| reorder_joins(input, cost_model))
+        .collect_vec();
+    plan.clone_with_inputs(&inputs)
+}
+
+fn is_inner_join(plan: &PlanRef) -> bool {
+    plan.as_logical_join()
+        .map_or(false, |join| join.join_type() == JoinType::Inner)
+}
+
+/// Relations and predicates of a flattened tree of inner joins.
+#[derive(Default)]
+struct JoinGraph {
+    /// The joined relations, in the order their columns appear in the output of the join tree.
+    relations: Vec<PlanRef>,
+    /// The index of the first column of each relation in the output of the join tree.
+    offsets: Vec<usize>,
+    /// The join predicates, referring to the output columns of the join tree.
+    predicates: Vec<ExprImpl>,
+}
+
+/// A join tree over the relations of a [`JoinGraph`].
+#[derive(Debug, Clone, PartialEq)]
+enum JoinTree {
+    Relation(usize),
+    Join(Box<JoinTree>, Box<JoinTree>),
+}
+
+impl JoinGraph {
+    fn flatten(&mut self, plan: PlanRef, offset: usize) {
+        if is_inner_join(&plan) {
+            let join = plan.as_logical_join().unwrap();
+            let left = join.left();
+            let left_len = left.schema().len();
+            self.flatten(left, offset);
+            self.flatten(join.right(), offset + left_len);
+            let mut shift =
+                ColIndexMapping::with_shift_offset(plan.schema().len(), offset as isize);
+            self.predicates
+                .extend(join.on().clone().rewrite_expr(&mut shift).conjunctions);
+        } else {
+            self.offsets.push(offset);
+            self.relations.push(plan);
+        }
+    }
+
+    fn column_num(&self) -> usize {
+        self.offsets.last().unwrap() + self.relations.last().unwrap().schema().len()
+    }
+
+    /// Returns the reordered plan, or `None` if the cardinalities cannot be estimated.
+    fn reorder(self, cost_model: JoinCostModel) -> Option<PlanRef> {
+        let relation_num = self.relations.len();
+        if relation_num < 3 || relation_num >= usize::BITS as usize {
+            return None;
+        }
+        let row_counts = self
+            .relations
+            .iter()
+            .map(estimate_row_count)
+            .collect::<Option<Vec<_>>>()?;
+
+        let column_num = self.column_num();
+        let column_to_relation = (0..relation_num)
+            .flat_map(|i| std::iter::repeat(i).take(self.relations[i].schema().len()))
+            .collect_vec();
+        let predicate_masks = self
+            .predicates
+            .iter()
+            .map(|expr| {
+                expr.collect_input_refs(column_num)
+                    .ones()
+                    .fold(0, |mask, col| mask | 1 << column_to_relation[col])
+            })
+            .collect_vec();
+        let selectivities = self.predicates.iter().map(|expr| {
+            expr_selectivity(expr, &|col| {
+                let relation = column_to_relation[col];
+                estimate_distinct_count(&self.relations[relation], col - self.offsets[relation])
+            })
+        });
+
+        let search = JoinOrderSearch {
+            row_counts,
+            predicates: predicate_masks
+                .iter()
+                .copied()
+                .zip_eq(selectivities)
+                .collect(),
+            cost_model,
+        };
+        let tree = if relation_num <= MAX_EXHAUSTIVE_SEARCH_RELATIONS {
+            search.exhaustive()
+        } else {
+            search.greedy()
+        };
+        Some(self.build(&tree, &predicate_masks, cost_model))
+    }
+
+    /// Builds the plan of the join tree, and restores the original column order on top of it.
+    fn build(
+        self,
+        tree: &JoinTree,
+        predicate_masks: &[usize],
+        cost_model: JoinCostModel,
+    ) -> PlanRef {
+        let column_num = self.column_num();
+        let relations = self
+            .relations
+            .iter()
+            .map(|relation| {
+                // Join trees nested below other operators are reordered independently.
+                let inputs = relation
+                    .inputs()
+                    .into_iter()
+                    .map(|input| reorder_joins(input, cost_model))
+                    .collect_vec();
+                relation.clone_with_inputs(&inputs)
+            })
+            .collect_vec();
+        let mut predicates = self.predicates.into_iter().map(Some).collect_vec();
+        let builder = JoinTreeBuilder {
+            relations: &relations,
+            offsets: &self.offsets,
+            predicate_masks,
+            column_num,
+        };
+        let (plan, columns, _) = builder.build(tree, &mut predicates, true);
+
+        if columns.iter().copied().eq(0..column_num) {
+            return plan;
+        }
+        let mut positions = vec![0; column_num];
+        for (position, &column) in columns.iter().enumerate() {
+            positions[column] = position;
+        }
+        let exprs = positions
+            .into_iter()
+            .map(|position| {
+                InputRef::new(position, plan.schema().fields()[position].data_type()).into()
+            })
+            .collect();
+        LogicalProject::create(plan, exprs)
+    }
+}
+
+struct JoinTreeBuilder<'a> {
+    relations: &'a [PlanRef],
+    offsets: &'a [usize],
+    /// The mask of relations referenced by each predicate.
+    predicate_masks: &'a [usize],
+    column_num: usize,
+}
+
+impl JoinTreeBuilder<'_> {
+    /// Returns the plan of the subtree, the original indices of its output columns, and the mask
+    /// of its relations.
+    fn build(
+        &self,
+        tree: &JoinTree,
+        predicates: &mut [Option<ExprImpl>],
+        is_root: bool,
+    ) -> (PlanRef, Vec<usize>, usize) {
+        match tree {
+            JoinTree::Relation(i) => {
+                let relation = self.relations[*i].clone();
+                let columns =
+                    (self.offsets[*i]..self.offsets[*i] + relation.schema().len()).collect_vec();
+                (relation, columns, 1 << *i)
+            }
+            JoinTree::Join(left, right) => {
+                let (left, left_columns, left_mask) = self.build(left, predicates, false);
+                let (right, right_columns, right_mask) = self.build(right, predicates, false);
+                let mask = left_mask | right_mask;
+                let columns = left_columns.into_iter().chain(right_columns).collect_vec();
+
+                let mut mapping = vec![None; self.column_num];
+                for (position, &column) in columns.iter().enumerate() {
+                    mapping[column] = Some(position);
+                }
+                let mut mapping = ColIndexMapping::new(mapping);
+
+                // A predicate is evaluated by the lowest join that has all of its inputs.
+                // Constant predicates are evaluated by the root.
+                let conjunctions = predicates
+                    .iter_mut()
+                    .zip_eq(self.predicate_masks)
+                    .filter(|(predicate, predicate_mask)| {
+                        let predicate_mask = **predicate_mask;
+                        predicate.is_some()
+                            && if predicate_mask == 0 {
+                                is_root
+                            } else {
+                                predicate_mask & !mask == 0
+                            }
+                    })
+                    .map(|(predicate, _)| mapping.rewrite_expr(predicate.take().unwrap()))
+                    .collect();
+
+                let join =
+                    LogicalJoin::new(left, right, JoinType::Inner, Condition { conjunctions });
+                (join.into(), columns, mask)
+            }
+        }
+    }
+}
+
+/// Searches the cheapest join tree given the estimated cardinalities.
+struct JoinOrderSearch {
+    row_counts: Vec<f64>,
+    /// The mask of referenced relations and the selectivity of each predicate.
+    predicates: Vec<(usize, f64)>,
+    cost_model: JoinCostModel,
+}
+
+impl JoinOrderSearch {
+    /// Estimated number of rows of the join of the relations in `mask`.
+    fn rows(&self, mask: usize) -> f64 {
+        let rows: f64 = (0..self.row_counts.len())
+            .filter(|i| mask & 1 << i != 0)
+            .map(|i| self.row_counts[i])
+            .product();
+        let selectivity: f64 = self
+            .predicates
+            .iter()
+            .filter(|(relations, _)| *relations != 0 && relations & !mask == 0)
+            .map(|(_, selectivity)| selectivity)
+            .product();
+        rows * selectivity
+    }
+
+    /// The cost of joining two subtrees, excluding the costs of the subtrees themselves.
+    fn join_cost(&self, left: usize, right: usize) -> f64 {
+        match self.cost_model {
+            JoinCostModel::Batch => self.rows(left | right) + self.rows(right),
+            JoinCostModel::Stream => self.rows(left) + self.rows(right),
+        }
+    }
+
+    /// Dynamic programming over all subsets of relations, considering bushy trees.
+    fn exhaustive(&self) -> JoinTree {
+        let full = (1usize << self.row_counts.len()) - 1;
+        let mut costs = vec![0.0; full + 1];
+        let mut splits = vec![(0, 0); full + 1];
+        for mask in 1..=full {
+            if mask.count_ones() == 1 {
+                continue;
+            }
+            let mut best = f64::INFINITY;
+            // Enumerate the non-empty proper subsets as the left side.
+            let mut left = (mask - 1) & mask;
+            while left != 0 {
+                let right = mask & !left;
+                let cost = costs[left] + costs[right] + self.join_cost(left, right);
+                if cost < best {
+                    best = cost;
+                    splits[mask] = (left, right);
+                }
+                left = (left - 1) & mask;
+            }
+            costs[mask] = best;
+        }
+        Self::tree_of(full, &splits)
+    }
+
+    fn tree_of(mask: usize, splits: &[(usize, usize)]) -> JoinTree {
+        if mask.count_ones() == 1 {
+            JoinTree::Relation(mask.trailing_zeros() as usize)
+        } else {
+            let (left, right) = splits[mask];
+            JoinTree::Join(
+                Box::new(Self::tree_of(left, splits)),
+                Box::new(Self::tree_of(right, splits)),
+            )
+        }
+    }
+
+    /// Repeatedly joins the pair of subtrees with the cheapest join.
+    fn greedy(&self) -> JoinTree {
+        let mut trees = (0..self.row_counts.len())
+            .map(|i| (1usize << i, JoinTree::Relation(i)))
+            .collect_vec();
+        while trees.len() > 1 {
+            let (left, right) = (0..trees.len())
+                .flat_map(|l| {
+                    (0..trees.len())
+                        .filter(move |&r| r != l)
+                        .map(move |r| (l, r))
+                })
+                .min_by(|&(l1, r1), &(l2, r2)| {
+                    let cost1 = self.join_cost(trees[l1].0, trees[r1].0);
+                    let cost2 = self.join_cost(trees[l2].0, trees[r2].0);
+                    cost1.partial_cmp(&cost2).unwrap()
+                })
+                .unwrap();
+            // Remove the one with the larger index first to keep the other index valid.
+            let (left, right) = if left > right {
+                let left = trees.remove(left);
+                (left, trees.remove(right))
+            } else {
+                let right = trees.remove(right);
+                (trees.remove(left), right)
+            };
+            trees.push((
+                left.0 | right.0,
+                JoinTree::Join(Box::new(left.1), Box::new(right.1)),
+            ));
+        }
+        trees.pop().unwrap().1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use risingwave_common::catalog::{ColumnDesc, ColumnId, TableDesc, TableId};
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::catalog::table_statistics::{ColumnStatistics, TableStatistics};
+    use crate::expr::FunctionCall;
+    use crate::optimizer::plan_node::LogicalScan;
+    use crate::session::{OptimizerContext, OptimizerContextRef};
+
+    /// Creates a scan of a table with the given row count and distinct counts of its columns.
+    fn scan(
+        ctx: &OptimizerContextRef,
+        name: &str,
+        row_count: Option<u64>,
+        distinct_counts: &[u64],
+    ) -> PlanRef {
+        let columns = (0..distinct_counts.len())
+            .map(|i| ColumnDesc::unnamed(ColumnId::new(i as i32), DataType::Int32))
+            .collect_vec();
+        let table_desc = TableDesc {
+            table_id: TableId::placeholder(),
+            order_desc: vec![],
+            columns,
+            distribution_keys: vec![],
+            pks: vec![],
+        };
+        let statistics = row_count.map(|row_count| {
+            Rc::new(TableStatistics {
+                row_count,
+                columns: distinct_counts
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &distinct_count)| {
+                        (
+                            ColumnId::new(i as i32),
+                            ColumnStatistics {
+                                distinct_count,
+                                null_fraction: 0.0,
+                            },
+                        )
+                    })
+                    .collect::<HashMap<_, _>>(),
+            })
+        });
+        LogicalScan::create(
+            name.to_string(),
+            Rc::new(table_desc),
+            vec![],
+            statistics,
+            ctx.clone(),
+        )
+        .unwrap()
+    }
+
+    fn eq(left: usize, right: usize) -> ExprImpl {
+        FunctionCall::new(
+            ExprType::Equal,
+            vec![
+                InputRef::new(left, DataType::Int32).into(),
+                InputRef::new(right, DataType::Int32).into(),
+            ],
+        )
+        .unwrap()
+        .into()
+    }
+
+    /// `(a JOIN b ON a.k = b.k) JOIN c ON b.j = c.j`, where `a JOIN b` is almost a cross product
+    /// while `b JOIN c` is highly selective.
+    fn join_abc(ctx: &OptimizerContextRef, analyzed: bool) -> PlanRef {
+        let rows = |n| if analyzed { Some(n) } else { None };
+        let a = scan(ctx, "a", rows(1000), &[1]);
+        let b = scan(ctx, "b", rows(1000), &[1, 1000]);
+        let c = scan(ctx, "c", rows(10), &[10]);
+        let ab = LogicalJoin::create(a, b, JoinType::Inner, eq(0, 1));
+        LogicalJoin::create(ab, c, JoinType::Inner, eq(2, 3))
+    }
+
+    #[tokio::test]
+    async fn test_estimate_row_count() {
+        let ctx = OptimizerContext::mock().await;
+        let plan = join_abc(&ctx, true);
+        // 1000 * 1000 * 10 rows, with selectivities 1/1 and 1/1000.
+        let rows = estimate_row_count(&plan).unwrap();
+        assert!((rows - 10000.0).abs() < 1e-6);
+        assert_eq!(estimate_row_count(&join_abc(&ctx, false)), None);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_joins() {
+        let ctx = OptimizerContext::mock().await;
+        for cost_model in [JoinCostModel::Batch, JoinCostModel::Stream] {
+            let plan = reorder_joins(join_abc(&ctx, true), cost_model);
+            let plan = match plan.as_logical_project() {
+                Some(project) => project.input(),
+                None => plan,
+            };
+            let join = plan.as_logical_join().unwrap();
+            // `b JOIN c` is evaluated first.
+            let (inner, outer) = if is_inner_join(&join.left()) {
+                (join.left(), join.right())
+            } else {
+                (join.right(), join.left())
+            };
+            assert_eq!(outer.as_logical_scan().unwrap().table_name(), "a");
+            let inner = inner.as_logical_join().unwrap();
+            let mut names = [inner.left(), inner.right()]
+                .iter()
+                .map(|p| p.as_logical_scan().unwrap().table_name().to_string())
+                .collect_vec();
+            names.sort();
+            assert_eq!(names, vec!["b", "c"]);
+            assert_eq!(inner.on().conjunctions.len(), 1);
+            assert_eq!(join.on().conjunctions.len(), 1);
+            assert_eq!(plan.schema().len(), 4);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keep_order_without_statistics() {
+        let ctx = OptimizerContext::mock().await;
+        let plan = reorder_joins(join_abc(&ctx, false), JoinCostModel::Batch);
+        let join = plan.as_logical_join().unwrap();
+        assert!(is_inner_join(&join.left()));
+        assert_eq!(join.right().as_logical_scan().unwrap().table_name(), "c");
+    }
+}
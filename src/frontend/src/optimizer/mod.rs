@@ -18,6 +18,7 @@ pub mod property;
 
 mod delta_join_solver;
 mod heuristic;
+mod join_order;
 mod plan_rewriter;
 mod plan_visitor;
 mod rule;
@@ -29,6 +30,7 @@ use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
 
 use self::heuristic::{ApplyOrder, HeuristicOptimizer};
+use self::join_order::{reorder_joins, JoinCostModel};
 use self::plan_node::{BatchProject, Convention, LogicalProject, StreamMaterialize};
 use self::rule::*;
 use crate::catalog::TableId;
@@ -116,8 +118,13 @@ impl PlanRoot {
         LogicalProject::create(self.plan, exprs)
     }
 
-    /// Apply logical optimization to the plan.
+    /// Apply logical optimization to the plan, ordering joins for batch execution.
     pub fn gen_optimized_logical_plan(&self) -> PlanRef {
+        self.gen_optimized_logical_plan_with(JoinCostModel::Batch)
+    }
+
+    /// Apply logical optimization to the plan, ordering joins with the given cost model.
+    fn gen_optimized_logical_plan_with(&self, join_cost_model: JoinCostModel) -> PlanRef {
         let mut plan = self.plan.clone();
 
         // Subquery Unnesting.
@@ -143,6 +150,10 @@ impl PlanRoot {
             heuristic_optimizer.optimize(plan)
         };
 
+        // Join Reordering, which only applies to the joins of analyzed tables. Join predicates
+        // must have been pushed down.
+        plan = reorder_joins(plan, join_cost_model);
+
         // Prune Columns
         //
         // Currently, the expressions in ORDER BY will be merged into the expressions in SELECT and
@@ -178,7 +189,25 @@ impl PlanRoot {
         // Convert to distributed plan
         plan = plan.to_distributed_with_required(&self.required_order, &self.required_dist)?;
 
-        // Add Project if the any position of `self.out_fields` is set to zero.
+        Ok(self.project_out_fields(plan))
+    }
+
+    /// Generate a batch plan for local execution, which runs as a single task without exchanges.
+    pub fn gen_batch_local_plan(&self) -> Result<PlanRef> {
+        // Logical optimization
+        let mut plan = self.gen_optimized_logical_plan();
+
+        // Convert to physical plan node
+        plan = plan.to_batch_with_order_required(&self.required_order)?;
+
+        // Convert to local plan
+        plan = plan.to_local_with_order_required(&self.required_order)?;
+
+        Ok(self.project_out_fields(plan))
+    }
+
+    /// Add Project if the any position of `self.out_fields` is set to zero.
+    fn project_out_fields(&self, plan: PlanRef) -> PlanRef {
         if self.out_fields.count_ones(..) != self.out_fields.len() {
             let exprs = self
                 .out_fields
@@ -186,17 +215,17 @@ impl PlanRoot {
                 .zip_eq(self.schema.fields.clone())
                 .map(|(index, field)| InputRef::new(index, field.data_type).into())
                 .collect();
-            plan = BatchProject::new(LogicalProject::new(plan, exprs)).into();
+            BatchProject::new(LogicalProject::new(plan, exprs)).into()
+        } else {
+            plan
         }
-
-        Ok(plan)
     }
 
     /// Generate create index or create materialize view plan.
     fn gen_stream_plan(&mut self) -> Result<PlanRef> {
         let plan = match self.plan.convention() {
             Convention::Logical => {
-                let plan = self.gen_optimized_logical_plan();
+                let plan = self.gen_optimized_logical_plan_with(JoinCostModel::Stream);
                 let (plan, out_col_change) = plan.logical_rewrite_for_stream()?;
                 self.required_dist = out_col_change
                     .rewrite_required_distribution(&self.required_dist)
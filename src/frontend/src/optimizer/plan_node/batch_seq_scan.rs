@@ -14,10 +14,15 @@
 
 use std::fmt;
 
+use itertools::Itertools;
 use risingwave_common::error::Result;
+use risingwave_common::types::ScalarImpl;
+use risingwave_common::util::value_encoding::serialize_cell;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::RowSeqScanNode;
-use risingwave_pb::plan_common::{CellBasedTableDesc, ColumnDesc as ProstColumnDesc};
+use risingwave_pb::plan_common::{
+    CellBasedTableDesc, ColumnDesc as ProstColumnDesc, OrderedColumnDesc as ProstOrderedColumnDesc,
+};
 
 use super::{PlanBase, PlanRef, ToBatchProst, ToDistributedBatch};
 use crate::optimizer::plan_node::{LogicalScan, ToLocalBatch};
@@ -28,23 +33,43 @@ use crate::optimizer::property::{Distribution, Order};
 pub struct BatchSeqScan {
     pub base: PlanBase,
     logical: LogicalScan,
+    /// Values of the leading columns of the table's storage key. Only rows matching them are
+    /// scanned.
+    pk_prefix: Vec<ScalarImpl>,
 }
 
 impl BatchSeqScan {
-    pub fn new_inner(logical: LogicalScan, dist: Distribution) -> Self {
+    pub fn new_inner(logical: LogicalScan, dist: Distribution, pk_prefix: Vec<ScalarImpl>) -> Self {
         let ctx = logical.base.ctx.clone();
         // TODO: derive from input
         let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
 
-        Self { base, logical }
+        Self {
+            base,
+            logical,
+            pk_prefix,
+        }
     }
 
     pub fn new(logical: LogicalScan) -> Self {
-        Self::new_inner(logical, Distribution::Any)
+        Self::new_inner(logical, Distribution::Any, vec![])
     }
 
-    pub fn with_dist(logical: LogicalScan) -> Self {
-        Self::new_inner(logical, Distribution::AnyShard)
+    /// Create a scan that only reads rows whose storage key starts with `pk_prefix`.
+    pub fn with_pk_prefix(logical: LogicalScan, pk_prefix: Vec<ScalarImpl>) -> Self {
+        assert!(pk_prefix.len() <= logical.table_desc().order_desc.len());
+        Self::new_inner(logical, Distribution::Any, pk_prefix)
+    }
+
+    /// Get the values of the leading storage key columns the scan is restricted to.
+    pub fn pk_prefix(&self) -> &[ScalarImpl] {
+        &self.pk_prefix
+    }
+
+    /// Whether the scan is restricted to a single primary key, and thus reads at most one row.
+    pub fn is_point_get(&self) -> bool {
+        let pk_len = self.logical.table_desc().order_desc.len();
+        pk_len > 0 && self.pk_prefix.len() == pk_len
     }
 
     /// Get a reference to the batch seq scan's logical.
@@ -60,16 +85,34 @@ impl fmt::Display for BatchSeqScan {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "BatchScan {{ table: {}, columns: [{}] }}",
+            "BatchScan {{ table: {}, columns: [{}]",
             self.logical.table_name(),
             self.logical.column_names().join(", ")
-        )
+        )?;
+        if !self.pk_prefix.is_empty() {
+            let order_desc = &self.logical.table_desc().order_desc;
+            write!(
+                f,
+                ", pk_prefix: [{}]",
+                self.pk_prefix
+                    .iter()
+                    .zip_eq(&order_desc[..self.pk_prefix.len()])
+                    .map(|(value, column)| format!("{} = {}", column.column_desc.name, value))
+                    .join(", ")
+            )?;
+        }
+        write!(f, " }}")
     }
 }
 
 impl ToDistributedBatch for BatchSeqScan {
     fn to_distributed(&self) -> Result<PlanRef> {
-        Ok(Self::with_dist(self.logical.clone()).into())
+        Ok(Self::new_inner(
+            self.logical.clone(),
+            Distribution::AnyShard,
+            self.pk_prefix.clone(),
+        )
+        .into())
     }
 }
 
@@ -85,15 +128,31 @@ impl ToBatchProst for BatchSeqScan {
         NodeBody::RowSeqScan(RowSeqScanNode {
             table_desc: Some(CellBasedTableDesc {
                 table_id: self.logical.table_desc().table_id.into(),
-                pk: vec![], // TODO:
+                pk: self
+                    .logical
+                    .table_desc()
+                    .order_desc
+                    .iter()
+                    .map(ProstOrderedColumnDesc::from)
+                    .collect(),
             }),
             column_descs,
+            pk_prefix: self
+                .pk_prefix
+                .iter()
+                .map(|value| serialize_cell(&Some(value.clone())).unwrap())
+                .collect(),
         })
     }
 }
 
 impl ToLocalBatch for BatchSeqScan {
     fn to_local(&self) -> Result<PlanRef> {
-        todo!()
+        Ok(Self::new_inner(
+            self.logical.clone(),
+            Distribution::Single,
+            self.pk_prefix.clone(),
+        )
+        .into())
     }
 }
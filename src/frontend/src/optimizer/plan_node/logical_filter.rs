@@ -22,7 +22,7 @@ use super::{
     ToStream,
 };
 use crate::expr::{assert_input_ref, ExprImpl};
-use crate::optimizer::plan_node::{BatchFilter, StreamFilter};
+use crate::optimizer::plan_node::{BatchFilter, BatchSeqScan, StreamFilter};
 use crate::risingwave_common::error::Result;
 use crate::utils::{ColIndexMapping, Condition};
 
@@ -144,6 +144,19 @@ impl ColPrunable for LogicalFilter {
 
 impl ToBatch for LogicalFilter {
     fn to_batch(&self) -> Result<PlanRef> {
+        if let Some(scan) = self.input().as_logical_scan() {
+            // Push equalities on the leading primary key columns into the scan, so that it only
+            // reads the matching key range instead of the whole table.
+            let (pk_prefix, predicate) = scan.split_pk_prefix(self.predicate().clone());
+            if !pk_prefix.is_empty() {
+                let new_input: PlanRef =
+                    BatchSeqScan::with_pk_prefix(scan.clone(), pk_prefix).into();
+                if predicate.always_true() {
+                    return Ok(new_input);
+                }
+                return Ok(BatchFilter::new(LogicalFilter::new(new_input, predicate)).into());
+            }
+        }
         let new_input = self.input().to_batch()?;
         let new_logical = self.clone_with_input(new_input);
         Ok(BatchFilter::new(new_logical).into())
@@ -17,13 +17,18 @@ use std::fmt;
 use std::rc::Rc;
 
 use itertools::Itertools;
+use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::{ColumnDesc, Schema, TableDesc};
 use risingwave_common::error::Result;
+use risingwave_common::types::ScalarImpl;
+use risingwave_expr::expr::build_from_prost;
 
 use super::{ColPrunable, PlanBase, PlanRef, StreamTableScan, ToBatch, ToStream};
+use crate::catalog::table_statistics::{ColumnStatistics, TableStatistics};
+use crate::expr::{Expr, ExprImpl, ExprType};
 use crate::optimizer::plan_node::BatchSeqScan;
 use crate::session::OptimizerContextRef;
-use crate::utils::ColIndexMapping;
+use crate::utils::{ColIndexMapping, Condition};
 
 /// `LogicalScan` returns contents of a table or other equivalent object
 #[derive(Debug, Clone)]
@@ -35,6 +40,8 @@ pub struct LogicalScan {
     table_desc: Rc<TableDesc>,
     // Descriptors of all indexes on this table
     indexes: Vec<(String, Rc<TableDesc>)>,
+    // Statistics of the table, if it has been analyzed
+    statistics: Option<Rc<TableStatistics>>,
 }
 
 impl LogicalScan {
@@ -81,14 +88,34 @@ impl LogicalScan {
             required_col_idx,
             table_desc,
             indexes,
+            statistics: None,
         }
     }
 
+    /// Attach the statistics of the table, which are used for cost estimation.
+    #[must_use]
+    pub fn with_statistics(mut self, statistics: Option<Rc<TableStatistics>>) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Get the statistics of the table, or `None` if it has never been analyzed.
+    pub fn statistics(&self) -> Option<&TableStatistics> {
+        self.statistics.as_deref()
+    }
+
+    /// Get the statistics of the `idx`-th output column.
+    pub fn column_statistics(&self, idx: usize) -> Option<&ColumnStatistics> {
+        let column_id = self.table_desc.columns[self.required_col_idx[idx]].column_id;
+        self.statistics()?.column(column_id)
+    }
+
     /// Create a [`LogicalScan`] node. Used by planner.
     pub fn create(
         table_name: String, // explain-only
         table_desc: Rc<TableDesc>,
         indexes: Vec<(String, Rc<TableDesc>)>,
+        statistics: Option<Rc<TableStatistics>>,
         ctx: OptimizerContextRef,
     ) -> Result<PlanRef> {
         Ok(Self::new(
@@ -98,6 +125,7 @@ impl LogicalScan {
             indexes,
             ctx,
         )
+        .with_statistics(statistics)
         .into())
     }
 
@@ -150,6 +178,44 @@ impl LogicalScan {
             .collect()
     }
 
+    /// Split `predicate` into the values it pins the leading storage key columns of the table to,
+    /// in key order, and the conjunctions left to be evaluated on the scanned rows.
+    pub fn split_pk_prefix(&self, predicate: Condition) -> (Vec<ScalarImpl>, Condition) {
+        let mut pinned = predicate
+            .conjunctions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, expr)| {
+                let (column, value) = pinned_column(expr)?;
+                Some((column, (i, value)))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut pk_prefix = vec![];
+        let mut consumed = HashSet::new();
+        for order in &self.table_desc.order_desc {
+            let op_idx = self.required_col_idx.iter().position(|&tb_idx| {
+                self.table_desc.columns[tb_idx].column_id == order.column_desc.column_id
+            });
+            match op_idx.and_then(|op_idx| pinned.remove(&op_idx)) {
+                Some((i, value)) => {
+                    pk_prefix.push(value);
+                    consumed.insert(i);
+                }
+                None => break,
+            }
+        }
+
+        let conjunctions = predicate
+            .conjunctions
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| !consumed.contains(i))
+            .map(|(_, expr)| expr)
+            .collect();
+        (pk_prefix, Condition { conjunctions })
+    }
+
     pub fn to_index_scan(&self, index_name: &str, index: &Rc<TableDesc>) -> LogicalScan {
         let mut new_required_col_idx = Vec::with_capacity(self.required_col_idx.len());
         let all_columns = index
@@ -177,6 +243,36 @@ impl LogicalScan {
 
 impl_plan_tree_node_for_leaf! {LogicalScan}
 
+/// If `expr` is `column = constant` or `constant = column` where the constant has the type of the
+/// column and evaluates to a non-null value, returns the column and the value.
+fn pinned_column(expr: &ExprImpl) -> Option<(usize, ScalarImpl)> {
+    let ExprImpl::FunctionCall(call) = expr else {
+        return None;
+    };
+    if call.get_expr_type() != ExprType::Equal {
+        return None;
+    }
+    let (column, constant) = match call.inputs() {
+        [ExprImpl::InputRef(input_ref), other] | [other, ExprImpl::InputRef(input_ref)] => {
+            (input_ref, other)
+        }
+        _ => return None,
+    };
+    if constant.has_input_ref()
+        || constant.has_correlated_input_ref()
+        || constant.has_subquery()
+        || constant.has_agg_call()
+        || constant.return_type() != column.return_type()
+    {
+        return None;
+    }
+    let value = build_from_prost(&constant.to_expr_proto())
+        .and_then(|expr| expr.eval(&DataChunk::new_dummy(1)))
+        .ok()?
+        .datum_at(0)?;
+    Some((column.index(), value))
+}
+
 impl fmt::Display for LogicalScan {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -202,6 +298,7 @@ impl ColPrunable for LogicalScan {
             self.indexes.clone(),
             self.base.ctx.clone(),
         )
+        .with_statistics(self.statistics.clone())
         .into()
     }
 }
@@ -248,6 +345,7 @@ impl ToStream for LogicalScan {
                         self.indexes.clone(),
                         self.base.ctx.clone(),
                     )
+                    .with_statistics(self.statistics.clone())
                     .into(),
                     ColIndexMapping::identity_or_none(self.schema().len(), new_len),
                 ))
@@ -33,6 +33,7 @@ use std::rc::Rc;
 
 use downcast_rs::{impl_downcast, Downcast};
 use dyn_clone::{self, DynClone};
+use fixedbitset::FixedBitSet;
 use paste::paste;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
@@ -122,13 +123,17 @@ impl dyn PlanNode {
         self.plan_base().append_only
     }
 
+    pub fn watermark_columns(&self) -> &FixedBitSet {
+        &self.plan_base().watermark_columns
+    }
+
     /// Serialize the plan node and its children to a batch plan proto.
     pub fn to_batch_prost(&self) -> BatchPlanProst {
         self.to_batch_prost_identity(true)
     }
 
     /// Serialize the plan node and its children to a batch plan proto without the identity field
-    /// (for testing).
+    /// and operator id (for testing).
     pub fn to_batch_prost_identity(&self, identity: bool) -> BatchPlanProst {
         let node_body = Some(self.to_batch_prost_body());
         let children = self
@@ -143,6 +148,7 @@ impl dyn PlanNode {
             } else {
                 "".into()
             },
+            operator_id: if identity { self.id().0 as u64 } else { 0 },
             node_body,
         }
     }
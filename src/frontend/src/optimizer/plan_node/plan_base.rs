@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use fixedbitset::FixedBitSet;
 use paste::paste;
 use risingwave_common::catalog::Schema;
 
@@ -38,11 +39,15 @@ pub struct PlanBase {
     /// The append-only property of the PlanNode's output is a stream-only property. Append-only
     /// means the stream contains only insert operation.
     pub append_only: bool,
+    /// The columns of the PlanNode's output that carry watermarks, a stream-only property. Rows
+    /// behind the watermark of a column will not appear in the stream anymore.
+    pub watermark_columns: FixedBitSet,
 }
 
 impl PlanBase {
     pub fn new_logical(ctx: OptimizerContextRef, schema: Schema, pk_indices: Vec<usize>) -> Self {
         let id = ctx.next_plan_node_id();
+        let watermark_columns = FixedBitSet::with_capacity(schema.len());
         Self {
             id,
             ctx,
@@ -52,6 +57,7 @@ impl PlanBase {
             order: Order::any().clone(),
             // Logical plan node won't touch `append_only` field
             append_only: true,
+            watermark_columns,
         }
     }
 
@@ -61,8 +67,10 @@ impl PlanBase {
         pk_indices: Vec<usize>,
         dist: Distribution,
         append_only: bool,
+        watermark_columns: FixedBitSet,
     ) -> Self {
         // assert!(!pk_indices.is_empty()); TODO: reopen it when ensure the pk for stream op
+        assert_eq!(watermark_columns.len(), schema.len());
         let id = ctx.next_plan_node_id();
         Self {
             id,
@@ -72,6 +80,7 @@ impl PlanBase {
             order: Order::any().clone(),
             pk_indices,
             append_only,
+            watermark_columns,
         }
     }
 
@@ -82,6 +91,7 @@ impl PlanBase {
         order: Order,
     ) -> Self {
         let id = ctx.next_plan_node_id();
+        let watermark_columns = FixedBitSet::with_capacity(schema.len());
         Self {
             id,
             ctx,
@@ -91,6 +101,7 @@ impl PlanBase {
             pk_indices: vec![],
             // Batch plan node won't touch `append_only` field
             append_only: true,
+            watermark_columns,
         }
     }
 }
@@ -119,6 +130,9 @@ macro_rules! impl_base_delegate {
                 pub fn append_only(&self) -> bool {
                     self.plan_base().append_only
                 }
+                pub fn watermark_columns(&self) -> &FixedBitSet {
+                    &self.plan_base().watermark_columns
+                }
             }
         })*
     }
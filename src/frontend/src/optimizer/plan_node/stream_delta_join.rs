@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use risingwave_common::catalog::ColumnDesc;
 use risingwave_pb::plan_common::JoinType;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
@@ -60,6 +61,7 @@ impl StreamDeltaJoin {
             logical.base.pk_indices.to_vec(),
             dist,
             append_only,
+            FixedBitSet::with_capacity(logical.schema().len()),
         );
 
         Self {
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::{DispatchStrategy, DispatcherType, ExchangeNode};
 
@@ -39,6 +40,7 @@ impl StreamExchange {
             pk_indices,
             dist,
             input.append_only(),
+            input.watermark_columns().clone(),
         );
         StreamExchange { base, input }
     }
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 use risingwave_pb::stream_plan::FilterNode;
 
@@ -42,6 +43,7 @@ impl StreamFilter {
             pk_indices,
             dist,
             logical.input().append_only(),
+            logical.input().watermark_columns().clone(),
         );
         StreamFilter { base, logical }
     }
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 
@@ -26,6 +27,13 @@ use crate::optimizer::property::Distribution;
 pub struct StreamHashAgg {
     pub base: PlanBase,
     logical: LogicalAgg,
+    /// Whether to emit the results of a group only once when its window is closed by the
+    /// watermark of a group key.
+    emit_on_window_close: bool,
+
+    /// Groups not updated for this long in processing time are forgotten, as requested by
+    /// `WITH (state_ttl_seconds = ...)`.
+    state_ttl_ms: Option<u64>,
 }
 
 impl StreamHashAgg {
@@ -48,9 +56,33 @@ impl StreamHashAgg {
                     .rewrite_provided_distribution(input_dist)
             }
         };
+
+        // Group keys come first in the output, and keep the watermarks of the input.
+        let mut watermark_columns = FixedBitSet::with_capacity(logical.schema().len());
+        for (idx, input_idx) in logical.group_keys().iter().enumerate() {
+            if input.watermark_columns().contains(*input_idx) {
+                watermark_columns.insert(idx);
+            }
+        }
+        let emit_on_window_close =
+            ctx.inner().emit_on_window_close && watermark_columns.count_ones(..) > 0;
+        let state_ttl_ms = ctx.inner().state_ttl_ms;
+
         // Hash agg executor might change the append-only behavior of the stream.
-        let base = PlanBase::new_stream(ctx, logical.schema().clone(), pk_indices, dist, false);
-        StreamHashAgg { base, logical }
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            dist,
+            false,
+            watermark_columns,
+        );
+        StreamHashAgg {
+            base,
+            logical,
+            emit_on_window_close,
+            state_ttl_ms,
+        }
     }
 
     pub fn agg_calls(&self) -> &[PlanAggCall] {
@@ -60,11 +92,16 @@ impl StreamHashAgg {
     pub fn distribution_keys(&self) -> &[usize] {
         self.logical.group_keys()
     }
+
+    pub fn emit_on_window_close(&self) -> bool {
+        self.emit_on_window_close
+    }
 }
 
 impl fmt::Display for StreamHashAgg {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("StreamHashAgg")
+        let mut builder = f.debug_struct("StreamHashAgg");
+        builder
             .field(
                 "group_keys",
                 &self
@@ -74,8 +111,14 @@ impl fmt::Display for StreamHashAgg {
                     .map(InputRefDisplay)
                     .collect_vec(),
             )
-            .field("aggs", &self.agg_calls())
-            .finish()
+            .field("aggs", &self.agg_calls());
+        if self.emit_on_window_close {
+            builder.field("emit_on_window_close", &true);
+        }
+        if let Some(state_ttl_ms) = self.state_ttl_ms {
+            builder.field("state_ttl_ms", &state_ttl_ms);
+        }
+        builder.finish()
     }
 }
 
@@ -107,6 +150,14 @@ impl ToStreamProst for StreamHashAgg {
                 .collect_vec(),
             table_ids: vec![],
             append_only: self.append_only(),
+            emit_on_window_close: self.emit_on_window_close,
+            // Group keys come first in the output.
+            watermark_key_positions: self
+                .watermark_columns()
+                .ones()
+                .map(|idx| idx as u32)
+                .collect_vec(),
+            state_ttl_ms: self.state_ttl_ms.unwrap_or(0),
         })
     }
 }
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_pb::plan_common::JoinType;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
@@ -41,9 +42,11 @@ pub struct StreamHashJoin {
     /// be create automatically when building the executors on meta service. For testing purpose
     /// only. Will remove after we have fully support shared state and index.
     is_delta: bool,
-}
 
-pub static DELTA_JOIN: &str = "RW_FORCE_DELTA_JOIN";
+    /// Rows older than this in processing time are forgotten by the join state, as requested by
+    /// `WITH (state_ttl_seconds = ...)`.
+    state_ttl_ms: Option<u64>,
+}
 
 impl StreamHashJoin {
     pub fn new(logical: LogicalJoin, eq_join_predicate: EqJoinPredicate) -> Self {
@@ -60,11 +63,8 @@ impl StreamHashJoin {
             &logical.l2o_col_mapping(),
         );
 
-        let force_delta = if let Some(config) = ctx.inner().session_ctx.get_config(DELTA_JOIN) {
-            config.is_set(false)
-        } else {
-            false
-        };
+        let force_delta = ctx.inner().session_ctx.config().get_force_delta_join();
+        let state_ttl_ms = ctx.inner().state_ttl_ms;
 
         // TODO: derive from input
         let base = PlanBase::new_stream(
@@ -73,6 +73,7 @@ impl StreamHashJoin {
             logical.base.pk_indices.to_vec(),
             dist,
             append_only,
+            FixedBitSet::with_capacity(logical.schema().len()),
         );
 
         Self {
@@ -80,6 +81,7 @@ impl StreamHashJoin {
             logical,
             eq_join_predicate,
             is_delta: force_delta,
+            state_ttl_ms,
         }
     }
 
@@ -120,7 +122,7 @@ impl fmt::Display for StreamHashJoin {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "{} {{ type: {:?}, predicate: {} }}",
+            "{} {{ type: {:?}, predicate: {}",
             if self.is_delta {
                 "StreamDeltaHashJoin"
             } else {
@@ -128,7 +130,11 @@ impl fmt::Display for StreamHashJoin {
             },
             self.logical.join_type(),
             self.eq_join_predicate()
-        )
+        )?;
+        if let Some(state_ttl_ms) = self.state_ttl_ms {
+            write!(f, ", state_ttl_ms: {}", state_ttl_ms)?;
+        }
+        write!(f, " }}")
     }
 }
 
@@ -180,6 +186,7 @@ impl ToStreamProst for StreamHashJoin {
                 .map(|idx| *idx as i32)
                 .collect_vec(),
             is_delta_join: self.is_delta,
+            state_ttl_ms: self.state_ttl_ms.unwrap_or(0),
             ..Default::default()
         })
     }
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 use risingwave_pb::stream_plan::HopWindowNode;
 
@@ -32,12 +33,21 @@ impl StreamHopWindow {
         let pk_indices = logical.base.pk_indices.to_vec();
         let input = logical.input();
 
+        // Window bounds derive their watermarks from the watermark of the time column.
+        let mut watermark_columns = input.watermark_columns().clone();
+        watermark_columns.grow(logical.schema().len());
+        if watermark_columns.contains(logical.time_col.index()) {
+            watermark_columns.insert(logical.window_start_col_idx());
+            watermark_columns.insert(logical.window_end_col_idx());
+        }
+
         let base = PlanBase::new_stream(
             ctx,
             logical.schema().clone(),
             pk_indices,
             input.distribution().clone(),
             logical.input().append_only(),
+            watermark_columns,
         );
         Self { base, logical }
     }
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 use risingwave_pb::stream_plan::StreamNode as ProstStreamPlan;
@@ -44,6 +45,7 @@ impl StreamIndexScan {
             logical.base.pk_indices.clone(),
             Distribution::HashShard(logical.map_distribution_keys()),
             false, // TODO: determine the `append-only` field of table scan
+            FixedBitSet::with_capacity(logical.schema().len()),
         );
         Self {
             base,
@@ -39,23 +39,27 @@ pub struct StreamMaterialize {
     /// Child of Materialize plan
     input: PlanRef,
     table: TableCatalog,
+    /// Whether an insert on an existing pk overwrites the existing row. See
+    /// [`Self::with_handle_pk_conflict`].
+    handle_pk_conflict: bool,
 }
 
 impl StreamMaterialize {
-    fn derive_plan_base(input: &PlanRef) -> Result<PlanBase> {
+    fn derive_plan_base(input: &PlanRef, handle_pk_conflict: bool) -> Result<PlanBase> {
         let ctx = input.ctx();
 
         let schema = Self::derive_schema(input.schema())?;
         let pk_indices = input.pk_indices();
 
         // Materialize executor won't change the append-only behavior of the stream, so it depends
-        // on input's `append_only`.
+        // on input's `append_only`, unless inserts may overwrite existing rows.
         Ok(PlanBase::new_stream(
             ctx,
             schema,
             pk_indices.to_vec(),
             input.distribution().clone(),
-            input.append_only(),
+            input.append_only() && !handle_pk_conflict,
+            input.watermark_columns().clone(),
         ))
     }
 
@@ -82,9 +86,14 @@ impl StreamMaterialize {
     }
 
     #[must_use]
-    pub fn new(input: PlanRef, table: TableCatalog) -> Self {
-        let base = Self::derive_plan_base(&input).unwrap();
-        Self { base, input, table }
+    pub fn new(input: PlanRef, table: TableCatalog, handle_pk_conflict: bool) -> Self {
+        let base = Self::derive_plan_base(&input, handle_pk_conflict).unwrap();
+        Self {
+            base,
+            input,
+            table,
+            handle_pk_conflict,
+        }
     }
 
     /// Create a materialize node.
@@ -110,7 +119,7 @@ impl StreamMaterialize {
             .enforce_if_not_satisfies(input, Order::any())?,
         };
 
-        let base = Self::derive_plan_base(&input)?;
+        let base = Self::derive_plan_base(&input, false)?;
         let schema = &base.schema;
         let pk_indices = &base.pk_indices;
 
@@ -181,9 +190,23 @@ impl StreamMaterialize {
             pks: pk_indices.clone(),
             is_index_on,
             distribution_keys: base.dist.dist_column_indices().to_vec(),
+            definition: "".to_string(),
+            statistics: None,
         };
 
-        Ok(Self { base, input, table })
+        Ok(Self {
+            base,
+            input,
+            table,
+            handle_pk_conflict: false,
+        })
+    }
+
+    /// Makes inserts on existing pks overwrite the existing rows, which are emitted as updates.
+    /// This is for tables with user-declared primary keys, whose inputs may repeat a key.
+    #[must_use]
+    pub fn with_handle_pk_conflict(self) -> Self {
+        Self::new(self.input, self.table, true)
     }
 
     /// Get a reference to the stream materialize's table.
@@ -247,7 +270,7 @@ impl PlanTreeNodeUnary for StreamMaterialize {
     }
 
     fn clone_with_input(&self, input: PlanRef) -> Self {
-        let new = Self::new(input, self.table().clone());
+        let new = Self::new(input, self.table().clone(), self.handle_pk_conflict);
         assert_eq!(new.plan_base().schema, self.plan_base().schema);
         assert_eq!(new.plan_base().pk_indices, self.plan_base().pk_indices);
         new
@@ -293,6 +316,7 @@ impl ToStreamProst for StreamMaterialize {
                 .iter()
                 .map(|idx| *idx as i32)
                 .collect_vec(),
+            handle_pk_conflict: self.handle_pk_conflict,
         })
     }
 }
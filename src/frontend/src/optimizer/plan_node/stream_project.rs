@@ -14,11 +14,12 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 use risingwave_pb::stream_plan::ProjectNode;
 
 use super::{LogicalProject, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
-use crate::expr::Expr;
+use crate::expr::{Expr, ExprImpl, ExprType};
 
 /// `StreamProject` implements [`super::LogicalProject`] to evaluate specified expressions on input
 /// rows.
@@ -26,6 +27,9 @@ use crate::expr::Expr;
 pub struct StreamProject {
     pub base: PlanBase,
     logical: LogicalProject,
+    /// `(input_idx, output_idx)` pairs, where the watermark of the output column is derived from
+    /// the watermark of the input column.
+    watermark_derivations: Vec<(usize, usize)>,
 }
 
 impl fmt::Display for StreamProject {
@@ -42,6 +46,18 @@ impl StreamProject {
         let distribution = logical
             .i2o_col_mapping()
             .rewrite_provided_distribution(input.distribution());
+
+        let mut watermark_derivations = vec![];
+        let mut watermark_columns = FixedBitSet::with_capacity(logical.schema().len());
+        for (output_idx, expr) in logical.exprs().iter().enumerate() {
+            if let Some(input_idx) = monotonic_input(expr)
+                && input.watermark_columns().contains(input_idx)
+            {
+                watermark_derivations.push((input_idx, output_idx));
+                watermark_columns.insert(output_idx);
+            }
+        }
+
         // Project executor won't change the append-only behavior of the stream, so it depends on
         // input's `append_only`.
         let base = PlanBase::new_stream(
@@ -50,8 +66,30 @@ impl StreamProject {
             pk_indices,
             distribution,
             logical.input().append_only(),
+            watermark_columns,
         );
-        StreamProject { base, logical }
+        StreamProject {
+            base,
+            logical,
+            watermark_derivations,
+        }
+    }
+}
+
+/// Returns the input column if `expr` is non-decreasing on it and depends on nothing else, so that
+/// the watermark of that column can be mapped through `expr`.
+fn monotonic_input(expr: &ExprImpl) -> Option<usize> {
+    match expr {
+        ExprImpl::InputRef(input_ref) => Some(input_ref.index()),
+        ExprImpl::FunctionCall(call) => match (call.get_expr_type(), call.inputs()) {
+            (
+                ExprType::TumbleStart | ExprType::Add | ExprType::Subtract,
+                [lhs, ExprImpl::Literal(_)],
+            ) => monotonic_input(lhs),
+            (ExprType::Add, [ExprImpl::Literal(_), rhs]) => monotonic_input(rhs),
+            _ => None,
+        },
+        _ => None,
     }
 }
 
@@ -75,6 +113,16 @@ impl ToStreamProst for StreamProject {
                 .iter()
                 .map(Expr::to_expr_proto)
                 .collect(),
+            watermark_input_key: self
+                .watermark_derivations
+                .iter()
+                .map(|(i, _)| *i as u32)
+                .collect(),
+            watermark_output_key: self
+                .watermark_derivations
+                .iter()
+                .map(|(_, o)| *o as u32)
+                .collect(),
         })
     }
 }
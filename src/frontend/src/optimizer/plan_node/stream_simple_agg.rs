@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 
@@ -40,7 +41,15 @@ impl StreamSimpleAgg {
         };
 
         // Simple agg executor might change the append-only behavior of the stream.
-        let base = PlanBase::new_stream(ctx, logical.schema().clone(), pk_indices, dist, false);
+        let watermark_columns = FixedBitSet::with_capacity(logical.schema().len());
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            dist,
+            false,
+            watermark_columns,
+        );
         StreamSimpleAgg { base, logical }
     }
 
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use risingwave_pb::plan_common::TableRefId;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 use risingwave_pb::stream_plan::SourceNode;
@@ -30,12 +31,23 @@ pub struct StreamSource {
 
 impl StreamSource {
     pub fn new(logical: LogicalSource) -> Self {
+        let source_catalog = &logical.source_catalog;
+        let mut watermark_columns = FixedBitSet::with_capacity(logical.schema().len());
+        for (idx, column) in source_catalog.columns.iter().enumerate() {
+            if source_catalog
+                .watermark_col_ids
+                .contains(&column.column_id())
+            {
+                watermark_columns.insert(idx);
+            }
+        }
         let base = PlanBase::new_stream(
             logical.ctx(),
             logical.schema().clone(),
             logical.pk_indices().to_vec(),
             Distribution::any().clone(),
             false, // TODO: determine the `append-only` field of source
+            watermark_columns,
         );
         Self { base, logical }
     }
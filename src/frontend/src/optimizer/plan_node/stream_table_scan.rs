@@ -15,6 +15,7 @@
 use std::fmt;
 use std::rc::Rc;
 
+use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_common::catalog::TableDesc;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
@@ -45,6 +46,7 @@ impl StreamTableScan {
             // follows upstream distribution from TableCatalog
             Distribution::HashShard(logical.map_distribution_keys()),
             false, // TODO: determine the `append-only` field of table scan
+            FixedBitSet::with_capacity(logical.schema().len()),
         );
         Self {
             base,
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use fixedbitset::FixedBitSet;
 use risingwave_pb::expr::InputRefExpr;
 use risingwave_pb::plan_common::ColumnOrder;
 use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
@@ -43,6 +44,7 @@ impl StreamTopN {
             logical.input().pk_indices().to_vec(),
             dist,
             false,
+            FixedBitSet::with_capacity(logical.schema().len()),
         );
         StreamTopN { base, logical }
     }
@@ -15,18 +15,22 @@
 use std::rc::Rc;
 
 use itertools::Itertools;
+use risingwave_common::array::{DataChunk, JsonbRef, JsonbValue};
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::{DataType, NaiveDateTimeWrapper, ScalarImpl};
+use risingwave_expr::expr::build_from_prost;
+use risingwave_expr::vector_op::jsonb::jsonb_to_text;
+use serde_json::Value;
 
 use crate::binder::{
-    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundSource, BoundWindowTableFunction,
-    Relation, WindowTableFunctionKind,
+    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundJsonbArrayElementsFunction,
+    BoundSource, BoundSystemTable, BoundWindowTableFunction, Relation, WindowTableFunctionKind,
 };
-use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef};
+use crate::expr::{Expr, ExprImpl, ExprType, FunctionCall, InputRef, Literal};
 use crate::optimizer::plan_node::{
     LogicalGenerateSeries, LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan,
-    LogicalSource, PlanRef,
+    LogicalSource, LogicalValues, PlanRef,
 };
 use crate::planner::Planner;
 
@@ -34,12 +38,14 @@ impl Planner {
     pub(super) fn plan_relation(&mut self, relation: Relation) -> Result<PlanRef> {
         match relation {
             Relation::BaseTable(t) => self.plan_base_table(*t),
+            Relation::SystemTable(st) => self.plan_sys_table(*st),
             // TODO: order is ignored in the subquery
             Relation::Subquery(q) => Ok(self.plan_query(q.query)?.as_subplan()),
             Relation::Join(join) => self.plan_join(*join),
             Relation::WindowTableFunction(tf) => self.plan_window_table_function(*tf),
             Relation::Source(s) => self.plan_source(*s),
             Relation::GenerateSeriesFunction(gs) => self.plan_generate_series_function(*gs),
+            Relation::JsonbArrayElementsFunction(f) => self.plan_jsonb_array_elements_function(*f),
         }
     }
 
@@ -52,10 +58,28 @@ impl Planner {
                 .iter()
                 .map(|x| (x.name.clone(), Rc::new(x.table_desc())))
                 .collect(),
+            base_table.table_catalog.statistics.map(Rc::new),
             self.ctx(),
         )
     }
 
+    /// System tables are planned as constant values, so they can be queried by any batch plan.
+    pub(super) fn plan_sys_table(&mut self, sys_table: BoundSystemTable) -> Result<PlanRef> {
+        let fields = sys_table.table_catalog.fields();
+        let rows: Vec<Vec<ExprImpl>> = sys_table
+            .rows
+            .into_iter()
+            .map(|row| {
+                row.0
+                    .into_iter()
+                    .zip_eq(fields.iter())
+                    .map(|(datum, field)| Literal::new(datum, field.data_type()).into())
+                    .collect()
+            })
+            .collect();
+        Ok(LogicalValues::create(rows, Schema::new(fields), self.ctx()))
+    }
+
     pub(super) fn plan_source(&mut self, source: BoundSource) -> Result<PlanRef> {
         Ok(LogicalSource::new(Rc::new(source.catalog), self.ctx()).into())
     }
@@ -134,6 +158,51 @@ impl Planner {
         ))
     }
 
+    /// The argument is constant, so the elements of the array are planned as constant values.
+    pub(super) fn plan_jsonb_array_elements_function(
+        &mut self,
+        table_function: BoundJsonbArrayElementsFunction,
+    ) -> Result<PlanRef> {
+        let data_type = table_function.data_type();
+        let schema = Schema::new(vec![Field::with_name(data_type.clone(), "value")]);
+
+        let array = build_from_prost(&table_function.arg.to_expr_proto())?
+            .eval(&DataChunk::new_dummy(1))?
+            .datum_at(0);
+        // The function returns no rows on `NULL`.
+        let elements = match array {
+            Some(ScalarImpl::Jsonb(array)) => match JsonbRef::from(&array).to_serde() {
+                Value::Array(elements) => elements,
+                Value::Object(_) => {
+                    return Err(ErrorCode::InvalidInputSyntax(
+                        "cannot extract elements from an object".to_string(),
+                    )
+                    .into())
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidInputSyntax(
+                        "cannot extract elements from a scalar".to_string(),
+                    )
+                    .into())
+                }
+            },
+            _ => vec![],
+        };
+
+        let rows = elements
+            .into_iter()
+            .map(|element| {
+                let datum = if table_function.as_text {
+                    jsonb_to_text(&element).map(ScalarImpl::Utf8)
+                } else {
+                    Some(ScalarImpl::Jsonb(JsonbValue::from_serde(element)))
+                };
+                vec![Literal::new(datum, data_type.clone()).into()]
+            })
+            .collect();
+        Ok(LogicalValues::create(rows, schema, self.ctx()))
+    }
+
     fn plan_tumble_window(
         &mut self,
         input: Relation,
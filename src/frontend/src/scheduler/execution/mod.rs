@@ -16,3 +16,5 @@ mod query;
 pub use query::*;
 mod stage;
 pub use stage::*;
+mod stats;
+pub use stats::*;
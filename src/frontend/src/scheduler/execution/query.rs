@@ -15,7 +15,9 @@
 use std::collections::HashMap;
 use std::mem::swap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use futures::future::try_join_all;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::batch_plan::{TaskId as TaskIdProst, TaskOutputId as TaskOutputIdProst};
@@ -28,10 +30,13 @@ use super::stage::StageEvent;
 use crate::scheduler::execution::query::QueryMessage::Stage;
 use crate::scheduler::execution::query::QueryState::{Failed, Pending};
 use crate::scheduler::execution::StageEvent::Scheduled;
-use crate::scheduler::execution::{StageExecution, ROOT_TASK_ID, ROOT_TASK_OUTPUT_ID};
+use crate::scheduler::execution::{QueryStats, StageExecution, ROOT_TASK_ID, ROOT_TASK_OUTPUT_ID};
 use crate::scheduler::plan_fragmenter::{Query, StageId};
 use crate::scheduler::worker_node_manager::WorkerNodeManagerRef;
-use crate::scheduler::{HummockSnapshotManagerRef, QueryResultFetcher};
+use crate::scheduler::{HummockSnapshotManagerRef, QueryParams, QueryResultFetcher};
+
+/// How long to wait for the tasks of a query to finish before taking whatever info they report.
+const TASK_INFO_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Message sent to a `QueryRunner` to control its execution.
 #[derive(Debug)]
@@ -96,6 +101,7 @@ impl QueryExecution {
     pub fn new(
         query: Query,
         epoch: u64,
+        params: QueryParams,
         worker_node_manager: WorkerNodeManagerRef,
         hummock_snapshot_manager: HummockSnapshotManagerRef,
     ) -> Self {
@@ -116,6 +122,7 @@ impl QueryExecution {
 
                 let stage_exec = Arc::new(StageExecution::new(
                     epoch,
+                    params.clone(),
                     query.stage_graph.stages[&stage_id].clone(),
                     worker_node_manager.clone(),
                     sender.clone(),
@@ -197,6 +204,23 @@ impl QueryExecution {
         }
     }
 
+    /// Collects the runtime statistics of the operators from all tasks of the query. Unfinished
+    /// tasks are waited for at most [`TASK_INFO_WAIT_TIMEOUT`] in total.
+    pub async fn collect_stats(&self) -> Result<QueryStats> {
+        let deadline = Instant::now() + TASK_INFO_WAIT_TIMEOUT;
+        let task_infos = try_join_all(
+            self.stage_executions
+                .values()
+                .map(|stage_execution| stage_execution.get_task_infos(deadline)),
+        )
+        .await?;
+        let mut stats = QueryStats::default();
+        for task_info in task_infos.iter().flatten() {
+            stats.add_task_info(task_info);
+        }
+        Ok(stats)
+    }
+
     /// Cancel execution of this query.
     pub async fn abort(&mut self) -> Result<()> {
         todo!()
@@ -380,6 +404,7 @@ mod tests {
         let query_execution = QueryExecution::new(
             create_query().await,
             100,
+            Default::default(),
             worker_node_manager,
             Arc::new(HummockSnapshotManager::new(Arc::new(
                 MockFrontendMetaClient {},
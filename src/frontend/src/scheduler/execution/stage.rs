@@ -15,8 +15,10 @@
 use std::collections::HashMap;
 use std::mem::swap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use arc_swap::ArcSwap;
+use futures::future::try_join_all;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
@@ -25,11 +27,14 @@ use risingwave_pb::batch_plan::{
     TaskId as TaskIdProst, TaskOutputId,
 };
 use risingwave_pb::common::HostAddress;
+use risingwave_pb::task_service::task_info::TaskStatus as TaskStatusProst;
+use risingwave_pb::task_service::TaskInfo;
 use risingwave_rpc_client::ComputeClient;
 use tokio::spawn;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
+use tokio::time::sleep;
 use tracing::{error, info};
 use uuid::Uuid;
 use StageEvent::Failed;
@@ -39,11 +44,13 @@ use crate::scheduler::execution::stage::StageState::Pending;
 use crate::scheduler::execution::QueryMessage;
 use crate::scheduler::plan_fragmenter::{ExecutionPlanNode, QueryStageRef, StageId};
 use crate::scheduler::worker_node_manager::WorkerNodeManagerRef;
+use crate::scheduler::QueryParams;
 
 // Root stage always has only one task.
 pub const ROOT_TASK_ID: u32 = 0;
 // Root task has only one output.
 pub const ROOT_TASK_OUTPUT_ID: u32 = 0;
+const TASK_INFO_POLL_INTERVAL: Duration = Duration::from_millis(10);
 pub(crate) type TaskId = u32;
 
 enum StageState {
@@ -89,6 +96,7 @@ struct TaskStatusHolder {
 
 pub struct StageExecution {
     epoch: u64,
+    params: QueryParams,
     stage: QueryStageRef,
     worker_node_manager: WorkerNodeManagerRef,
     tasks: Arc<HashMap<TaskId, TaskStatusHolder>>,
@@ -103,6 +111,7 @@ pub struct StageExecution {
 
 struct StageRunner {
     epoch: u64,
+    params: QueryParams,
     state: Arc<RwLock<StageState>>,
     stage: QueryStageRef,
     worker_node_manager: WorkerNodeManagerRef,
@@ -133,6 +142,7 @@ impl TaskStatusHolder {
 impl StageExecution {
     pub fn new(
         epoch: u64,
+        params: QueryParams,
         stage: QueryStageRef,
         worker_node_manager: WorkerNodeManagerRef,
         msg_sender: Sender<QueryMessage>,
@@ -144,6 +154,7 @@ impl StageExecution {
             .collect();
         Self {
             epoch,
+            params,
             stage,
             worker_node_manager,
             tasks: Arc::new(tasks),
@@ -161,6 +172,7 @@ impl StageExecution {
                 let (sender, receiver) = channel(100);
                 let runner = StageRunner {
                     epoch: self.epoch,
+                    params: self.params.clone(),
                     stage: self.stage.clone(),
                     worker_node_manager: self.worker_node_manager.clone(),
                     tasks: self.tasks.clone(),
@@ -206,6 +218,37 @@ impl StageExecution {
         self.tasks[&task_id].get_status()
     }
 
+    /// Fetches the info of all tasks, including the runtime statistics of their operators.
+    ///
+    /// When this method is called, all tasks should have been scheduled. The tasks are polled
+    /// concurrently until they finish, or until `deadline` has passed. The infos of tasks that did
+    /// not finish in time are returned as they are, with a running status.
+    pub async fn get_task_infos(&self, deadline: Instant) -> Result<Vec<TaskInfo>> {
+        try_join_all(self.tasks.iter().map(|(task_id, status_holder)| {
+            let task_host = status_holder.get_status().task_host_unchecked();
+            let task_id = TaskIdProst {
+                query_id: self.stage.query_id.id.clone(),
+                stage_id: self.stage.id,
+                task_id: *task_id,
+            };
+            async move {
+                let compute_client = ComputeClient::new((&task_host).into()).await?;
+                let mut task_info = compute_client.get_task_info(task_id.clone()).await?;
+                while Instant::now() < deadline {
+                    if task_info.task_status == TaskStatusProst::Finished as i32
+                        || task_info.task_status == TaskStatusProst::Failed as i32
+                    {
+                        break;
+                    }
+                    sleep(TASK_INFO_POLL_INTERVAL).await;
+                    task_info = compute_client.get_task_info(task_id.clone()).await?;
+                }
+                Ok::<_, RwError>(task_info)
+            }
+        }))
+        .await
+    }
+
     /// Returns all exchange sources for `output_id`. Each `ExchangeSource` is identified by
     /// producer `TaskId` and `output_id`, since each task may produce output to several channels.
     ///
@@ -308,7 +351,12 @@ impl StageRunner {
 
         let t_id = task_id.task_id;
         compute_client
-            .create_task2(task_id, plan_fragment, self.epoch)
+            .create_task2(
+                task_id,
+                plan_fragment,
+                self.epoch,
+                self.params.task_limits(),
+            )
             .await?;
 
         self.tasks[&t_id].inner.store(Arc::new(TaskStatus {
@@ -352,6 +400,7 @@ impl StageRunner {
                             children: vec![],
                             // TODO: Generate meaningful identify
                             identity: Uuid::new_v4().to_string(),
+                            operator_id: execution_plan_node.plan_node_id.0 as u64,
                             node_body: Some(NodeBody::Exchange(ExchangeNode {
                                 sources: exchange_sources,
                                 input_schema: execution_plan_node.schema.clone(),
@@ -363,6 +412,7 @@ impl StageRunner {
                             children: vec![],
                             // TODO: Generate meaningful identify
                             identity: Uuid::new_v4().to_string(),
+                            operator_id: execution_plan_node.plan_node_id.0 as u64,
                             node_body: Some(NodeBody::MergeSortExchange(MergeSortExchangeNode {
                                 exchange: Some(ExchangeNode {
                                     sources: exchange_sources,
@@ -381,12 +431,15 @@ impl StageRunner {
                     .iter()
                     .map(|e| self.convert_plan_node(&*e, task_id))
                     .collect();
+                let mut node_body = execution_plan_node.node.clone();
+                self.params.bind_node_body(&mut node_body);
 
                 PlanNodeProst {
                     children,
                     // TODO: Generate meaningful identify
                     identity: Uuid::new_v4().to_string(),
-                    node_body: Some(execution_plan_node.node.clone()),
+                    operator_id: execution_plan_node.plan_node_id.0 as u64,
+                    node_body: Some(node_body),
                 }
             }
         }
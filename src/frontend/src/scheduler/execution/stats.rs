@@ -0,0 +1,149 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use risingwave_pb::task_service::task_info::TaskStatus;
+use risingwave_pb::task_service::{OperatorStats as ProstOperatorStats, TaskInfo};
+
+/// Runtime statistics of an operator, aggregated over all tasks of its stage.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct OperatorStats {
+    /// Number of tasks reporting the operator.
+    pub tasks: usize,
+    /// Total number of output rows.
+    pub rows: u64,
+    /// Total number of output chunks.
+    pub chunks: u64,
+    /// The longest time spent by the operator in a single task, including its inputs.
+    pub elapsed: Duration,
+    /// The largest high-water mark of the memory reserved by the operator in a single task, in
+    /// bytes. Only operators buffering their input, such as hash aggregations, joins and sorts,
+    /// reserve memory.
+    pub peak_memory_bytes: u64,
+}
+
+impl OperatorStats {
+    fn merge(&mut self, task_stats: &ProstOperatorStats) {
+        self.tasks += 1;
+        self.rows += task_stats.rows;
+        self.chunks += task_stats.chunks;
+        self.elapsed = self
+            .elapsed
+            .max(Duration::from_nanos(task_stats.elapsed_ns));
+        self.peak_memory_bytes = self.peak_memory_bytes.max(task_stats.peak_memory_bytes);
+    }
+
+    /// Formats the statistics. The verbose form also includes the number of tasks.
+    pub fn explain(&self, verbose: bool) -> String {
+        let mut s = format!(
+            "actual rows={}, chunks={}, time={:.3}ms, peak memory={}B",
+            self.rows,
+            self.chunks,
+            self.elapsed.as_secs_f64() * 1000.0,
+            self.peak_memory_bytes
+        );
+        if verbose {
+            s += &format!(", tasks={}", self.tasks);
+        }
+        s
+    }
+}
+
+/// Runtime statistics of a query collected from all of its tasks, used by `EXPLAIN ANALYZE`.
+#[derive(Debug, Default)]
+pub struct QueryStats {
+    /// Statistics of each operator, keyed by the id of its plan node.
+    operators: HashMap<u64, OperatorStats>,
+    /// Wall-clock time of the whole query.
+    pub elapsed: Duration,
+    /// The largest high-water mark of the memory reserved by a single task, in bytes.
+    pub peak_task_memory_bytes: u64,
+    /// Number of tasks that had not finished when their statistics were collected, so their
+    /// statistics may be partial.
+    pub unfinished_tasks: usize,
+}
+
+impl QueryStats {
+    /// Merges the operator statistics reported by a task.
+    pub fn add_task_info(&mut self, task_info: &TaskInfo) {
+        if task_info.task_status != TaskStatus::Finished as i32
+            && task_info.task_status != TaskStatus::Failed as i32
+        {
+            self.unfinished_tasks += 1;
+        }
+        self.peak_task_memory_bytes = self.peak_task_memory_bytes.max(task_info.peak_memory_bytes);
+        for task_stats in &task_info.operator_stats {
+            self.operators
+                .entry(task_stats.operator_id)
+                .or_default()
+                .merge(task_stats);
+        }
+    }
+
+    /// Returns the statistics of the operator of the given plan node, or `None` if it was never
+    /// executed.
+    pub fn get_operator(&self, operator_id: u64) -> Option<&OperatorStats> {
+        self.operators.get(&operator_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_task_stats() {
+        let task_info = |task_status: TaskStatus, rows, elapsed_ns, peak_memory_bytes| TaskInfo {
+            task_status: task_status as i32,
+            operator_stats: vec![ProstOperatorStats {
+                operator_id: 3,
+                identity: "HashAggExecutor".to_string(),
+                rows,
+                chunks: 1,
+                elapsed_ns,
+                peak_memory_bytes,
+            }],
+            peak_memory_bytes: peak_memory_bytes * 2,
+            ..Default::default()
+        };
+        let mut stats = QueryStats::default();
+        stats.add_task_info(&task_info(TaskStatus::Finished, 10, 2_000_000, 100));
+        stats.add_task_info(&task_info(TaskStatus::Running, 5, 3_000_000, 50));
+        assert_eq!(stats.unfinished_tasks, 1);
+        assert_eq!(stats.peak_task_memory_bytes, 200);
+
+        assert!(stats.get_operator(4).is_none());
+        let operator = stats.get_operator(3).unwrap();
+        assert_eq!(
+            operator,
+            &OperatorStats {
+                tasks: 2,
+                rows: 15,
+                chunks: 2,
+                elapsed: Duration::from_millis(3),
+                peak_memory_bytes: 100,
+            }
+        );
+        assert_eq!(
+            operator.explain(false),
+            "actual rows=15, chunks=2, time=3.000ms, peak memory=100B"
+        );
+        assert_eq!(
+            operator.explain(true),
+            "actual rows=15, chunks=2, time=3.000ms, peak memory=100B, tasks=2"
+        );
+    }
+}
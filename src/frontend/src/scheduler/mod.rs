@@ -13,11 +13,21 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::PlanNode as PlanNodeProst;
+use risingwave_pb::expr::expr_node::{RexNode, Type as ExprType};
+use risingwave_pb::expr::ExprNode;
+use risingwave_pb::task_service::TaskLimits;
+
+use crate::expr::{Expr, Literal};
 use crate::session::SessionImpl;
 
 #[allow(dead_code)]
 mod execution;
+pub use execution::{OperatorStats, QueryStats};
 mod hummock_snapshot_manager;
 pub use hummock_snapshot_manager::*;
 #[allow(dead_code)]
@@ -29,16 +39,182 @@ pub mod worker_node_manager;
 /// Context for mpp query execution.
 pub struct ExecutionContext {
     session: Arc<SessionImpl>,
+    params: QueryParams,
 }
 
 pub type ExecutionContextRef = Arc<ExecutionContext>;
 
 impl ExecutionContext {
     pub fn new(session: Arc<SessionImpl>) -> Self {
-        Self { session }
+        let params = QueryParams {
+            now: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_micros() as i64,
+            deadline: session
+                .config()
+                .get_statement_timeout()
+                .map(|timeout| Instant::now() + timeout),
+            memory_quota: session.config().get_query_memory_quota().unwrap_or(0),
+        };
+        Self { session, params }
     }
 
     pub fn session(&self) -> &SessionImpl {
         &self.session
     }
+
+    pub fn query_params(&self) -> QueryParams {
+        self.params.clone()
+    }
+}
+
+/// Parameters of a query fixed when it starts, shared by all its tasks however late they are
+/// scheduled.
+#[derive(Clone, Debug, Default)]
+pub struct QueryParams {
+    /// The value of `now()` in the query, in microseconds since the unix epoch.
+    now: i64,
+    /// The query fails once the deadline passes, set by `statement_timeout` when the query
+    /// starts.
+    deadline: Option<Instant>,
+    /// Memory quota of the query on each compute node in bytes, set by `query_memory_quota`. 0
+    /// means no limit.
+    memory_quota: u64,
+}
+
+impl QueryParams {
+    /// Returns the limits of a task scheduled now. The deadline is sent as the time left until
+    /// it, since the clocks of the compute nodes may differ from the clock of the frontend.
+    pub fn task_limits(&self) -> TaskLimits {
+        TaskLimits {
+            // At least 1 ms, since 0 means no timeout.
+            timeout_ms: self.deadline.map_or(0, |deadline| {
+                (deadline
+                    .saturating_duration_since(Instant::now())
+                    .as_millis() as u64)
+                    .max(1)
+            }),
+            memory_quota: self.memory_quota,
+        }
+    }
+
+    /// Replaces the calls of `now()` in the plan with the start time of the query, so that all
+    /// tasks of the query see the same value.
+    pub fn bind_plan(&self, plan: &mut PlanNodeProst) {
+        if let Some(node_body) = &mut plan.node_body {
+            self.bind_node_body(node_body);
+        }
+        for child in &mut plan.children {
+            self.bind_plan(child);
+        }
+    }
+
+    /// Replaces the calls of `now()` in the expressions of a plan node, see [`Self::bind_plan`].
+    pub fn bind_node_body(&self, node_body: &mut NodeBody) {
+        let exprs: Vec<&mut ExprNode> = match node_body {
+            NodeBody::Project(node) => node.select_list.iter_mut().collect(),
+            NodeBody::Filter(node) => node.search_condition.iter_mut().collect(),
+            NodeBody::Values(node) => node
+                .tuples
+                .iter_mut()
+                .flat_map(|tuple| &mut tuple.cells)
+                .collect(),
+            NodeBody::NestedLoopJoin(node) => node.join_cond.iter_mut().collect(),
+            NodeBody::HashJoin(node) => node.condition.iter_mut().collect(),
+            NodeBody::SortAgg(node) => node.group_keys.iter_mut().collect(),
+            _ => vec![],
+        };
+        for expr in exprs {
+            self.bind_expr(expr);
+        }
+    }
+
+    fn bind_expr(&self, expr: &mut ExprNode) {
+        if expr.expr_type == ExprType::Now as i32 {
+            *expr = Literal::new(Some(ScalarImpl::Int64(self.now)), DataType::Timestampz)
+                .to_expr_proto();
+        } else if let Some(RexNode::FuncCall(func_call)) = &mut expr.rex_node {
+            for child in &mut func_call.children {
+                self.bind_expr(child);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use risingwave_pb::batch_plan::{FilterNode, ProjectNode};
+    use risingwave_pb::expr::FunctionCall as FunctionCallProst;
+
+    use super::*;
+    use crate::expr::{ExprImpl, FunctionCall, InputRef};
+
+    #[test]
+    fn test_bind_now() {
+        let now: ExprImpl = FunctionCall::new(ExprType::Now, vec![]).unwrap().into();
+        let cond: ExprImpl = FunctionCall::new(
+            ExprType::GreaterThan,
+            vec![InputRef::new(0, DataType::Timestampz).into(), now.clone()],
+        )
+        .unwrap()
+        .into();
+        let mut plan = PlanNodeProst {
+            children: vec![PlanNodeProst {
+                node_body: Some(NodeBody::Project(ProjectNode {
+                    select_list: vec![now.to_expr_proto()],
+                })),
+                ..Default::default()
+            }],
+            node_body: Some(NodeBody::Filter(FilterNode {
+                search_condition: Some(cond.to_expr_proto()),
+            })),
+            ..Default::default()
+        };
+
+        let params = QueryParams {
+            now: 1_000_000,
+            ..Default::default()
+        };
+        params.bind_plan(&mut plan);
+
+        let literal =
+            Literal::new(Some(ScalarImpl::Int64(1_000_000)), DataType::Timestampz).to_expr_proto();
+        let Some(NodeBody::Filter(filter)) = &plan.node_body else {
+            panic!("expect filter");
+        };
+        let Some(RexNode::FuncCall(FunctionCallProst { children })) =
+            &filter.search_condition.as_ref().unwrap().rex_node
+        else {
+            panic!("expect function call");
+        };
+        assert_eq!(children[1], literal);
+        let Some(NodeBody::Project(project)) = &plan.children[0].node_body else {
+            panic!("expect project");
+        };
+        assert_eq!(project.select_list, vec![literal]);
+    }
+
+    #[test]
+    fn test_task_limits() {
+        assert_eq!(QueryParams::default().task_limits(), TaskLimits::default());
+
+        let params = QueryParams {
+            deadline: Some(Instant::now() + Duration::from_secs(60)),
+            memory_quota: 100,
+            ..Default::default()
+        };
+        let limits = params.task_limits();
+        assert!(limits.timeout_ms > 50_000 && limits.timeout_ms <= 60_000);
+        assert_eq!(limits.memory_quota, 100);
+
+        // A task scheduled after the deadline times out right away.
+        let params = QueryParams {
+            deadline: Some(Instant::now()),
+            ..Default::default()
+        };
+        assert_eq!(params.task_limits().timeout_ms, 1);
+    }
 }
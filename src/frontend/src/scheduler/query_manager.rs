@@ -13,19 +13,21 @@
 // limitations under the License.
 
 use std::fmt::{Debug, Formatter};
+use std::time::Instant;
 
 use futures::Stream;
-use futures_async_stream::try_stream;
+use futures_async_stream::{for_await, try_stream};
 use log::debug;
 use risingwave_common::array::DataChunk;
 use risingwave_common::error::{Result, RwError};
-use risingwave_pb::batch_plan::{PlanNode as BatchPlanProst, TaskId, TaskOutputId};
+use risingwave_pb::batch_plan::{PlanFragment, PlanNode as BatchPlanProst, TaskId, TaskOutputId};
 use risingwave_pb::common::HostAddress;
 use risingwave_rpc_client::{ComputeClient, ExchangeSource};
 use uuid::Uuid;
 
 use super::HummockSnapshotManagerRef;
-use crate::scheduler::execution::QueryExecution;
+use crate::optimizer::property::Distribution;
+use crate::scheduler::execution::{QueryExecution, QueryStats, ROOT_TASK_ID, ROOT_TASK_OUTPUT_ID};
 use crate::scheduler::plan_fragmenter::Query;
 use crate::scheduler::worker_node_manager::WorkerNodeManagerRef;
 use crate::scheduler::ExecutionContextRef;
@@ -64,8 +66,8 @@ impl QueryManager {
     /// This is kept for dml only.
     pub async fn schedule_single(
         &self,
-        _context: ExecutionContextRef,
-        plan: BatchPlanProst,
+        context: ExecutionContextRef,
+        mut plan: BatchPlanProst,
     ) -> Result<impl Stream<Item = Result<DataChunk>>> {
         let worker_node_addr = self.worker_node_manager.next_random()?.host.unwrap();
         let compute_client: ComputeClient = ComputeClient::new((&worker_node_addr).into()).await?;
@@ -83,6 +85,7 @@ impl QueryManager {
 
         let epoch = self.hummock_snapshot_manager.get_epoch().await?;
 
+        context.query_params().bind_plan(&mut plan);
         compute_client
             .create_task(task_id.clone(), plan, epoch)
             .await?;
@@ -97,9 +100,54 @@ impl QueryManager {
         Ok(query_result_fetcher.run())
     }
 
+    /// Runs the whole plan as a single task on a random compute node, without splitting it into
+    /// stages. This saves the scheduling overhead for small
+    /// queries such as point lookups.
+    ///
+    /// The plan must not contain any exchange, see `gen_batch_local_plan`.
+    pub async fn schedule_local(
+        &self,
+        context: ExecutionContextRef,
+        mut plan: BatchPlanProst,
+    ) -> Result<impl DataChunkStream> {
+        let worker_node_addr = self.worker_node_manager.next_random()?.host.unwrap();
+        let compute_client: ComputeClient = ComputeClient::new((&worker_node_addr).into()).await?;
+
+        let task_id = TaskId {
+            query_id: Uuid::new_v4().to_string(),
+            stage_id: 0,
+            task_id: ROOT_TASK_ID,
+        };
+        let task_output_id = TaskOutputId {
+            task_id: Some(task_id.clone()),
+            output_id: ROOT_TASK_OUTPUT_ID,
+        };
+        let params = context.query_params();
+        params.bind_plan(&mut plan);
+        let plan_fragment = PlanFragment {
+            root: Some(plan),
+            exchange_info: Some(Distribution::Single.to_prost(1)),
+        };
+
+        let epoch = self.hummock_snapshot_manager.get_epoch().await?;
+
+        compute_client
+            .create_task2(task_id, plan_fragment, epoch, params.task_limits())
+            .await?;
+
+        let query_result_fetcher = QueryResultFetcher::new(
+            epoch,
+            self.hummock_snapshot_manager.clone(),
+            task_output_id,
+            worker_node_addr,
+        );
+
+        Ok(query_result_fetcher.run())
+    }
+
     pub async fn schedule(
         &self,
-        _context: ExecutionContextRef,
+        context: ExecutionContextRef,
         query: Query,
     ) -> Result<impl DataChunkStream> {
         // Cheat compiler to resolve type
@@ -108,6 +156,7 @@ impl QueryManager {
         let query_execution = QueryExecution::new(
             query,
             epoch,
+            context.query_params(),
             self.worker_node_manager.clone(),
             self.hummock_snapshot_manager.clone(),
         );
@@ -116,6 +165,36 @@ impl QueryManager {
 
         Ok(query_result_fetcher.run())
     }
+
+    /// Executes the query to the end and discards its results, then collects the runtime
+    /// statistics of the operators from all tasks. This is used by `EXPLAIN ANALYZE`.
+    pub async fn schedule_with_stats(
+        &self,
+        context: ExecutionContextRef,
+        query: Query,
+    ) -> Result<QueryStats> {
+        let epoch = self.hummock_snapshot_manager.get_epoch().await?;
+
+        let query_execution = QueryExecution::new(
+            query,
+            epoch,
+            context.query_params(),
+            self.worker_node_manager.clone(),
+            self.hummock_snapshot_manager.clone(),
+        );
+
+        let start_time = Instant::now();
+        let query_result_fetcher = query_execution.start().await?;
+        #[for_await]
+        for chunk in query_result_fetcher.run() {
+            chunk?;
+        }
+        let elapsed = start_time.elapsed();
+
+        let mut stats = query_execution.collect_stats().await?;
+        stats.elapsed = elapsed;
+        Ok(stats)
+    }
 }
 
 impl QueryResultFetcher {
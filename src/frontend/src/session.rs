@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Formatter;
 use std::marker::Sync;
@@ -21,23 +20,25 @@ use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
-use parking_lot::RwLock;
+use parking_lot::{RwLock, RwLockReadGuard};
 use pgwire::pg_response::PgResponse;
 use pgwire::pg_server::{Session, SessionManager};
 use risingwave_common::config::FrontendConfig;
 use risingwave_common::error::Result;
+use risingwave_common::session_config::{ConfigMap, SessionVariable};
 use risingwave_common::util::addr::HostAddr;
 use risingwave_pb::common::WorkerType;
 use risingwave_rpc_client::MetaClient;
+use risingwave_sqlparser::ast::ObjectName;
 use risingwave_sqlparser::parser::Parser;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::watch;
 use tokio::task::JoinHandle;
 
+use crate::binder::Binder;
 use crate::catalog::catalog_service::{CatalogReader, CatalogWriter, CatalogWriterImpl};
 use crate::catalog::root_catalog::Catalog;
 use crate::handler::handle;
-use crate::handler::query::IMPLICIT_FLUSH;
 use crate::meta_client::{FrontendMetaClient, FrontendMetaClientImpl};
 use crate::observer::observer_manager::ObserverManager;
 use crate::optimizer::plan_node::PlanNodeId;
@@ -49,6 +50,12 @@ pub struct OptimizerContext {
     pub session_ctx: Arc<SessionImpl>,
     // We use `AtomicI32` here because  `Arc<T>` implements `Send` only when `T: Send + Sync`.
     pub next_id: AtomicI32,
+    /// Whether the streaming aggregations of the plan only emit the results of closed windows, as
+    /// requested by `WITH (emit_on_window_close = true)`.
+    pub emit_on_window_close: bool,
+    /// The processing-time TTL of the streaming join and hash aggregation states of the plan, as
+    /// requested by `WITH (state_ttl_seconds = ...)`.
+    pub state_ttl_ms: Option<u64>,
 }
 
 #[derive(Clone, Debug)]
@@ -84,6 +91,8 @@ impl OptimizerContext {
         Self {
             session_ctx,
             next_id: AtomicI32::new(0),
+            emit_on_window_close: false,
+            state_ttl_ms: None,
         }
     }
 
@@ -93,6 +102,8 @@ impl OptimizerContext {
         Self {
             session_ctx: Arc::new(SessionImpl::mock()),
             next_id: AtomicI32::new(0),
+            emit_on_window_close: false,
+            state_ttl_ms: None,
         }
         .into()
     }
@@ -259,32 +270,18 @@ impl FrontendEnv {
 pub struct SessionImpl {
     env: FrontendEnv,
     database: String,
-    /// Stores the value of configurations.
-    config_map: RwLock<HashMap<String, ConfigEntry>>,
-}
-
-#[derive(Clone)]
-pub struct ConfigEntry {
-    str_val: String,
-}
-
-impl ConfigEntry {
-    pub fn new(str_val: String) -> Self {
-        ConfigEntry { str_val }
-    }
-
-    /// Only used for boolean configurations.
-    pub fn is_set(&self, default: bool) -> bool {
-        self.str_val.parse().unwrap_or(default)
-    }
+    user_name: String,
+    /// Stores the value of session variables.
+    config_map: RwLock<ConfigMap>,
 }
 
 impl SessionImpl {
-    pub fn new(env: FrontendEnv, database: String) -> Self {
+    pub fn new(env: FrontendEnv, database: String, user_name: String) -> Self {
         Self {
             env,
             database,
-            config_map: Self::init_config_map(),
+            user_name,
+            config_map: Default::default(),
         }
     }
 
@@ -293,7 +290,8 @@ impl SessionImpl {
         Self {
             env: FrontendEnv::mock(),
             database: "dev".to_string(),
-            config_map: Self::init_config_map(),
+            user_name: risingwave_common::catalog::DEFAULT_SUPER_USER.to_string(),
+            config_map: Default::default(),
         }
     }
 
@@ -305,28 +303,80 @@ impl SessionImpl {
         &self.database
     }
 
-    /// Set configuration values in this session.
-    /// For example, `set_config("RW_IMPLICIT_FLUSH", true)` will implicit flush for every inserts.
-    pub fn set_config(&self, key: &str, val: &str) {
-        self.config_map
-            .write()
-            .insert(key.to_string(), ConfigEntry::new(val.to_string()));
+    pub fn user_name(&self) -> &str {
+        &self.user_name
     }
 
-    /// Get configuration values in this session.
-    pub fn get_config(&self, key: &str) -> Option<ConfigEntry> {
-        let reader = self.config_map.read();
-        reader.get(key).cloned()
+    /// Resolve a possibly unqualified relation name to `(schema_name, relation_name)` with the
+    /// `search_path` of this session.
+    pub fn resolve_relation_name(&self, name: ObjectName) -> Result<(String, String)> {
+        let (schema_name, relation_name) = Binder::resolve_schema_qualified_name(name)?;
+        let schema_name = match schema_name {
+            Some(schema_name) => schema_name,
+            None => self.resolve_unqualified_relation_schema(&relation_name)?,
+        };
+        Ok((schema_name, relation_name))
     }
 
-    fn init_config_map() -> RwLock<HashMap<String, ConfigEntry>> {
-        let mut map = HashMap::new();
-        // FIXME: May need better init way + default config.
-        map.insert(
-            IMPLICIT_FLUSH.to_string(),
-            ConfigEntry::new("false".to_string()),
-        );
-        RwLock::new(map)
+    fn resolve_unqualified_relation_schema(&self, relation_name: &str) -> Result<String> {
+        let catalog = self.env.catalog_reader().read_guard();
+        let search_path = self.config().get_search_path();
+        if let Some(schema_name) = catalog.resolve_relation_schema(
+            &self.database,
+            &search_path,
+            &self.user_name,
+            relation_name,
+        )? {
+            return Ok(schema_name.to_string());
+        }
+        // Not found. Fall back to the schema for creation to report the error.
+        Ok(catalog
+            .first_valid_schema(&self.database, &search_path, &self.user_name)?
+            .name()
+            .to_string())
+    }
+
+    /// Resolve the name of an object to create to `(schema_name, object_name)`. Unqualified
+    /// names are created in the first existing schema on the `search_path` of this session.
+    pub fn resolve_name_for_create(&self, name: ObjectName) -> Result<(String, String)> {
+        let (schema_name, object_name) = Binder::resolve_schema_qualified_name(name)?;
+        let schema_name = match schema_name {
+            Some(schema_name) => schema_name,
+            None => self
+                .env
+                .catalog_reader()
+                .read_guard()
+                .first_valid_schema(
+                    &self.database,
+                    &self.config().get_search_path(),
+                    &self.user_name,
+                )?
+                .name()
+                .to_string(),
+        };
+        Ok((schema_name, object_name))
+    }
+
+    /// Set a session variable in this session.
+    /// For example, `set_config("RW_IMPLICIT_FLUSH", "true")` will implicit flush for every
+    /// inserts.
+    pub fn set_config(&self, key: &str, val: &str) -> Result<&'static SessionVariable> {
+        self.config_map.write().set(key, val)
+    }
+
+    /// Reset a session variable to its default value.
+    pub fn reset_config(&self, key: &str) -> Result<&'static SessionVariable> {
+        self.config_map.write().reset(key)
+    }
+
+    /// Reset all session variables to their default values.
+    pub fn reset_all_config(&self) {
+        self.config_map.write().reset_all()
+    }
+
+    /// Get the session variables of this session.
+    pub fn config(&self) -> RwLockReadGuard<ConfigMap> {
+        self.config_map.read()
     }
 }
 
@@ -341,10 +391,12 @@ impl SessionManager for SessionManagerImpl {
     fn connect(
         &self,
         database: &str,
+        user_name: &str,
     ) -> std::result::Result<Arc<dyn Session>, Box<dyn Error + Send + Sync>> {
         Ok(Arc::new(SessionImpl::new(
             self.env.clone(),
             database.to_string(),
+            user_name.to_string(),
         )))
     }
 }
@@ -390,6 +442,13 @@ impl Session for SessionImpl {
         let rsp = handle(self, stmt).await?;
         Ok(rsp)
     }
+
+    fn parameter_status(&self) -> Vec<(String, String)> {
+        self.config()
+            .reported()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
 }
 
 #[cfg(test)]
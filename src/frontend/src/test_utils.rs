@@ -21,11 +21,14 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 use pgwire::pg_response::PgResponse;
 use pgwire::pg_server::{Session, SessionManager};
-use risingwave_common::catalog::{TableId, DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+use risingwave_common::catalog::{
+    TableId, DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, DEFAULT_SUPER_USER,
+};
 use risingwave_common::error::Result;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    TableStatistics as ProstTableStatistics,
 };
 use risingwave_pb::stream_plan::StreamNode;
 use risingwave_sqlparser::ast::Statement;
@@ -52,6 +55,7 @@ impl SessionManager for LocalFrontend {
     fn connect(
         &self,
         _database: &str,
+        _user_name: &str,
     ) -> std::result::Result<Arc<dyn Session>, Box<dyn Error + Send + Sync>> {
         Ok(self.session_ref())
     }
@@ -88,10 +92,7 @@ impl LocalFrontend {
             let session = self.session_ref();
 
             let bound = {
-                let mut binder = Binder::new(
-                    session.env().catalog_reader().read_guard(),
-                    session.database().to_string(),
-                );
+                let mut binder = Binder::new(&session);
                 binder.bind(Statement::Query(query.clone()))?
             };
             Planner::new(OptimizerContext::new(session).into())
@@ -107,6 +108,7 @@ impl LocalFrontend {
         Arc::new(SessionImpl::new(
             self.env.clone(),
             DEFAULT_DATABASE_NAME.to_string(),
+            DEFAULT_SUPER_USER.to_string(),
         ))
     }
 }
@@ -206,6 +208,26 @@ impl CatalogWriter for MockCatalogWriter {
             .drop_table(database_id, schema_id, table_id);
         Ok(())
     }
+
+    async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: ProstTableStatistics,
+    ) -> Result<()> {
+        let schema_id = *self
+            .table_id_to_schema_id
+            .read()
+            .get(&table_id.table_id)
+            .unwrap();
+        let database_id = self.get_database_id_by_schema(schema_id);
+        self.catalog.write().update_table_statistics(
+            database_id,
+            schema_id,
+            table_id,
+            (&statistics).into(),
+        );
+        Ok(())
+    }
 }
 
 impl MockCatalogWriter {
@@ -23,6 +23,7 @@ use std::sync::Arc;
 use anyhow::{anyhow, Result};
 pub use resolve_id::*;
 use risingwave_frontend::binder::Binder;
+use risingwave_frontend::handler::util::normalized_definition;
 use risingwave_frontend::handler::{
     create_index, create_mv, create_source, create_table, drop_table,
 };
@@ -170,7 +171,7 @@ impl TestCase {
 
         if let Some(ref config_map) = self.with_config_map {
             for (key, val) in config_map {
-                session.set_config(key, val);
+                session.set_config(key, val)?;
             }
         }
 
@@ -241,6 +242,7 @@ impl TestCase {
         let statements = Parser::parse_sql(sql).unwrap();
         for stmt in statements {
             let context = OptimizerContext::new(session.clone());
+            let definition = normalized_definition(&stmt);
             match stmt.clone() {
                 Statement::Query(_) | Statement::Insert { .. } | Statement::Delete { .. } => {
                     if result.is_some() {
@@ -252,14 +254,27 @@ impl TestCase {
                     }
                     result = Some(ret);
                 }
-                Statement::CreateTable { name, columns, .. } => {
-                    create_table::handle_create_table(context, name, columns).await?;
+                Statement::CreateTable {
+                    name,
+                    columns,
+                    constraints,
+                    ..
+                } => {
+                    create_table::handle_create_table(
+                        context,
+                        name,
+                        columns,
+                        constraints,
+                        definition,
+                    )
+                    .await?;
                 }
                 Statement::CreateSource {
                     is_materialized,
                     stmt,
                 } => {
-                    create_source::handle_create_source(context, is_materialized, stmt).await?;
+                    create_source::handle_create_source(context, is_materialized, stmt, definition)
+                        .await?;
                 }
                 Statement::CreateIndex {
                     name,
@@ -268,7 +283,10 @@ impl TestCase {
                     // TODO: support unique and if_not_exist in planner test
                     ..
                 } => {
-                    create_index::handle_create_index(context, name, table_name, columns).await?;
+                    create_index::handle_create_index(
+                        context, name, table_name, columns, definition,
+                    )
+                    .await?;
                 }
                 Statement::CreateView {
                     materialized: true,
@@ -277,7 +295,7 @@ impl TestCase {
                     query,
                     ..
                 } => {
-                    create_mv::handle_create_mv(context, name, query).await?;
+                    create_mv::handle_create_mv(context, name, query, definition).await?;
                 }
                 Statement::Drop(drop_statement) => {
                     drop_table::handle_drop_table(context, drop_statement.object_name).await?;
@@ -297,10 +315,7 @@ impl TestCase {
         let mut ret = TestCaseResult::default();
 
         let bound = {
-            let mut binder = Binder::new(
-                session.env().catalog_reader().read_guard(),
-                session.database().to_string(),
-            );
+            let mut binder = Binder::new(&session);
             match binder.bind(stmt.clone()) {
                 Ok(bound) => bound,
                 Err(err) => {
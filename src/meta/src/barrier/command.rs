@@ -18,9 +18,13 @@ use futures::future::try_join_all;
 use risingwave_common::catalog::TableId;
 use risingwave_common::error::{Result, RwError, ToRwResult};
 use risingwave_common::util::epoch::Epoch;
+use risingwave_connector::SplitImpl;
 use risingwave_pb::common::ActorInfo;
 use risingwave_pb::data::barrier::Mutation;
-use risingwave_pb::data::{AddMutation, DispatcherMutation, NothingMutation, StopMutation};
+use risingwave_pb::data::{
+    AddMutation, DispatcherMutation, NothingMutation, SourceChangeSplit, SourceChangeSplitMutation,
+    StopMutation,
+};
 use risingwave_pb::stream_service::DropActorsRequest;
 use uuid::Uuid;
 
@@ -61,6 +65,13 @@ pub enum Command {
         table_sink_map: HashMap<TableId, Vec<ActorId>>,
         dispatches: HashMap<(ActorId, DispatcherId), Vec<ActorInfo>>,
     },
+
+    /// `SourceSplitAssignment` generates a `Splits` barrier carrying the full split list of each
+    /// source actor whose assignment changes, e.g. when new Kafka partitions are discovered.
+    ///
+    /// After the barrier is collected, the new assignment is persisted into the table fragments so
+    /// that the actors are rebuilt with it on recovery.
+    SourceSplitAssignment(HashMap<ActorId, Vec<SplitImpl>>),
 }
 
 impl Command {
@@ -68,6 +79,17 @@ impl Command {
         Self::Plain(Mutation::Nothing(NothingMutation {}))
     }
 
+    /// A short name of the command, used in barrier traces.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Plain(Mutation::Nothing(_)) => "Checkpoint",
+            Command::Plain(_) => "Plain",
+            Command::DropMaterializedView(_) => "DropMaterializedView",
+            Command::CreateMaterializedView { .. } => "CreateMaterializedView",
+            Command::SourceSplitAssignment(_) => "SourceSplitAssignment",
+        }
+    }
+
     pub fn creating_table_id(&self) -> Option<TableId> {
         match self {
             Command::CreateMaterializedView {
@@ -92,7 +114,7 @@ pub struct CommandContext<'a, S> {
     pub prev_epoch: &'a Epoch,
     pub curr_epoch: &'a Epoch,
 
-    command: Command,
+    pub command: Command,
 }
 
 impl<'a, S> CommandContext<'a, S> {
@@ -142,6 +164,24 @@ where
                     .collect();
                 Mutation::Add(AddMutation { mutations })
             }
+
+            Command::SourceSplitAssignment(assignment) => {
+                let mutations = assignment
+                    .iter()
+                    .map(|(&actor_id, splits)| SourceChangeSplit {
+                        actor_id,
+                        split_type: splits
+                            .first()
+                            .map(|split| split.get_type())
+                            .unwrap_or_default(),
+                        stream_source_splits: splits
+                            .iter()
+                            .map(|split| split.to_json_bytes().to_vec())
+                            .collect(),
+                    })
+                    .collect();
+                Mutation::Splits(SourceChangeSplitMutation { mutations })
+            }
         };
 
         Ok(mutation)
@@ -219,6 +259,12 @@ where
                     )
                     .await?;
             }
+
+            Command::SourceSplitAssignment(assignment) => {
+                self.fragment_manager
+                    .update_actor_splits(assignment)
+                    .await?;
+            }
         }
 
         Ok(())
@@ -12,13 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::VecDeque;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::iter::once;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use futures::future::try_join_all;
 use itertools::Itertools;
+use parking_lot::Mutex;
 use risingwave_common::catalog::TableId;
 use risingwave_common::error::{ErrorCode, Result, RwError, ToRwResult};
 use risingwave_common::util::epoch::INVALID_EPOCH;
@@ -26,6 +28,9 @@ use risingwave_hummock_sdk::HummockEpoch;
 use risingwave_pb::common::worker_node::State::Running;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::data::Barrier;
+use risingwave_pb::meta::barrier_trace::NodeTrace;
+use risingwave_pb::meta::BarrierTrace;
+use risingwave_pb::stream_service::inject_barrier_response::FinishedSourceSplits;
 use risingwave_pb::stream_service::{InjectBarrierRequest, InjectBarrierResponse};
 use smallvec::SmallVec;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
@@ -37,10 +42,11 @@ pub use self::command::Command;
 use self::command::CommandContext;
 use self::info::BarrierActorInfo;
 use self::notifier::{Notifier, UnfinishedNotifiers};
+use self::trace::{node_trace, BarrierTraces};
 use crate::cluster::{ClusterManagerRef, META_NODE_ID};
 use crate::hummock::HummockManagerRef;
 use crate::manager::{CatalogManagerRef, MetaSrvEnv};
-use crate::model::BarrierManagerState;
+use crate::model::{ActorId, BarrierManagerState};
 use crate::rpc::metrics::MetaMetrics;
 use crate::storage::MetaStore;
 use crate::stream::FragmentManagerRef;
@@ -49,6 +55,7 @@ mod command;
 mod info;
 mod notifier;
 mod recovery;
+mod trace;
 
 type Scheduled = (Command, SmallVec<[Notifier; 1]>);
 
@@ -157,6 +164,12 @@ pub struct GlobalBarrierManager<S: MetaStore> {
 
     metrics: Arc<MetaMetrics>,
 
+    /// Latency breakdown of recently collected barriers.
+    traces: Mutex<BarrierTraces>,
+
+    /// Splits that source actors have reported to have read to their end.
+    finished_source_splits: Mutex<HashMap<ActorId, HashSet<String>>>,
+
     env: MetaSrvEnv<S>,
 }
 
@@ -190,6 +203,8 @@ where
             scheduled_barriers: ScheduledBarriers::new(),
             hummock_manager,
             metrics,
+            traces: Default::default(),
+            finished_source_splits: Default::default(),
             env,
         }
     }
@@ -275,8 +290,11 @@ where
                     // Then try to finish the barrier for Create MVs.
                     let actors_to_finish = command_ctx.actors_to_finish();
                     unfinished.add(new_epoch.0, actors_to_finish, notifiers);
-                    for finished in responses.into_iter().flat_map(|r| r.finished_create_mviews) {
-                        unfinished.finish_actors(finished.epoch, once(finished.actor_id));
+                    for response in responses {
+                        for finished in response.finished_create_mviews {
+                            unfinished.finish_actors(finished.epoch, once(finished.actor_id));
+                        }
+                        self.add_finished_source_splits(response.finished_source_splits);
                     }
 
                     state.prev_epoch = new_epoch;
@@ -312,9 +330,12 @@ where
         command_context: &CommandContext<'a, S>,
     ) -> Result<Vec<InjectBarrierResponse>> {
         let timer = self.metrics.barrier_latency.start_timer();
+        let start = Instant::now();
 
         // Wait for all barriers collected
         let result = self.inject_barrier(command_context).await;
+        let collect_sync_duration = start.elapsed();
+        let mut commit_duration = Duration::ZERO;
         // Commit this epoch to Hummock
         if command_context.prev_epoch.0 != INVALID_EPOCH {
             match result {
@@ -322,9 +343,14 @@ where
                     // We must ensure all epochs are committed in ascending order, because
                     // the storage engine will query from new to old in the order in which
                     // the L0 layer files are generated. see https://github.com/singularity-data/risingwave/issues/1251
+                    let commit_start = Instant::now();
                     self.hummock_manager
                         .commit_epoch(command_context.prev_epoch.0)
                         .await?;
+                    commit_duration = commit_start.elapsed();
+                    self.metrics
+                        .barrier_commit_latency
+                        .observe(commit_duration.as_secs_f64());
                 }
                 Err(_) => {
                     self.hummock_manager
@@ -333,19 +359,102 @@ where
                 }
             };
         }
-        let responses = result?;
+        let (responses, node_traces) = result?;
 
         timer.observe_duration();
+        self.record_trace(
+            command_context,
+            collect_sync_duration,
+            commit_duration,
+            start.elapsed(),
+            node_traces,
+        );
         command_context.post_collect().await?; // do some post stuffs
 
         Ok(responses)
     }
 
+    /// Record the latency breakdown of a collected barrier into metrics and recent traces.
+    fn record_trace(
+        &self,
+        command_context: &CommandContext<'_, S>,
+        collect_sync_duration: Duration,
+        commit_duration: Duration,
+        total_duration: Duration,
+        nodes: Vec<NodeTrace>,
+    ) {
+        self.metrics
+            .barrier_collect_sync_latency
+            .observe(collect_sync_duration.as_secs_f64());
+        for node in &nodes {
+            let worker_id = node.worker_id.to_string();
+            self.metrics
+                .barrier_collect_latency
+                .with_label_values(&[&worker_id])
+                .observe(Duration::from_micros(node.collect_duration_us).as_secs_f64());
+            self.metrics
+                .barrier_sync_latency
+                .with_label_values(&[&worker_id])
+                .observe(Duration::from_micros(node.sync_duration_us).as_secs_f64());
+        }
+
+        self.traces.lock().push(BarrierTrace {
+            epoch: command_context.curr_epoch.0,
+            prev_epoch: command_context.prev_epoch.0,
+            command: command_context.command.name().to_string(),
+            collect_sync_duration_us: collect_sync_duration.as_micros() as u64,
+            commit_duration_us: commit_duration.as_micros() as u64,
+            total_duration_us: total_duration.as_micros() as u64,
+            nodes,
+        });
+    }
+
+    /// Returns at most `limit` recently collected barriers with the longest latency, the slowest
+    /// first.
+    pub fn slowest_barrier_traces(&self, limit: usize) -> Vec<BarrierTrace> {
+        self.traces.lock().slowest(limit)
+    }
+
+    fn add_finished_source_splits(&self, finished: Vec<FinishedSourceSplits>) {
+        let mut finished_source_splits = self.finished_source_splits.lock();
+        for finished in finished {
+            finished_source_splits
+                .entry(finished.actor_id)
+                .or_default()
+                .extend(finished.split_ids);
+        }
+    }
+
+    /// The splits that each source actor has reported to have read to their end.
+    pub fn finished_source_splits(&self) -> HashMap<ActorId, HashSet<String>> {
+        self.finished_source_splits.lock().clone()
+    }
+
+    /// Forget the finished splits of `actor_ids` that are not in `split_ids` anymore, e.g. expired
+    /// Kinesis shards.
+    pub fn retain_finished_source_splits(
+        &self,
+        actor_ids: impl IntoIterator<Item = ActorId>,
+        split_ids: &HashSet<String>,
+    ) {
+        let mut finished_source_splits = self.finished_source_splits.lock();
+        for actor_id in actor_ids {
+            if let Entry::Occupied(mut finished) = finished_source_splits.entry(actor_id) {
+                finished
+                    .get_mut()
+                    .retain(|split_id| split_ids.contains(split_id));
+                if finished.get().is_empty() {
+                    finished.remove();
+                }
+            }
+        }
+    }
+
     /// Inject barrier to all computer nodes.
     async fn inject_barrier<'a>(
         &self,
         command_context: &CommandContext<'a, S>,
-    ) -> Result<Vec<InjectBarrierResponse>> {
+    ) -> Result<(Vec<InjectBarrierResponse>, Vec<NodeTrace>)> {
         let mutation = command_context.to_mutation().await?;
         let info = command_context.info;
 
@@ -385,17 +494,21 @@ where
                     );
 
                     // This RPC returns only if this worker node has collected this barrier.
-                    client
+                    let start = Instant::now();
+                    let response = client
                         .inject_barrier(request)
                         .await
                         .map(tonic::Response::<_>::into_inner)
-                        .to_rw_result()
+                        .to_rw_result()?;
+                    let trace = node_trace(node, start.elapsed(), &response);
+
+                    Ok::<_, RwError>((response, trace))
                 }
                 .into()
             }
         });
 
-        try_join_all(collect_futures).await
+        Ok(try_join_all(collect_futures).await?.into_iter().unzip())
     }
 
     /// Resolve actor information from cluster and fragment manager.
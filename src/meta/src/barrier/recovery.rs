@@ -99,7 +99,7 @@ where
             );
 
             match self.inject_barrier(&command_ctx).await {
-                Ok(response) => {
+                Ok((response, _)) => {
                     if let Err(err) = command_ctx.post_collect().await {
                         error!("post_collect failed: {}", err);
                         return Err(err);
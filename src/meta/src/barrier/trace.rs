@@ -0,0 +1,165 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use itertools::Itertools;
+use risingwave_pb::common::WorkerNode;
+use risingwave_pb::meta::barrier_trace::NodeTrace;
+use risingwave_pb::meta::BarrierTrace;
+use risingwave_pb::stream_service::InjectBarrierResponse;
+
+/// Max number of recent barrier traces kept in memory.
+const MAX_BARRIER_TRACES: usize = 1024;
+
+/// A bounded buffer of the traces of recently collected barriers, used for finding out the slowest
+/// epochs and where the time is spent.
+#[derive(Default)]
+pub struct BarrierTraces {
+    traces: VecDeque<BarrierTrace>,
+}
+
+impl BarrierTraces {
+    /// Record the trace of a barrier, evicting the oldest one if the buffer is full.
+    pub fn push(&mut self, trace: BarrierTrace) {
+        if self.traces.len() == MAX_BARRIER_TRACES {
+            self.traces.pop_front();
+        }
+        self.traces.push_back(trace);
+    }
+
+    /// Returns at most `limit` traces with the longest total duration, the slowest first.
+    pub fn slowest(&self, limit: usize) -> Vec<BarrierTrace> {
+        self.traces
+            .iter()
+            .sorted_by_key(|trace| Reverse(trace.total_duration_us))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Build the trace of a barrier on a single compute node from its `InjectBarrier` response.
+pub fn node_trace(
+    node: &WorkerNode,
+    rpc_duration: Duration,
+    response: &InjectBarrierResponse,
+) -> NodeTrace {
+    let (slowest_actor_id, slowest_actor_duration_us) = response
+        .actor_collect_times
+        .iter()
+        .max_by_key(|t| t.duration_us)
+        .map(|t| (t.actor_id, t.duration_us))
+        .unwrap_or_default();
+
+    NodeTrace {
+        worker_id: node.id,
+        host: node.host.clone(),
+        rpc_duration_us: rpc_duration.as_micros() as u64,
+        collect_duration_us: response.collect_duration_us,
+        sync_duration_us: response.sync_duration_us,
+        slowest_actor_id,
+        slowest_actor_duration_us,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_pb::stream_service::inject_barrier_response::ActorCollectTime;
+
+    use super::*;
+
+    fn trace(epoch: u64, total_duration_us: u64) -> BarrierTrace {
+        BarrierTrace {
+            epoch,
+            total_duration_us,
+            ..Default::default()
+        }
+    }
+
+    fn epochs(traces: &[BarrierTrace]) -> Vec<u64> {
+        traces.iter().map(|trace| trace.epoch).collect()
+    }
+
+    #[test]
+    fn test_slowest() {
+        let mut traces = BarrierTraces::default();
+        assert!(traces.slowest(3).is_empty());
+
+        for (epoch, total_duration_us) in [(1, 30), (2, 10), (3, 50), (4, 20), (5, 40)] {
+            traces.push(trace(epoch, total_duration_us));
+        }
+        assert_eq!(epochs(&traces.slowest(3)), vec![3, 5, 1]);
+        assert_eq!(epochs(&traces.slowest(10)), vec![3, 5, 1, 4, 2]);
+        assert!(traces.slowest(0).is_empty());
+    }
+
+    #[test]
+    fn test_evict_oldest() {
+        let mut traces = BarrierTraces::default();
+        // The oldest trace is the slowest one.
+        traces.push(trace(0, u64::MAX));
+        for epoch in 1..MAX_BARRIER_TRACES as u64 {
+            traces.push(trace(epoch, epoch));
+        }
+        assert_eq!(traces.traces.len(), MAX_BARRIER_TRACES);
+        assert_eq!(epochs(&traces.slowest(1)), vec![0]);
+
+        traces.push(trace(MAX_BARRIER_TRACES as u64, 0));
+        assert_eq!(traces.traces.len(), MAX_BARRIER_TRACES);
+        assert_eq!(
+            epochs(&traces.slowest(1)),
+            vec![MAX_BARRIER_TRACES as u64 - 1]
+        );
+    }
+
+    #[test]
+    fn test_node_trace() {
+        let node = WorkerNode {
+            id: 1,
+            ..Default::default()
+        };
+        let response = InjectBarrierResponse {
+            actor_collect_times: vec![
+                ActorCollectTime {
+                    actor_id: 1,
+                    duration_us: 100,
+                },
+                ActorCollectTime {
+                    actor_id: 2,
+                    duration_us: 300,
+                },
+                ActorCollectTime {
+                    actor_id: 3,
+                    duration_us: 0,
+                },
+            ],
+            collect_duration_us: 400,
+            sync_duration_us: 200,
+            ..Default::default()
+        };
+        let trace = node_trace(&node, Duration::from_micros(700), &response);
+        assert_eq!(trace.worker_id, 1);
+        assert_eq!(trace.rpc_duration_us, 700);
+        assert_eq!(trace.slowest_actor_id, 2);
+        assert_eq!(trace.slowest_actor_duration_us, 300);
+
+        // No actor on the node.
+        let trace = node_trace(&node, Duration::ZERO, &InjectBarrierResponse::default());
+        assert_eq!(trace.slowest_actor_id, 0);
+        assert_eq!(trace.slowest_actor_duration_us, 0);
+    }
+}
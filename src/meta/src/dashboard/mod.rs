@@ -28,6 +28,7 @@ use tower_http::cors::{self, CorsLayer};
 use tower_http::services::ServeDir;
 
 use crate::cluster::ClusterManagerRef;
+use crate::manager::StreamClientsRef;
 use crate::storage::MetaStore;
 use crate::stream::FragmentManagerRef;
 
@@ -36,6 +37,7 @@ pub struct DashboardService<S: MetaStore> {
     pub dashboard_addr: SocketAddr,
     pub cluster_manager: ClusterManagerRef<S>,
     pub fragment_manager: FragmentManagerRef<S>,
+    pub stream_clients: StreamClientsRef,
 
     // TODO: replace with catalog manager.
     pub meta_store: Arc<S>,
@@ -44,11 +46,20 @@ pub struct DashboardService<S: MetaStore> {
 pub type Service<S> = Arc<DashboardService<S>>;
 
 mod handlers {
+    use std::collections::{BTreeSet, HashMap};
+    use std::time::{Duration, Instant};
+
+    use axum::extract::Query;
     use axum::Json;
+    use itertools::Itertools;
+    use risingwave_common::catalog::TableId as CatalogTableId;
     use risingwave_pb::catalog::Table;
     use risingwave_pb::common::WorkerNode;
+    use risingwave_pb::meta::table_fragments::Fragment;
     use risingwave_pb::meta::ActorLocation;
     use risingwave_pb::stream_plan::StreamActor;
+    use risingwave_pb::stream_service::{ActorStats, GetActorStatsRequest};
+    use serde::{Deserialize, Serialize};
     use serde_json::json;
 
     use super::*;
@@ -135,6 +146,342 @@ mod handlers {
 
         Ok(Json(table_fragments))
     }
+
+    /// The window over which the statistics of `/fragment_graph` are measured by default.
+    const DEFAULT_STATS_WINDOW_MS: u64 = 1000;
+    const MAX_STATS_WINDOW_MS: u64 = 10_000;
+
+    #[derive(Deserialize)]
+    pub struct FragmentGraphParams {
+        /// The window over which the statistics are measured, in milliseconds.
+        window_ms: Option<u64>,
+    }
+
+    /// The statistics of an actor over a window, from two snapshots of its cumulative statistics.
+    #[derive(Serialize, Clone, Copy, Debug, Default, PartialEq)]
+    pub struct ActorRates {
+        input_rows_per_sec: f64,
+        output_rows_per_sec: f64,
+        /// The fraction of the window spent blocked on sending to the output channels.
+        output_blocked_ratio: f64,
+        barrier_align_count: u64,
+        barrier_align_seconds: f64,
+    }
+
+    impl ActorRates {
+        fn between(before: &ActorStats, after: &ActorStats, window: Duration) -> Self {
+            let secs = window.as_secs_f64();
+            Self {
+                input_rows_per_sec: after.input_rows.saturating_sub(before.input_rows) as f64
+                    / secs,
+                output_rows_per_sec: after.output_rows.saturating_sub(before.output_rows) as f64
+                    / secs,
+                output_blocked_ratio: after
+                    .output_blocked_ns
+                    .saturating_sub(before.output_blocked_ns)
+                    as f64
+                    / 1e9
+                    / secs,
+                barrier_align_count: after
+                    .barrier_align_count
+                    .saturating_sub(before.barrier_align_count),
+                barrier_align_seconds: (after.barrier_align_seconds - before.barrier_align_seconds)
+                    .max(0.0),
+            }
+        }
+    }
+
+    /// A fragment of the graph of a materialized view, annotated with the statistics of its
+    /// actors over the window.
+    #[derive(Serialize, Debug)]
+    pub struct FragmentStats {
+        fragment_id: u32,
+        upstream_fragment_ids: BTreeSet<u32>,
+        /// The statistics of each actor, missing if its compute node is unreachable or it was
+        /// rebuilt within the window.
+        actors: Vec<(u32, Option<ActorRates>)>,
+        input_rows_per_sec: f64,
+        output_rows_per_sec: f64,
+        /// The average of `output_blocked_ratio` of the actors.
+        output_blocked_ratio: f64,
+        avg_barrier_align_ms: f64,
+    }
+
+    #[derive(Serialize)]
+    pub struct FragmentGraph {
+        window_ms: u64,
+        fragments: Vec<FragmentStats>,
+        /// The fragment most likely to be slowing down the graph: its upstreams are blocked on
+        /// sending to it the longest, while it's the least blocked on its own output.
+        bottleneck: Option<u32>,
+        /// The compute nodes whose actors have no statistics, as they failed to respond.
+        unreachable_worker_ids: Vec<u32>,
+    }
+
+    /// Aggregate the statistics of the actors per fragment, from the snapshots taken at the start
+    /// and the end of the window.
+    fn fragment_stats(
+        fragments: &[&Fragment],
+        before: &HashMap<u32, ActorStats>,
+        after: &HashMap<u32, ActorStats>,
+        window: Duration,
+    ) -> Vec<FragmentStats> {
+        let actor_fragment: HashMap<u32, u32> = fragments
+            .iter()
+            .flat_map(|fragment| &fragment.actors)
+            .map(|actor| (actor.actor_id, actor.fragment_id))
+            .collect();
+
+        fragments
+            .iter()
+            .map(|fragment| {
+                let actors = fragment
+                    .actors
+                    .iter()
+                    .map(|actor| {
+                        let rates = before
+                            .get(&actor.actor_id)
+                            .zip(after.get(&actor.actor_id))
+                            .map(|(before, after)| ActorRates::between(before, after, window));
+                        (actor.actor_id, rates)
+                    })
+                    .collect_vec();
+                let rates = actors.iter().filter_map(|(_, rates)| *rates).collect_vec();
+                let barrier_align_count: u64 = rates.iter().map(|r| r.barrier_align_count).sum();
+                let barrier_align_seconds: f64 =
+                    rates.iter().map(|r| r.barrier_align_seconds).sum();
+                FragmentStats {
+                    fragment_id: fragment.fragment_id,
+                    upstream_fragment_ids: fragment
+                        .actors
+                        .iter()
+                        .flat_map(|actor| &actor.upstream_actor_id)
+                        .filter_map(|id| actor_fragment.get(id).copied())
+                        .collect(),
+                    input_rows_per_sec: rates.iter().map(|r| r.input_rows_per_sec).sum(),
+                    output_rows_per_sec: rates.iter().map(|r| r.output_rows_per_sec).sum(),
+                    output_blocked_ratio: if rates.is_empty() {
+                        0.0
+                    } else {
+                        rates.iter().map(|r| r.output_blocked_ratio).sum::<f64>()
+                            / rates.len() as f64
+                    },
+                    avg_barrier_align_ms: if barrier_align_count == 0 {
+                        0.0
+                    } else {
+                        barrier_align_seconds * 1e3 / barrier_align_count as f64
+                    },
+                    actors,
+                }
+            })
+            .collect()
+    }
+
+    /// Find the fragment whose upstreams are blocked the most relative to itself.
+    fn find_bottleneck(fragments: &[FragmentStats]) -> Option<u32> {
+        let blocked_ratio: HashMap<u32, f64> = fragments
+            .iter()
+            .map(|f| (f.fragment_id, f.output_blocked_ratio))
+            .collect();
+        fragments
+            .iter()
+            .filter_map(|f| {
+                let upstream_blocked_ratio = f
+                    .upstream_fragment_ids
+                    .iter()
+                    .map(|id| blocked_ratio[id])
+                    .fold(0.0, f64::max);
+                let score = upstream_blocked_ratio - f.output_blocked_ratio;
+                (score > 0.0).then(|| (f.fragment_id, score))
+            })
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .map(|(fragment_id, _)| fragment_id)
+    }
+
+    /// Take a snapshot of the statistics of the actors on all running compute nodes. The nodes that
+    /// fail to respond are skipped and returned.
+    async fn collect_actor_stats<S: MetaStore>(
+        srv: &Service<S>,
+    ) -> (HashMap<u32, ActorStats>, Vec<u32>) {
+        use risingwave_pb::common::worker_node::State::Running;
+        use risingwave_pb::common::WorkerType;
+
+        let mut actor_stats = HashMap::new();
+        let mut unreachable_worker_ids = vec![];
+        let nodes = srv
+            .cluster_manager
+            .list_worker_node(WorkerType::ComputeNode, Some(Running))
+            .await;
+        for node in nodes {
+            let stats = match srv.stream_clients.get(&node).await {
+                Ok(mut client) => client
+                    .get_actor_stats(GetActorStatsRequest {})
+                    .await
+                    .map(|resp| resp.into_inner().actor_stats)
+                    .map_err(anyhow::Error::from),
+                Err(e) => Err(anyhow::Error::from(e)),
+            };
+            match stats {
+                Ok(stats) => actor_stats.extend(stats),
+                Err(e) => {
+                    tracing::warn!("failed to get actor stats from worker {}: {}", node.id, e);
+                    unreachable_worker_ids.push(node.id);
+                }
+            }
+        }
+        (actor_stats, unreachable_worker_ids)
+    }
+
+    pub async fn get_fragment_graph<S: MetaStore>(
+        Path(table_id): Path<u32>,
+        Query(params): Query<FragmentGraphParams>,
+        Extension(srv): Extension<Service<S>>,
+    ) -> Result<Json<FragmentGraph>> {
+        let table_fragments = srv
+            .fragment_manager
+            .list_table_fragments()
+            .await
+            .map_err(err)?
+            .into_iter()
+            .find(|f| f.table_id() == CatalogTableId::new(table_id))
+            .ok_or_else(|| anyhow!("table {} not found", table_id))
+            .map_err(err)?;
+
+        let window_ms = params
+            .window_ms
+            .unwrap_or(DEFAULT_STATS_WINDOW_MS)
+            .clamp(1, MAX_STATS_WINDOW_MS);
+        let (before, mut unreachable_worker_ids) = collect_actor_stats(&srv).await;
+        let start_time = Instant::now();
+        tokio::time::sleep(Duration::from_millis(window_ms)).await;
+        let (after, unreachable) = collect_actor_stats(&srv).await;
+        let window = start_time.elapsed();
+        unreachable_worker_ids.extend(unreachable);
+        unreachable_worker_ids.sort_unstable();
+        unreachable_worker_ids.dedup();
+
+        let fragments = fragment_stats(&table_fragments.fragments(), &before, &after, window);
+        let bottleneck = find_bottleneck(&fragments);
+
+        Ok(Json(FragmentGraph {
+            window_ms,
+            fragments,
+            bottleneck,
+            unreachable_worker_ids,
+        }))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Builds a fragment whose actors are `actor_ids`, each with `upstream_actor_ids`.
+        fn fragment(fragment_id: u32, actor_ids: &[u32], upstream_actor_ids: &[u32]) -> Fragment {
+            Fragment {
+                fragment_id,
+                actors: actor_ids
+                    .iter()
+                    .map(|&actor_id| StreamActor {
+                        actor_id,
+                        fragment_id,
+                        upstream_actor_id: upstream_actor_ids.to_vec(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }
+        }
+
+        fn stats(input_rows: u64, output_rows: u64, output_blocked_ms: u64) -> ActorStats {
+            ActorStats {
+                input_rows,
+                output_rows,
+                output_blocked_ns: output_blocked_ms * 1_000_000,
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn test_fragment_stats() {
+            let fragments = [fragment(1, &[1, 2], &[]), fragment(2, &[3], &[1, 2])];
+            let fragments = fragments.iter().collect_vec();
+            let before = HashMap::from([
+                (1, stats(100, 100, 0)),
+                (2, stats(100, 100, 0)),
+                (
+                    3,
+                    ActorStats {
+                        barrier_align_count: 1,
+                        barrier_align_seconds: 1.0,
+                        ..stats(200, 0, 0)
+                    },
+                ),
+            ]);
+            let after = HashMap::from([
+                (1, stats(300, 300, 400)),
+                // The counters of a rebuilt actor restart from zero.
+                (2, stats(50, 50, 0)),
+                (
+                    3,
+                    ActorStats {
+                        barrier_align_count: 3,
+                        barrier_align_seconds: 1.2,
+                        ..stats(450, 0, 100)
+                    },
+                ),
+            ]);
+            let result = fragment_stats(&fragments, &before, &after, Duration::from_secs(2));
+
+            assert_eq!(result[0].fragment_id, 1);
+            assert!(result[0].upstream_fragment_ids.is_empty());
+            assert_eq!(result[0].input_rows_per_sec, 100.0);
+            assert_eq!(result[0].output_rows_per_sec, 100.0);
+            assert_eq!(result[0].output_blocked_ratio, 0.1);
+            assert_eq!(result[0].actors[1], (2, Some(ActorRates::default())));
+
+            assert_eq!(result[1].fragment_id, 2);
+            assert_eq!(result[1].upstream_fragment_ids, BTreeSet::from([1]));
+            assert_eq!(result[1].input_rows_per_sec, 125.0);
+            assert_eq!(result[1].output_blocked_ratio, 0.05);
+            assert!((result[1].avg_barrier_align_ms - 100.0).abs() < 1e-6);
+
+            // Actors missing from a snapshot have no statistics.
+            let after = HashMap::from([(1, stats(300, 300, 400))]);
+            let result = fragment_stats(&fragments, &before, &after, Duration::from_secs(2));
+            assert_eq!(result[0].actors[1], (2, None));
+            assert_eq!(result[0].input_rows_per_sec, 100.0);
+            assert_eq!(result[0].output_blocked_ratio, 0.2);
+            assert_eq!(result[1].actors[0], (3, None));
+            assert_eq!(result[1].output_blocked_ratio, 0.0);
+        }
+
+        #[test]
+        fn test_find_bottleneck() {
+            // 1 -> 2 -> 3, with 1 blocked on 2, and 2 blocked on 3 a bit.
+            let fragments = [
+                fragment(1, &[1], &[]),
+                fragment(2, &[2], &[1]),
+                fragment(3, &[3], &[2]),
+            ];
+            let fragments = fragments.iter().collect_vec();
+            let before = HashMap::from([
+                (1, stats(0, 0, 0)),
+                (2, stats(0, 0, 0)),
+                (3, stats(0, 0, 0)),
+            ]);
+            let after = HashMap::from([
+                (1, stats(0, 0, 900)),
+                (2, stats(0, 0, 200)),
+                (3, stats(0, 0, 0)),
+            ]);
+            let result = fragment_stats(&fragments, &before, &after, Duration::from_secs(1));
+            assert_eq!(find_bottleneck(&result), Some(2));
+
+            // No fragment is blocked: no bottleneck.
+            let result = fragment_stats(&fragments, &before, &before, Duration::from_secs(1));
+            assert_eq!(find_bottleneck(&result), None);
+        }
+    }
 }
 
 impl<S> DashboardService<S>
@@ -149,6 +496,7 @@ where
             .route("/clusters/:ty", get(list_clusters::<S>))
             .route("/actors", get(list_actors::<S>))
             .route("/fragments", get(list_table_fragments::<S>))
+            .route("/fragment_graph/:table_id", get(get_fragment_graph::<S>))
             .route("/materialized_views", get(list_materialized_views::<S>))
             .layer(
                 ServiceBuilder::new()
@@ -87,6 +87,10 @@ pub struct MetaNodeOpts {
     #[clap(long, default_value = "100")]
     checkpoint_interval: u32,
 
+    /// Interval in ms of discovering new splits (e.g. Kafka partitions) of sources.
+    #[clap(long, default_value = "10000")]
+    split_discovery_interval: u32,
+
     /// Whether to enable fail-on-recovery. If not set, default to enable. Should only be used in
     /// e2e tests.
     #[clap(long)]
@@ -110,6 +114,7 @@ pub async fn start(opts: MetaNodeOpts) {
     };
     let max_heartbeat_interval = Duration::from_millis(opts.max_heartbeat_interval as u64);
     let checkpoint_interval = Duration::from_millis(opts.checkpoint_interval as u64);
+    let split_discovery_interval = Duration::from_millis(opts.split_discovery_interval as u64);
 
     tracing::info!("Meta server listening at {}", addr);
     let (join_handle, _shutdown_send) = rpc_serve(
@@ -122,6 +127,7 @@ pub async fn start(opts: MetaNodeOpts) {
         MetaOpts {
             enable_recovery: !opts.disable_recovery,
             checkpoint_interval,
+            split_discovery_interval,
         },
     )
     .await
@@ -23,7 +23,7 @@ use risingwave_common::ensure;
 use risingwave_common::error::ErrorCode::{CatalogError, InternalError};
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
-use risingwave_pb::catalog::{Database, Schema, Source, Table};
+use risingwave_pb::catalog::{Database, Schema, Source, Table, TableStatistics};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use tokio::sync::{Mutex, MutexGuard};
 
@@ -304,6 +304,32 @@ where
         }
     }
 
+    /// Replaces the statistics of the given table and notifies the frontends.
+    pub async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: TableStatistics,
+    ) -> Result<CatalogVersion> {
+        let _core = self.core.lock().await;
+        let table = Table::select(self.env.meta_store(), &table_id).await?;
+        if let Some(mut table) = table {
+            table.statistics = Some(statistics);
+            table.insert(self.env.meta_store()).await?;
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Update, Info::TableV2(table))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "table doesn't exist".to_string(),
+            )))
+        }
+    }
+
     pub async fn start_create_source_procedure(&self, source: &Source) -> Result<()> {
         let mut core = self.core.lock().await;
         let key = (source.database_id, source.schema_id, source.name.clone());
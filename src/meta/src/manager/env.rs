@@ -51,6 +51,8 @@ where
 pub struct MetaOpts {
     pub enable_recovery: bool,
     pub checkpoint_interval: Duration,
+    /// Interval of listing splits of all sources to discover new partitions or shards.
+    pub split_discovery_interval: Duration,
 }
 
 impl Default for MetaOpts {
@@ -58,6 +60,7 @@ impl Default for MetaOpts {
         Self {
             enable_recovery: false,
             checkpoint_interval: Duration::from_millis(100),
+            split_discovery_interval: Duration::from_secs(10),
         }
     }
 }
@@ -16,13 +16,14 @@ use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
-use risingwave_common::error::Result;
+use risingwave_common::error::{Result, ToRwResult};
+use risingwave_connector::SplitImpl;
 use risingwave_pb::meta::table_fragments::fragment::FragmentType;
 use risingwave_pb::meta::table_fragments::{ActorState, ActorStatus, Fragment};
 use risingwave_pb::meta::TableFragments as ProstTableFragments;
 use risingwave_pb::stream_plan::source_node::SourceType;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
-use risingwave_pb::stream_plan::{StreamActor, StreamNode};
+use risingwave_pb::stream_plan::{SourceNode, StreamActor, StreamNode, StreamSourceState};
 
 use super::{ActorId, FragmentId};
 use crate::cluster::{ParallelUnitId, WorkerId};
@@ -172,6 +173,96 @@ impl TableFragments {
         None
     }
 
+    fn find_stream_source(stream_node: &StreamNode) -> Option<&SourceNode> {
+        if let Some(NodeBody::Source(s)) = stream_node.node_body.as_ref() {
+            if s.source_type == SourceType::Source as i32 {
+                return Some(s);
+            }
+        }
+
+        stream_node.input.iter().find_map(Self::find_stream_source)
+    }
+
+    fn find_stream_source_mut(stream_node: &mut StreamNode) -> Option<&mut SourceNode> {
+        if let Some(NodeBody::Source(s)) = stream_node.node_body.as_mut() {
+            if s.source_type == SourceType::Source as i32 {
+                return Some(s);
+            }
+        }
+
+        stream_node
+            .input
+            .iter_mut()
+            .find_map(Self::find_stream_source_mut)
+    }
+
+    /// Returns whether all actors of this table have been created.
+    pub fn is_created(&self) -> bool {
+        self.actor_status
+            .values()
+            .all(|actor_status| actor_status.state == ActorState::Running as i32)
+    }
+
+    /// Returns the stream source actors and their assigned splits, group by source id and then by
+    /// fragment.
+    pub fn stream_source_splits(
+        &self,
+    ) -> Result<HashMap<SourceId, Vec<BTreeMap<ActorId, Vec<SplitImpl>>>>> {
+        let mut result: HashMap<_, Vec<_>> = HashMap::new();
+        for fragment in self.fragments.values() {
+            let mut source_actors: HashMap<_, BTreeMap<_, _>> = HashMap::new();
+            for actor in &fragment.actors {
+                if let Some(source) = Self::find_stream_source(actor.nodes.as_ref().unwrap()) {
+                    let source_id = source.table_ref_id.as_ref().unwrap().table_id as SourceId;
+                    let splits = match &source.stream_source_state {
+                        Some(state) => state
+                            .stream_source_splits
+                            .iter()
+                            .map(|split| {
+                                SplitImpl::restore_from_bytes(state.split_type.clone(), split)
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()
+                            .to_rw_result()?,
+                        None => vec![],
+                    };
+                    source_actors
+                        .entry(source_id)
+                        .or_default()
+                        .insert(actor.actor_id, splits);
+                }
+            }
+
+            for (source_id, actors) in source_actors {
+                result.entry(source_id).or_default().push(actors);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Replace the splits of the given stream source actors.
+    pub fn update_stream_source_splits(&mut self, assignment: &HashMap<ActorId, Vec<SplitImpl>>) {
+        for fragment in self.fragments.values_mut() {
+            for actor in &mut fragment.actors {
+                let Some(splits) = assignment.get(&actor.actor_id) else {
+                    continue;
+                };
+                if let Some(source) = Self::find_stream_source_mut(actor.nodes.as_mut().unwrap()) {
+                    source.stream_source_state = Some(StreamSourceState {
+                        split_type: splits
+                            .first()
+                            .map(|split| split.get_type())
+                            .unwrap_or_default(),
+                        stream_source_splits: splits
+                            .iter()
+                            .map(|split| split.to_json_bytes().to_vec())
+                            .collect(),
+                    });
+                }
+            }
+        }
+    }
+
     /// Returns actors that contains Chain node.
     pub fn chain_actor_ids(&self) -> Vec<ActorId> {
         self.fragments
@@ -34,6 +34,14 @@ pub struct MetaMetrics {
     pub grpc_latency: HistogramVec,
     /// latency of each barrier
     pub barrier_latency: Histogram,
+    /// latency from injecting a barrier until it's collected and synced on all compute nodes
+    pub barrier_collect_sync_latency: Histogram,
+    /// latency of collecting a barrier from all actors on each compute node
+    pub barrier_collect_latency: HistogramVec,
+    /// latency of syncing the states of an epoch on each compute node
+    pub barrier_sync_latency: HistogramVec,
+    /// latency of committing an epoch to Hummock
+    pub barrier_commit_latency: Histogram,
 
     /// max committed epoch
     pub max_committed_epoch: IntGauge,
@@ -79,6 +87,37 @@ impl MetaMetrics {
         );
         let barrier_latency = register_histogram_with_registry!(opts, registry).unwrap();
 
+        let opts = histogram_opts!(
+            "meta_barrier_collect_sync_duration_seconds",
+            "barrier latency from injection until collected and synced on all compute nodes",
+            exponential_buckets(0.1, 1.5, 16).unwrap() // max 43s
+        );
+        let barrier_collect_sync_latency =
+            register_histogram_with_registry!(opts, registry).unwrap();
+
+        let opts = histogram_opts!(
+            "meta_barrier_collect_duration_seconds",
+            "barrier collect latency on each compute node",
+            exponential_buckets(0.001, 2.0, 16).unwrap() // max 32s
+        );
+        let barrier_collect_latency =
+            register_histogram_vec_with_registry!(opts, &["worker_id"], registry).unwrap();
+
+        let opts = histogram_opts!(
+            "meta_barrier_sync_duration_seconds",
+            "state store sync latency of an epoch on each compute node",
+            exponential_buckets(0.001, 2.0, 16).unwrap() // max 32s
+        );
+        let barrier_sync_latency =
+            register_histogram_vec_with_registry!(opts, &["worker_id"], registry).unwrap();
+
+        let opts = histogram_opts!(
+            "meta_barrier_commit_duration_seconds",
+            "hummock epoch commit latency",
+            exponential_buckets(0.001, 2.0, 16).unwrap() // max 32s
+        );
+        let barrier_commit_latency = register_histogram_with_registry!(opts, registry).unwrap();
+
         let max_committed_epoch = register_int_gauge_with_registry!(
             "storage_max_committed_epoch",
             "max committed epoch",
@@ -173,6 +212,10 @@ impl MetaMetrics {
 
             grpc_latency,
             barrier_latency,
+            barrier_collect_sync_latency,
+            barrier_collect_latency,
+            barrier_sync_latency,
+            barrier_commit_latency,
 
             max_committed_epoch,
             uncommitted_sst_num,
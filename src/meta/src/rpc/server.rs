@@ -136,6 +136,7 @@ pub async fn rpc_serve_with_store<S: MetaStore>(
             dashboard_addr,
             cluster_manager: cluster_manager.clone(),
             fragment_manager: fragment_manager.clone(),
+            stream_clients: env.stream_clients_ref(),
             meta_store: env.meta_store_ref(),
         };
         // TODO: join dashboard service back to local thread.
@@ -164,6 +165,7 @@ pub async fn rpc_serve_with_store<S: MetaStore>(
             cluster_manager.clone(),
             barrier_manager.clone(),
             catalog_manager_v2.clone(),
+            fragment_manager.clone(),
         )
         .await
         .unwrap(),
@@ -213,6 +215,7 @@ pub async fn rpc_serve_with_store<S: MetaStore>(
         stream_manager,
         fragment_manager.clone(),
         cluster_manager.clone(),
+        barrier_manager.clone(),
     );
     let hummock_srv = HummockServiceImpl::new(
         hummock_manager.clone(),
@@ -371,6 +371,23 @@ where
             version,
         }))
     }
+
+    async fn update_table_statistics(
+        &self,
+        request: Request<UpdateTableStatisticsRequest>,
+    ) -> Result<Response<UpdateTableStatisticsResponse>, Status> {
+        let request = request.into_inner();
+        let version = self
+            .catalog_manager
+            .update_table_statistics(request.table_id, request.statistics.unwrap_or_default())
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(UpdateTableStatisticsResponse {
+            status: None,
+            version,
+        }))
+    }
 }
 
 impl<S> DdlServiceImpl<S>
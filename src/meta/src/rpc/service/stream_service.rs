@@ -19,6 +19,7 @@ use risingwave_pb::meta::stream_manager_service_server::StreamManagerService;
 use risingwave_pb::meta::*;
 use tonic::{Request, Response, Status};
 
+use crate::barrier::BarrierManagerRef;
 use crate::cluster::ClusterManagerRef;
 use crate::manager::MetaSrvEnv;
 use crate::model::TableFragments;
@@ -37,6 +38,7 @@ where
     global_stream_manager: GlobalStreamManagerRef<S>,
     fragment_manager: FragmentManagerRef<S>,
     cluster_manager: ClusterManagerRef<S>,
+    barrier_manager: BarrierManagerRef<S>,
 }
 
 impl<S> StreamServiceImpl<S>
@@ -48,12 +50,14 @@ where
         global_stream_manager: GlobalStreamManagerRef<S>,
         fragment_manager: FragmentManagerRef<S>,
         cluster_manager: ClusterManagerRef<S>,
+        barrier_manager: BarrierManagerRef<S>,
     ) -> Self {
         StreamServiceImpl {
             env,
             global_stream_manager,
             fragment_manager,
             cluster_manager,
+            barrier_manager,
         }
     }
 }
@@ -140,4 +144,17 @@ where
             .map_err(|e| e.to_grpc_status())?;
         Ok(Response::new(FlushResponse { status: None }))
     }
+
+    #[cfg_attr(coverage, no_coverage)]
+    async fn list_barrier_traces(
+        &self,
+        request: Request<ListBarrierTracesRequest>,
+    ) -> TonicResponse<ListBarrierTracesResponse> {
+        let req = request.into_inner();
+
+        let traces = self
+            .barrier_manager
+            .slowest_barrier_traces(req.limit as usize);
+        Ok(Response::new(ListBarrierTracesResponse { traces }))
+    }
 }
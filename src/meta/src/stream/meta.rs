@@ -20,6 +20,7 @@ use risingwave_common::catalog::TableId;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::try_match_expand;
+use risingwave_connector::SplitImpl;
 use risingwave_pb::meta::table_fragments::fragment::FragmentType;
 use risingwave_pb::meta::table_fragments::ActorState;
 use risingwave_pb::stream_plan::StreamActor;
@@ -243,6 +244,36 @@ where
         }
     }
 
+    /// Persist the new split assignment of stream source actors, so that the actors are rebuilt
+    /// with it on recovery.
+    pub async fn update_actor_splits(
+        &self,
+        assignment: &HashMap<ActorId, Vec<SplitImpl>>,
+    ) -> Result<()> {
+        let map = &mut self.core.write().await.table_fragments;
+
+        let mut transaction = Transaction::default();
+        let mut updated_tables = vec![];
+        for table_fragments in map.values().filter(|table_fragments| {
+            table_fragments
+                .actor_ids()
+                .iter()
+                .any(|actor_id| assignment.contains_key(actor_id))
+        }) {
+            let mut table_fragments = table_fragments.clone();
+            table_fragments.update_stream_source_splits(assignment);
+            table_fragments.upsert_in_transaction(&mut transaction)?;
+            updated_tables.push(table_fragments);
+        }
+
+        self.meta_store.txn(transaction).await?;
+        for table_fragments in updated_tables {
+            map.insert(table_fragments.table_id(), table_fragments);
+        }
+
+        Ok(())
+    }
+
     /// Used in [`crate::barrier::GlobalBarrierManager`]
     pub async fn load_all_actors(&self, with_creating_table: Option<TableId>) -> ActorInfos {
         let mut actor_maps = HashMap::new();
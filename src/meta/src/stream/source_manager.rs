@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
 use futures::future::try_join_all;
@@ -28,19 +29,27 @@ use risingwave_pb::stream_service::{
     CreateSourceRequest as ComputeNodeCreateSourceRequest,
     DropSourceRequest as ComputeNodeDropSourceRequest,
 };
+use tokio::sync::Mutex;
+use tokio::time::MissedTickBehavior;
 
-use crate::barrier::BarrierManagerRef;
+use crate::barrier::{BarrierManagerRef, Command};
 use crate::cluster::ClusterManagerRef;
 use crate::manager::{CatalogManagerRef, MetaSrvEnv, SourceId, StreamClient};
 use crate::model::ActorId;
 use crate::storage::MetaStore;
+use crate::stream::FragmentManagerRef;
 
 pub type SourceManagerRef<S> = Arc<SourceManager<S>>;
 
 pub struct SourceManager<S: MetaStore> {
     env: MetaSrvEnv<S>,
     cluster_manager: ClusterManagerRef<S>,
+    barrier_manager: BarrierManagerRef<S>,
     catalog_manager: CatalogManagerRef<S>,
+    fragment_manager: FragmentManagerRef<S>,
+
+    /// Split enumerators of stream sources, kept across split discovery rounds.
+    enumerators: Mutex<HashMap<SourceId, SplitEnumeratorImpl>>,
 }
 
 impl<S> SourceManager<S>
@@ -50,17 +59,21 @@ where
     pub async fn new(
         env: MetaSrvEnv<S>,
         cluster_manager: ClusterManagerRef<S>,
-        _barrier_manager: BarrierManagerRef<S>,
+        barrier_manager: BarrierManagerRef<S>,
         catalog_manager: CatalogManagerRef<S>,
+        fragment_manager: FragmentManagerRef<S>,
     ) -> Result<Self> {
         Ok(Self {
             env,
             cluster_manager,
+            barrier_manager,
             catalog_manager,
+            fragment_manager,
+            enumerators: Mutex::new(HashMap::new()),
         })
     }
 
-    async fn fetch_splits_for_source(&self, source: &Source) -> Result<Vec<SplitImpl>> {
+    fn create_enumerator(source: &Source) -> Result<SplitEnumeratorImpl> {
         let info = match source.get_info()? {
             Info::StreamSource(s) => s,
             _ => {
@@ -71,8 +84,11 @@ where
         };
 
         let properties = ConnectorProperties::new(info.properties.clone())?;
-        SplitEnumeratorImpl::create(properties)
-            .to_rw_result()?
+        SplitEnumeratorImpl::create(properties).to_rw_result()
+    }
+
+    async fn fetch_splits_for_source(&self, source: &Source) -> Result<Vec<SplitImpl>> {
+        Self::create_enumerator(source)?
             .list_splits()
             .await
             .to_rw_result()
@@ -176,12 +192,290 @@ where
                 async move { client.drop_source(request).await.to_rw_result() }
             });
         let _responses: Vec<_> = try_join_all(futures).await?;
+        self.enumerators.lock().await.remove(&source_id);
 
         Ok(())
     }
 
-    pub async fn run(&self) -> Result<()> {
-        // todo: in the future, split change will be pushed as a long running service
+    /// List the splits of a stream source with its cached enumerator.
+    async fn list_splits(&self, source_id: SourceId) -> Result<Vec<SplitImpl>> {
+        let mut enumerators = self.enumerators.lock().await;
+        let enumerator = match enumerators.entry(source_id) {
+            Entry::Occupied(o) => o.into_mut(),
+            Entry::Vacant(v) => {
+                let catalog_guard = self.catalog_manager.get_catalog_core_guard().await;
+                let source = catalog_guard.get_source(source_id).await?.ok_or_else(|| {
+                    RwError::from(InternalError(format!(
+                        "could not find source catalog for {}",
+                        source_id
+                    )))
+                })?;
+                let mut enumerator = Self::create_enumerator(&source)?;
+                // The splits in the initial listing are assigned when the source is created, so
+                // the splits discovered from now on are new, and are read in full.
+                enumerator.read_from_earliest();
+                v.insert(enumerator)
+            }
+        };
+
+        enumerator.list_splits().await.to_rw_result()
+    }
+
+    /// Assign the splits that are not assigned to any actor of a fragment yet, and drop the
+    /// assigned splits that are no longer discovered, which have been read to their end and
+    /// expired, e.g. closed Kinesis shards past the retention period. Returns the full split list
+    /// of the actors whose assignment changes.
+    ///
+    /// A split split or merged from assigned splits must be read after all its parents. The actor
+    /// reading a split holds back its children until it finishes the split, so a child goes to the
+    /// actor of its parents that are not `finished` yet. If they are read by more than one actor,
+    /// e.g. a shard merged from shards on two actors, the child is held back until all but one of
+    /// them report the parents finished. Any other split goes to the actor with the fewest splits.
+    fn assign_new_splits(
+        discovered: &[SplitImpl],
+        actor_splits: &BTreeMap<ActorId, Vec<SplitImpl>>,
+        finished: &HashMap<ActorId, HashSet<String>>,
+    ) -> HashMap<ActorId, Vec<SplitImpl>> {
+        if actor_splits.is_empty() {
+            return HashMap::new();
+        }
+
+        let discovered_ids: HashSet<_> = discovered.iter().map(SplitImpl::id).collect();
+        let mut actor_splits = actor_splits.clone();
+        let mut changed = HashSet::new();
+        for (&actor_id, splits) in &mut actor_splits {
+            let len = splits.len();
+            splits.retain(|split| discovered_ids.contains(&split.id()));
+            if splits.len() != len {
+                changed.insert(actor_id);
+            }
+        }
+
+        let mut split_actors: HashMap<_, _> = actor_splits
+            .iter()
+            .flat_map(|(&actor_id, splits)| splits.iter().map(move |split| (split.id(), actor_id)))
+            .collect();
+        let mut held_back = HashSet::new();
+        for split in discovered {
+            if split_actors.contains_key(&split.id()) {
+                continue;
+            }
+            let parent_ids = split.parent_ids();
+            if parent_ids
+                .iter()
+                .any(|parent_id| held_back.contains(parent_id))
+            {
+                held_back.insert(split.id());
+                continue;
+            }
+            let parent_actors = parent_ids
+                .iter()
+                .filter_map(|parent_id| {
+                    let actor_id = *split_actors.get(parent_id)?;
+                    let parent_finished = finished
+                        .get(&actor_id)
+                        .map_or(false, |finished| finished.contains(parent_id));
+                    (!parent_finished).then(|| actor_id)
+                })
+                .unique()
+                .collect_vec();
+            let actor_id = match parent_actors.as_slice() {
+                [] => {
+                    *actor_splits
+                        .iter()
+                        .min_by_key(|(_, splits)| splits.len())
+                        .unwrap()
+                        .0
+                }
+                [actor_id] => *actor_id,
+                _ => {
+                    held_back.insert(split.id());
+                    continue;
+                }
+            };
+            actor_splits.get_mut(&actor_id).unwrap().push(split.clone());
+            split_actors.insert(split.id(), actor_id);
+            changed.insert(actor_id);
+        }
+
+        actor_splits
+            .into_iter()
+            .filter(|(actor_id, _)| changed.contains(actor_id))
+            .collect()
+    }
+
+    /// Discover new splits of all stream sources in created materialized views, and hand them to
+    /// the source actors with a barrier.
+    async fn discover_splits(&self) -> Result<()> {
+        let mut source_fragments: HashMap<SourceId, Vec<_>> = HashMap::new();
+        for table_fragments in self.fragment_manager.list_table_fragments().await? {
+            if !table_fragments.is_created() {
+                continue;
+            }
+            for (source_id, fragments) in table_fragments.stream_source_splits()? {
+                source_fragments
+                    .entry(source_id)
+                    .or_default()
+                    .extend(fragments);
+            }
+        }
+
+        let mut assignment = HashMap::new();
+        for (source_id, fragments) in source_fragments {
+            let discovered = match self.list_splits(source_id).await {
+                Ok(splits) => splits,
+                Err(e) => {
+                    log::warn!("failed to list splits of source {}: {}", source_id, e);
+                    continue;
+                }
+            };
+            let discovered_ids: HashSet<_> = discovered.iter().map(SplitImpl::id).collect();
+            for actor_splits in fragments {
+                self.barrier_manager
+                    .retain_finished_source_splits(actor_splits.keys().copied(), &discovered_ids);
+                let finished = self.barrier_manager.finished_source_splits();
+                assignment.extend(Self::assign_new_splits(
+                    &discovered,
+                    &actor_splits,
+                    &finished,
+                ));
+            }
+        }
+
+        if !assignment.is_empty() {
+            log::info!("assigning new splits to source actors: {:?}", assignment);
+            self.barrier_manager
+                .run_command(Command::SourceSplitAssignment(assignment))
+                .await?;
+        }
+
         Ok(())
     }
+
+    /// Periodically discover new splits of stream sources, e.g. partitions added to a Kafka topic
+    /// or shards created by a Kinesis resharding, and assign them to the source actors.
+    pub async fn run(&self) -> Result<()> {
+        let mut ticker = tokio::time::interval(self.env.opts.split_discovery_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.discover_splits().await {
+                log::warn!("failed to discover new splits: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemStore;
+
+    fn kafka_split(partition: i32) -> SplitImpl {
+        let json = format!(
+            r#"{{"topic":"t","partition":{},"start_offset":null,"stop_offset":null}}"#,
+            partition
+        );
+        SplitImpl::restore_from_bytes("kafka".to_string(), json.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_assign_new_splits() {
+        let actor_splits = BTreeMap::from([
+            (1, vec![kafka_split(0), kafka_split(2)]),
+            (2, vec![kafka_split(1)]),
+        ]);
+
+        // No new partitions.
+        let discovered = (0..3).map(kafka_split).collect_vec();
+        let assignment = SourceManager::<MemStore>::assign_new_splits(
+            &discovered,
+            &actor_splits,
+            &HashMap::new(),
+        );
+        assert!(assignment.is_empty());
+
+        // Partitions 3 and 4 are added to the topic.
+        let discovered = (0..5).map(kafka_split).collect_vec();
+        let assignment = SourceManager::<MemStore>::assign_new_splits(
+            &discovered,
+            &actor_splits,
+            &HashMap::new(),
+        );
+        let ids = |actor_id: ActorId| {
+            assignment[&actor_id]
+                .iter()
+                .map(SplitImpl::id)
+                .sorted()
+                .collect_vec()
+        };
+        assert_eq!(ids(1), vec!["0", "2", "4"]);
+        assert_eq!(ids(2), vec!["1", "3"]);
+    }
+
+    fn kinesis_split(shard_id: &str, parent_shard_ids: &[&str]) -> SplitImpl {
+        let json = format!(
+            r#"{{"shard_id":"{}","start_position":"Earliest","end_position":"None","parent_shard_ids":{:?}}}"#,
+            shard_id, parent_shard_ids
+        );
+        SplitImpl::restore_from_bytes("kinesis".to_string(), json.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_assign_child_shards() {
+        let actor_splits = BTreeMap::from([
+            (1, vec![kinesis_split("s0", &[])]),
+            (2, vec![kinesis_split("s1", &[])]),
+        ]);
+        let ids = |assignment: &HashMap<ActorId, Vec<SplitImpl>>, actor_id: ActorId| {
+            assignment[&actor_id]
+                .iter()
+                .map(SplitImpl::id)
+                .sorted()
+                .collect_vec()
+        };
+
+        // `s1` is split into `s2` and `s3`, which go to the actor of `s1` to be read after it.
+        // Then `s0` and `s3` are merged into `s4`, which is held back while both are read on
+        // different actors, and so is `s5` split from it.
+        let discovered = vec![
+            kinesis_split("s0", &[]),
+            kinesis_split("s1", &[]),
+            kinesis_split("s2", &["s1"]),
+            kinesis_split("s3", &["s1"]),
+            kinesis_split("s4", &["s0", "s3"]),
+            kinesis_split("s5", &["s4"]),
+        ];
+        let assignment = SourceManager::<MemStore>::assign_new_splits(
+            &discovered,
+            &actor_splits,
+            &HashMap::new(),
+        );
+        assert!(!assignment.contains_key(&1));
+        assert_eq!(ids(&assignment, 2), vec!["s1", "s2", "s3"]);
+
+        // Once `s3` is finished, `s4` and `s5` go to the actor of `s0`, which reads them after
+        // `s0`.
+        let actor_splits = BTreeMap::from([
+            (1, vec![kinesis_split("s0", &[])]),
+            (2, assignment[&2].clone()),
+        ]);
+        let finished = HashMap::from([(2, HashSet::from(["s1".to_string(), "s3".to_string()]))]);
+        let assignment =
+            SourceManager::<MemStore>::assign_new_splits(&discovered, &actor_splits, &finished);
+        assert!(!assignment.contains_key(&2));
+        assert_eq!(ids(&assignment, 1), vec!["s0", "s4", "s5"]);
+
+        // `s1` expires, so it is dropped from its actor.
+        let actor_splits =
+            BTreeMap::from([(1, assignment[&1].clone()), (2, actor_splits[&2].clone())]);
+        let discovered = discovered
+            .into_iter()
+            .filter(|split| split.id() != "s1")
+            .collect_vec();
+        let assignment =
+            SourceManager::<MemStore>::assign_new_splits(&discovered, &actor_splits, &finished);
+        assert!(!assignment.contains_key(&1));
+        assert_eq!(ids(&assignment, 2), vec!["s2", "s3"]);
+    }
 }
@@ -784,6 +784,13 @@ mod tests {
         ) -> std::result::Result<Response<SyncSourcesResponse>, Status> {
             Ok(Response::new(SyncSourcesResponse::default()))
         }
+
+        async fn get_actor_stats(
+            &self,
+            _request: Request<GetActorStatsRequest>,
+        ) -> std::result::Result<Response<GetActorStatsResponse>, Status> {
+            unimplemented!()
+        }
     }
 
     struct MockServices {
@@ -853,6 +860,7 @@ mod tests {
                     cluster_manager.clone(),
                     barrier_manager.clone(),
                     catalog_manager.clone(),
+                    fragment_manager.clone(),
                 )
                 .await?,
             );
@@ -258,6 +258,7 @@ fn make_stream_node() -> StreamNode {
             column_ids: vec![0_i32, 1_i32],
             column_orders: vec![make_column_order(1), make_column_order(2)],
             distribution_keys: Default::default(),
+            handle_pk_conflict: false,
         })),
         fields: vec![], // TODO: fill this later
         operator_id: 7,
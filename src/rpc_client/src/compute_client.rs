@@ -15,6 +15,7 @@
 use std::fmt::{Debug, Formatter};
 use std::time::Duration;
 
+use futures::channel::mpsc::{unbounded, UnboundedSender};
 use futures::StreamExt;
 use log::trace;
 use risingwave_common::array::DataChunk;
@@ -26,8 +27,8 @@ use risingwave_pb::batch_plan::{ExchangeInfo, PlanFragment, PlanNode, TaskId, Ta
 use risingwave_pb::task_service::exchange_service_client::ExchangeServiceClient;
 use risingwave_pb::task_service::task_service_client::TaskServiceClient;
 use risingwave_pb::task_service::{
-    CreateTaskRequest, CreateTaskResponse, GetDataRequest, GetDataResponse, GetStreamRequest,
-    GetStreamResponse,
+    get_stream_request, CreateTaskRequest, CreateTaskResponse, GetDataRequest, GetDataResponse,
+    GetStreamRequest, GetStreamResponse, GetTaskInfoRequest, TaskInfo, TaskLimits,
 };
 use tonic::transport::{Channel, Endpoint};
 use tonic::Streaming;
@@ -77,18 +78,31 @@ impl ComputeClient {
             .into_inner())
     }
 
+    /// Starts a stream exchange with `permits` initial permits. Returns the message stream and the
+    /// sender to grant more permits with.
     pub async fn get_stream(
         &self,
         up_fragment_id: u32,
         down_fragment_id: u32,
-    ) -> Result<Streaming<GetStreamResponse>> {
-        Ok(self
+        permits: u32,
+    ) -> Result<(
+        Streaming<GetStreamResponse>,
+        UnboundedSender<GetStreamRequest>,
+    )> {
+        let (request_tx, request_rx) = unbounded();
+        request_tx
+            .unbounded_send(GetStreamRequest {
+                value: Some(get_stream_request::Value::Get(get_stream_request::Get {
+                    up_fragment_id,
+                    down_fragment_id,
+                    permits,
+                })),
+            })
+            .unwrap();
+        let stream = self
             .exchange_client
             .to_owned()
-            .get_stream(GetStreamRequest {
-                up_fragment_id,
-                down_fragment_id,
-            })
+            .get_stream(request_rx)
             .await
             .to_rw_result_with(|| {
                 format!(
@@ -96,7 +110,8 @@ impl ComputeClient {
                     self.addr, up_fragment_id, down_fragment_id
                 )
             })?
-            .into_inner())
+            .into_inner();
+        Ok((stream, request_tx))
     }
 
     // TODO: Remove this
@@ -113,6 +128,7 @@ impl ComputeClient {
                 task_id: Some(task_id),
                 plan: Some(plan),
                 epoch,
+                limits: None,
             })
             .await?;
         Ok(())
@@ -123,17 +139,34 @@ impl ComputeClient {
         task_id: TaskId,
         plan: PlanFragment,
         epoch: u64,
+        limits: TaskLimits,
     ) -> Result<()> {
         let _ = self
             .create_task_inner(CreateTaskRequest {
                 task_id: Some(task_id),
                 plan: Some(plan),
                 epoch,
+                limits: Some(limits),
             })
             .await?;
         Ok(())
     }
 
+    /// Get the status and operator statistics of a task.
+    pub async fn get_task_info(&self, task_id: TaskId) -> Result<TaskInfo> {
+        let resp = self
+            .task_client
+            .to_owned()
+            .get_task_info(GetTaskInfoRequest {
+                task_id: Some(task_id),
+            })
+            .await
+            .to_rw_result()?
+            .into_inner();
+        resp.task_info
+            .ok_or_else(|| InternalError("no task info in response".to_string()).into())
+    }
+
     async fn create_task_inner(&self, req: CreateTaskRequest) -> Result<CreateTaskResponse> {
         Ok(self
             .task_client
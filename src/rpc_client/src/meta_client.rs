@@ -24,6 +24,7 @@ use risingwave_common::util::addr::HostAddr;
 use risingwave_hummock_sdk::{HummockEpoch, HummockSSTableId, HummockVersionId};
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    TableStatistics as ProstTableStatistics,
 };
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::ddl_service::ddl_service_client::DdlServiceClient;
@@ -34,6 +35,7 @@ use risingwave_pb::ddl_service::{
     CreateSourceResponse, DropDatabaseRequest, DropDatabaseResponse, DropMaterializedSourceRequest,
     DropMaterializedSourceResponse, DropMaterializedViewRequest, DropMaterializedViewResponse,
     DropSchemaRequest, DropSchemaResponse, DropSourceRequest, DropSourceResponse,
+    UpdateTableStatisticsRequest, UpdateTableStatisticsResponse,
 };
 use risingwave_pb::hummock::hummock_manager_service_client::HummockManagerServiceClient;
 use risingwave_pb::hummock::{
@@ -52,9 +54,10 @@ use risingwave_pb::meta::notification_service_client::NotificationServiceClient;
 use risingwave_pb::meta::stream_manager_service_client::StreamManagerServiceClient;
 use risingwave_pb::meta::{
     ActivateWorkerNodeRequest, ActivateWorkerNodeResponse, AddWorkerNodeRequest,
-    AddWorkerNodeResponse, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse, FlushRequest,
-    FlushResponse, HeartbeatRequest, HeartbeatResponse, ListAllNodesRequest, ListAllNodesResponse,
-    SubscribeRequest, SubscribeResponse,
+    AddWorkerNodeResponse, BarrierTrace, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse,
+    FlushRequest, FlushResponse, HeartbeatRequest, HeartbeatResponse, ListAllNodesRequest,
+    ListAllNodesResponse, ListBarrierTracesRequest, ListBarrierTracesResponse, SubscribeRequest,
+    SubscribeResponse,
 };
 use risingwave_pb::stream_plan::StreamNode;
 use tokio::sync::mpsc::{Receiver, UnboundedSender};
@@ -220,6 +223,19 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: ProstTableStatistics,
+    ) -> Result<CatalogVersion> {
+        let request = UpdateTableStatisticsRequest {
+            table_id: table_id.table_id(),
+            statistics: Some(statistics),
+        };
+        let resp = self.inner.update_table_statistics(request).await?;
+        Ok(resp.version)
+    }
+
     pub async fn drop_database(&self, database_id: u32) -> Result<CatalogVersion> {
         let request = DropDatabaseRequest { database_id };
         let resp = self.inner.drop_database(request).await?;
@@ -307,6 +323,13 @@ impl MetaClient {
         self.inner.flush(request).await?;
         Ok(())
     }
+
+    /// Get the latency breakdown of at most `limit` slowest barriers collected recently.
+    pub async fn list_barrier_traces(&self, limit: u32) -> Result<Vec<BarrierTrace>> {
+        let request = ListBarrierTracesRequest { limit };
+        let resp = self.inner.list_barrier_traces(request).await?;
+        Ok(resp.traces)
+    }
 }
 
 #[async_trait]
@@ -475,6 +498,7 @@ macro_rules! for_all_meta_rpc {
             ,{ cluster_client, list_all_nodes, ListAllNodesRequest, ListAllNodesResponse }
             ,{ heartbeat_client, heartbeat, HeartbeatRequest, HeartbeatResponse }
             ,{ stream_client, flush, FlushRequest, FlushResponse }
+            ,{ stream_client, list_barrier_traces, ListBarrierTracesRequest, ListBarrierTracesResponse }
             ,{ ddl_client, create_materialized_source, CreateMaterializedSourceRequest, CreateMaterializedSourceResponse }
             ,{ ddl_client, create_materialized_view, CreateMaterializedViewRequest, CreateMaterializedViewResponse }
             ,{ ddl_client, create_source, CreateSourceRequest, CreateSourceResponse }
@@ -485,6 +509,7 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, drop_source, DropSourceRequest, DropSourceResponse }
             ,{ ddl_client, drop_database, DropDatabaseRequest, DropDatabaseResponse }
             ,{ ddl_client, drop_schema, DropSchemaRequest, DropSchemaResponse }
+            ,{ ddl_client, update_table_statistics, UpdateTableStatisticsRequest, UpdateTableStatisticsResponse }
             ,{ hummock_client, pin_version, PinVersionRequest, PinVersionResponse }
             ,{ hummock_client, unpin_version, UnpinVersionRequest, UnpinVersionResponse }
             ,{ hummock_client, pin_snapshot, PinSnapshotRequest, PinSnapshotResponse }
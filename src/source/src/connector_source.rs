@@ -102,17 +102,18 @@ impl StreamSourceReader for ConnectorStreamReader {
             None => Ok(StreamChunkWithState {
                 chunk: StreamChunk::default(),
                 split_offset_mapping: None,
+                finished_splits: self.reader.finished_splits(),
             }),
             Some(batch) => {
                 let mut events = Vec::with_capacity(batch.len());
                 let mut split_offset_mapping: HashMap<String, String> = HashMap::new();
 
                 for msg in batch {
-                    if let Some(content) = msg.payload {
+                    if msg.payload.is_some() {
                         *split_offset_mapping
                             .entry(msg.split_id.clone())
                             .or_insert_with(|| "".to_string()) = msg.offset.to_string();
-                        events.push(self.parser.parse(content.as_ref(), &self.columns)?);
+                        events.push(self.parser.parse(&msg, &self.columns)?);
                     }
                 }
                 let mut ops = Vec::with_capacity(events.iter().map(|e| e.ops.len()).sum());
@@ -129,6 +130,7 @@ impl StreamSourceReader for ConnectorStreamReader {
                         None,
                     ),
                     split_offset_mapping: Some(split_offset_mapping),
+                    finished_splits: vec![],
                 })
             }
         }
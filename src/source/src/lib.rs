@@ -60,6 +60,9 @@ pub enum SourceFormat {
     Protobuf,
     DebeziumJson,
     Avro,
+    DebeziumAvro,
+    Maxwell,
+    CanalJson,
 }
 
 #[derive(Debug, EnumAsInner)]
@@ -91,6 +94,8 @@ impl StreamSourceReader for SourceStreamReaderImpl {
 pub struct StreamChunkWithState {
     pub chunk: StreamChunk,
     pub split_offset_mapping: Option<HashMap<String, String>>,
+    /// Splits that have been read to their end. The reader returns nothing more for them.
+    pub finished_splits: Vec<String>,
 }
 
 #[async_trait]
@@ -25,12 +25,12 @@ use risingwave_common::types::DataType;
 use risingwave_common::util::epoch::UNIX_SINGULARITY_DATE_EPOCH;
 use risingwave_connector::ConnectorProperties;
 use risingwave_pb::catalog::StreamSourceInfo;
-use risingwave_pb::plan_common::RowFormatType;
+use risingwave_pb::plan_common::{RowFormatType, WatermarkDesc};
 
 use crate::connector_source::ConnectorSource;
 use crate::row_id::{RowId, RowIdGenerator};
 use crate::table_v2::TableSourceV2;
-use crate::{SourceFormat, SourceImpl, SourceParserImpl};
+use crate::{SourceFormat, SourceImpl, SourceMetaColumn, SourceParserImpl};
 
 pub type SourceRef = Arc<SourceImpl>;
 
@@ -78,6 +78,9 @@ pub struct SourceDesc {
     // TODO: change to Option<usize> when pk supported in the future.
     pub row_id_index: usize,
     pub row_id_generator: Arc<Mutex<RowIdGenerator>>,
+
+    /// Watermark definitions declared on the source columns, see `WATERMARK FOR`.
+    pub watermark_descs: Vec<WatermarkDesc>,
 }
 
 impl SourceDesc {
@@ -102,6 +105,9 @@ impl SourceManager for MemSourceManager {
             RowFormatType::Protobuf => SourceFormat::Protobuf,
             RowFormatType::DebeziumJson => SourceFormat::DebeziumJson,
             RowFormatType::Avro => SourceFormat::Avro,
+            RowFormatType::DebeziumAvro => SourceFormat::DebeziumAvro,
+            RowFormatType::Maxwell => SourceFormat::Maxwell,
+            RowFormatType::CanalJson => SourceFormat::CanalJson,
         };
 
         if format == SourceFormat::Protobuf && info.row_schema_location.is_empty() {
@@ -110,6 +116,12 @@ impl SourceManager for MemSourceManager {
             )));
         }
 
+        if format == SourceFormat::DebeziumAvro && info.row_schema_location.is_empty() {
+            return Err(RwError::from(ProtocolError(
+                "schema registry url not provided".to_string(),
+            )));
+        }
+
         let parser =
             SourceParserImpl::create(&format, &info.properties, info.row_schema_location.as_str())?;
 
@@ -123,7 +135,9 @@ impl SourceManager for MemSourceManager {
                     name: c.name.clone(),
                     data_type: DataType::from(&c.column_type.unwrap()),
                     column_id: ColumnId::from(c.column_id),
-                    skip_parse: idx as i32 == info.row_id_index,
+                    // Metadata columns are not in the payload and filled by the parser later.
+                    skip_parse: idx as i32 == info.row_id_index
+                        || SourceMetaColumn::from_name(&c.name).is_some(),
                 }
             })
             .collect::<Vec<SourceColumnDesc>>();
@@ -134,6 +148,7 @@ impl SourceManager for MemSourceManager {
             info.row_id_index
         );
         let row_id_index = info.row_id_index as usize;
+        let watermark_descs = info.watermark_descs.clone();
 
         let source = SourceImpl::Connector(ConnectorSource {
             config: ConnectorProperties::new(info.properties)?,
@@ -150,6 +165,7 @@ impl SourceManager for MemSourceManager {
                 self.worker_id,
                 *UNIX_SINGULARITY_DATE_EPOCH,
             ))),
+            watermark_descs,
         };
 
         let mut tables = self.get_sources()?;
@@ -185,6 +201,7 @@ impl SourceManager for MemSourceManager {
                 self.worker_id,
                 *UNIX_SINGULARITY_DATE_EPOCH,
             ))),
+            watermark_descs: vec![],
         };
 
         sources.insert(*table_id, desc);
@@ -264,6 +281,7 @@ mod tests {
             row_id_index: 0,
             pk_column_ids: vec![0],
             columns,
+            watermark_descs: vec![],
         };
         let source_id = TableId::default();
 
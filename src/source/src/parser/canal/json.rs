@@ -0,0 +1,212 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use risingwave_common::array::Op;
+use risingwave_common::error::ErrorCode::ProtocolError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::parser::common::json_parse_value;
+use crate::{Event, SourceColumnDesc, SourceParser};
+
+const CANAL_INSERT_OP: &str = "INSERT";
+const CANAL_UPDATE_OP: &str = "UPDATE";
+const CANAL_DELETE_OP: &str = "DELETE";
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanalEvent {
+    #[serde(rename = "type")]
+    pub op: String,
+    #[serde(default)]
+    pub is_ddl: bool,
+    /// The rows after an insert or update, or the rows deleted.
+    pub data: Option<Vec<BTreeMap<String, Value>>>,
+    /// The previous values of the columns changed by an update, one entry per row in `data`.
+    pub old: Option<Vec<BTreeMap<String, Value>>>,
+    pub ts: Option<i64>,
+}
+
+/// Parser for the flat JSON messages produced by Canal from the MySQL binlog. A message holds all
+/// the rows changed by a single statement.
+#[derive(Debug)]
+pub struct CanalJsonParser {}
+
+impl CanalJsonParser {
+    /// Canal encodes all column values as strings.
+    fn parse_value(column: &SourceColumnDesc, value: Option<&Value>) -> Datum {
+        let value = match value {
+            Some(Value::String(s)) => match column.data_type {
+                DataType::Boolean => Value::Bool(!matches!(s.as_str(), "0" | "false")),
+                DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::Float32
+                | DataType::Float64
+                | DataType::Decimal
+                | DataType::Jsonb => serde_json::from_str(s).ok()?,
+                _ => Value::String(s.clone()),
+            },
+            Some(value) => value.clone(),
+            None => return None,
+        };
+        json_parse_value(column, Some(&value)).ok()
+    }
+
+    fn value_to_datums(columns: &[SourceColumnDesc], map: &BTreeMap<String, Value>) -> Vec<Datum> {
+        columns
+            .iter()
+            .map(|column| {
+                if column.skip_parse {
+                    None
+                } else {
+                    Self::parse_value(column, map.get(&column.name))
+                }
+            })
+            .collect()
+    }
+}
+
+impl SourceParser for CanalJsonParser {
+    fn parse(&self, payload: &[u8], columns: &[SourceColumnDesc]) -> Result<Event> {
+        let event: CanalEvent = serde_json::from_slice(payload)
+            .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
+
+        let op = event.op.as_str();
+        // Schema changes such as `ALTER` and transaction boundaries carry no rows.
+        if event.is_ddl || !matches!(op, CANAL_INSERT_OP | CANAL_UPDATE_OP | CANAL_DELETE_OP) {
+            return Ok(Event::default());
+        }
+        let data = event.data.ok_or_else(|| {
+            RwError::from(ProtocolError(format!(
+                "data is missing for canal op {}",
+                op
+            )))
+        })?;
+
+        let mut result = Event::default();
+        match op {
+            CANAL_UPDATE_OP => {
+                let old = event.old.unwrap_or_default();
+                if old.len() != data.len() {
+                    return Err(RwError::from(ProtocolError(
+                        "old and data of canal update event mismatch".to_string(),
+                    )));
+                }
+                for (after, old) in data.iter().zip(old) {
+                    // Only the changed columns are kept in `old`.
+                    let mut before = after.clone();
+                    before.extend(old);
+
+                    let before = Self::value_to_datums(columns, &before);
+                    let after = Self::value_to_datums(columns, after);
+                    if before != after {
+                        result.ops.extend([Op::UpdateDelete, Op::UpdateInsert]);
+                        result.rows.extend([before, after]);
+                    }
+                }
+            }
+            _ => {
+                let row_op = if op == CANAL_DELETE_OP {
+                    Op::Delete
+                } else {
+                    Op::Insert
+                };
+                for row in &data {
+                    result.ops.push(row_op);
+                    result.rows.push(Self::value_to_datums(columns, row));
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::ColumnId;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+
+    fn get_test_columns() -> Vec<SourceColumnDesc> {
+        vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "name".to_string(),
+                data_type: DataType::Varchar,
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "weight".to_string(),
+                data_type: DataType::Float64,
+                column_id: ColumnId::from(2),
+                skip_parse: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_canal_json_parser() {
+        let parser = CanalJsonParser {};
+        let columns = get_test_columns();
+
+        let data = r#"{"data":[{"id":"1","name":"scooter","weight":"1.5"},{"id":"2","name":null,"weight":"8.1"}],"database":"test","es":1589373515000,"id":3,"isDdl":false,"mysqlType":{"id":"int(11)","name":"varchar(255)","weight":"double"},"old":null,"pkNames":["id"],"sql":"","table":"t","ts":1589373515477,"type":"INSERT"}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::Insert, Op::Insert]);
+        assert_eq!(
+            event.rows,
+            vec![
+                vec![
+                    Some(ScalarImpl::Int32(1)),
+                    Some(ScalarImpl::Utf8("scooter".to_string())),
+                    Some(ScalarImpl::Float64(1.5.into()))
+                ],
+                vec![
+                    Some(ScalarImpl::Int32(2)),
+                    None,
+                    Some(ScalarImpl::Float64(8.1.into()))
+                ],
+            ]
+        );
+
+        let data = r#"{"data":[{"id":"1","name":"car","weight":"1.5"}],"database":"test","es":1589373560000,"id":9,"isDdl":false,"mysqlType":{"id":"int(11)","name":"varchar(255)","weight":"double"},"old":[{"name":"scooter"}],"pkNames":["id"],"sql":"","table":"t","ts":1589373560798,"type":"UPDATE"}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::UpdateDelete, Op::UpdateInsert]);
+        assert_eq!(
+            event.rows[0][1],
+            Some(ScalarImpl::Utf8("scooter".to_string()))
+        );
+        assert_eq!(event.rows[1][1], Some(ScalarImpl::Utf8("car".to_string())));
+
+        let data = r#"{"data":[{"id":"2","name":null,"weight":"8.1"}],"database":"test","es":1589373600000,"id":10,"isDdl":false,"old":null,"pkNames":["id"],"sql":"","table":"t","ts":1589373600000,"type":"DELETE"}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::Delete]);
+        assert_eq!(event.rows[0][0], Some(ScalarImpl::Int32(2)));
+
+        let data = r#"{"data":null,"database":"test","es":1589373700000,"id":11,"isDdl":true,"old":null,"pkNames":null,"sql":"ALTER TABLE t ADD COLUMN c INT","table":"t","ts":1589373700000,"type":"ALTER"}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert!(event.ops.is_empty());
+    }
+}
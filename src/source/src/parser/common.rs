@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use num_traits::FromPrimitive;
+use risingwave_common::array::JsonbValue;
 use risingwave_common::error::ErrorCode::{self, InternalError};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::{DataType, Decimal, ScalarImpl, ScalarRef};
@@ -80,6 +81,17 @@ pub(crate) fn json_parse_value(
                 v.to_owned_scalar()
             ))
         }
+        DataType::Jsonb => make_ScalarImpl!(value, |v: &Value| ScalarImpl::Jsonb(
+            JsonbValue::from_serde(v.clone())
+        )),
+        DataType::Bytea => match value.and_then(|v| v.as_str()) {
+            None => Err(RwError::from(InternalError("parse error".to_string()))),
+            Some(v) => Ok(ScalarImpl::Bytea(v.parse()?)),
+        },
+        DataType::Uuid => match value.and_then(|v| v.as_str()) {
+            None => Err(RwError::from(InternalError("parse error".to_string()))),
+            Some(v) => Ok(ScalarImpl::Uuid(v.parse()?)),
+        },
         DataType::Date => match value.and_then(|v| v.as_str()) {
             None => Err(RwError::from(InternalError("parse error".to_string()))),
             Some(date_str) => match str_to_date(date_str) {
@@ -0,0 +1,244 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use apache_avro::from_avro_datum;
+use apache_avro::types::Value;
+use risingwave_common::array::Op;
+use risingwave_common::error::ErrorCode::ProtocolError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::Datum;
+
+use super::{DEBEZIUM_CREATE_OP, DEBEZIUM_DELETE_OP, DEBEZIUM_READ_OP, DEBEZIUM_UPDATE_OP};
+use crate::parser::avro_parser::from_avro_value;
+use crate::parser::schema_registry::ConfluentSchemaResolver;
+use crate::{Event, SourceColumnDesc, SourceParser};
+
+/// Parser for Debezium change events encoded by the Avro converter, whose schemas are stored in a
+/// Confluent schema registry.
+#[derive(Debug)]
+pub struct DebeziumAvroParser {
+    schema_resolver: ConfluentSchemaResolver,
+}
+
+impl DebeziumAvroParser {
+    pub fn new(schema_registry_url: &str) -> Result<Self> {
+        Ok(Self {
+            schema_resolver: ConfluentSchemaResolver::new(schema_registry_url)?,
+        })
+    }
+
+    /// Optional fields are encoded as a union with `null`.
+    fn unwrap_union(value: &Value) -> &Value {
+        match value {
+            Value::Union(_, value) => value,
+            value => value,
+        }
+    }
+
+    fn get_field<'a>(fields: &'a [(String, Value)], name: &str) -> Option<&'a Value> {
+        fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| Self::unwrap_union(value))
+    }
+
+    fn record_to_datums(
+        columns: &[SourceColumnDesc],
+        record: Option<&Value>,
+        name: &str,
+        op: &str,
+    ) -> Result<Vec<Datum>> {
+        let fields = match record {
+            Some(Value::Record(fields)) => fields,
+            _ => {
+                return Err(RwError::from(ProtocolError(format!(
+                    "{} is missing for debezium op {}",
+                    name, op
+                ))));
+            }
+        };
+
+        Ok(columns
+            .iter()
+            .map(|column| {
+                if column.skip_parse {
+                    return None;
+                }
+                match Self::get_field(fields, &column.name) {
+                    None | Some(Value::Null) => None,
+                    Some(value) => from_avro_value(column, value.clone()).ok(),
+                }
+            })
+            .collect())
+    }
+}
+
+impl SourceParser for DebeziumAvroParser {
+    fn parse(&self, payload: &[u8], columns: &[SourceColumnDesc]) -> Result<Event> {
+        let (schema, mut datum) = self.schema_resolver.resolve(payload)?;
+        let fields = match from_avro_datum(&schema, &mut datum, None) {
+            Ok(Value::Record(fields)) => fields,
+            Ok(_) => {
+                return Err(RwError::from(ProtocolError(
+                    "debezium avro event is not a record".to_string(),
+                )));
+            }
+            Err(e) => return Err(RwError::from(ProtocolError(e.to_string()))),
+        };
+
+        let op = match Self::get_field(&fields, "op") {
+            Some(Value::String(op)) => op.as_str(),
+            // Transaction metadata marking the `BEGIN` and `END` of a transaction.
+            _ => return Ok(Event::default()),
+        };
+        let before = Self::get_field(&fields, "before");
+        let after = Self::get_field(&fields, "after");
+
+        match op {
+            DEBEZIUM_UPDATE_OP => {
+                let before = Self::record_to_datums(columns, before, "before", op)?;
+                let after = Self::record_to_datums(columns, after, "after", op)?;
+                if before == after {
+                    return Ok(Event::default());
+                }
+                Ok(Event {
+                    ops: vec![Op::UpdateDelete, Op::UpdateInsert],
+                    rows: vec![before, after],
+                })
+            }
+            DEBEZIUM_CREATE_OP | DEBEZIUM_READ_OP => Ok(Event {
+                ops: vec![Op::Insert],
+                rows: vec![Self::record_to_datums(columns, after, "after", op)?],
+            }),
+            DEBEZIUM_DELETE_OP => Ok(Event {
+                ops: vec![Op::Delete],
+                rows: vec![Self::record_to_datums(columns, before, "before", op)?],
+            }),
+            _ => Err(RwError::from(ProtocolError(format!(
+                "unknown debezium op: {}",
+                op
+            )))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use apache_avro::{to_avro_datum, Schema};
+    use risingwave_common::catalog::ColumnId;
+    use risingwave_common::types::{DataType, ScalarImpl};
+
+    use super::*;
+
+    const ENVELOPE_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "Envelope",
+        "fields": [
+            {"name": "before", "type": ["null", {
+                "type": "record",
+                "name": "Value",
+                "fields": [
+                    {"name": "id", "type": "int"},
+                    {"name": "name", "type": ["null", "string"]}
+                ]
+            }]},
+            {"name": "after", "type": ["null", "Value"]},
+            {"name": "op", "type": "string"},
+            {"name": "ts_ms", "type": ["null", "long"]}
+        ]
+    }"#;
+
+    fn get_test_columns() -> Vec<SourceColumnDesc> {
+        vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "name".to_string(),
+                data_type: DataType::Varchar,
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+        ]
+    }
+
+    fn row(id: i32, name: Option<&str>) -> Value {
+        Value::Union(
+            1,
+            Box::new(Value::Record(vec![
+                ("id".to_string(), Value::Int(id)),
+                (
+                    "name".to_string(),
+                    match name {
+                        Some(name) => Value::Union(1, Box::new(Value::String(name.to_string()))),
+                        None => Value::Union(0, Box::new(Value::Null)),
+                    },
+                ),
+            ])),
+        )
+    }
+
+    fn encode(schema: &Schema, before: Value, after: Value, op: &str) -> Vec<u8> {
+        let event = Value::Record(vec![
+            ("before".to_string(), before),
+            ("after".to_string(), after),
+            ("op".to_string(), Value::String(op.to_string())),
+            ("ts_ms".to_string(), Value::Union(0, Box::new(Value::Null))),
+        ]);
+        let mut payload = vec![0, 0, 0, 0, 1];
+        payload.extend(to_avro_datum(schema, event).unwrap());
+        payload
+    }
+
+    #[test]
+    fn test_debezium_avro_parser() {
+        let schema = Schema::parse_str(ENVELOPE_SCHEMA).unwrap();
+        let parser = DebeziumAvroParser::new("http://localhost:8081").unwrap();
+        parser.schema_resolver.insert_schema(1, schema.clone());
+        let columns = get_test_columns();
+        let null = || Value::Union(0, Box::new(Value::Null));
+
+        let payload = encode(&schema, null(), row(1, Some("scooter")), DEBEZIUM_CREATE_OP);
+        let event = parser.parse(&payload, &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::Insert]);
+        assert_eq!(
+            event.rows,
+            vec![vec![
+                Some(ScalarImpl::Int32(1)),
+                Some(ScalarImpl::Utf8("scooter".to_string()))
+            ]]
+        );
+
+        let payload = encode(
+            &schema,
+            row(1, Some("scooter")),
+            row(1, None),
+            DEBEZIUM_UPDATE_OP,
+        );
+        let event = parser.parse(&payload, &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::UpdateDelete, Op::UpdateInsert]);
+        assert_eq!(event.rows[1], vec![Some(ScalarImpl::Int32(1)), None]);
+
+        let payload = encode(&schema, row(1, None), null(), DEBEZIUM_DELETE_OP);
+        let event = parser.parse(&payload, &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::Delete]);
+        assert_eq!(event.rows, vec![vec![Some(ScalarImpl::Int32(1)), None]]);
+
+        let payload = encode(&schema, null(), null(), DEBEZIUM_DELETE_OP);
+        assert!(parser.parse(&payload, &columns).is_err());
+    }
+}
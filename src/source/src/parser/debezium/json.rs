@@ -24,20 +24,10 @@ use risingwave_common::types::Datum;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::{DEBEZIUM_CREATE_OP, DEBEZIUM_DELETE_OP, DEBEZIUM_READ_OP, DEBEZIUM_UPDATE_OP};
 use crate::parser::common::json_parse_value;
 use crate::{Event, SourceColumnDesc, SourceParser};
 
-const DEBEZIUM_READ_OP: &str = "r";
-const DEBEZIUM_CREATE_OP: &str = "c";
-const DEBEZIUM_UPDATE_OP: &str = "u";
-const DEBEZIUM_DELETE_OP: &str = "d";
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct DebeziumEvent {
-    pub payload: Payload,
-}
-
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Payload {
@@ -45,7 +35,7 @@ pub struct Payload {
     pub after: Option<BTreeMap<String, Value>>,
     pub op: String,
     #[serde(rename = "ts_ms")]
-    pub ts_ms: i64,
+    pub ts_ms: Option<i64>,
 }
 
 #[derive(Debug)]
@@ -68,10 +58,32 @@ impl DebeziumJsonParser {
 
 impl SourceParser for DebeziumJsonParser {
     fn parse(&self, payload: &[u8], columns: &[SourceColumnDesc]) -> Result<Event> {
-        let event: DebeziumEvent = serde_json::from_slice(payload)
+        let event: Value = serde_json::from_slice(payload)
             .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
 
-        let mut payload = event.payload;
+        // With `schemas.enable` set on the JSON converter, the change event is wrapped in an
+        // envelope together with its schema, otherwise it's the payload itself.
+        let payload = match event {
+            Value::Object(mut envelope) if envelope.contains_key("payload") => {
+                envelope.remove("payload").unwrap()
+            }
+            event => event,
+        };
+
+        match &payload {
+            // The tombstone following a delete event, which is only meaningful to log compaction.
+            Value::Null => return Ok(Event::default()),
+            // Transaction metadata marking the `BEGIN` and `END` of a transaction.
+            Value::Object(payload)
+                if !payload.contains_key("op") && payload.contains_key("status") =>
+            {
+                return Ok(Event::default());
+            }
+            _ => {}
+        }
+
+        let mut payload: Payload = serde_json::from_value(payload)
+            .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
 
         match payload.op.as_str() {
             DEBEZIUM_UPDATE_OP => {
@@ -329,4 +341,34 @@ mod test {
         assert_eq!(result.rows.len(), 0);
         assert_eq!(result.ops.len(), 0);
     }
+
+    #[test]
+    fn test_debezium_json_parser_without_schema() {
+        let data = r#"{"before":null,"after":{"id":103,"name":"12-pack drill bits","description":"12-pack of drill bits","weight":0.8},"source":{"version":"1.7.1.Final","connector":"mysql","name":"dbserver1","ts_ms":1639551564000,"snapshot":"false","db":"inventory","table":"products"},"op":"c","ts_ms":1639551564960,"transaction":null}"#;
+        let parser = DebeziumJsonParser {};
+        let columns = get_test_columns();
+        let result = parser.parse(data.as_ref(), columns.as_ref()).unwrap();
+        assert_eq!(result.ops, vec![Op::Insert]);
+        assert_eq!(result.rows[0][0], Some(ScalarImpl::Int32(103)));
+        assert_eq!(result.rows[0][3], Some(ScalarImpl::Float64(0.8.into())));
+    }
+
+    #[test]
+    fn test_debezium_json_parser_tombstone_and_transaction() {
+        let parser = DebeziumJsonParser {};
+        let columns = get_test_columns();
+
+        let data = r#"{"schema":null,"payload":null}"#;
+        let result = parser.parse(data.as_ref(), columns.as_ref()).unwrap();
+        assert!(result.ops.is_empty());
+
+        let data =
+            r#"{"status":"BEGIN","id":"571:53195829","event_count":null,"data_collections":null}"#;
+        let result = parser.parse(data.as_ref(), columns.as_ref()).unwrap();
+        assert!(result.ops.is_empty());
+
+        let data = r#"{"status":"END","id":"571:53195832","event_count":2,"data_collections":[{"data_collection":"s1.a","event_count":1}]}"#;
+        let result = parser.parse(data.as_ref(), columns.as_ref()).unwrap();
+        assert!(result.ops.is_empty());
+    }
 }
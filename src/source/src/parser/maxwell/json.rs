@@ -0,0 +1,175 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use risingwave_common::array::Op;
+use risingwave_common::error::ErrorCode::ProtocolError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::Datum;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::parser::common::json_parse_value;
+use crate::{Event, SourceColumnDesc, SourceParser};
+
+const MAXWELL_INSERT_OP: &str = "insert";
+const MAXWELL_BOOTSTRAP_INSERT_OP: &str = "bootstrap-insert";
+const MAXWELL_UPDATE_OP: &str = "update";
+const MAXWELL_DELETE_OP: &str = "delete";
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaxwellEvent {
+    #[serde(rename = "type")]
+    pub op: String,
+    /// The row after an insert or update, or the row deleted.
+    pub data: Option<BTreeMap<String, Value>>,
+    /// The previous values of the columns changed by an update.
+    pub old: Option<BTreeMap<String, Value>>,
+    pub ts: Option<i64>,
+}
+
+/// Parser for the JSON change events produced by Maxwell from the MySQL binlog.
+#[derive(Debug)]
+pub struct MaxwellParser {}
+
+impl MaxwellParser {
+    fn value_to_datums(columns: &[SourceColumnDesc], map: &BTreeMap<String, Value>) -> Vec<Datum> {
+        columns
+            .iter()
+            .map(|column| {
+                if column.skip_parse {
+                    None
+                } else {
+                    json_parse_value(column, map.get(&column.name)).ok()
+                }
+            })
+            .collect()
+    }
+}
+
+impl SourceParser for MaxwellParser {
+    fn parse(&self, payload: &[u8], columns: &[SourceColumnDesc]) -> Result<Event> {
+        let event: MaxwellEvent = serde_json::from_slice(payload)
+            .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
+
+        let op = event.op.as_str();
+        let data = match op {
+            MAXWELL_INSERT_OP
+            | MAXWELL_BOOTSTRAP_INSERT_OP
+            | MAXWELL_UPDATE_OP
+            | MAXWELL_DELETE_OP => event.data.ok_or_else(|| {
+                RwError::from(ProtocolError(format!(
+                    "data is missing for maxwell op {}",
+                    op
+                )))
+            })?,
+            // `bootstrap-start`, `bootstrap-complete` and schema changes such as `table-alter`
+            // carry no rows.
+            _ => return Ok(Event::default()),
+        };
+
+        match op {
+            MAXWELL_UPDATE_OP => {
+                // Only the changed columns are kept in `old`.
+                let mut old = data.clone();
+                old.extend(event.old.unwrap_or_default());
+
+                let before = Self::value_to_datums(columns, &old);
+                let after = Self::value_to_datums(columns, &data);
+                if before == after {
+                    return Ok(Event::default());
+                }
+                Ok(Event {
+                    ops: vec![Op::UpdateDelete, Op::UpdateInsert],
+                    rows: vec![before, after],
+                })
+            }
+            MAXWELL_DELETE_OP => Ok(Event {
+                ops: vec![Op::Delete],
+                rows: vec![Self::value_to_datums(columns, &data)],
+            }),
+            _ => Ok(Event {
+                ops: vec![Op::Insert],
+                rows: vec![Self::value_to_datums(columns, &data)],
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::ColumnId;
+    use risingwave_common::types::{DataType, ScalarImpl};
+
+    use super::*;
+
+    fn get_test_columns() -> Vec<SourceColumnDesc> {
+        vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "name".to_string(),
+                data_type: DataType::Varchar,
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_maxwell_parser() {
+        let parser = MaxwellParser {};
+        let columns = get_test_columns();
+
+        let data = r#"{"database":"test","table":"t","type":"insert","ts":1449786310,"xid":940752,"commit":true,"data":{"id":1,"name":"scooter","weight":1.5}}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::Insert]);
+        assert_eq!(
+            event.rows,
+            vec![vec![
+                Some(ScalarImpl::Int32(1)),
+                Some(ScalarImpl::Utf8("scooter".to_string()))
+            ]]
+        );
+
+        let data = r#"{"database":"test","table":"t","type":"update","ts":1449786341,"xid":940786,"commit":true,"data":{"id":1,"name":"car","weight":1.5},"old":{"name":"scooter"}}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::UpdateDelete, Op::UpdateInsert]);
+        assert_eq!(
+            event.rows[0][1],
+            Some(ScalarImpl::Utf8("scooter".to_string()))
+        );
+        assert_eq!(event.rows[1][1], Some(ScalarImpl::Utf8("car".to_string())));
+
+        // Only a column not in the source is updated.
+        let data = r#"{"database":"test","table":"t","type":"update","ts":1449786341,"xid":940787,"commit":true,"data":{"id":1,"name":"car","weight":2.5},"old":{"weight":1.5}}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert!(event.ops.is_empty());
+
+        let data = r#"{"database":"test","table":"t","type":"delete","ts":1449786400,"xid":940800,"commit":true,"data":{"id":1,"name":"car","weight":2.5}}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert_eq!(event.ops, vec![Op::Delete]);
+        assert_eq!(event.rows[0][0], Some(ScalarImpl::Int32(1)));
+
+        let data =
+            r#"{"database":"test","table":"t","type":"bootstrap-start","ts":1450557744,"data":{}}"#;
+        let event = parser.parse(data.as_bytes(), &columns).unwrap();
+        assert!(event.ops.is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{ByteaValue, JsonbValue};
+use risingwave_common::error::ErrorCode::ProtocolError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum, NaiveDateTimeWrapper, ScalarImpl};
+use serde_json::{Map, Value};
+
+/// The metadata of a message that can be declared as a column of a source, e.g. `_key BYTEA`.
+/// These columns are populated from the message metadata instead of parsed from the payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceMetaColumn {
+    /// `_key`: the message key, `BYTEA` or `VARCHAR`.
+    Key,
+    /// `_timestamp`: the message timestamp, `TIMESTAMP`, `TIMESTAMPZ` or milliseconds as `BIGINT`.
+    Timestamp,
+    /// `_partition`: the split the message comes from, `VARCHAR`, `INT` or `BIGINT`.
+    Partition,
+    /// `_offset`: the offset of the message in its split, `VARCHAR` or `BIGINT`.
+    Offset,
+    /// `_headers`: the message headers as a JSON object, `JSONB` or `VARCHAR`.
+    Headers,
+}
+
+impl SourceMetaColumn {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "_key" => Some(Self::Key),
+            "_timestamp" => Some(Self::Timestamp),
+            "_partition" => Some(Self::Partition),
+            "_offset" => Some(Self::Offset),
+            "_headers" => Some(Self::Headers),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Key => "_key",
+            Self::Timestamp => "_timestamp",
+            Self::Partition => "_partition",
+            Self::Offset => "_offset",
+            Self::Headers => "_headers",
+        }
+    }
+
+    /// Check whether the metadata can be exposed as a column of the given type.
+    pub fn check_data_type(&self, data_type: &DataType) -> Result<()> {
+        let supported = match self {
+            Self::Key => matches!(data_type, DataType::Bytea | DataType::Varchar),
+            Self::Timestamp => matches!(
+                data_type,
+                DataType::Timestamp | DataType::Timestampz | DataType::Int64
+            ),
+            Self::Partition => matches!(
+                data_type,
+                DataType::Varchar | DataType::Int32 | DataType::Int64
+            ),
+            Self::Offset => matches!(data_type, DataType::Varchar | DataType::Int64),
+            Self::Headers => matches!(data_type, DataType::Jsonb | DataType::Varchar),
+        };
+        if supported {
+            Ok(())
+        } else {
+            Err(RwError::from(ProtocolError(format!(
+                "metadata column {} can not be of type {:?}",
+                self.name(),
+                data_type
+            ))))
+        }
+    }
+
+    /// Extract the value of this metadata column from a message.
+    pub(crate) fn extract(
+        &self,
+        data_type: &DataType,
+        split_id: &str,
+        offset: &str,
+        meta: &risingwave_connector::SourceMeta,
+    ) -> Datum {
+        match self {
+            Self::Key => meta.key.as_ref().map(|key| match data_type {
+                DataType::Varchar => ScalarImpl::Utf8(String::from_utf8_lossy(key).into_owned()),
+                _ => ScalarImpl::Bytea(ByteaValue::from(key.as_ref())),
+            }),
+            Self::Timestamp => meta.timestamp.and_then(|millis| match data_type {
+                DataType::Timestamp => NaiveDateTimeWrapper::with_secs_nsecs(
+                    millis.div_euclid(1000),
+                    millis.rem_euclid(1000) as u32 * 1_000_000,
+                )
+                .ok()
+                .map(ScalarImpl::NaiveDateTime),
+                // `TIMESTAMPZ` is stored as microseconds since unix epoch.
+                DataType::Timestampz => Some(ScalarImpl::Int64(millis * 1000)),
+                _ => Some(ScalarImpl::Int64(millis)),
+            }),
+            Self::Partition => Self::extract_string(data_type, split_id),
+            Self::Offset => Self::extract_string(data_type, offset),
+            Self::Headers => {
+                let headers: Map<String, Value> = meta
+                    .headers
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = value.as_ref().map_or(Value::Null, |value| {
+                            Value::String(String::from_utf8_lossy(value).into_owned())
+                        });
+                        (key.clone(), value)
+                    })
+                    .collect();
+                let headers = Value::Object(headers);
+                Some(match data_type {
+                    DataType::Jsonb => ScalarImpl::Jsonb(JsonbValue::from_serde(headers)),
+                    _ => ScalarImpl::Utf8(headers.to_string()),
+                })
+            }
+        }
+    }
+
+    fn extract_string(data_type: &DataType, value: &str) -> Datum {
+        match data_type {
+            DataType::Int32 => value.parse().ok().map(ScalarImpl::Int32),
+            DataType::Int64 => value.parse().ok().map(ScalarImpl::Int64),
+            _ => Some(ScalarImpl::Utf8(value.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use risingwave_connector::SourceMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_extract_meta_columns() {
+        let meta = SourceMeta {
+            key: Some(Bytes::from("user-1")),
+            timestamp: Some(1_600_000_000_123),
+            headers: vec![
+                ("trace".to_string(), Some(Bytes::from("abc"))),
+                ("empty".to_string(), None),
+            ],
+        };
+        let extract = |column: &str, data_type: DataType| {
+            SourceMetaColumn::from_name(column)
+                .unwrap()
+                .extract(&data_type, "3", "42", &meta)
+        };
+
+        assert_eq!(
+            extract("_key", DataType::Bytea),
+            Some(ScalarImpl::Bytea(ByteaValue::from("user-1".as_bytes())))
+        );
+        assert_eq!(
+            extract("_key", DataType::Varchar),
+            Some(ScalarImpl::Utf8("user-1".to_string()))
+        );
+        assert_eq!(
+            extract("_timestamp", DataType::Timestamp),
+            Some(ScalarImpl::NaiveDateTime(
+                NaiveDateTimeWrapper::with_secs_nsecs(1_600_000_000, 123_000_000).unwrap()
+            ))
+        );
+        assert_eq!(
+            extract("_timestamp", DataType::Int64),
+            Some(ScalarImpl::Int64(1_600_000_000_123))
+        );
+        assert_eq!(
+            extract("_partition", DataType::Int32),
+            Some(ScalarImpl::Int32(3))
+        );
+        assert_eq!(
+            extract("_offset", DataType::Varchar),
+            Some(ScalarImpl::Utf8("42".to_string()))
+        );
+        assert_eq!(
+            extract("_headers", DataType::Varchar),
+            Some(ScalarImpl::Utf8(
+                r#"{"empty":null,"trace":"abc"}"#.to_string()
+            ))
+        );
+
+        assert!(SourceMetaColumn::Key
+            .check_data_type(&DataType::Int32)
+            .is_err());
+        assert!(SourceMetaColumn::from_name("key").is_none());
+    }
+}
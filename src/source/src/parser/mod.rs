@@ -16,22 +16,30 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+pub use canal::*;
 pub use debezium::*;
 pub use json_parser::*;
+pub use maxwell::*;
+pub use meta::*;
 pub use protobuf_parser::*;
 use risingwave_common::array::Op;
 use risingwave_common::error::ErrorCode::ProtocolError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::Datum;
+use risingwave_connector::SourceMessage;
 
 use crate::{SourceColumnDesc, SourceFormat};
 
 #[allow(dead_code)]
 mod avro_parser;
+mod canal;
 mod common;
 mod debezium;
 mod json_parser;
+mod maxwell;
+mod meta;
 mod protobuf_parser;
+mod schema_registry;
 
 #[derive(Debug, Default)]
 pub struct Event {
@@ -53,15 +61,43 @@ pub enum SourceParserImpl {
     Json(JSONParser),
     Protobuf(ProtobufParser),
     DebeziumJson(DebeziumJsonParser),
+    DebeziumAvro(DebeziumAvroParser),
+    Maxwell(MaxwellParser),
+    CanalJson(CanalJsonParser),
 }
 
 impl SourceParserImpl {
-    pub fn parse(&self, payload: &[u8], columns: &[SourceColumnDesc]) -> Result<Event> {
-        match self {
+    /// Parse the payload of `message`, and fill the metadata columns such as `_key` with the
+    /// metadata of `message`.
+    pub fn parse(&self, message: &SourceMessage, columns: &[SourceColumnDesc]) -> Result<Event> {
+        let payload = match message.payload.as_deref() {
+            Some(payload) => payload,
+            None => return Ok(Event::default()),
+        };
+        let mut event = match self {
             Self::Json(parser) => parser.parse(payload, columns),
             Self::Protobuf(parser) => parser.parse(payload, columns),
             Self::DebeziumJson(parser) => parser.parse(payload, columns),
+            Self::DebeziumAvro(parser) => parser.parse(payload, columns),
+            Self::Maxwell(parser) => parser.parse(payload, columns),
+            Self::CanalJson(parser) => parser.parse(payload, columns),
+        }?;
+
+        for (idx, column) in columns.iter().enumerate() {
+            if let Some(meta_column) = SourceMetaColumn::from_name(&column.name) {
+                let datum = meta_column.extract(
+                    &column.data_type,
+                    &message.split_id,
+                    &message.offset,
+                    &message.meta,
+                );
+                for row in &mut event.rows {
+                    row[idx] = datum.clone();
+                }
+            }
         }
+
+        Ok(event)
     }
 
     pub fn create(
@@ -83,6 +119,11 @@ impl SourceParserImpl {
                 SourceParserImpl::Protobuf(ProtobufParser::new(schema_location, message_name)?)
             }
             SourceFormat::DebeziumJson => SourceParserImpl::DebeziumJson(DebeziumJsonParser {}),
+            SourceFormat::DebeziumAvro => {
+                SourceParserImpl::DebeziumAvro(DebeziumAvroParser::new(schema_location)?)
+            }
+            SourceFormat::Maxwell => SourceParserImpl::Maxwell(MaxwellParser {}),
+            SourceFormat::CanalJson => SourceParserImpl::CanalJson(CanalJsonParser {}),
             _ => {
                 return Err(RwError::from(ProtocolError(
                     "format not support".to_string(),
@@ -0,0 +1,131 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use apache_avro::Schema;
+use hyper::body::Buf;
+use hyper::{Client, StatusCode, Uri};
+use parking_lot::Mutex;
+use risingwave_common::error::ErrorCode::{InternalError, ProtocolError};
+use risingwave_common::error::{Result, RwError};
+use serde_derive::Deserialize;
+
+/// The first byte of a message in the Confluent wire format.
+const CONFLUENT_MAGIC_BYTE: u8 = 0;
+
+#[derive(Debug, Deserialize)]
+struct SchemaResponse {
+    schema: String,
+}
+
+/// Resolves the writer schema of messages in the Confluent wire format, i.e. a zero magic byte and
+/// a 4-byte big-endian schema id, followed by the Avro binary encoded datum. Schemas are fetched
+/// from the schema registry on first use and cached by id.
+#[derive(Debug)]
+pub struct ConfluentSchemaResolver {
+    url: String,
+    schemas: Mutex<HashMap<i32, Arc<Schema>>>,
+}
+
+impl ConfluentSchemaResolver {
+    pub fn new(url: &str) -> Result<Self> {
+        let url = url.trim_end_matches('/').to_string();
+        url.parse::<Uri>().map_err(|e| {
+            RwError::from(ProtocolError(format!(
+                "invalid schema registry url {}: {}",
+                url, e
+            )))
+        })?;
+        Ok(Self {
+            url,
+            schemas: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Split `payload` into its writer schema and the Avro datum.
+    pub fn resolve<'a>(&self, payload: &'a [u8]) -> Result<(Arc<Schema>, &'a [u8])> {
+        if payload.len() < 5 || payload[0] != CONFLUENT_MAGIC_BYTE {
+            return Err(RwError::from(ProtocolError(
+                "message is not in the confluent wire format".to_string(),
+            )));
+        }
+        let schema_id = i32::from_be_bytes(payload[1..5].try_into().unwrap());
+        Ok((self.get_schema(schema_id)?, &payload[5..]))
+    }
+
+    fn get_schema(&self, schema_id: i32) -> Result<Arc<Schema>> {
+        if let Some(schema) = self.schemas.lock().get(&schema_id) {
+            return Ok(schema.clone());
+        }
+
+        // Parsing is synchronous, so block the current worker thread on the first message of each
+        // schema. New schemas only show up when the upstream table is altered.
+        let schema = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(self.fetch_schema(schema_id))
+        })?;
+        let schema = Arc::new(schema);
+        self.schemas.lock().insert(schema_id, schema.clone());
+        Ok(schema)
+    }
+
+    async fn fetch_schema(&self, schema_id: i32) -> Result<Schema> {
+        let url: Uri = format!("{}/schemas/ids/{}", self.url, schema_id)
+            .parse()
+            .map_err(|e| RwError::from(InternalError(format!("{}", e))))?;
+        let res = Client::new().get(url).await.map_err(|e| {
+            RwError::from(InternalError(format!(
+                "failed to fetch schema {} from schema registry: {}",
+                schema_id, e
+            )))
+        })?;
+        if res.status() != StatusCode::OK {
+            return Err(RwError::from(InternalError(format!(
+                "failed to fetch schema {} from schema registry: {}",
+                schema_id,
+                res.status()
+            ))));
+        }
+        let body = hyper::body::aggregate(res)
+            .await
+            .map_err(|e| RwError::from(InternalError(e.to_string())))?;
+        let res: SchemaResponse = serde_json::from_reader(body.reader())
+            .map_err(|e| RwError::from(ProtocolError(e.to_string())))?;
+        Schema::parse_str(&res.schema).map_err(|e| RwError::from(ProtocolError(e.to_string())))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn insert_schema(&self, schema_id: i32, schema: Schema) {
+        self.schemas.lock().insert(schema_id, Arc::new(schema));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_schema() {
+        let resolver = ConfluentSchemaResolver::new("http://localhost:8081/").unwrap();
+        resolver.insert_schema(7, Schema::parse_str(r#""long""#).unwrap());
+
+        let (schema, datum) = resolver.resolve(&[0, 0, 0, 0, 7, 2]).unwrap();
+        assert_eq!(*schema, Schema::Long);
+        assert_eq!(datum, &[2]);
+
+        assert!(resolver.resolve(&[1, 0, 0, 0, 7, 2]).is_err());
+        assert!(resolver.resolve(&[0, 0, 0]).is_err());
+    }
+}
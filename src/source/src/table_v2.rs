@@ -148,6 +148,7 @@ impl StreamSourceReader for TableV2StreamReader {
         Ok(StreamChunkWithState {
             chunk,
             split_offset_mapping: None,
+            finished_splits: vec![],
         })
     }
 }
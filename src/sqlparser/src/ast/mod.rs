@@ -662,6 +662,26 @@ impl fmt::Display for ShowObject {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ShowCreateType {
+    Table,
+    MaterializedView,
+    Source,
+    Index,
+}
+
+impl fmt::Display for ShowCreateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShowCreateType::Table => f.write_str("TABLE"),
+            ShowCreateType::MaterializedView => f.write_str("MATERIALIZED VIEW"),
+            ShowCreateType::Source => f.write_str("SOURCE"),
+            ShowCreateType::Index => f.write_str("INDEX"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CommentObject {
@@ -779,6 +799,13 @@ pub enum Statement {
     },
     /// SHOW COMMAND
     ShowObjects(ShowObject),
+    /// SHOW CREATE COMMAND
+    ShowCreateObject {
+        /// Show create object type
+        create_type: ShowCreateType,
+        /// Show create object name
+        name: ObjectName,
+    },
     /// DROP
     Drop(DropStatement),
     /// SET <variable>
@@ -795,6 +822,11 @@ pub enum Statement {
     ///
     /// Note: this is a PostgreSQL-specific statement.
     ShowVariable { variable: Vec<Ident> },
+    /// RESET <variable>
+    ///
+    /// Note: this is a PostgreSQL-specific statement. `RESET ALL` is represented with the
+    /// variable `ALL`.
+    ResetVariable { variable: Ident },
     /// `{ BEGIN [ TRANSACTION | WORK ] | START TRANSACTION } ...`
     StartTransaction { modes: Vec<TransactionMode> },
     /// `SET TRANSACTION ...`
@@ -925,6 +957,10 @@ impl fmt::Display for Statement {
                 write!(f, "SHOW {}", show_object)?;
                 Ok(())
             }
+            Statement::ShowCreateObject { create_type, name } => {
+                write!(f, "SHOW CREATE {} {}", create_type, name)?;
+                Ok(())
+            }
             Statement::Insert {
                 table_name,
                 columns,
@@ -1131,6 +1167,7 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::ResetVariable { variable } => write!(f, "RESET {}", variable),
             Statement::StartTransaction { modes } => {
                 write!(f, "START TRANSACTION")?;
                 if !modes.is_empty() {
@@ -20,7 +20,9 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 use super::ObjectType;
-use crate::ast::{display_comma_separated, ColumnDef, ObjectName, SqlOption, TableConstraint};
+use crate::ast::{
+    display_comma_separated, ColumnDef, Expr, Ident, ObjectName, SqlOption, TableConstraint,
+};
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
 
@@ -72,18 +74,39 @@ macro_rules! impl_fmt_display {
 pub struct CreateSourceStatement {
     pub if_not_exists: bool,
     pub columns: Vec<ColumnDef>,
+    pub source_watermarks: Vec<SourceWatermark>,
     pub constraints: Vec<TableConstraint>,
     pub source_name: ObjectName,
     pub with_properties: WithProperties,
     pub source_schema: SourceSchema,
 }
 
+/// `WATERMARK FOR <column> AS <expr>` in the column list of `CREATE SOURCE`. The watermark of
+/// `column` is derived by evaluating `expr` on the column values read.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceWatermark {
+    pub column: Ident,
+    pub expr: Expr,
+}
+
+impl fmt::Display for SourceWatermark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WATERMARK FOR {} AS {}", self.column, self.expr)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SourceSchema {
     Protobuf(ProtobufSchema),
     // Keyword::PROTOBUF ProtobufSchema
-    Json, // Keyword::JSON
+    Json,         // Keyword::JSON
+    DebeziumJson, // Keyword::DEBEZIUM_JSON
+    DebeziumAvro(DebeziumAvroSchema),
+    // Keyword::DEBEZIUM_AVRO DebeziumAvroSchema
+    Maxwell,   // Keyword::MAXWELL
+    CanalJson, // Keyword::CANAL_JSON
 }
 
 impl ParseTo for SourceSchema {
@@ -93,9 +116,20 @@ impl ParseTo for SourceSchema {
         } else if p.parse_keywords(&[Keyword::PROTOBUF]) {
             impl_parse_to!(protobuf_schema: ProtobufSchema, p);
             SourceSchema::Protobuf(protobuf_schema)
+        } else if p.parse_keywords(&[Keyword::DEBEZIUM_JSON]) {
+            SourceSchema::DebeziumJson
+        } else if p.parse_keywords(&[Keyword::DEBEZIUM_AVRO]) {
+            impl_parse_to!(debezium_avro_schema: DebeziumAvroSchema, p);
+            SourceSchema::DebeziumAvro(debezium_avro_schema)
+        } else if p.parse_keywords(&[Keyword::MAXWELL]) {
+            SourceSchema::Maxwell
+        } else if p.parse_keywords(&[Keyword::CANAL_JSON]) {
+            SourceSchema::CanalJson
         } else {
             return Err(ParserError::ParserError(
-                "expected JSON | PROTOBUF after ROW FORMAT".to_string(),
+                "expected JSON | PROTOBUF | DEBEZIUM_JSON | DEBEZIUM_AVRO | MAXWELL | CANAL_JSON \
+                 after ROW FORMAT"
+                    .to_string(),
             ));
         };
         Ok(schema)
@@ -107,6 +141,12 @@ impl fmt::Display for SourceSchema {
         match self {
             SourceSchema::Protobuf(protobuf_schema) => write!(f, "PROTOBUF {}", protobuf_schema),
             SourceSchema::Json => write!(f, "JSON"),
+            SourceSchema::DebeziumJson => write!(f, "DEBEZIUM_JSON"),
+            SourceSchema::DebeziumAvro(debezium_avro_schema) => {
+                write!(f, "DEBEZIUM_AVRO {}", debezium_avro_schema)
+            }
+            SourceSchema::Maxwell => write!(f, "MAXWELL"),
+            SourceSchema::CanalJson => write!(f, "CANAL_JSON"),
         }
     }
 }
@@ -148,13 +188,63 @@ impl fmt::Display for ProtobufSchema {
     }
 }
 
+// sql_grammar!(DebeziumAvroSchema {
+//     [Keyword::ROW, Keyword::SCHEMA, Keyword::LOCATION, Keyword::CONFLUENT, Keyword::SCHEMA,
+//      Keyword::REGISTRY],
+//     row_schema_location: AstString,
+// });
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DebeziumAvroSchema {
+    pub row_schema_location: AstString,
+}
+
+impl ParseTo for DebeziumAvroSchema {
+    fn parse_to(p: &mut Parser) -> Result<Self, ParserError> {
+        impl_parse_to!(
+            [
+                Keyword::ROW,
+                Keyword::SCHEMA,
+                Keyword::LOCATION,
+                Keyword::CONFLUENT,
+                Keyword::SCHEMA,
+                Keyword::REGISTRY
+            ],
+            p
+        );
+        impl_parse_to!(row_schema_location: AstString, p);
+        Ok(Self {
+            row_schema_location,
+        })
+    }
+}
+
+impl fmt::Display for DebeziumAvroSchema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut v: Vec<String> = vec![];
+        impl_fmt_display!(
+            [
+                Keyword::ROW,
+                Keyword::SCHEMA,
+                Keyword::LOCATION,
+                Keyword::CONFLUENT,
+                Keyword::SCHEMA,
+                Keyword::REGISTRY
+            ],
+            v
+        );
+        impl_fmt_display!(row_schema_location, v, self);
+        v.iter().join(" ").fmt(f)
+    }
+}
+
 impl ParseTo for CreateSourceStatement {
     fn parse_to(p: &mut Parser) -> Result<Self, ParserError> {
         impl_parse_to!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], p);
         impl_parse_to!(source_name: ObjectName, p);
 
         // parse columns
-        let (columns, constraints) = p.parse_columns()?;
+        let (columns, source_watermarks, constraints) = p.parse_columns_with_watermark()?;
 
         impl_parse_to!(with_properties: WithProperties, p);
         impl_parse_to!([Keyword::ROW, Keyword::FORMAT], p);
@@ -162,6 +252,7 @@ impl ParseTo for CreateSourceStatement {
         Ok(Self {
             if_not_exists,
             columns,
+            source_watermarks,
             constraints,
             source_name,
             with_properties,
@@ -175,6 +266,12 @@ impl fmt::Display for CreateSourceStatement {
         let mut v: Vec<String> = vec![];
         impl_fmt_display!(if_not_exists => [Keyword::IF, Keyword::NOT, Keyword::EXISTS], v, self);
         impl_fmt_display!(source_name, v, self);
+        if !self.columns.is_empty() || !self.source_watermarks.is_empty() {
+            let mut defs = self.columns.iter().map(|c| c.to_string()).collect_vec();
+            defs.extend(self.source_watermarks.iter().map(|w| w.to_string()));
+            defs.extend(self.constraints.iter().map(|c| c.to_string()));
+            v.push(format!("({})", defs.join(", ")));
+        }
         impl_fmt_display!(with_properties, v, self);
         impl_fmt_display!([Keyword::ROW, Keyword::FORMAT], v);
         impl_fmt_display!(source_schema, v, self);
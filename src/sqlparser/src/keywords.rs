@@ -105,6 +105,7 @@ define_keywords!(
     CACHE,
     CALL,
     CALLED,
+    CANAL_JSON,
     CARDINALITY,
     CASCADE,
     CASCADED,
@@ -130,6 +131,7 @@ define_keywords!(
     COMMIT,
     COMMITTED,
     CONDITION,
+    CONFLUENT,
     CONNECT,
     CONSTRAINT,
     CONTAINS,
@@ -165,6 +167,8 @@ define_keywords!(
     DATE,
     DAY,
     DEALLOCATE,
+    DEBEZIUM_AVRO,
+    DEBEZIUM_JSON,
     DEC,
     DECIMAL,
     DECLARE,
@@ -251,6 +255,7 @@ define_keywords!(
     ISOLATION,
     JOIN,
     JSON,
+    JSONB,
     KEY,
     LANGUAGE,
     LARGE,
@@ -270,6 +275,7 @@ define_keywords!(
     MATCH,
     MATERIALIZED,
     MAX,
+    MAXWELL,
     MEMBER,
     MERGE,
     MESSAGE,
@@ -350,6 +356,7 @@ define_keywords!(
     REFERENCES,
     REFERENCING,
     REGCLASS,
+    REGISTRY,
     REGR_AVGX,
     REGR_AVGY,
     REGR_COUNT,
@@ -364,6 +371,7 @@ define_keywords!(
     REPAIR,
     REPEATABLE,
     REPLACE,
+    RESET,
     RESTRICT,
     RESULT,
     RETURN,
@@ -483,6 +491,7 @@ define_keywords!(
     VIEW,
     VIEWS,
     VIRTUAL,
+    WATERMARK,
     WHEN,
     WHENEVER,
     WHERE,
@@ -174,6 +174,9 @@ impl Parser {
                 Keyword::COPY => Ok(self.parse_copy()?),
                 Keyword::SET => Ok(self.parse_set()?),
                 Keyword::SHOW => Ok(self.parse_show()?),
+                Keyword::RESET => Ok(Statement::ResetVariable {
+                    variable: self.parse_identifier()?,
+                }),
                 Keyword::DESCRIBE => Ok(Statement::Describe {
                     name: self.parse_object_name()?,
                 }),
@@ -210,6 +213,8 @@ impl Parser {
     }
 
     pub fn parse_analyze(&mut self) -> Result<Statement, ParserError> {
+        // `ANALYZE TABLE t` is accepted as well for compatibility.
+        let _ = self.parse_keyword(Keyword::TABLE);
         let table_name = self.parse_object_name()?;
 
         Ok(Statement::Analyze { table_name })
@@ -962,16 +967,27 @@ impl Parser {
             Token::TildeAsterisk => Some(BinaryOperator::PGRegexIMatch),
             Token::ExclamationMarkTilde => Some(BinaryOperator::PGRegexNotMatch),
             Token::ExclamationMarkTildeAsterisk => Some(BinaryOperator::PGRegexNotIMatch),
+            Token::Arrow => Some(BinaryOperator::PGJsonGet),
+            Token::LongArrow => Some(BinaryOperator::PGJsonGetText),
+            Token::HashArrow => Some(BinaryOperator::PGJsonGetPath),
+            Token::HashLongArrow => Some(BinaryOperator::PGJsonGetPathText),
+            Token::AtArrow => Some(BinaryOperator::PGJsonContains),
             Token::Word(w) => match w.keyword {
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
                 Keyword::LIKE => Some(BinaryOperator::Like),
                 Keyword::ILIKE => Some(BinaryOperator::ILike),
+                Keyword::SIMILAR => {
+                    self.expect_keyword(Keyword::TO)?;
+                    Some(BinaryOperator::SimilarTo)
+                }
                 Keyword::NOT => {
                     if self.parse_keyword(Keyword::LIKE) {
                         Some(BinaryOperator::NotLike)
                     } else if self.parse_keyword(Keyword::ILIKE) {
                         Some(BinaryOperator::NotILike)
+                    } else if self.parse_keywords(&[Keyword::SIMILAR, Keyword::TO]) {
+                        Some(BinaryOperator::NotSimilarTo)
                     } else {
                         None
                     }
@@ -1129,6 +1145,7 @@ impl Parser {
                 Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
                 _ => Ok(0),
             },
             Token::Word(w) if w.keyword == Keyword::IS => Ok(17),
@@ -1136,6 +1153,7 @@ impl Parser {
             Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+            Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
             Token::Eq
             | Token::Lt
             | Token::LtEq
@@ -1150,6 +1168,11 @@ impl Parser {
             | Token::Spaceship => Ok(20),
             Token::Pipe => Ok(21),
             Token::Caret | Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(22),
+            Token::Arrow
+            | Token::LongArrow
+            | Token::HashArrow
+            | Token::HashLongArrow
+            | Token::AtArrow => Ok(22),
             Token::Ampersand => Ok(23),
             Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
             Token::Mul | Token::Div | Token::Mod | Token::StringConcat => Ok(40),
@@ -1520,15 +1543,37 @@ impl Parser {
     }
 
     pub fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
+        let (columns, _, constraints) = self.parse_columns_inner(false)?;
+        Ok((columns, constraints))
+    }
+
+    /// Parse the column list of `CREATE SOURCE`, which may also contain
+    /// `WATERMARK FOR <column> AS <expr>` definitions.
+    pub fn parse_columns_with_watermark(
+        &mut self,
+    ) -> Result<(Vec<ColumnDef>, Vec<SourceWatermark>, Vec<TableConstraint>), ParserError> {
+        self.parse_columns_inner(true)
+    }
+
+    fn parse_columns_inner(
+        &mut self,
+        allow_watermark: bool,
+    ) -> Result<(Vec<ColumnDef>, Vec<SourceWatermark>, Vec<TableConstraint>), ParserError> {
         let mut columns = vec![];
+        let mut watermarks = vec![];
         let mut constraints = vec![];
         if !self.consume_token(&Token::LParen) || self.consume_token(&Token::RParen) {
-            return Ok((columns, constraints));
+            return Ok((columns, watermarks, constraints));
         }
 
         loop {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
+            } else if allow_watermark && self.parse_keywords(&[Keyword::WATERMARK, Keyword::FOR]) {
+                let column = self.parse_identifier()?;
+                self.expect_keyword(Keyword::AS)?;
+                let expr = self.parse_expr()?;
+                watermarks.push(SourceWatermark { column, expr });
             } else if let Token::Word(_) = self.peek_token() {
                 columns.push(self.parse_column_def()?);
             } else {
@@ -1543,7 +1588,7 @@ impl Parser {
             }
         }
 
-        Ok((columns, constraints))
+        Ok((columns, watermarks, constraints))
     }
 
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
@@ -2000,6 +2045,7 @@ impl Parser {
                     }
                 }
                 Keyword::BYTEA => Ok(DataType::Bytea),
+                Keyword::JSONB => Ok(DataType::Jsonb),
                 Keyword::NUMERIC | Keyword::DECIMAL | Keyword::DEC => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
                     Ok(DataType::Decimal(precision, scale))
@@ -2460,6 +2506,20 @@ impl Parser {
 
     pub fn parse_set(&mut self) -> Result<Statement, ParserError> {
         let modifier = self.parse_one_of_keywords(&[Keyword::SESSION, Keyword::LOCAL]);
+        if self.parse_keywords(&[Keyword::TIME, Keyword::ZONE]) {
+            // `SET TIME ZONE <value>` is an alias of `SET timezone = <value>`.
+            let token = self.peek_token();
+            let value = match (self.parse_value(), token) {
+                (Ok(value), _) => SetVariableValue::Literal(value),
+                (Err(_), Token::Word(ident)) => SetVariableValue::Ident(ident.to_ident()),
+                (Err(_), unexpected) => self.expected("variable value", unexpected)?,
+            };
+            return Ok(Statement::SetVariable {
+                local: modifier == Some(Keyword::LOCAL),
+                variable: Ident::new("timezone"),
+                value: vec![value],
+            });
+        }
         let variable = self.parse_identifier()?;
         if self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
             let mut values = vec![];
@@ -2543,6 +2603,9 @@ impl Parser {
                             .expected("VIEWS or SOURCES after MATERIALIZED", self.peek_token());
                     }
                 }
+                Keyword::CREATE => {
+                    return self.parse_show_create();
+                }
                 Keyword::COLUMNS => {
                     if self.parse_keyword(Keyword::FROM) {
                         return Ok(Statement::ShowColumn {
@@ -2561,6 +2624,28 @@ impl Parser {
         })
     }
 
+    /// Parse the object type and name after `show create`.
+    pub fn parse_show_create(&mut self) -> Result<Statement, ParserError> {
+        let create_type = if self.parse_keyword(Keyword::TABLE) {
+            ShowCreateType::Table
+        } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) {
+            ShowCreateType::MaterializedView
+        } else if self.parse_keyword(Keyword::SOURCE) {
+            ShowCreateType::Source
+        } else if self.parse_keyword(Keyword::INDEX) {
+            ShowCreateType::Index
+        } else {
+            return self.expected(
+                "TABLE, MATERIALIZED VIEW, SOURCE or INDEX after SHOW CREATE",
+                self.peek_token(),
+            );
+        };
+        Ok(Statement::ShowCreateObject {
+            create_type,
+            name: self.parse_object_name()?,
+        })
+    }
+
     /// Parser `from schema` after `show tables` and `show materialized views`, if not conclude
     /// `from` then use default schema name.
     pub fn parse_from_and_identifier(&mut self) -> Result<Option<Ident>, ParserError> {
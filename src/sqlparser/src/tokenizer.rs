@@ -134,6 +134,16 @@ pub enum Token {
     DoubleExclamationMark,
     /// AtSign `@` used for PostgreSQL abs operator
     AtSign,
+    /// `->`, access a JSON object field or array element in PostgreSQL
+    Arrow,
+    /// `->>`, access a JSON object field or array element as text in PostgreSQL
+    LongArrow,
+    /// `#>`, access a JSON value at the specified path in PostgreSQL
+    HashArrow,
+    /// `#>>`, access a JSON value at the specified path as text in PostgreSQL
+    HashLongArrow,
+    /// `@>`, JSON containment operator in PostgreSQL
+    AtArrow,
     /// `|/`, a square root math operator in PostgreSQL
     PGSquareRoot,
     /// `||/` , a cube root math operator in PostgreSQL
@@ -189,6 +199,11 @@ impl fmt::Display for Token {
             Token::ExclamationMarkTilde => f.write_str("!~"),
             Token::ExclamationMarkTildeAsterisk => f.write_str("!~*"),
             Token::AtSign => f.write_str("@"),
+            Token::Arrow => f.write_str("->"),
+            Token::LongArrow => f.write_str("->>"),
+            Token::HashArrow => f.write_str("#>"),
+            Token::HashLongArrow => f.write_str("#>>"),
+            Token::AtArrow => f.write_str("@>"),
             Token::ShiftLeft => f.write_str("<<"),
             Token::ShiftRight => f.write_str(">>"),
             Token::PGSquareRoot => f.write_str("|/"),
@@ -475,6 +490,13 @@ impl<'a> Tokenizer<'a> {
                                 comment,
                             })))
                         }
+                        Some('>') => {
+                            chars.next(); // consume the '>'
+                            match chars.peek() {
+                                Some('>') => self.consume_and_return(chars, Token::LongArrow),
+                                _ => Ok(Some(Token::Arrow)),
+                            }
+                        }
                         // a regular '-' operator
                         _ => Ok(Some(Token::Minus)),
                     }
@@ -576,8 +598,26 @@ impl<'a> Tokenizer<'a> {
                         _ => Ok(Some(Token::Tilde)),
                     }
                 }
-                '#' => self.consume_and_return(chars, Token::Sharp),
-                '@' => self.consume_and_return(chars, Token::AtSign),
+                '#' => {
+                    chars.next(); // consume
+                    match chars.peek() {
+                        Some('>') => {
+                            chars.next();
+                            match chars.peek() {
+                                Some('>') => self.consume_and_return(chars, Token::HashLongArrow),
+                                _ => Ok(Some(Token::HashArrow)),
+                            }
+                        }
+                        _ => Ok(Some(Token::Sharp)),
+                    }
+                }
+                '@' => {
+                    chars.next(); // consume
+                    match chars.peek() {
+                        Some('>') => self.consume_and_return(chars, Token::AtArrow),
+                        _ => Ok(Some(Token::AtSign)),
+                    }
+                }
                 other => self.consume_and_return(chars, Token::Char(other)),
             },
             None => Ok(None),
@@ -1221,6 +1261,41 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_pg_json_ops() {
+        let sql = "SELECT j->'a', j->>0, j#>'{a}', j#>>'{a}', j@>'{}'";
+        let mut tokenizer = Tokenizer::new(sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("j", None),
+            Token::Arrow,
+            Token::SingleQuotedString("a".into()),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("j", None),
+            Token::LongArrow,
+            Token::Number("0".into(), false),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("j", None),
+            Token::HashArrow,
+            Token::SingleQuotedString("{a}".into()),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("j", None),
+            Token::HashLongArrow,
+            Token::SingleQuotedString("{a}".into()),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("j", None),
+            Token::AtArrow,
+            Token::SingleQuotedString("{}".into()),
+        ];
+        compare(expected, tokens);
+    }
+
     fn compare(expected: Vec<Token>, actual: Vec<Token>) {
         // println!("------------------------------");
         // println!("tokens   = {:?}", actual);
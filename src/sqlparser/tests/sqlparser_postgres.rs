@@ -445,6 +445,7 @@ fn parse_set() {
 
     one_statement_parses_to("SET a TO b", "SET a = b");
     one_statement_parses_to("SET SESSION a = b", "SET a = b");
+    one_statement_parses_to("SET TIME ZONE 'UTC'", "SET timezone = 'UTC'");
 
     assert_eq!(
         parse_sql_statements("SET"),
@@ -487,6 +488,54 @@ fn parse_show() {
     )
 }
 
+#[test]
+fn parse_show_create() {
+    let stmt = verified_stmt("SHOW CREATE MATERIALIZED VIEW s.mv");
+    assert_eq!(
+        stmt,
+        Statement::ShowCreateObject {
+            create_type: ShowCreateType::MaterializedView,
+            name: ObjectName(vec!["s".into(), "mv".into()]),
+        }
+    );
+
+    verified_stmt("SHOW CREATE TABLE t");
+    verified_stmt("SHOW CREATE SOURCE src");
+    verified_stmt("SHOW CREATE INDEX idx");
+    assert!(parse_sql_statements("SHOW CREATE DATABASE d").is_err());
+}
+
+#[test]
+fn parse_analyze() {
+    let stmt = verified_stmt("ANALYZE TABLE s.t");
+    assert_eq!(
+        stmt,
+        Statement::Analyze {
+            table_name: ObjectName(vec!["s".into(), "t".into()]),
+        }
+    );
+    one_statement_parses_to("ANALYZE t", "ANALYZE TABLE t");
+}
+
+#[test]
+fn parse_reset() {
+    let stmt = verified_stmt("RESET a");
+    assert_eq!(
+        stmt,
+        Statement::ResetVariable {
+            variable: "a".into()
+        }
+    );
+
+    let stmt = verified_stmt("RESET ALL");
+    assert_eq!(
+        stmt,
+        Statement::ResetVariable {
+            variable: "ALL".into()
+        }
+    );
+}
+
 #[test]
 fn parse_deallocate() {
     let stmt = verified_stmt("DEALLOCATE a");
@@ -695,6 +744,39 @@ fn parse_pg_regex_match_ops() {
     }
 }
 
+#[test]
+fn parse_pg_json_ops() {
+    let pg_json_ops = &[
+        ("->", BinaryOperator::PGJsonGet),
+        ("->>", BinaryOperator::PGJsonGetText),
+        ("#>", BinaryOperator::PGJsonGetPath),
+        ("#>>", BinaryOperator::PGJsonGetPathText),
+        ("@>", BinaryOperator::PGJsonContains),
+    ];
+
+    for (str_op, op) in pg_json_ops {
+        let select = verified_only_select(&format!("SELECT j {} 'a'", &str_op));
+        assert_eq!(
+            SelectItem::UnnamedExpr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("j"))),
+                op: op.clone(),
+                right: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
+            }),
+            select.projection[0]
+        );
+    }
+
+    let sql = "SELECT CAST(j AS JSONB) -> 'a' = '1'";
+    let select = verified_only_select(sql);
+    assert!(matches!(
+        &select.projection[0],
+        SelectItem::UnnamedExpr(Expr::BinaryOp {
+            op: BinaryOperator::Eq,
+            ..
+        })
+    ));
+}
+
 #[test]
 fn parse_map_access_expr() {
     let zero = "0".to_string();
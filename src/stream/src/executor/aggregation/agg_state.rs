@@ -84,6 +84,12 @@ impl<S: StateStore> AggState<S> {
         Ok(())
     }
 
+    /// Forget the previous states without building the changes, e.g. when the results are only
+    /// emitted once the window closes.
+    pub fn discard_changes(&mut self) {
+        self.prev_states = None;
+    }
+
     /// Build changes into `builders` and `new_ops`, according to previous and current states. Note
     /// that for [`crate::executor::HashAggExecutor`].
     ///
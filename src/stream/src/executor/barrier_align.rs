@@ -26,6 +26,8 @@ pub enum AlignedMessage {
     Barrier(Barrier),
 }
 
+/// Align the barriers of two streams. Watermarks are dropped, as joins may still emit rows with
+/// older values afterwards.
 #[try_stream(ok = AlignedMessage, error = StreamExecutorError)]
 pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessageStream) {
     // TODO: handle stream end
@@ -36,6 +38,7 @@ pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessage
                 while let Some(msg) = right.next().await {
                     match msg? {
                         Message::Chunk(chunk) => yield AlignedMessage::Right(chunk),
+                        Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                         Message::Barrier(_) => {
                             panic!("right barrier received while left stream end")
                         }
@@ -48,6 +51,7 @@ pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessage
                 while let Some(msg) = left.next().await {
                     match msg? {
                         Message::Chunk(chunk) => yield AlignedMessage::Left(chunk),
+                        Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                         Message::Barrier(_) => {
                             panic!("left barrier received while right stream end")
                         }
@@ -57,10 +61,12 @@ pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessage
             }
             Either::Left((Some(msg), _)) => match msg? {
                 Message::Chunk(chunk) => yield AlignedMessage::Left(chunk),
+                Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                 Message::Barrier(_) => loop {
                     // received left barrier, waiting for right barrier
                     match right.next().await.unwrap()? {
                         Message::Chunk(chunk) => yield AlignedMessage::Right(chunk),
+                        Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                         Message::Barrier(barrier) => {
                             yield AlignedMessage::Barrier(barrier);
                             break;
@@ -70,10 +76,12 @@ pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessage
             },
             Either::Right((Some(msg), _)) => match msg? {
                 Message::Chunk(chunk) => yield AlignedMessage::Right(chunk),
+                Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                 Message::Barrier(_) => loop {
                     // received right barrier, waiting for left barrier
                     match left.next().await.unwrap()? {
                         Message::Chunk(chunk) => yield AlignedMessage::Left(chunk),
+                        Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                         Message::Barrier(barrier) => {
                             yield AlignedMessage::Barrier(barrier);
                             break;
@@ -16,6 +16,7 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use futures::channel::mpsc::Sender;
@@ -29,7 +30,10 @@ use risingwave_common::util::addr::{is_local_address, HostAddr};
 use risingwave_common::util::hash_util::CRC32FastBuilder;
 use tracing::event;
 
-use crate::executor::{Barrier, BoxedExecutor, Message, Mutation, StreamConsumer};
+use crate::executor::monitor::StreamingMetrics;
+use crate::executor::{
+    Barrier, BoxedExecutor, Message, Mutation, StreamConsumer, Watermark, WatermarkStatus,
+};
 use crate::task::{ActorId, DispatcherId, SharedContext};
 
 /// `Output` provides an interface for `Dispatcher` to send data into downstream actors.
@@ -147,6 +151,8 @@ struct DispatchExecutorInner {
     dispatchers: Vec<DispatcherImpl>,
     actor_id: u32,
     context: Arc<SharedContext>,
+    metrics: Arc<StreamingMetrics>,
+    actor_id_str: String,
 }
 
 impl DispatchExecutorInner {
@@ -160,8 +166,13 @@ impl DispatchExecutorInner {
     }
 
     async fn dispatch(&mut self, msg: Message) -> Result<()> {
+        let start_time = Instant::now();
         match msg {
             Message::Chunk(chunk) => {
+                self.metrics
+                    .actor_out_record_cnt
+                    .with_label_values(&[&self.actor_id_str])
+                    .inc_by(chunk.cardinality() as u64);
                 if self.dispatchers.len() == 1 {
                     // special clone optimization when there is only one downstream dispatcher
                     self.single_inner_mut().dispatch_data(chunk).await?;
@@ -179,7 +190,21 @@ impl DispatchExecutorInner {
                 }
                 self.post_mutate_outputs(&mutation).await?;
             }
+            Message::Watermark(watermark) => {
+                for dispatcher in &mut self.dispatchers {
+                    dispatcher.dispatch_watermark(watermark.clone()).await?;
+                }
+            }
+            Message::WatermarkStatus(status) => {
+                for dispatcher in &mut self.dispatchers {
+                    dispatcher.dispatch_watermark_status(status).await?;
+                }
+            }
         };
+        self.metrics
+            .actor_output_buffer_blocking_duration
+            .with_label_values(&[&self.actor_id_str])
+            .inc_by(start_time.elapsed().as_nanos() as u64);
         Ok(())
     }
 
@@ -267,6 +292,7 @@ impl DispatchExecutor {
         dispatchers: Vec<DispatcherImpl>,
         actor_id: u32,
         context: Arc<SharedContext>,
+        metrics: Arc<StreamingMetrics>,
     ) -> Self {
         Self {
             input,
@@ -274,6 +300,8 @@ impl DispatchExecutor {
                 dispatchers,
                 actor_id,
                 context,
+                metrics,
+                actor_id_str: actor_id.to_string(),
             },
         }
     }
@@ -323,6 +351,25 @@ macro_rules! impl_dispatcher {
                 }
             }
 
+            pub async fn dispatch_watermark(&mut self, watermark: Watermark) -> Result<()> {
+                match self {
+                    $( Self::$variant_name(inner) => inner.dispatch_watermark(watermark).await, )*
+                }
+            }
+
+            pub async fn dispatch_watermark_status(
+                &mut self,
+                status: WatermarkStatus,
+            ) -> Result<()> {
+                match self {
+                    $(
+                        Self::$variant_name(inner) => {
+                            inner.dispatch_watermark_status(status).await
+                        }
+                    )*
+                }
+            }
+
             pub fn set_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>) {
                 match self {
                     $( Self::$variant_name(inner) => inner.set_outputs(outputs), )*
@@ -368,6 +415,8 @@ macro_rules! define_dispatcher_associated_types {
     () => {
         type DataFuture<'a> = impl DispatchFuture<'a>;
         type BarrierFuture<'a> = impl DispatchFuture<'a>;
+        type WatermarkFuture<'a> = impl DispatchFuture<'a>;
+        type WatermarkStatusFuture<'a> = impl DispatchFuture<'a>;
     };
 }
 
@@ -376,9 +425,16 @@ pub trait DispatchFuture<'a> = Future<Output = Result<()>> + Send;
 pub trait Dispatcher: Debug + 'static {
     type DataFuture<'a>: DispatchFuture<'a>;
     type BarrierFuture<'a>: DispatchFuture<'a>;
+    type WatermarkFuture<'a>: DispatchFuture<'a>;
+    type WatermarkStatusFuture<'a>: DispatchFuture<'a>;
 
     fn dispatch_data(&mut self, chunk: StreamChunk) -> Self::DataFuture<'_>;
     fn dispatch_barrier(&mut self, barrier: Barrier) -> Self::BarrierFuture<'_>;
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_>;
+    fn dispatch_watermark_status(
+        &mut self,
+        status: WatermarkStatus,
+    ) -> Self::WatermarkStatusFuture<'_>;
 
     fn set_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>);
     fn add_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>);
@@ -433,6 +489,29 @@ impl Dispatcher for RoundRobinDataDispatcher {
         }
     }
 
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_> {
+        async move {
+            // always broadcast watermark
+            for output in &mut self.outputs {
+                output.send(Message::Watermark(watermark.clone())).await?;
+            }
+            Ok(())
+        }
+    }
+
+    fn dispatch_watermark_status(
+        &mut self,
+        status: WatermarkStatus,
+    ) -> Self::WatermarkStatusFuture<'_> {
+        async move {
+            // always broadcast watermark status
+            for output in &mut self.outputs {
+                output.send(Message::WatermarkStatus(status)).await?;
+            }
+            Ok(())
+        }
+    }
+
     fn set_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>) {
         self.outputs = outputs.into_iter().collect();
         self.cur = self.cur.min(self.outputs.len() - 1);
@@ -511,6 +590,29 @@ impl Dispatcher for HashDataDispatcher {
         }
     }
 
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_> {
+        async move {
+            // always broadcast watermark
+            for output in &mut self.outputs {
+                output.send(Message::Watermark(watermark.clone())).await?;
+            }
+            Ok(())
+        }
+    }
+
+    fn dispatch_watermark_status(
+        &mut self,
+        status: WatermarkStatus,
+    ) -> Self::WatermarkStatusFuture<'_> {
+        async move {
+            // always broadcast watermark status
+            for output in &mut self.outputs {
+                output.send(Message::WatermarkStatus(status)).await?;
+            }
+            Ok(())
+        }
+    }
+
     fn dispatch_data(&mut self, chunk: StreamChunk) -> Self::DataFuture<'_> {
         async move {
             // A chunk can be shuffled into multiple output chunks that to be sent to downstreams.
@@ -687,6 +789,27 @@ impl Dispatcher for BroadcastDispatcher {
         }
     }
 
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_> {
+        async move {
+            for output in self.outputs.values_mut() {
+                output.send(Message::Watermark(watermark.clone())).await?;
+            }
+            Ok(())
+        }
+    }
+
+    fn dispatch_watermark_status(
+        &mut self,
+        status: WatermarkStatus,
+    ) -> Self::WatermarkStatusFuture<'_> {
+        async move {
+            for output in self.outputs.values_mut() {
+                output.send(Message::WatermarkStatus(status)).await?;
+            }
+            Ok(())
+        }
+    }
+
     fn set_outputs(&mut self, outputs: impl IntoIterator<Item = BoxedOutput>) {
         self.outputs = Self::into_pairs(outputs).collect()
     }
@@ -747,6 +870,23 @@ impl Dispatcher for SimpleDispatcher {
         }
     }
 
+    fn dispatch_watermark(&mut self, watermark: Watermark) -> Self::WatermarkFuture<'_> {
+        async move {
+            self.output.send(Message::Watermark(watermark)).await?;
+            Ok(())
+        }
+    }
+
+    fn dispatch_watermark_status(
+        &mut self,
+        status: WatermarkStatus,
+    ) -> Self::WatermarkStatusFuture<'_> {
+        async move {
+            self.output.send(Message::WatermarkStatus(status)).await?;
+            Ok(())
+        }
+    }
+
     fn dispatch_data(&mut self, chunk: StreamChunk) -> Self::DataFuture<'_> {
         async move {
             self.output.send(Message::Chunk(chunk)).await?;
@@ -920,7 +1060,14 @@ mod tests {
     async fn test_configuration_change() {
         let schema = Schema { fields: vec![] };
         let (mut tx, rx) = channel(16);
-        let input = Box::new(ReceiverExecutor::new(schema.clone(), vec![], rx));
+        let metrics = Arc::new(StreamingMetrics::unused());
+        let input = Box::new(ReceiverExecutor::new(
+            schema.clone(),
+            vec![],
+            rx,
+            233,
+            metrics.clone(),
+        ));
         let data_sink = Arc::new(Mutex::new(vec![]));
         let actor_id = 233;
         let output = Box::new(MockOutput::new(actor_id, data_sink));
@@ -935,6 +1082,7 @@ mod tests {
             ))],
             actor_id,
             ctx.clone(),
+            metrics,
         ))
         .execute();
         pin_mut!(executor);
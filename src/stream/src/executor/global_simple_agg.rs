@@ -265,6 +265,8 @@ impl<S: StateStore> SimpleAggExecutor<S> {
                     )
                     .await?;
                 }
+                // The output of a simple agg has no column to carry the watermark.
+                Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                 Message::Barrier(barrier) => {
                     let next_epoch = barrier.epoch.curr;
                     if let Some(chunk) =
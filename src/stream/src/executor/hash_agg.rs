@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -21,13 +21,18 @@ use futures_async_stream::try_stream;
 use iter_chunks::IterChunks;
 use itertools::Itertools;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::StreamChunk;
+use risingwave_common::array::{DataChunk, Op, Row, RowDeserializer, StreamChunk};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::Schema;
 use risingwave_common::collection::evictable::EvictableHashMap;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::hash::{HashCode, HashKey};
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::util::epoch::Epoch;
 use risingwave_common::util::hash_util::CRC32FastBuilder;
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::write_batch::WriteBatch;
 use risingwave_storage::{Keyspace, StateStore};
 
 use super::{pk_input_arrays, Executor, PkDataTypes, PkIndicesRef, StreamExecutorResult};
@@ -35,7 +40,7 @@ use crate::executor::aggregation::{
     agg_input_arrays, generate_agg_schema, generate_managed_agg_state, AggCall, AggState,
 };
 use crate::executor::error::StreamExecutorError;
-use crate::executor::{BoxedMessageStream, Message, PkIndices, PROCESSING_WINDOW_SIZE};
+use crate::executor::{BoxedMessageStream, Message, PkIndices, Watermark, PROCESSING_WINDOW_SIZE};
 
 /// [`HashAggExecutor`] could process large amounts of data using a state backend. It works as
 /// follows:
@@ -47,6 +52,15 @@ use crate::executor::{BoxedMessageStream, Message, PkIndices, PROCESSING_WINDOW_
 /// * Upon a barrier is received, the executor will call `.flush` on the storage backend, so that
 ///   all modifications will be flushed to the storage backend. Meanwhile, the executor will go
 ///   through `modified_keys`, and produce a stream chunk based on the state changes.
+/// * Upon a watermark on a group key column is received, rows falling behind it are dropped as
+///   late. On the next barrier, the groups behind the watermark are closed: their states are
+///   cleaned up, and their final results are emitted if `emit_on_window_close` is set, in which
+///   case no changes are emitted before then. The groups to close are found with an in-memory index
+///   of the groups by the watermark columns, which is rebuilt from the states on recovery, along
+///   with the watermarks persisted when the windows are closed.
+/// * With state TTL, the epoch of the last update of each group is persisted along with its states.
+///   On every barrier, a bounded batch of the groups is checked, and those not updated within the
+///   TTL are forgotten without emitting any changes.
 pub struct HashAggExecutor<K: HashKey, S: StateStore> {
     input: Box<dyn Executor>,
 
@@ -80,6 +94,120 @@ struct HashAggExecutorExtra<S: StateStore> {
     /// Indices of the columns
     /// all of the aggregation functions in this executor should depend on same group of keys
     key_indices: Vec<usize>,
+
+    /// Whether to emit the result of a group only once, when the watermark passes its window.
+    emit_on_window_close: bool,
+
+    /// Positions in the group key of the columns with watermarks.
+    watermark_key_positions: Vec<usize>,
+
+    /// Groups not updated for this long in processing time are forgotten. `None` means no TTL.
+    state_ttl_ms: Option<u64>,
+}
+
+/// The key under which the watermark of a group key column is persisted in the row count state,
+/// followed by the position of the column. Serialized group keys begin with a null tag of `0` or
+/// `1`, so it never collides with the state of a group.
+const WATERMARK_KEY_PREFIX: u8 = 0xff;
+
+fn watermark_key(pos: usize) -> Vec<u8> {
+    [&[WATERMARK_KEY_PREFIX][..], &(pos as u32).to_be_bytes()].concat()
+}
+
+/// The key under which the epoch of the last update of a group is persisted in the row count state
+/// with state TTL, followed by the serialized group key. Like [`WATERMARK_KEY_PREFIX`], it never
+/// collides with the state of a group, and these keys sort right before the watermarks.
+const UPDATE_EPOCH_KEY_PREFIX: u8 = 0xfe;
+
+fn update_epoch_key(group_key: &[u8]) -> Vec<u8> {
+    [&[UPDATE_EPOCH_KEY_PREFIX][..], group_key].concat()
+}
+
+/// The maximum number of groups checked by each call to [`HashAggExecutor::expire_groups`], which
+/// is made on every barrier.
+const SWEEP_BATCH_SIZE: usize = 1024;
+
+/// The progress of sweeping the groups expired by the state TTL.
+#[derive(Default)]
+struct ExpirySweep {
+    /// The epoch when the last pass over the groups started.
+    last_sweep_epoch: u64,
+    /// The key to resume the current pass from, if it's not done yet.
+    cursor: Option<Vec<u8>>,
+}
+
+/// Indexes the groups by the values of their group key columns with watermarks, so that the groups
+/// behind a watermark are found without going through all of them.
+struct WindowIndex<K: HashKey> {
+    /// `position in the group key -> value -> groups`. Groups with a null value are never closed.
+    by_pos: HashMap<usize, BTreeMap<ScalarImpl, HashSet<K>>>,
+}
+
+impl<K: HashKey> WindowIndex<K> {
+    fn new(positions: &[usize]) -> Self {
+        Self {
+            by_pos: positions
+                .iter()
+                .map(|&pos| (pos, BTreeMap::new()))
+                .collect(),
+        }
+    }
+
+    fn insert(&mut self, key: &K, key_row: &Row) {
+        for (&pos, index) in &mut self.by_pos {
+            if let Some(val) = &key_row[pos] {
+                index.entry(val.clone()).or_default().insert(key.clone());
+            }
+        }
+    }
+
+    /// Remove the groups behind any of the watermarks from the index, and return them ordered by
+    /// their serialized group key.
+    fn take_expired(
+        &mut self,
+        watermarks: &HashMap<usize, ScalarImpl>,
+        key_data_types: &[DataType],
+    ) -> StreamExecutorResult<Vec<(K, Row)>> {
+        let mut expired_keys = HashSet::new();
+        for (pos, watermark) in watermarks {
+            if let Some(index) = self.by_pos.get_mut(pos) {
+                let rest = index.split_off(watermark);
+                for (_, keys) in std::mem::replace(index, rest) {
+                    expired_keys.extend(keys);
+                }
+            }
+        }
+
+        let mut expired = Vec::with_capacity(expired_keys.len());
+        for key in expired_keys {
+            let key_row = key
+                .clone()
+                .deserialize(key_data_types.iter())
+                .map_err(StreamExecutorError::eval_error)?;
+            // Remove the group from the index of other positions.
+            for (pos, index) in &mut self.by_pos {
+                let Some(val) = &key_row[*pos] else {
+                    continue;
+                };
+                if let Some(keys) = index.get_mut(val) {
+                    keys.remove(&key);
+                    if keys.is_empty() {
+                        index.remove(val);
+                    }
+                }
+            }
+            let serialized = key_row
+                .serialize()
+                .map_err(StreamExecutorError::eval_error)?;
+            expired.push((serialized, key, key_row));
+        }
+        expired.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+
+        Ok(expired
+            .into_iter()
+            .map(|(_, key, key_row)| (key, key_row))
+            .collect())
+    }
 }
 
 impl<K: HashKey, S: StateStore> Executor for HashAggExecutor<K, S> {
@@ -108,6 +236,9 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         pk_indices: PkIndices,
         executor_id: u64,
         key_indices: Vec<usize>,
+        emit_on_window_close: bool,
+        watermark_key_positions: Vec<usize>,
+        state_ttl_ms: Option<u64>,
     ) -> Result<Self> {
         let input_info = input.info();
         let schema = generate_agg_schema(input.as_ref(), &agg_calls, Some(&key_indices));
@@ -123,6 +254,9 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                 keyspace,
                 agg_calls,
                 key_indices,
+                emit_on_window_close,
+                watermark_key_positions,
+                state_ttl_ms,
             },
             _phantom: PhantomData,
         })
@@ -179,6 +313,38 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         Ok(result)
     }
 
+    /// Hide the rows whose group key falls behind the watermark, as their windows are closed.
+    fn filter_late_rows(
+        key_indices: &[usize],
+        watermarks: &HashMap<usize, ScalarImpl>,
+        chunk: StreamChunk,
+    ) -> StreamExecutorResult<StreamChunk> {
+        if watermarks.is_empty() {
+            return Ok(chunk);
+        }
+        let (ops, columns, visibility) = chunk.into_inner();
+        let mut new_visibility = Vec::with_capacity(ops.len());
+        for row_idx in 0..ops.len() {
+            let visible = match &visibility {
+                Some(visibility) => visibility
+                    .is_set(row_idx)
+                    .map_err(StreamExecutorError::eval_error)?,
+                None => true,
+            };
+            let late = watermarks.iter().any(|(&pos, watermark)| {
+                columns[key_indices[pos]]
+                    .array_ref()
+                    .datum_at(row_idx)
+                    .map_or(false, |val| &val < watermark)
+            });
+            new_visibility.push(visible && !late);
+        }
+        let new_visibility = new_visibility
+            .try_into()
+            .map_err(StreamExecutorError::eval_error)?;
+        Ok(StreamChunk::new(ops, columns, Some(new_visibility)))
+    }
+
     async fn apply_chunk(
         &HashAggExecutorExtra::<S> {
             ref key_indices,
@@ -190,6 +356,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             ..
         }: &HashAggExecutorExtra<S>,
         state_map: &mut EvictableHashMap<K, Option<Box<AggState<S>>>>,
+        window_index: &mut WindowIndex<K>,
         chunk: StreamChunk,
         epoch: u64,
     ) -> StreamExecutorResult<()> {
@@ -233,6 +400,19 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             // Retrieve previous state from the KeyedState.
             let states = state_map.put(key.to_owned(), None);
 
+            // A group missing in the cache may be a new one, so index it by its window.
+            let key_row = match states {
+                Some(_) => None,
+                None => {
+                    let key_row = key
+                        .clone()
+                        .deserialize(key_data_types.iter())
+                        .map_err(StreamExecutorError::eval_error)?;
+                    window_index.insert(&key, &key_row);
+                    Some(key_row)
+                }
+            };
+
             let key = key.clone();
             // To leverage more parallelism in IO operations, fetching and updating states for every
             // unique keys is created as futures and run in parallel.
@@ -246,11 +426,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                         Some(s) => s.unwrap(),
                         None => Box::new(
                             generate_managed_agg_state(
-                                Some(
-                                    &key.clone()
-                                        .deserialize(key_data_types.iter())
-                                        .map_err(StreamExecutorError::eval_error)?,
-                                ),
+                                key_row.as_ref(),
                                 agg_calls,
                                 keyspace,
                                 input_pk_data_types.clone(),
@@ -298,6 +474,8 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             ref key_indices,
             ref keyspace,
             ref schema,
+            emit_on_window_close,
+            state_ttl_ms,
             ..
         }: &'a HashAggExecutorExtra<S>,
         state_map: &'a mut EvictableHashMap<K, Option<Box<AggState<S>>>>,
@@ -311,8 +489,9 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         let (write_batch, dirty_cnt) = {
             let mut write_batch = store.start_write_batch();
             let mut dirty_cnt = 0;
+            let key_data_types = &schema.data_types()[..key_indices.len()];
 
-            for states in state_map.values_mut() {
+            for (key, states) in state_map.iter_mut() {
                 if states.as_ref().unwrap().is_dirty() {
                     dirty_cnt += 1;
                     for state in &mut states.as_mut().unwrap().managed_states {
@@ -320,6 +499,19 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                             .flush(&mut write_batch)
                             .map_err(StreamExecutorError::agg_state_error)?;
                     }
+                    // Record the update, so that the group isn't expired by the state TTL.
+                    if state_ttl_ms.is_some() {
+                        let group_key = key
+                            .clone()
+                            .deserialize(key_data_types.iter())
+                            .map_err(StreamExecutorError::eval_error)?
+                            .serialize()
+                            .map_err(StreamExecutorError::eval_error)?;
+                        write_batch.prefixify(&keyspace[0]).put(
+                            update_epoch_key(&group_key),
+                            StorageValue::new_default_put(epoch.to_be_bytes().to_vec()),
+                        );
+                    }
                 }
             }
             (write_batch, dirty_cnt)
@@ -335,43 +527,52 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                 .await
                 .map_err(StreamExecutorError::agg_state_error)?;
 
-            // --- Produce the stream chunk ---
-            let mut batches = IterChunks::chunks(state_map.iter_mut(), PROCESSING_WINDOW_SIZE);
-            while let Some(batch) = batches.next() {
-                // --- Create array builders ---
-                // As the datatype is retrieved from schema, it contains both group key and
-                // aggregation state outputs.
-                let mut builders = schema
-                    .create_array_builders(dirty_cnt * 2)
-                    .map_err(StreamExecutorError::eval_error)?;
-                let mut new_ops = Vec::with_capacity(dirty_cnt);
-
-                // --- Retrieve modified states and put the changes into the builders ---
-                for (key, states) in batch {
-                    let appended = states
-                        .as_mut()
-                        .unwrap()
-                        .build_changes(&mut builders[key_indices.len()..], &mut new_ops, epoch)
-                        .await
-                        .map_err(StreamExecutorError::agg_state_error)?;
+            if emit_on_window_close {
+                // The results are emitted only once, when the window closes.
+                for states in state_map.values_mut() {
+                    states.as_mut().unwrap().discard_changes();
+                }
+            } else {
+                // --- Produce the stream chunk ---
+                let mut batches = IterChunks::chunks(state_map.iter_mut(), PROCESSING_WINDOW_SIZE);
+                while let Some(batch) = batches.next() {
+                    // --- Create array builders ---
+                    // As the datatype is retrieved from schema, it contains both group key and
+                    // aggregation state outputs.
+                    let mut builders = schema
+                        .create_array_builders(dirty_cnt * 2)
+                        .map_err(StreamExecutorError::eval_error)?;
+                    let mut new_ops = Vec::with_capacity(dirty_cnt);
+
+                    // --- Retrieve modified states and put the changes into the builders ---
+                    for (key, states) in batch {
+                        let appended = states
+                            .as_mut()
+                            .unwrap()
+                            .build_changes(&mut builders[key_indices.len()..], &mut new_ops, epoch)
+                            .await
+                            .map_err(StreamExecutorError::agg_state_error)?;
 
-                    for _ in 0..appended {
-                        key.clone()
-                            .deserialize_to_builders(&mut builders[..key_indices.len()])
-                            .map_err(StreamExecutorError::eval_error)?;
+                        for _ in 0..appended {
+                            key.clone()
+                                .deserialize_to_builders(&mut builders[..key_indices.len()])
+                                .map_err(StreamExecutorError::eval_error)?;
+                        }
                     }
-                }
 
-                let columns: Vec<Column> = builders
-                    .into_iter()
-                    .map(|builder| -> Result<_> { Ok(Column::new(Arc::new(builder.finish()?))) })
-                    .try_collect()
-                    .map_err(StreamExecutorError::eval_error)?;
+                    let columns: Vec<Column> = builders
+                        .into_iter()
+                        .map(|builder| -> Result<_> {
+                            Ok(Column::new(Arc::new(builder.finish()?)))
+                        })
+                        .try_collect()
+                        .map_err(StreamExecutorError::eval_error)?;
 
-                let chunk = StreamChunk::new(new_ops, columns, None);
+                    let chunk = StreamChunk::new(new_ops, columns, None);
 
-                trace!("output_chunk: {:?}", &chunk);
-                yield chunk;
+                    trace!("output_chunk: {:?}", &chunk);
+                    yield chunk;
+                }
             }
 
             // evict cache to target capacity
@@ -385,6 +586,311 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         }
     }
 
+    /// Close the groups whose window key falls behind the watermarks. Their final results are
+    /// emitted if `emit_on_window_close` is set, and their states are cleaned up, as no more rows
+    /// can be added to them. The watermarks are persisted along, to drop the late rows after
+    /// recovery as well.
+    #[try_stream(ok = StreamChunk, error = StreamExecutorError)]
+    async fn close_windows<'a>(
+        &HashAggExecutorExtra::<S> {
+            ref key_indices,
+            ref agg_calls,
+            ref input_pk_indices,
+            ref input_schema,
+            ref keyspace,
+            ref schema,
+            emit_on_window_close,
+            state_ttl_ms,
+            ..
+        }: &'a HashAggExecutorExtra<S>,
+        state_map: &'a mut EvictableHashMap<K, Option<Box<AggState<S>>>>,
+        window_index: &'a mut WindowIndex<K>,
+        watermarks: &'a HashMap<usize, ScalarImpl>,
+        epoch: u64,
+    ) {
+        let key_data_types = &schema.data_types()[..key_indices.len()];
+        let expired = window_index.take_expired(watermarks, key_data_types)?;
+
+        let input_pk_data_types: PkDataTypes = input_pk_indices
+            .iter()
+            .map(|idx| input_schema.fields[*idx].data_type.clone())
+            .collect();
+        let mut write_batch = keyspace[0].state_store().start_write_batch();
+        let mut builders = schema
+            .create_array_builders(PROCESSING_WINDOW_SIZE)
+            .map_err(StreamExecutorError::eval_error)?;
+        let mut cardinality = 0;
+
+        for (key, key_row) in expired {
+            // All states have been flushed, so the cached states can be dropped or reused.
+            let cached_states = state_map.pop(&key).flatten();
+
+            // --- Emit the final results ---
+            if emit_on_window_close {
+                let mut states = match cached_states {
+                    Some(states) => states,
+                    None => Box::new(
+                        generate_managed_agg_state(
+                            Some(&key_row),
+                            agg_calls,
+                            keyspace,
+                            input_pk_data_types.clone(),
+                            epoch,
+                            None,
+                        )
+                        .await?,
+                    ),
+                };
+                let row_count = states
+                    .row_count(epoch)
+                    .await
+                    .map_err(StreamExecutorError::agg_state_error)?;
+                if row_count > 0 {
+                    for (builder, datum) in builders.iter_mut().zip_eq(key_row.0.iter()) {
+                        builder
+                            .append_datum(datum)
+                            .map_err(StreamExecutorError::eval_error)?;
+                    }
+                    for (builder, state) in builders[key_indices.len()..]
+                        .iter_mut()
+                        .zip_eq(states.managed_states.iter_mut())
+                    {
+                        let datum = state
+                            .get_output(epoch)
+                            .await
+                            .map_err(StreamExecutorError::agg_state_error)?;
+                        builder
+                            .append_datum(&datum)
+                            .map_err(StreamExecutorError::eval_error)?;
+                    }
+                    cardinality += 1;
+                }
+
+                if cardinality == PROCESSING_WINDOW_SIZE {
+                    let new_builders = schema
+                        .create_array_builders(PROCESSING_WINDOW_SIZE)
+                        .map_err(StreamExecutorError::eval_error)?;
+                    let columns: Vec<Column> = std::mem::replace(&mut builders, new_builders)
+                        .into_iter()
+                        .map(|builder| -> Result<_> {
+                            Ok(Column::new(Arc::new(builder.finish()?)))
+                        })
+                        .try_collect()
+                        .map_err(StreamExecutorError::eval_error)?;
+                    yield StreamChunk::new(vec![Op::Insert; cardinality], columns, None);
+                    cardinality = 0;
+                }
+            }
+
+            // --- Clean up the states of the group ---
+            let group_key = key_row
+                .serialize()
+                .map_err(StreamExecutorError::eval_error)?;
+            Self::delete_group_states(
+                keyspace,
+                &group_key,
+                state_ttl_ms.is_some(),
+                &mut write_batch,
+                epoch,
+            )
+            .await?;
+        }
+
+        if cardinality > 0 {
+            let columns: Vec<Column> = builders
+                .into_iter()
+                .map(|builder| -> Result<_> { Ok(Column::new(Arc::new(builder.finish()?))) })
+                .try_collect()
+                .map_err(StreamExecutorError::eval_error)?;
+            yield StreamChunk::new(vec![Op::Insert; cardinality], columns, None);
+        }
+
+        // --- Persist the watermarks ---
+        let mut local = write_batch.prefixify(&keyspace[0]);
+        for (&pos, watermark) in watermarks {
+            local.put(
+                watermark_key(pos),
+                StorageValue::new_default_put(
+                    serialize_cell(&Some(watermark.clone()))
+                        .map_err(StreamExecutorError::eval_error)?,
+                ),
+            );
+        }
+
+        write_batch
+            .ingest(epoch)
+            .await
+            .map_err(StreamExecutorError::agg_state_error)?;
+    }
+
+    /// Delete all the states of a group, along with the epoch of its last update if `with_ttl`.
+    async fn delete_group_states(
+        keyspace: &[Keyspace<S>],
+        group_key: &[u8],
+        with_ttl: bool,
+        write_batch: &mut WriteBatch<S>,
+        epoch: u64,
+    ) -> StreamExecutorResult<()> {
+        for agg_keyspace in keyspace {
+            let group_keyspace = agg_keyspace.append(group_key.to_vec());
+            let state_keys = group_keyspace.scan(None, epoch).await?;
+            let mut local = write_batch.prefixify(&group_keyspace);
+            for (state_key, _) in state_keys {
+                local.delete(state_key);
+            }
+        }
+        if with_ttl {
+            write_batch
+                .prefixify(&keyspace[0])
+                .delete(update_epoch_key(group_key));
+        }
+        Ok(())
+    }
+
+    /// Forget the groups not updated within the state TTL in processing time. Their states are
+    /// deleted without emitting any changes, so their last results are kept downstream, and a group
+    /// updated again later starts over from empty states. Each call checks at most
+    /// [`SWEEP_BATCH_SIZE`] groups, resuming from where the last call stopped, and a new pass over
+    /// the groups starts once a quarter of the TTL has passed since the last one started.
+    async fn expire_groups(
+        &HashAggExecutorExtra::<S> {
+            ref key_indices,
+            ref keyspace,
+            ref schema,
+            state_ttl_ms,
+            ..
+        }: &HashAggExecutorExtra<S>,
+        state_map: &mut EvictableHashMap<K, Option<Box<AggState<S>>>>,
+        sweep: &mut ExpirySweep,
+        epoch: u64,
+    ) -> StreamExecutorResult<()> {
+        let Some(ttl) = state_ttl_ms else {
+            return Ok(());
+        };
+        let start_key = match sweep.cursor.take() {
+            Some(start_key) => start_key,
+            None => {
+                if Epoch(epoch).physical_time()
+                    < Epoch(sweep.last_sweep_epoch).physical_time() + ttl / 4
+                {
+                    return Ok(());
+                }
+                sweep.last_sweep_epoch = epoch;
+                vec![UPDATE_EPOCH_KEY_PREFIX]
+            }
+        };
+        let min_epoch = Epoch(epoch).subtract_ms(ttl).0;
+
+        // The scan runs into the watermarks after the last group.
+        let entries = keyspace[0]
+            .scan_with_start_key(start_key, Some(SWEEP_BATCH_SIZE), epoch)
+            .await?;
+        let update_epochs = entries
+            .iter()
+            .take_while(|(key, _)| key.first() == Some(&UPDATE_EPOCH_KEY_PREFIX))
+            .collect_vec();
+
+        let key_data_types = &schema.data_types()[..key_indices.len()];
+        let row_deserializer = RowDeserializer::new(key_data_types.to_vec());
+        let mut write_batch = keyspace[0].state_store().start_write_batch();
+        let mut expired_key_rows = vec![];
+        for (key, value) in &update_epochs {
+            let updated_epoch = u64::from_be_bytes(value[..].try_into().unwrap());
+            if updated_epoch >= min_epoch {
+                continue;
+            }
+            let group_key = &key[1..];
+            Self::delete_group_states(keyspace, group_key, true, &mut write_batch, epoch).await?;
+            expired_key_rows.push(
+                row_deserializer
+                    .deserialize(group_key)
+                    .map_err(StreamExecutorError::eval_error)?,
+            );
+        }
+        write_batch
+            .ingest(epoch)
+            .await
+            .map_err(StreamExecutorError::agg_state_error)?;
+
+        // All states have been flushed, so the cached states of the expired groups are just stale.
+        for key in Self::build_hash_keys(&expired_key_rows, key_data_types)? {
+            state_map.pop(&key);
+        }
+
+        // Resume from the key right after the last checked one, unless the pass is done.
+        if update_epochs.len() == SWEEP_BATCH_SIZE {
+            let mut next_key = update_epochs.last().unwrap().0.to_vec();
+            next_key.push(0);
+            sweep.cursor = Some(next_key);
+        }
+        Ok(())
+    }
+
+    /// Build the hash keys of the groups from their group key rows.
+    fn build_hash_keys(
+        key_rows: &[Row],
+        key_data_types: &[DataType],
+    ) -> StreamExecutorResult<Vec<K>> {
+        let mut keys = Vec::with_capacity(key_rows.len());
+        for key_rows in key_rows.chunks(PROCESSING_WINDOW_SIZE) {
+            let key_chunk = DataChunk::from_rows(key_rows, key_data_types)
+                .map_err(StreamExecutorError::eval_error)?;
+            let all_indices = (0..key_data_types.len()).collect_vec();
+            let hash_codes = key_chunk
+                .get_hash_values(&all_indices, CRC32FastBuilder)
+                .map_err(StreamExecutorError::eval_error)?;
+            keys.extend(
+                K::build_from_hash_code(&all_indices, &key_chunk, hash_codes)
+                    .map_err(StreamExecutorError::eval_error)?,
+            );
+        }
+        Ok(keys)
+    }
+
+    /// Restore the watermarks and rebuild the window index from the states, after the executor is
+    /// (re)started.
+    async fn recover_windows(
+        &HashAggExecutorExtra::<S> {
+            ref key_indices,
+            ref keyspace,
+            ref schema,
+            ..
+        }: &HashAggExecutorExtra<S>,
+        window_index: &mut WindowIndex<K>,
+        watermarks: &mut HashMap<usize, ScalarImpl>,
+        epoch: u64,
+    ) -> StreamExecutorResult<()> {
+        let key_data_types = &schema.data_types()[..key_indices.len()];
+        let row_deserializer = RowDeserializer::new(key_data_types.to_vec());
+
+        // The row count state has exactly one entry per group, besides the epochs of their last
+        // updates with state TTL.
+        let mut key_rows = vec![];
+        for (key, value) in keyspace[0].scan(None, epoch).await? {
+            if key.first() == Some(&UPDATE_EPOCH_KEY_PREFIX) {
+                continue;
+            } else if key.first() == Some(&WATERMARK_KEY_PREFIX) {
+                let pos = u32::from_be_bytes(key[1..].try_into().unwrap()) as usize;
+                let watermark = deserialize_cell(&value[..], &key_data_types[pos])
+                    .map_err(StreamExecutorError::eval_error)?;
+                watermarks.insert(pos, watermark.unwrap());
+            } else {
+                key_rows.push(
+                    row_deserializer
+                        .deserialize(&key)
+                        .map_err(StreamExecutorError::eval_error)?,
+                );
+            }
+        }
+
+        let keys = Self::build_hash_keys(&key_rows, key_data_types)?;
+        for (key, key_row) in keys.iter().zip_eq(&key_rows) {
+            window_index.insert(key, key_row);
+        }
+
+        Ok(())
+    }
+
     #[try_stream(ok = Message, error = StreamExecutorError)]
     async fn execute_inner(self) {
         let HashAggExecutor { input, extra, .. } = self;
@@ -392,12 +898,21 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         // The cached states. `HashKey -> (prev_value, value)`.
         let mut state_map = EvictableHashMap::new(1 << 16);
 
+        // The watermarks of the group key columns, by their position in the group key.
+        let mut watermarks = HashMap::new();
+        let mut watermarks_advanced = false;
+        let mut window_index = WindowIndex::new(&extra.watermark_key_positions);
+        let mut expiry_sweep = ExpirySweep::default();
+
         let mut input = input.execute();
         let first_msg = input.next().await.unwrap()?;
         let barrier = first_msg
             .into_barrier()
             .expect("the first message received by agg executor must be a barrier");
         let mut epoch = barrier.epoch.curr;
+        if !extra.watermark_key_positions.is_empty() {
+            Self::recover_windows(&extra, &mut window_index, &mut watermarks, epoch).await?;
+        }
         yield Message::Barrier(barrier);
 
         #[for_await]
@@ -405,8 +920,30 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             let msg = msg?;
             match msg {
                 Message::Chunk(chunk) => {
-                    Self::apply_chunk(&extra, &mut state_map, chunk, epoch).await?;
+                    let chunk = Self::filter_late_rows(&extra.key_indices, &watermarks, chunk)?;
+                    Self::apply_chunk(&extra, &mut state_map, &mut window_index, chunk, epoch)
+                        .await?;
+                }
+                Message::Watermark(watermark) => {
+                    // Only the watermarks of the group key can be used to close the windows.
+                    if let Some(pos) = extra
+                        .key_indices
+                        .iter()
+                        .position(|&idx| idx == watermark.col_idx)
+                        && extra.watermark_key_positions.contains(&pos)
+                    {
+                        if watermarks
+                            .get(&pos)
+                            .map_or(true, |val| val < &watermark.val)
+                        {
+                            watermarks.insert(pos, watermark.val);
+                            watermarks_advanced = true;
+                        }
+                    }
                 }
+                // The windows are closed by the watermarks of the input, so the output is idle
+                // whenever the input is.
+                Message::WatermarkStatus(status) => yield Message::WatermarkStatus(status),
                 Message::Barrier(barrier) => {
                     let next_epoch = barrier.epoch.curr;
                     assert_eq!(epoch, barrier.epoch.prev);
@@ -416,6 +953,30 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                         yield Message::Chunk(chunk?);
                     }
 
+                    if watermarks_advanced {
+                        #[for_await]
+                        for chunk in Self::close_windows(
+                            &extra,
+                            &mut state_map,
+                            &mut window_index,
+                            &watermarks,
+                            epoch,
+                        ) {
+                            yield Message::Chunk(chunk?);
+                        }
+                        // The group key columns come first in the output.
+                        for (&pos, val) in &watermarks {
+                            yield Message::Watermark(Watermark::new(
+                                pos,
+                                extra.schema[pos].data_type(),
+                                val.clone(),
+                            ));
+                        }
+                        watermarks_advanced = false;
+                    }
+
+                    Self::expire_groups(&extra, &mut state_map, &mut expiry_sweep, epoch).await?;
+
                     yield Message::Barrier(barrier);
                     epoch = next_epoch;
                 }
@@ -437,13 +998,13 @@ mod tests {
     use risingwave_common::catalog::{Field, Schema};
     use risingwave_common::error::Result;
     use risingwave_common::hash::{calc_hash_key_kind, HashKey, HashKeyDispatcher};
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_expr::expr::*;
     use risingwave_storage::{Keyspace, StateStore};
 
     use crate::executor::aggregation::{AggArgs, AggCall};
     use crate::executor::test_utils::*;
-    use crate::executor::{Executor, HashAggExecutor, Message, PkIndices};
+    use crate::executor::{Executor, HashAggExecutor, Message, PkIndices, Watermark};
 
     struct HashAggExecutorDispatcher<S: StateStore>(PhantomData<S>);
 
@@ -454,6 +1015,9 @@ mod tests {
         keyspace: Vec<Keyspace<S>>,
         pk_indices: PkIndices,
         executor_id: u64,
+        emit_on_window_close: bool,
+        watermark_key_positions: Vec<usize>,
+        state_ttl_ms: Option<u64>,
     }
 
     impl<S: StateStore> HashKeyDispatcher for HashAggExecutorDispatcher<S> {
@@ -468,6 +1032,9 @@ mod tests {
                 args.pk_indices,
                 args.executor_id,
                 args.key_indices,
+                args.emit_on_window_close,
+                args.watermark_key_positions,
+                args.state_ttl_ms,
             )?))
         }
     }
@@ -479,6 +1046,9 @@ mod tests {
         keyspace: Vec<Keyspace<impl StateStore>>,
         pk_indices: PkIndices,
         executor_id: u64,
+        emit_on_window_close: bool,
+        watermark_key_positions: Vec<usize>,
+        state_ttl_ms: Option<u64>,
     ) -> Box<dyn Executor> {
         let keys = key_indices
             .iter()
@@ -491,6 +1061,9 @@ mod tests {
             keyspace,
             pk_indices,
             executor_id,
+            emit_on_window_close,
+            watermark_key_positions,
+            state_ttl_ms,
         };
         let kind = calc_hash_key_kind(&keys);
         HashAggExecutorDispatcher::dispatch_by_kind(kind, args).unwrap()
@@ -518,6 +1091,11 @@ mod tests {
         test_local_hash_aggregation_min_append_only(create_in_memory_keyspace_agg(2)).await
     }
 
+    #[tokio::test]
+    async fn test_hash_aggregation_emit_on_window_close_in_memory() {
+        test_hash_aggregation_emit_on_window_close(create_in_memory_keyspace_agg(2)).await
+    }
+
     async fn test_local_hash_aggregation_count(keyspace: Vec<Keyspace<impl StateStore>>) {
         let schema = Schema {
             fields: vec![Field::unnamed(DataType::Int64)],
@@ -563,8 +1141,17 @@ mod tests {
             },
         ];
 
-        let hash_agg =
-            new_boxed_hash_agg_executor(Box::new(source), agg_calls, keys, keyspace, vec![], 1);
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            keys,
+            keyspace,
+            vec![],
+            1,
+            false,
+            vec![],
+            None,
+        );
         let mut hash_agg = hash_agg.execute();
 
         // Consume the init barrier
@@ -658,6 +1245,9 @@ mod tests {
             keyspace,
             vec![],
             1,
+            false,
+            vec![],
+            None,
         );
         let mut hash_agg = hash_agg.execute();
 
@@ -739,8 +1329,17 @@ mod tests {
             },
         ];
 
-        let hash_agg =
-            new_boxed_hash_agg_executor(Box::new(source), agg_calls, keys, keyspace, vec![], 1);
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            keys,
+            keyspace,
+            vec![],
+            1,
+            false,
+            vec![],
+            None,
+        );
         let mut hash_agg = hash_agg.execute();
 
         // Consume the init barrier
@@ -826,8 +1425,17 @@ mod tests {
             },
         ];
 
-        let hash_agg =
-            new_boxed_hash_agg_executor(Box::new(source), agg_calls, keys, keyspace, vec![], 1);
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            keys,
+            keyspace,
+            vec![],
+            1,
+            false,
+            vec![],
+            None,
+        );
         let mut hash_agg = hash_agg.execute();
 
         // Consume the init barrier
@@ -864,6 +1472,324 @@ mod tests {
         );
     }
 
+    async fn test_hash_aggregation_emit_on_window_close(keyspace: Vec<Keyspace<impl StateStore>>) {
+        let schema = Schema {
+            fields: vec![
+                // window column
+                Field::unnamed(DataType::Int64),
+                // data column to sum
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let watermark = |val| Watermark::new(0, DataType::Int64, ScalarImpl::Int64(val));
+        let (mut tx, source) = MockSource::channel(schema, PkIndices::new());
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I  I
+            + 1 10
+            + 1 20
+            + 2  5",
+        ));
+        tx.push_barrier(2, false);
+        tx.push_watermark(watermark(2));
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I   I
+            + 1 100
+            + 3   7",
+        ));
+        tx.push_barrier(3, false);
+        tx.push_watermark(watermark(3));
+        tx.push_barrier(4, false);
+
+        let keys = vec![0];
+        let agg_calls = vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                append_only: true,
+            },
+            AggCall {
+                kind: AggKind::Sum,
+                args: AggArgs::Unary(DataType::Int64, 1),
+                return_type: DataType::Int64,
+                append_only: true,
+            },
+        ];
+
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            keys,
+            keyspace,
+            vec![],
+            1,
+            true,
+            vec![0],
+            None,
+        );
+        let mut hash_agg = hash_agg.execute();
+
+        // Consume the init barrier
+        hash_agg.next().await.unwrap().unwrap();
+        // No result is emitted before the window closes.
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // The late row of window `1` is dropped.
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I  I
+                + 1 2 30"
+            )
+            .sorted_rows(),
+        );
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(msg.into_watermark().unwrap(), watermark(2));
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // The states of window `1` are cleaned up, so it won't be emitted again.
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 2 1 5"
+            )
+            .sorted_rows(),
+        );
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(msg.into_watermark().unwrap(), watermark(3));
+    }
+
+    #[tokio::test]
+    async fn test_hash_aggregation_recover_windows() {
+        let keyspace = create_in_memory_keyspace_agg(2);
+        let schema = Schema {
+            fields: vec![
+                // window column
+                Field::unnamed(DataType::Int64),
+                // data column to sum
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let watermark = |val| Watermark::new(0, DataType::Int64, ScalarImpl::Int64(val));
+        let agg_calls = vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                append_only: true,
+            },
+            AggCall {
+                kind: AggKind::Sum,
+                args: AggArgs::Unary(DataType::Int64, 1),
+                return_type: DataType::Int64,
+                append_only: true,
+            },
+        ];
+
+        // Window `1` is closed before the executor is rebuilt.
+        let (mut tx, source) = MockSource::channel(schema.clone(), PkIndices::new());
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I  I
+            + 1 10
+            + 2  5",
+        ));
+        tx.push_barrier(2, false);
+        tx.push_watermark(watermark(2));
+        tx.push_barrier(3, false);
+
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls.clone(),
+            vec![0],
+            keyspace.clone(),
+            vec![],
+            1,
+            true,
+            vec![0],
+            None,
+        );
+        let mut hash_agg = hash_agg.execute();
+        hash_agg.next().await.unwrap().unwrap();
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I  I
+                + 1 1 10"
+            )
+            .sorted_rows(),
+        );
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(msg.into_watermark().unwrap(), watermark(2));
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // After recovery, the persisted watermark still drops the late row of window `1`, and
+        // window `2` is found in the states to be closed.
+        let (mut tx, source) = MockSource::channel(schema, PkIndices::new());
+        tx.push_barrier(4, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I   I
+            + 1 100
+            + 2   1
+            + 3   7",
+        ));
+        tx.push_watermark(watermark(3));
+        tx.push_barrier(5, false);
+
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            vec![0],
+            keyspace,
+            vec![],
+            1,
+            true,
+            vec![0],
+            None,
+        );
+        let mut hash_agg = hash_agg.execute();
+        hash_agg.next().await.unwrap().unwrap();
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 2 2 6"
+            )
+            .sorted_rows(),
+        );
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(msg.into_watermark().unwrap(), watermark(3));
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hash_aggregation_state_ttl() {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+        let epoch = |ms| risingwave_common::util::epoch::Epoch::from_physical_time(ms).0;
+        let (mut tx, source) = MockSource::channel(schema, PkIndices::new());
+        tx.push_barrier(epoch(1000), false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I
+            + 1
+            + 2",
+        ));
+        tx.push_barrier_with_prev_epoch(epoch(1500), epoch(1000));
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I
+            + 1",
+        ));
+        tx.push_barrier_with_prev_epoch(epoch(2200), epoch(1500));
+        tx.push_barrier_with_prev_epoch(epoch(2600), epoch(2200));
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I
+            + 1
+            + 2",
+        ));
+        tx.push_barrier_with_prev_epoch(epoch(3000), epoch(2600));
+
+        let agg_calls = vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                append_only: false,
+            },
+            AggCall {
+                kind: AggKind::Count,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                append_only: false,
+            },
+        ];
+
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            vec![0],
+            create_in_memory_keyspace_agg(2),
+            vec![],
+            1,
+            false,
+            vec![],
+            Some(1000),
+        );
+        let mut hash_agg = hash_agg.execute();
+        hash_agg.next().await.unwrap().unwrap();
+
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 1 1 1
+                + 2 1 1"
+            )
+            .sorted_rows(),
+        );
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                "  I I I
+                U- 1 1 1
+                U+ 1 2 2"
+            )
+            .sorted_rows(),
+        );
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // Group `2` was last updated at 1s, so it's expired at 2.2s without emitting anything.
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // Group `1` is still alive, while group `2` starts over.
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                "  I I I
+                U- 1 2 2
+                U+ 1 3 3
+                +  2 1 1"
+            )
+            .sorted_rows(),
+        );
+    }
+
     trait SortedRows {
         fn sorted_rows(self) -> Vec<(Op, Row)>;
     }
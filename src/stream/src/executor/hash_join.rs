@@ -326,6 +326,7 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
         key_indices: Vec<usize>,
         ks_l: Keyspace<S>,
         ks_r: Keyspace<S>,
+        state_ttl_ms: Option<u64>,
     ) -> Self {
         let side_l_column_n = input_l.schema().len();
 
@@ -372,6 +373,7 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
                     params_l.key_indices.clone(),
                     col_l_datatypes.clone(),
                     ks_l.clone(),
+                    state_ttl_ms,
                 ), // TODO: decide the target cap
                 key_indices: params_l.key_indices,
                 col_types: col_l_datatypes,
@@ -386,6 +388,7 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
                     params_r.key_indices.clone(),
                     col_r_datatypes.clone(),
                     ks_r.clone(),
+                    state_ttl_ms,
                 ), // TODO: decide the target cap
                 key_indices: params_r.key_indices,
                 col_types: col_r_datatypes,
@@ -444,6 +447,7 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
                 state.flush(&mut write_batch)?;
             }
             write_batch.ingest(epoch).await.unwrap();
+            side.ht.sweep_expired().await?;
         }
 
         // evict the LRU cache
@@ -584,10 +588,16 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
                     } else {
                         hashjoin_chunk_builder.forward_if_not_matched(*op, &row)?;
                     }
-                    entry_value.insert(pk, JoinRow::new(value, degree));
+                    entry_value.insert(pk, JoinRow::new(value, degree, epoch));
                 }
                 Op::Delete | Op::UpdateDelete => {
-                    if let Some(v) = side_update.ht.get_mut_without_cached(key).await? {
+                    if side_update.ht.state_ttl_enabled() {
+                        // The row may have been expired and forgotten, in which case its matches
+                        // are not counted in the degrees and must be left untouched.
+                        if key.has_null() || !side_update.ht.remove_if_exists(key, pk).await? {
+                            continue;
+                        }
+                    } else if let Some(v) = side_update.ht.get_mut_without_cached(key).await? {
                         // remove the row by it's primary key
                         v.remove(pk);
                     }
@@ -685,6 +695,7 @@ mod tests {
             vec![],
             ks_l,
             ks_r,
+            None,
         );
         (tx_l, tx_r, Box::new(executor).execute())
     }
@@ -1732,4 +1743,103 @@ mod tests {
             )
         );
     }
+
+    #[tokio::test]
+    async fn test_streaming_hash_inner_join_with_state_ttl() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let (mut tx_l, source_l) = MockSource::channel(schema.clone(), vec![1]);
+        let (mut tx_r, source_r) = MockSource::channel(schema, vec![1]);
+        let (ks_l, ks_r) = create_in_memory_keyspace();
+        let executor = HashJoinExecutor::<Key64, MemoryStateStore, { JoinType::Inner }>::new(
+            Box::new(source_l),
+            Box::new(source_r),
+            JoinParams::new(vec![0]),
+            JoinParams::new(vec![0]),
+            vec![1],
+            1,
+            None,
+            "HashJoinExecutor".to_string(),
+            vec![],
+            ks_l.clone(),
+            ks_r.clone(),
+            Some(1000),
+        );
+        let mut hash_join = Box::new(executor).execute();
+        let epoch = |ms| risingwave_common::util::epoch::Epoch::from_physical_time(ms).0;
+
+        tx_l.push_barrier(epoch(1000), false);
+        tx_r.push_barrier(epoch(1000), false);
+        hash_join.next().await.unwrap().unwrap();
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 4",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty("I I I I")
+        );
+
+        tx_l.push_barrier(epoch(1500), false);
+        tx_r.push_barrier(epoch(1500), false);
+        hash_join.next().await.unwrap().unwrap();
+
+        // the left row is still alive
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 7",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I I I
+                + 1 4 1 7"
+            )
+        );
+
+        tx_l.push_barrier(epoch(2500), false);
+        tx_r.push_barrier(epoch(2500), false);
+        hash_join.next().await.unwrap().unwrap();
+
+        // the left row is expired
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 8",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty("I I I I")
+        );
+
+        // deleting the expired row is ignored
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 1 4",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty("I I I I")
+        );
+
+        tx_l.push_barrier(epoch(3000), false);
+        tx_r.push_barrier(epoch(3000), false);
+        hash_join.next().await.unwrap().unwrap();
+        assert!(ks_l.scan(None, epoch(2500)).await.unwrap().is_empty());
+        assert_eq!(ks_r.scan(None, epoch(2500)).await.unwrap().len(), 2);
+
+        // the sweep deletes the expired right row from the state store
+        tx_l.push_barrier(epoch(4000), false);
+        tx_r.push_barrier(epoch(4000), false);
+        hash_join.next().await.unwrap().unwrap();
+        assert_eq!(ks_r.scan(None, epoch(3000)).await.unwrap().len(), 1);
+    }
 }
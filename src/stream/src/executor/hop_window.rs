@@ -18,14 +18,14 @@ use futures::StreamExt;
 use futures_async_stream::try_stream;
 use num_traits::CheckedSub;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::{DataChunk, StreamChunk};
+use risingwave_common::array::{DataChunk, Row, StreamChunk};
 use risingwave_common::types::{DataType, IntervalUnit, ScalarImpl};
 use risingwave_expr::expr::expr_binary_nonnull::new_binary_expr;
 use risingwave_expr::expr::{Expression, InputRefExpression, LiteralExpression};
 use risingwave_pb::expr::expr_node;
 
 use super::error::StreamExecutorError;
-use super::{BoxedExecutor, Executor, ExecutorInfo, Message};
+use super::{BoxedExecutor, Executor, ExecutorInfo, Message, Watermark};
 
 pub struct HopWindowExecutor {
     pub input: BoxedExecutor,
@@ -93,6 +93,7 @@ impl HopWindowExecutor {
             })?
             .get();
 
+        let input_data_types = input.schema().data_types();
         let schema = self.info.schema;
         let time_col_data_type = schema.fields()[time_col_idx].data_type();
         let time_col_ref = InputRefExpression::new(time_col_data_type, self.time_col_idx).boxed();
@@ -171,6 +172,36 @@ impl HopWindowExecutor {
         #[for_await]
         for msg in input.execute() {
             let msg = msg?;
+            if let Message::Watermark(watermark) = msg {
+                if watermark.col_idx == time_col_idx {
+                    // The earliest window that later rows may fall into starts at the hop start of
+                    // the watermark.
+                    let mut row = vec![None; input_data_types.len()];
+                    row[time_col_idx] = Some(watermark.val.clone());
+                    let data_chunk = DataChunk::from_rows(&[Row(row)], &input_data_types)
+                        .map_err(StreamExecutorError::eval_error)?;
+                    let hop_start_col = hop_start
+                        .eval(&data_chunk)
+                        .map_err(StreamExecutorError::eval_error)?;
+                    let hop_start_chunk = DataChunk::new(vec![Column::new(hop_start_col)], None);
+                    let window_start_idx = input_data_types.len();
+                    for (idx, expr) in [
+                        (window_start_idx, &window_start_exprs[0]),
+                        (window_start_idx + 1, &window_end_exprs[0]),
+                    ] {
+                        let val = expr
+                            .eval(&hop_start_chunk)
+                            .map_err(StreamExecutorError::eval_error)?
+                            .datum_at(0);
+                        if let Some(val) = val {
+                            yield Message::Watermark(Watermark::new(idx, DataType::Timestamp, val));
+                        }
+                    }
+                }
+                // Columns of the input are kept as is.
+                yield Message::Watermark(watermark);
+                continue;
+            }
             let Message::Chunk(chunk) = msg else {
                 // TODO: syn has not supported `let_else`, we desugar here manually.
                 yield std::task::Poll::Ready(msg);
@@ -207,13 +238,14 @@ impl HopWindowExecutor {
 
 #[cfg(test)]
 mod tests {
+    use chrono::NaiveDate;
     use futures::StreamExt;
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::catalog::{Field, Schema};
-    use risingwave_common::types::{DataType, IntervalUnit};
+    use risingwave_common::types::{DataType, IntervalUnit, NaiveDateTimeWrapper, ScalarImpl};
 
     use crate::executor::test_utils::MockSource;
-    use crate::executor::{Executor, ExecutorInfo, StreamChunk};
+    use crate::executor::{Executor, ExecutorInfo, Message, StreamChunk, Watermark};
 
     #[tokio::test]
     async fn test_execute() {
@@ -293,4 +325,53 @@ mod tests {
             )
         );
     }
+
+    #[tokio::test]
+    async fn test_watermark() {
+        let field1 = Field::unnamed(DataType::Int64);
+        let field2 = Field::with_name(DataType::Timestamp, "created_at");
+        let schema = Schema::new(vec![field1, field2]);
+        let timestamp = |h, m| {
+            ScalarImpl::NaiveDateTime(NaiveDateTimeWrapper::new(
+                NaiveDate::from_ymd(2022, 2, 2).and_hms(h, m, 0),
+            ))
+        };
+
+        let input = MockSource::with_messages(
+            schema.clone(),
+            vec![0],
+            vec![Message::Watermark(Watermark::new(
+                1,
+                DataType::Timestamp,
+                timestamp(10, 22),
+            ))],
+        )
+        .boxed();
+
+        let executor = super::HopWindowExecutor::new(
+            input,
+            ExecutorInfo {
+                schema,
+                pk_indices: vec![0],
+                identity: "test".to_string(),
+            },
+            1,
+            IntervalUnit::from_minutes(15),
+            IntervalUnit::from_minutes(30),
+        )
+        .boxed();
+        let mut stream = executor.execute();
+
+        // Rows after the watermark fall into windows starting from 10:00 at the earliest.
+        let expected = [
+            Watermark::new(2, DataType::Timestamp, timestamp(10, 0)),
+            Watermark::new(3, DataType::Timestamp, timestamp(10, 30)),
+            Watermark::new(1, DataType::Timestamp, timestamp(10, 22)),
+        ];
+        for watermark in expected {
+            let msg = stream.next().await.unwrap().unwrap();
+            assert_eq!(msg.into_watermark().unwrap(), watermark);
+        }
+        assert!(stream.next().await.unwrap().unwrap().is_stop());
+    }
 }
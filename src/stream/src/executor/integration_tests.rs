@@ -26,6 +26,7 @@ use risingwave_expr::expr::*;
 use super::*;
 use crate::executor::aggregation::{AggArgs, AggCall};
 use crate::executor::dispatch::*;
+use crate::executor::monitor::StreamingMetrics;
 use crate::executor::receiver::ReceiverExecutor;
 use crate::executor::test_utils::create_in_memory_keyspace_agg;
 use crate::executor::{
@@ -43,7 +44,13 @@ async fn test_merger_sum_aggr() {
         let schema = Schema {
             fields: vec![Field::unnamed(DataType::Int64)],
         };
-        let input = ReceiverExecutor::new(schema, vec![], input_rx);
+        let input = ReceiverExecutor::new(
+            schema,
+            vec![],
+            input_rx,
+            0,
+            Arc::new(StreamingMetrics::unused()),
+        );
         let append_only = false;
         // for the local aggregator, we need two states: row count and sum
         let aggregator = LocalSimpleAggExecutor::new(
@@ -99,7 +106,14 @@ async fn test_merger_sum_aggr() {
     let schema = Schema {
         fields: vec![Field::unnamed(DataType::Int64)],
     };
-    let receiver_op = Box::new(ReceiverExecutor::new(schema.clone(), vec![], rx));
+    let metrics = Arc::new(StreamingMetrics::unused());
+    let receiver_op = Box::new(ReceiverExecutor::new(
+        schema.clone(),
+        vec![],
+        rx,
+        0,
+        metrics.clone(),
+    ));
     let dispatcher = DispatchExecutor::new(
         receiver_op,
         vec![DispatcherImpl::RoundRobin(RoundRobinDataDispatcher::new(
@@ -107,13 +121,14 @@ async fn test_merger_sum_aggr() {
         ))],
         0,
         ctx,
+        metrics.clone(),
     );
     let context = SharedContext::for_test().into();
     let actor = Actor::new(dispatcher, 0, context);
     handles.push(tokio::spawn(actor.run()));
 
     // use a merge operator to collect data from dispatchers before sending them to aggregator
-    let merger = MergeExecutor::new(schema, vec![], 0, outputs);
+    let merger = MergeExecutor::new(schema, vec![], 0, outputs, metrics);
 
     // for global aggregator, we need to sum data and sum row count
     let append_only = false;
@@ -148,6 +163,7 @@ async fn test_merger_sum_aggr() {
             Box::new(InputRefExpression::new(DataType::Int64, 1)),
         ],
         3,
+        vec![],
     );
 
     let items = Arc::new(Mutex::new(vec![]));
@@ -219,6 +235,7 @@ impl StreamConsumer for MockConsumer {
                 match item? {
                     Message::Chunk(chunk) => data.lock().unwrap().push(chunk),
                     Message::Barrier(barrier) => yield barrier,
+                    Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                 }
             }
         }
@@ -105,6 +105,8 @@ impl LocalSimpleAggExecutor {
                     Self::apply_chunk(&agg_calls, &mut states, chunk)?;
                     is_dirty = true;
                 }
+                // The output of a simple agg has no column to carry the watermark.
+                Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                 m @ Message::Barrier(_) => {
                     if is_dirty {
                         is_dirty = false;
@@ -113,6 +113,8 @@ impl LookupUnionExecutor {
                     end = false;
                     match msg {
                         msg @ Message::Chunk(_) => yield msg,
+                        // Watermarks of the inputs are not aligned, so they are not propagated.
+                        Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                         Message::Barrier(barrier) => {
                             if let Some(this_barrier) = &this_barrier {
                                 if this_barrier != &barrier {
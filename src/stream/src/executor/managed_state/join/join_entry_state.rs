@@ -49,6 +49,9 @@ pub struct JoinEntryState<S: StateStore> {
 
     /// The keyspace to operate on.
     keyspace: Keyspace<S>,
+
+    /// Rows inserted before this epoch are expired by state TTL, and removed once cached.
+    min_epoch: u64,
 }
 
 impl<S: StateStore> JoinEntryState<S> {
@@ -63,6 +66,7 @@ impl<S: StateStore> JoinEntryState<S> {
             data_types,
             pk_data_types,
             keyspace,
+            min_epoch: 0,
         }
     }
 
@@ -82,6 +86,7 @@ impl<S: StateStore> JoinEntryState<S> {
                 data_types,
                 pk_data_types,
                 keyspace,
+                min_epoch: 0,
             }))
         } else {
             Ok(None)
@@ -174,9 +179,38 @@ impl<S: StateStore> JoinEntryState<S> {
         }
 
         self.cached = Some(cached);
+        self.remove_expired();
         Ok(())
     }
 
+    /// Removes the rows inserted before `min_epoch`. Rows not cached yet are removed when the
+    /// cache is populated.
+    pub fn expire(&mut self, min_epoch: u64) {
+        self.min_epoch = self.min_epoch.max(min_epoch);
+        self.remove_expired();
+    }
+
+    fn remove_expired(&mut self) {
+        let Some(cached) = self.cached.as_ref() else {
+            return;
+        };
+        let expired = cached
+            .iter()
+            .filter(|(_, row)| row.is_expired(self.min_epoch))
+            .map(|(pk, _)| pk.clone())
+            .collect_vec();
+        for pk in expired {
+            self.remove(pk);
+        }
+    }
+
+    pub async fn contains(&mut self, pk: &PkType, epoch: u64) -> Result<bool> {
+        if self.cached.is_none() {
+            self.populate_cache(epoch).await?;
+        }
+        Ok(self.cached.as_ref().unwrap().contains_key(pk))
+    }
+
     #[allow(dead_code)]
     pub fn clear_cache(&mut self) {
         assert!(
@@ -243,7 +277,7 @@ mod tests {
             let row: Row = row_ref.into();
             let pk = pk_indices.iter().map(|idx| row[*idx].clone()).collect_vec();
             let pk = Row(pk);
-            let join_row = JoinRow { row, degree: 0 };
+            let join_row = JoinRow::new(row, 0, 0);
             managed_state.insert(pk, join_row);
         }
 
@@ -24,6 +24,7 @@ use risingwave_common::collection::evictable::EvictableHashMap;
 use risingwave_common::error::{ErrorCode, Result as RwResult};
 use risingwave_common::hash::{HashKey, PrecomputedBuildHasher};
 use risingwave_common::types::{DataType, Datum};
+use risingwave_common::util::epoch::Epoch;
 use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
 use risingwave_storage::{Keyspace, StateStore};
 
@@ -32,6 +33,8 @@ use risingwave_storage::{Keyspace, StateStore};
 pub struct JoinRow {
     pub row: Row,
     degree: u64,
+    /// The epoch in which the row was inserted, used to expire the row with state TTL.
+    epoch: u64,
 }
 
 impl Index<usize> for JoinRow {
@@ -43,8 +46,12 @@ impl Index<usize> for JoinRow {
 }
 
 impl JoinRow {
-    pub fn new(row: Row, degree: u64) -> Self {
-        Self { row, degree }
+    pub fn new(row: Row, degree: u64, epoch: u64) -> Self {
+        Self { row, degree, epoch }
+    }
+
+    pub fn is_expired(&self, min_epoch: u64) -> bool {
+        self.epoch < min_epoch
     }
 
     #[allow(dead_code)]
@@ -85,6 +92,11 @@ impl JoinRow {
         degree_buf.put_u64_le(self.degree);
         vec.extend_from_slice(&degree_buf);
 
+        // Serialize epoch.
+        let mut epoch_buf: Vec<u8> = vec![];
+        epoch_buf.put_u64_le(self.epoch);
+        vec.extend_from_slice(&epoch_buf);
+
         Ok(vec)
     }
 }
@@ -108,15 +120,26 @@ impl JoinRowDeserializer {
             values.push(deserialize_cell(&mut data, ty)?);
         }
         let degree = data.get_u64_le();
+        // Rows written before the epoch was recorded are treated as inserted at epoch 0.
+        let epoch = if data.has_remaining() {
+            data.get_u64_le()
+        } else {
+            0
+        };
         Ok(JoinRow {
             row: Row(values),
             degree,
+            epoch,
         })
     }
 }
 
 type PkType = Row;
 
+/// The maximum number of rows scanned by each call to [`JoinHashMap::sweep_expired`], which is
+/// made on every barrier.
+const SWEEP_BATCH_SIZE: usize = 1024;
+
 pub type StateValueType = JoinRow;
 pub type HashValueType<S> = JoinEntryState<S>;
 
@@ -133,6 +156,12 @@ pub struct JoinHashMap<K: HashKey, S: StateStore> {
     keyspace: Keyspace<S>,
     /// Current epoch
     current_epoch: u64,
+    /// Rows inserted longer ago than this in processing time are expired. `None` means no TTL.
+    state_ttl_ms: Option<u64>,
+    /// The epoch when the last pass of sweeping expired rows started.
+    last_sweep_epoch: u64,
+    /// The key to resume the current pass of sweeping from, if it's not done yet.
+    sweep_cursor: Option<Vec<u8>>,
 }
 
 impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
@@ -143,6 +172,7 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
         join_key_indices: Vec<usize>,
         data_types: Vec<DataType>,
         keyspace: Keyspace<S>,
+        state_ttl_ms: Option<u64>,
     ) -> Self {
         let pk_data_types = pk_indices
             .iter()
@@ -160,6 +190,9 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
             pk_data_types: pk_data_types.into(),
             keyspace,
             current_epoch: 0,
+            state_ttl_ms,
+            last_sweep_epoch: 0,
+            sweep_cursor: None,
         }
     }
 
@@ -167,6 +200,16 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
         self.current_epoch = epoch;
     }
 
+    pub fn state_ttl_enabled(&self) -> bool {
+        self.state_ttl_ms.is_some()
+    }
+
+    /// Rows inserted before this epoch are expired, if state TTL is enabled.
+    fn min_epoch(&self) -> Option<u64> {
+        self.state_ttl_ms
+            .map(|ttl| Epoch(self.current_epoch).subtract_ms(ttl).0)
+    }
+
     fn get_state_keyspace(&self, key: &K) -> RwResult<Keyspace<S>> {
         // TODO: in pure in-memory engine, we should not do this serialization.
         let key = key.clone().deserialize(self.join_key_data_types.iter())?;
@@ -194,10 +237,12 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
 
     /// Returns a mutable reference to the value of the key in the memory, if does not exist, look
     /// up in remote storage and return, if still not exist, return None.
+    /// With state TTL enabled, the expired rows are removed from the returned state.
     pub async fn get_mut(&mut self, key: &K) -> Option<&mut HashValueType<S>> {
+        let min_epoch = self.min_epoch();
         let state = self.inner.get(key);
         // TODO: we should probably implement a entry function for `LruCache`
-        match state {
+        let mut state = match state {
             Some(_) => self.inner.get_mut(key),
             None => {
                 let remote_state = self.fetch_cached_state(key).await.unwrap();
@@ -206,7 +251,70 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
                     self.inner.get_mut(key).unwrap()
                 })
             }
+        };
+        if let (Some(state), Some(min_epoch)) = (state.as_deref_mut(), min_epoch) {
+            state.expire(min_epoch);
+        }
+        state
+    }
+
+    /// Removes the row of `pk` under `key` if it has not been expired or removed yet. Returns
+    /// whether the row was removed.
+    pub async fn remove_if_exists(&mut self, key: &K, pk: PkType) -> RwResult<bool> {
+        let epoch = self.current_epoch;
+        match self.get_mut(key).await {
+            Some(state) if state.contains(&pk, epoch).await? => {
+                state.remove(pk);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Deletes expired rows from the state store, including those under keys that are no longer
+    /// accessed. Each call scans at most [`SWEEP_BATCH_SIZE`] rows, resuming from where the last
+    /// call stopped, and a new pass over the state starts once a quarter of the TTL has passed
+    /// since the last one started. Expired rows still in the cache are removed lazily on access.
+    pub async fn sweep_expired(&mut self) -> RwResult<()> {
+        let (ttl, min_epoch) = match (self.state_ttl_ms, self.min_epoch()) {
+            (Some(ttl), Some(min_epoch)) => (ttl, min_epoch),
+            _ => return Ok(()),
+        };
+        let epoch = self.current_epoch;
+        let start_key = match self.sweep_cursor.take() {
+            Some(start_key) => start_key,
+            None => {
+                if Epoch(epoch).physical_time()
+                    < Epoch(self.last_sweep_epoch).physical_time() + ttl / 4
+                {
+                    return Ok(());
+                }
+                self.last_sweep_epoch = epoch;
+                vec![]
+            }
+        };
+
+        let rows = self
+            .keyspace
+            .scan_with_start_key(start_key, Some(SWEEP_BATCH_SIZE), epoch)
+            .await?;
+        let deserializer = JoinRowDeserializer::new(self.data_types.to_vec());
+        let mut write_batch = self.keyspace.state_store().start_write_batch();
+        let mut local = write_batch.prefixify(&self.keyspace);
+        for (key, value) in &rows {
+            if deserializer.deserialize(value)?.is_expired(min_epoch) {
+                local.delete(key);
+            }
+        }
+        write_batch.ingest(epoch).await?;
+
+        // Resume from the key right after the last scanned one, unless the pass is done.
+        if rows.len() == SWEEP_BATCH_SIZE {
+            let mut next_key = rows.last().unwrap().0.to_vec();
+            next_key.push(0);
+            self.sweep_cursor = Some(next_key);
         }
+        Ok(())
     }
 
     /// Returns a mutable reference to the value of the key in the memory, if does not exist, look
@@ -309,3 +417,58 @@ impl<K: HashKey, S: StateStore> DerefMut for JoinHashMap<K, S> {
         &mut self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::TableId;
+    use risingwave_common::hash::Key64;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_storage::memory::MemoryStateStore;
+    use risingwave_storage::storage_value::StorageValue;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sweep_expired_incrementally() {
+        let keyspace = Keyspace::table_root(MemoryStateStore::new(), &TableId::new(0));
+        let epoch = |ms| Epoch::from_physical_time(ms).0;
+        let mut ht = JoinHashMap::<Key64, _>::new(
+            16,
+            vec![0],
+            vec![0],
+            vec![DataType::Int64],
+            keyspace.clone(),
+            Some(1000),
+        );
+
+        // Rows inserted at 1s are expired at 3s, except the last one inserted at 2.5s.
+        let num_rows = SWEEP_BATCH_SIZE + 6;
+        let mut write_batch = keyspace.state_store().start_write_batch();
+        let mut local = write_batch.prefixify(&keyspace);
+        for i in 0..num_rows {
+            let inserted_at = if i == num_rows - 1 { 2500 } else { 1000 };
+            let row = JoinRow::new(
+                Row(vec![Some(ScalarImpl::Int64(i as i64))]),
+                0,
+                epoch(inserted_at),
+            );
+            local.put(
+                (i as u64).to_be_bytes(),
+                StorageValue::new_default_put(row.serialize().unwrap()),
+            );
+        }
+        write_batch.ingest(epoch(1000)).await.unwrap();
+
+        // A barrier sweeps one batch of rows at most.
+        ht.update_epoch(epoch(3000));
+        ht.sweep_expired().await.unwrap();
+        let rows = keyspace.scan(None, epoch(3000)).await.unwrap();
+        assert_eq!(rows.len(), 6);
+
+        // The next one finishes the pass.
+        ht.sweep_expired().await.unwrap();
+        let rows = keyspace.scan(None, epoch(3000)).await.unwrap();
+        assert_eq!(rows.len(), 1);
+        assert!(ht.sweep_cursor.is_none());
+    }
+}
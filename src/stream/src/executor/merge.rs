@@ -12,27 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
 use async_trait::async_trait;
-use futures::channel::mpsc::{Receiver, Sender};
+use futures::channel::mpsc::{Receiver, Sender, UnboundedSender};
 use futures::future::select_all;
-use futures::{SinkExt, StreamExt};
+use futures::{Future, FutureExt, SinkExt, StreamExt};
 use futures_async_stream::{for_await, try_stream};
 use itertools::Itertools;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
-use risingwave_pb::task_service::GetStreamResponse;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_pb::task_service::{get_stream_request, GetStreamRequest, GetStreamResponse};
 use risingwave_rpc_client::ComputeClient;
 use tonic::Streaming;
 use tracing_futures::Instrument;
 
 use super::error::StreamExecutorError;
+use super::monitor::StreamingMetrics;
 use super::*;
-use crate::task::UpDownActorIds;
+use crate::task::{UpDownActorIds, REMOTE_INPUT_PERMITS, REMOTE_INPUT_PERMITS_BATCH};
 
 /// Receive data from `gRPC` and forwards to `MergerExecutor`/`ReceiverExecutor`
 pub struct RemoteInput {
     stream: Streaming<GetStreamResponse>,
     sender: Sender<Message>,
+
+    /// Grants the permits of the forwarded messages back to the upstream.
+    permits_tx: UnboundedSender<GetStreamRequest>,
+
+    up_down_ids: UpDownActorIds,
+
+    metrics: Arc<StreamingMetrics>,
 }
 
 impl RemoteInput {
@@ -42,12 +55,29 @@ impl RemoteInput {
         client: ComputeClient,
         up_down_ids: UpDownActorIds,
         sender: Sender<Message>,
+        metrics: Arc<StreamingMetrics>,
     ) -> Result<Self> {
-        let stream = client.get_stream(up_down_ids.0, up_down_ids.1).await?;
-        Ok(Self { stream, sender })
+        let (stream, permits_tx) = client
+            .get_stream(up_down_ids.0, up_down_ids.1, REMOTE_INPUT_PERMITS)
+            .await?;
+        Ok(Self {
+            stream,
+            sender,
+            permits_tx,
+            up_down_ids,
+            metrics,
+        })
     }
 
     pub async fn run(mut self) {
+        let up_actor_id = self.up_down_ids.0.to_string();
+        let down_actor_id = self.up_down_ids.1.to_string();
+        let recv_blocked_duration = self
+            .metrics
+            .exchange_recv_blocked_duration
+            .with_label_values(&[&up_actor_id, &down_actor_id]);
+        let mut consumed_permits = 0;
+
         #[for_await]
         for data_res in self.stream {
             match data_res {
@@ -59,7 +89,27 @@ impl RemoteInput {
                     );
                     match msg_res {
                         Ok(msg) => {
+                            let start_time = Instant::now();
                             self.sender.send(msg).await.unwrap();
+                            recv_blocked_duration.inc_by(start_time.elapsed().as_nanos() as u64);
+
+                            // The permit is only returned once the message is buffered by the
+                            // receiving actor, so a slow actor holds back its upstream.
+                            consumed_permits += 1;
+                            if consumed_permits >= REMOTE_INPUT_PERMITS_BATCH {
+                                let add_permits = GetStreamRequest {
+                                    value: Some(get_stream_request::Value::AddPermits(
+                                        get_stream_request::AddPermits {
+                                            permits: consumed_permits,
+                                        },
+                                    )),
+                                };
+                                if self.permits_tx.unbounded_send(add_permits).is_err() {
+                                    error!("RemoteInput failed to add permits");
+                                    break;
+                                }
+                                consumed_permits = 0;
+                            }
                         }
                         Err(e) => {
                             error!("RemoteInput forward message error:{}", e);
@@ -86,6 +136,8 @@ pub struct MergeExecutor {
     actor_id: u32,
 
     info: ExecutorInfo,
+
+    metrics: Arc<StreamingMetrics>,
 }
 
 impl MergeExecutor {
@@ -94,10 +146,12 @@ impl MergeExecutor {
         pk_indices: PkIndices,
         actor_id: u32,
         inputs: Vec<Receiver<Message>>,
+        metrics: Arc<StreamingMetrics>,
     ) -> Self {
         Self {
             upstreams: inputs,
             actor_id,
+            metrics,
             info: ExecutorInfo {
                 schema,
                 pk_indices,
@@ -107,6 +161,108 @@ impl MergeExecutor {
     }
 }
 
+/// Receive the next message from the `idx`th upstream.
+fn upstream_future(
+    idx: usize,
+    upstream: Receiver<Message>,
+) -> impl Future<Output = (Option<Message>, (usize, Receiver<Message>))> + Unpin {
+    upstream
+        .into_future()
+        .map(move |(msg, upstream)| (msg, (idx, upstream)))
+}
+
+/// Aligns the watermarks of the upstreams. The watermark of a column is only advanced when all of
+/// the active upstreams have advanced it, i.e. it's the minimum of their watermarks.
+///
+/// An upstream that reports [`WatermarkStatus::Idle`], e.g. a source actor with no splits or whose
+/// splits have no new data, doesn't hold back the watermarks until it reports
+/// [`WatermarkStatus::Active`] again. The merged stream is idle when all upstreams are.
+struct WatermarkAligner {
+    /// Whether each upstream is idle.
+    idle: Vec<bool>,
+    /// The latest watermark of each upstream, by column.
+    upstream_watermarks: HashMap<usize, (DataType, Vec<Option<ScalarImpl>>)>,
+    /// The latest watermark emitted downstream, by column.
+    emitted: HashMap<usize, ScalarImpl>,
+}
+
+impl WatermarkAligner {
+    fn new(num_upstreams: usize) -> Self {
+        Self {
+            idle: vec![false; num_upstreams],
+            upstream_watermarks: HashMap::new(),
+            emitted: HashMap::new(),
+        }
+    }
+
+    /// Receive a watermark from the `upstream_idx`th upstream, and return the aligned watermark
+    /// if it advances.
+    fn handle_watermark(&mut self, upstream_idx: usize, watermark: Watermark) -> Option<Watermark> {
+        let num_upstreams = self.idle.len();
+        let (_, watermarks) = self
+            .upstream_watermarks
+            .entry(watermark.col_idx)
+            .or_insert_with(|| (watermark.data_type.clone(), vec![None; num_upstreams]));
+        let upstream = &mut watermarks[upstream_idx];
+        if upstream.as_ref().map_or(true, |val| val < &watermark.val) {
+            *upstream = Some(watermark.val);
+        }
+        self.align(watermark.col_idx)
+    }
+
+    /// Receive the status of the `upstream_idx`th upstream. Return the status of the merged stream
+    /// if it changes, and the aligned watermarks that advance as the upstream turns idle.
+    fn handle_status(
+        &mut self,
+        upstream_idx: usize,
+        status: WatermarkStatus,
+    ) -> (Option<WatermarkStatus>, Vec<Watermark>) {
+        let was_idle = self.all_idle();
+        self.idle[upstream_idx] = status == WatermarkStatus::Idle;
+        let merged_status = match (was_idle, self.all_idle()) {
+            (false, true) => Some(WatermarkStatus::Idle),
+            (true, false) => Some(WatermarkStatus::Active),
+            _ => None,
+        };
+
+        let col_indices = self
+            .upstream_watermarks
+            .keys()
+            .copied()
+            .sorted()
+            .collect_vec();
+        let watermarks = col_indices
+            .into_iter()
+            .filter_map(|col_idx| self.align(col_idx))
+            .collect();
+        (merged_status, watermarks)
+    }
+
+    fn all_idle(&self) -> bool {
+        self.idle.iter().all(|idle| *idle)
+    }
+
+    fn align(&mut self, col_idx: usize) -> Option<Watermark> {
+        let (data_type, watermarks) = &self.upstream_watermarks[&col_idx];
+        let min = watermarks
+            .iter()
+            .zip_eq(&self.idle)
+            .filter(|(_, idle)| !**idle)
+            .map(|(val, _)| val.as_ref())
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .min()?
+            .clone();
+        match self.emitted.get(&col_idx) {
+            Some(emitted) if emitted >= &min => None,
+            _ => {
+                self.emitted.insert(col_idx, min.clone());
+                Some(Watermark::new(col_idx, data_type.clone(), min))
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl Executor for MergeExecutor {
     fn execute(self: Box<Self>) -> BoxedMessageStream {
@@ -129,23 +285,35 @@ impl Executor for MergeExecutor {
 impl MergeExecutor {
     #[try_stream(ok = Message, error = StreamExecutorError)]
     async fn execute_inner(self) {
-        let mut upstreams = self.upstreams;
+        let mut watermark_aligner = WatermarkAligner::new(self.upstreams.len());
+        let mut upstreams = self.upstreams.into_iter().enumerate().collect_vec();
+        let actor_id_string = self.actor_id.to_string();
+        let in_record_cnt = self
+            .metrics
+            .actor_in_record_cnt
+            .with_label_values(&[&actor_id_string]);
+        let barrier_align_duration = self
+            .metrics
+            .actor_barrier_align_duration
+            .with_label_values(&[&actor_id_string]);
 
         loop {
-            // Futures of all active upstreams.
+            // Futures of all active upstreams, tagged with the index of the upstream.
             let mut active = upstreams
                 .into_iter()
-                .map(|ch| ch.into_future())
+                .map(|(idx, ch)| upstream_future(idx, ch))
                 .collect_vec();
             // Channels that're blocked by the barrier to align.
             let mut blocked = Vec::with_capacity(active.len());
             // The current barrier to align.
             let mut current_barrier = None;
+            // When the first barrier of the epoch arrived.
+            let mut align_start_time = None;
 
             // 1. Align the barriers.
             while !active.is_empty() {
                 // Poll upstreams and get a message from the ready one.
-                let ((message, from), _id, remainings) = select_all(active)
+                let ((message, (upstream_idx, from)), _id, remainings) = select_all(active)
                     .instrument(tracing::trace_span!("idle"))
                     .await;
 
@@ -157,11 +325,35 @@ impl MergeExecutor {
                 active = remainings;
 
                 match message {
-                    Message::Chunk(_) => {
+                    Message::Chunk(ref chunk) => {
+                        in_record_cnt.inc_by(chunk.cardinality() as u64);
                         // We may still receive message from this channel.
-                        active.push(from.into_future());
+                        active.push(upstream_future(upstream_idx, from));
                         yield message;
                     }
+                    Message::Watermark(watermark) => {
+                        active.push(upstream_future(upstream_idx, from));
+                        if let Some(watermark) =
+                            watermark_aligner.handle_watermark(upstream_idx, watermark)
+                        {
+                            yield Message::Watermark(watermark);
+                        }
+                    }
+                    Message::WatermarkStatus(status) => {
+                        active.push(upstream_future(upstream_idx, from));
+                        let (status, watermarks) =
+                            watermark_aligner.handle_status(upstream_idx, status);
+                        // Turn active before the watermarks of the new active upstreams.
+                        if let Some(status @ WatermarkStatus::Active) = status {
+                            yield Message::WatermarkStatus(status);
+                        }
+                        for watermark in watermarks {
+                            yield Message::Watermark(watermark);
+                        }
+                        if let Some(status @ WatermarkStatus::Idle) = status {
+                            yield Message::WatermarkStatus(status);
+                        }
+                    }
                     Message::Barrier(barrier) => {
                         // Align the barrier.
                         if let Some(current_barrier) = current_barrier.as_ref() {
@@ -174,14 +366,16 @@ impl MergeExecutor {
                             assert_eq!(&barrier, current_barrier);
                         } else {
                             current_barrier = Some(barrier);
+                            align_start_time = Some(Instant::now());
                         }
                         // We'll not receive message from this channel during this epoch.
-                        blocked.push(from);
+                        blocked.push((upstream_idx, from));
                     }
                 }
             }
 
             // 2. Yield the barrier to downstream once all barriers collected from upstream.
+            barrier_align_duration.observe(align_start_time.unwrap().elapsed().as_secs_f64());
             let barrier = current_barrier.unwrap();
             let to_stop = barrier.is_to_stop_actor(self.actor_id);
             yield Message::Barrier(barrier);
@@ -199,7 +393,7 @@ impl MergeExecutor {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
     use std::sync::Arc;
     use std::thread::sleep;
     use std::time::Duration;
@@ -209,6 +403,7 @@ mod tests {
     use futures::SinkExt;
     use itertools::Itertools;
     use risingwave_common::array::{Op, StreamChunk};
+    use risingwave_common::types::DataType;
     use risingwave_pb::data::StreamMessage;
     use risingwave_pb::task_service::exchange_service_server::{
         ExchangeService, ExchangeServiceServer,
@@ -230,6 +425,81 @@ mod tests {
         StreamChunk::new(ops, vec![], None)
     }
 
+    #[test]
+    fn test_watermark_aligner() {
+        let watermark =
+            |col_idx, val| Watermark::new(col_idx, DataType::Int64, ScalarImpl::Int64(val));
+        let mut aligner = WatermarkAligner::new(2);
+
+        // Not all upstreams have the watermark yet.
+        assert_eq!(aligner.handle_watermark(0, watermark(0, 10)), None);
+        assert_eq!(aligner.handle_watermark(0, watermark(1, 10)), None);
+        assert_eq!(
+            aligner.handle_watermark(1, watermark(0, 5)),
+            Some(watermark(0, 5))
+        );
+        // The minimum doesn't advance.
+        assert_eq!(aligner.handle_watermark(1, watermark(0, 3)), None);
+        assert_eq!(aligner.handle_watermark(0, watermark(0, 20)), None);
+        assert_eq!(
+            aligner.handle_watermark(1, watermark(0, 30)),
+            Some(watermark(0, 20))
+        );
+        assert_eq!(
+            aligner.handle_watermark(1, watermark(1, 15)),
+            Some(watermark(1, 10))
+        );
+    }
+
+    #[test]
+    fn test_watermark_aligner_idle_upstream() {
+        let watermark = |val| Watermark::new(0, DataType::Int64, ScalarImpl::Int64(val));
+        let mut aligner = WatermarkAligner::new(3);
+
+        // Upstream 2 never sends anything, e.g. a source actor with no splits, until it reports
+        // itself idle.
+        assert_eq!(aligner.handle_watermark(0, watermark(10)), None);
+        assert_eq!(aligner.handle_watermark(1, watermark(20)), None);
+        assert_eq!(
+            aligner.handle_status(2, WatermarkStatus::Idle),
+            (None, vec![watermark(10)])
+        );
+
+        // A slow upstream keeps holding back the watermark, however long it sends nothing.
+        assert_eq!(aligner.handle_watermark(1, watermark(30)), None);
+
+        // Upstream 0 turns idle too.
+        assert_eq!(
+            aligner.handle_status(0, WatermarkStatus::Idle),
+            (None, vec![watermark(30)])
+        );
+
+        // An idle upstream turns active again, and holds back the watermark from then on.
+        assert_eq!(
+            aligner.handle_status(0, WatermarkStatus::Active),
+            (None, vec![])
+        );
+        assert_eq!(aligner.handle_watermark(1, watermark(40)), None);
+        assert_eq!(
+            aligner.handle_watermark(0, watermark(35)),
+            Some(watermark(35))
+        );
+
+        // The merged stream is idle when all upstreams are, and nothing advances.
+        assert_eq!(
+            aligner.handle_status(0, WatermarkStatus::Idle),
+            (None, vec![watermark(40)])
+        );
+        assert_eq!(
+            aligner.handle_status(1, WatermarkStatus::Idle),
+            (Some(WatermarkStatus::Idle), vec![])
+        );
+        assert_eq!(
+            aligner.handle_status(2, WatermarkStatus::Active),
+            (Some(WatermarkStatus::Active), vec![])
+        );
+    }
+
     #[tokio::test]
     async fn test_merger() {
         const CHANNEL_NUMBER: usize = 10;
@@ -240,7 +510,13 @@ mod tests {
             txs.push(tx);
             rxs.push(rx);
         }
-        let merger = MergeExecutor::new(Schema::default(), vec![], 0, rxs);
+        let merger = MergeExecutor::new(
+            Schema::default(),
+            vec![],
+            0,
+            rxs,
+            Arc::new(StreamingMetrics::unused()),
+        );
         let mut handles = Vec::with_capacity(CHANNEL_NUMBER);
 
         let epochs = (10..1000u64).step_by(10).collect_vec();
@@ -311,10 +587,15 @@ mod tests {
 
         async fn get_stream(
             &self,
-            _request: Request<GetStreamRequest>,
+            request: Request<Streaming<GetStreamRequest>>,
         ) -> std::result::Result<Response<Self::GetStreamStream>, Status> {
             let (tx, rx) = tokio::sync::mpsc::channel(10);
             self.rpc_called.store(true, Ordering::SeqCst);
+            // the downstream grants the initial permits when starting the exchange
+            let get_req = request.into_inner().message().await?.unwrap();
+            assert_matches!(get_req.value, Some(get_stream_request::Value::Get(get)) => {
+                assert_eq!(get.permits, REMOTE_INPUT_PERMITS);
+            });
             // send stream_chunk
             let stream_chunk = StreamChunk::default().to_protobuf();
             tx.send(Ok(GetStreamResponse {
@@ -345,6 +626,124 @@ mod tests {
         }
     }
 
+    /// Sends empty chunks as long as the downstream grants permits, and counts the sent ones.
+    struct PermitExchangeService {
+        sent: Arc<AtomicU32>,
+    }
+
+    #[async_trait::async_trait]
+    impl ExchangeService for PermitExchangeService {
+        type GetDataStream = ReceiverStream<std::result::Result<GetDataResponse, Status>>;
+        type GetStreamStream = ReceiverStream<std::result::Result<GetStreamResponse, Status>>;
+
+        async fn get_data(
+            &self,
+            _: Request<GetDataRequest>,
+        ) -> std::result::Result<Response<Self::GetDataStream>, Status> {
+            unimplemented!()
+        }
+
+        async fn get_stream(
+            &self,
+            request: Request<Streaming<GetStreamRequest>>,
+        ) -> std::result::Result<Response<Self::GetStreamStream>, Status> {
+            // Large enough that the permits, not the buffer, stop the sending.
+            let (tx, rx) = tokio::sync::mpsc::channel(4 * REMOTE_INPUT_PERMITS as usize);
+            let mut requests = request.into_inner();
+            let get_req = requests.message().await?.unwrap();
+            let mut permits = match get_req.value {
+                Some(get_stream_request::Value::Get(get)) => get.permits,
+                _ => unreachable!(),
+            };
+            let sent = self.sent.clone();
+            tokio::spawn(async move {
+                loop {
+                    while permits == 0 {
+                        match requests.message().await {
+                            Ok(Some(GetStreamRequest {
+                                value: Some(get_stream_request::Value::AddPermits(add)),
+                            })) => permits += add.permits,
+                            _ => return,
+                        }
+                    }
+                    permits -= 1;
+                    let response = GetStreamResponse {
+                        message: Some(StreamMessage {
+                            stream_message: Some(
+                                risingwave_pb::data::stream_message::StreamMessage::StreamChunk(
+                                    StreamChunk::default().to_protobuf(),
+                                ),
+                            ),
+                        }),
+                    };
+                    if tx.send(Ok(response)).await.is_err() {
+                        return;
+                    }
+                    sent.fetch_add(1, Ordering::SeqCst);
+                }
+            });
+            Ok(Response::new(ReceiverStream::new(rx)))
+        }
+    }
+
+    async fn wait_for_sent(sent: &AtomicU32, expected: u32) {
+        for _ in 0..100 {
+            if sent.load(Ordering::SeqCst) >= expected {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        // Give the upstream a chance to send more than it's allowed to.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(sent.load(Ordering::SeqCst), expected);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_remote_input_permits() {
+        let sent = Arc::new(AtomicU32::new(0));
+        let addr = "127.0.0.1:12349".parse().unwrap();
+        let (shutdown_send, mut shutdown_recv) = tokio::sync::mpsc::unbounded_channel();
+        let exchange_svc = ExchangeServiceServer::new(PermitExchangeService { sent: sent.clone() });
+        let join_handle = tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(exchange_svc)
+                .serve_with_shutdown(addr, async move {
+                    shutdown_recv.recv().await;
+                })
+                .await
+                .unwrap();
+        });
+        sleep(Duration::from_secs(1));
+
+        // The receiving actor buffers a single message and doesn't consume it yet.
+        let (tx, mut rx) = channel(0);
+        let input_handle = tokio::spawn(async move {
+            let remote_input = RemoteInput::create(
+                ComputeClient::new(addr.into()).await.unwrap(),
+                (0, 0),
+                tx,
+                Arc::new(StreamingMetrics::unused()),
+            )
+            .await
+            .unwrap();
+            remote_input.run().await
+        });
+
+        // The upstream stops once the initial permits are used up.
+        wait_for_sent(&sent, REMOTE_INPUT_PERMITS).await;
+
+        // Once a batch of messages is consumed, their permits are granted back and the upstream
+        // resumes.
+        for _ in 0..=REMOTE_INPUT_PERMITS_BATCH {
+            assert_matches!(rx.next().await.unwrap(), Message::Chunk(_));
+        }
+        wait_for_sent(&sent, REMOTE_INPUT_PERMITS + REMOTE_INPUT_PERMITS_BATCH).await;
+
+        input_handle.abort();
+        shutdown_send.send(()).unwrap();
+        join_handle.await.unwrap();
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_stream_exchange_client() {
         let rpc_called = Arc::new(AtomicBool::new(false));
@@ -372,10 +771,14 @@ mod tests {
         assert!(server_run.load(Ordering::SeqCst));
         let (tx, mut rx) = channel(16);
         let input_handle = tokio::spawn(async move {
-            let remote_input =
-                RemoteInput::create(ComputeClient::new(addr.into()).await.unwrap(), (0, 0), tx)
-                    .await
-                    .unwrap();
+            let remote_input = RemoteInput::create(
+                ComputeClient::new(addr.into()).await.unwrap(),
+                (0, 0),
+                tx,
+                Arc::new(StreamingMetrics::unused()),
+            )
+            .await
+            .unwrap();
             remote_input.run().await
         });
         assert_matches!(rx.next().await.unwrap(), Message::Chunk(chunk) => {
@@ -24,14 +24,19 @@ use risingwave_common::array::column::Column;
 use risingwave_common::array::{ArrayImpl, ArrayRef, DataChunk, StreamChunk};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::Schema;
-use risingwave_common::error::Result;
-use risingwave_common::types::DataType;
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError, ToRwResult};
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
+use risingwave_connector::SplitImpl;
 use risingwave_pb::common::ActorInfo;
 use risingwave_pb::data::barrier::Mutation as ProstMutation;
 use risingwave_pb::data::stream_message::StreamMessage;
 use risingwave_pb::data::{
     AddMutation, Barrier as ProstBarrier, DispatcherMutation, Epoch as ProstEpoch, NothingMutation,
-    StopMutation, StreamMessage as ProstStreamMessage, UpdateMutation,
+    SourceChangeSplit, SourceChangeSplitMutation, StopMutation,
+    StreamMessage as ProstStreamMessage, UpdateMutation, Watermark as ProstWatermark,
+    WatermarkStatus as ProstWatermarkStatus,
 };
 use smallvec::SmallVec;
 use tracing::trace_span;
@@ -165,6 +170,8 @@ pub enum Mutation {
     Stop(HashSet<ActorId>),
     UpdateOutputs(HashMap<(ActorId, DispatcherId), Vec<ActorInfo>>),
     AddOutput(HashMap<(ActorId, DispatcherId), Vec<ActorInfo>>),
+    /// Splits newly assigned to source actors by the meta service.
+    SourceChangeSplit(HashMap<ActorId, Vec<SplitImpl>>),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -316,6 +323,24 @@ impl Barrier {
                         })
                         .collect(),
                 })),
+                Some(Mutation::SourceChangeSplit(changes)) => {
+                    Some(ProstMutation::Splits(SourceChangeSplitMutation {
+                        mutations: changes
+                            .iter()
+                            .map(|(&actor_id, splits)| SourceChangeSplit {
+                                actor_id,
+                                split_type: splits
+                                    .first()
+                                    .map(|split| split.get_type())
+                                    .unwrap_or_default(),
+                                stream_source_splits: splits
+                                    .iter()
+                                    .map(|split| split.to_json_bytes().to_vec())
+                                    .collect(),
+                            })
+                            .collect(),
+                    }))
+                }
             },
             span: vec![],
         }
@@ -356,6 +381,26 @@ impl Barrier {
                 )
                 .into(),
             ),
+            ProstMutation::Splits(changes) => Some(
+                Mutation::SourceChangeSplit(
+                    changes
+                        .mutations
+                        .iter()
+                        .map(|change| {
+                            let splits = change
+                                .stream_source_splits
+                                .iter()
+                                .map(|split| {
+                                    SplitImpl::restore_from_bytes(change.split_type.clone(), split)
+                                })
+                                .collect::<anyhow::Result<Vec<_>>>()
+                                .to_rw_result()?;
+                            Ok((change.actor_id, splits))
+                        })
+                        .collect::<Result<HashMap<ActorId, Vec<SplitImpl>>>>()?,
+                )
+                .into(),
+            ),
         };
         let epoch = prost.get_epoch().unwrap();
         Ok(Barrier {
@@ -370,10 +415,83 @@ impl Barrier {
     }
 }
 
+/// A watermark of column `col_idx`: all following rows in the stream have a value no less than
+/// `val` in that column. Rows violating this are considered late and may be dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watermark {
+    pub col_idx: usize,
+    pub data_type: DataType,
+    pub val: ScalarImpl,
+}
+
+impl Watermark {
+    pub fn new(col_idx: usize, data_type: DataType, val: ScalarImpl) -> Self {
+        Self {
+            col_idx,
+            data_type,
+            val,
+        }
+    }
+
+    /// Move the watermark to another column, e.g. when the column is projected.
+    pub fn with_idx(self, col_idx: usize) -> Self {
+        Self { col_idx, ..self }
+    }
+
+    pub fn to_protobuf(&self) -> Result<ProstWatermark> {
+        Ok(ProstWatermark {
+            col_idx: self.col_idx as u32,
+            data_type: Some(self.data_type.to_protobuf()),
+            val: serialize_cell(&Some(self.val.clone()))?,
+        })
+    }
+
+    pub fn from_protobuf(prost: &ProstWatermark) -> Result<Self> {
+        let data_type = DataType::from(prost.get_data_type()?);
+        let val = deserialize_cell(prost.val.as_slice(), &data_type)?.ok_or_else(|| {
+            RwError::from(InternalError(
+                "watermark value must not be null".to_string(),
+            ))
+        })?;
+        Ok(Self {
+            col_idx: prost.col_idx as usize,
+            data_type,
+            val,
+        })
+    }
+}
+
+/// Whether the upstream has rows to come, emitted by source actors as it changes. An idle stream,
+/// e.g. from a source actor without splits or whose splits have no new data, doesn't hold back the
+/// watermarks aligned with other streams until it turns active again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatermarkStatus {
+    Active,
+    Idle,
+}
+
+impl WatermarkStatus {
+    pub fn to_protobuf(self) -> ProstWatermarkStatus {
+        match self {
+            Self::Active => ProstWatermarkStatus::Active,
+            Self::Idle => ProstWatermarkStatus::Idle,
+        }
+    }
+
+    pub fn from_protobuf(prost: ProstWatermarkStatus) -> Self {
+        match prost {
+            ProstWatermarkStatus::Active => Self::Active,
+            ProstWatermarkStatus::Idle => Self::Idle,
+        }
+    }
+}
+
 #[derive(Debug, EnumAsInner, PartialEq)]
 pub enum Message {
     Chunk(StreamChunk),
     Barrier(Barrier),
+    Watermark(Watermark),
+    WatermarkStatus(WatermarkStatus),
 }
 
 impl<'a> TryFrom<&'a Message> for &'a Barrier {
@@ -381,7 +499,7 @@ impl<'a> TryFrom<&'a Message> for &'a Barrier {
 
     fn try_from(m: &'a Message) -> std::result::Result<Self, Self::Error> {
         match m {
-            Message::Chunk(_) => Err(()),
+            Message::Chunk(_) | Message::Watermark(_) | Message::WatermarkStatus(_) => Err(()),
             Message::Barrier(b) => Ok(b),
         }
     }
@@ -410,6 +528,10 @@ impl Message {
                 StreamMessage::StreamChunk(prost_stream_chunk)
             }
             Self::Barrier(barrier) => StreamMessage::Barrier(barrier.clone().to_protobuf()),
+            Self::Watermark(watermark) => StreamMessage::Watermark(watermark.to_protobuf()?),
+            Self::WatermarkStatus(status) => {
+                StreamMessage::WatermarkStatus(status.to_protobuf() as i32)
+            }
         };
         let prost_stream_msg = ProstStreamMessage {
             stream_message: Some(prost),
@@ -425,6 +547,18 @@ impl Message {
             StreamMessage::Barrier(ref barrier) => {
                 Message::Barrier(Barrier::from_protobuf(barrier)?)
             }
+            StreamMessage::Watermark(ref watermark) => {
+                Message::Watermark(Watermark::from_protobuf(watermark)?)
+            }
+            StreamMessage::WatermarkStatus(status) => {
+                let status = ProstWatermarkStatus::from_i32(*status).ok_or_else(|| {
+                    RwError::from(InternalError(format!(
+                        "unknown watermark status {}",
+                        status
+                    )))
+                })?;
+                Message::WatermarkStatus(WatermarkStatus::from_protobuf(status))
+            }
         };
         Ok(res)
     }
@@ -13,13 +13,23 @@
 // limitations under the License.
 
 use prometheus::core::{AtomicU64, GenericCounterVec};
-use prometheus::{register_int_counter_vec_with_registry, Registry};
+use prometheus::{
+    exponential_buckets, histogram_opts, register_histogram_vec_with_registry,
+    register_int_counter_vec_with_registry, HistogramVec, Registry,
+};
 
 pub struct StreamingMetrics {
     pub registry: Registry,
     pub actor_row_count: GenericCounterVec<AtomicU64>,
+    pub actor_in_record_cnt: GenericCounterVec<AtomicU64>,
+    pub actor_out_record_cnt: GenericCounterVec<AtomicU64>,
+    pub actor_output_buffer_blocking_duration: GenericCounterVec<AtomicU64>,
+    pub actor_barrier_align_duration: HistogramVec,
 
     pub source_output_row_count: GenericCounterVec<AtomicU64>,
+
+    pub exchange_send_blocked_duration: GenericCounterVec<AtomicU64>,
+    pub exchange_recv_blocked_duration: GenericCounterVec<AtomicU64>,
 }
 
 impl StreamingMetrics {
@@ -32,6 +42,39 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let actor_in_record_cnt = register_int_counter_vec_with_registry!(
+            "stream_actor_in_record_cnt",
+            "Total number of rows that have been received by each actor from its upstreams",
+            &["actor_id"],
+            registry
+        )
+        .unwrap();
+
+        let actor_out_record_cnt = register_int_counter_vec_with_registry!(
+            "stream_actor_out_record_cnt",
+            "Total number of rows that have been dispatched by each actor to its downstreams",
+            &["actor_id"],
+            registry
+        )
+        .unwrap();
+
+        let actor_output_buffer_blocking_duration = register_int_counter_vec_with_registry!(
+            "stream_actor_output_buffer_blocking_duration_ns",
+            "Total time that each actor has been blocked on sending to its output channels",
+            &["actor_id"],
+            registry
+        )
+        .unwrap();
+
+        let opts = histogram_opts!(
+            "stream_actor_barrier_align_duration",
+            "Time between receiving the first and the last barrier of an epoch from the upstreams \
+            of each actor",
+            exponential_buckets(0.0001, 2.0, 20).unwrap() // max 52s
+        );
+        let actor_barrier_align_duration =
+            register_histogram_vec_with_registry!(opts, &["actor_id"], registry).unwrap();
+
         let source_output_row_count = register_int_counter_vec_with_registry!(
             "stream_source_output_rows_counts",
             "Total number of rows that have been output from source",
@@ -40,10 +83,34 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let exchange_send_blocked_duration = register_int_counter_vec_with_registry!(
+            "stream_exchange_send_blocked_duration_ns",
+            "Total time that the upstream of a remote exchange has waited for permits from the \
+            downstream",
+            &["up_actor_id", "down_actor_id"],
+            registry
+        )
+        .unwrap();
+
+        let exchange_recv_blocked_duration = register_int_counter_vec_with_registry!(
+            "stream_exchange_recv_blocked_duration_ns",
+            "Total time that the downstream of a remote exchange has waited for the receiving actor \
+            to consume messages",
+            &["up_actor_id", "down_actor_id"],
+            registry
+        )
+        .unwrap();
+
         Self {
             registry,
             actor_row_count,
+            actor_in_record_cnt,
+            actor_out_record_cnt,
+            actor_output_buffer_blocking_duration,
+            actor_barrier_align_duration,
             source_output_row_count,
+            exchange_send_blocked_duration,
+            exchange_recv_blocked_duration,
         }
     }
 
@@ -16,13 +16,13 @@ use futures::StreamExt;
 use futures_async_stream::try_stream;
 use itertools::Itertools;
 use risingwave_common::array::Op::*;
-use risingwave_common::array::Row;
+use risingwave_common::array::{Op, Row, StreamChunk};
 use risingwave_common::catalog::{ColumnDesc, ColumnId, Schema};
 use risingwave_common::util::sort_util::OrderPair;
 use risingwave_storage::table::state_table::StateTable;
 use risingwave_storage::{Keyspace, StateStore};
 
-use crate::executor::error::StreamExecutorError;
+use crate::executor::error::{StreamExecutorError, StreamExecutorResult};
 use crate::executor::{
     BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef,
 };
@@ -36,6 +36,13 @@ pub struct MaterializeExecutor<S: StateStore> {
     /// Columns of arrange keys (including pk, group keys, join keys, etc.)
     arrange_columns: Vec<usize>,
 
+    /// Whether an insert on an existing key overwrites the existing row. This is set for tables
+    /// with user-declared primary keys, whose inputs may repeat a key.
+    handle_pk_conflict: bool,
+
+    /// Epoch of the latest barrier, at which the existing rows are read.
+    epoch: u64,
+
     info: ExecutorInfo,
 }
 
@@ -46,6 +53,7 @@ impl<S: StateStore> MaterializeExecutor<S> {
         keys: Vec<OrderPair>,
         column_ids: Vec<ColumnId>,
         executor_id: u64,
+        handle_pk_conflict: bool,
     ) -> Self {
         let arrange_columns: Vec<usize> = keys.iter().map(|k| k.column_idx).collect();
         let arrange_order_types = keys.iter().map(|k| k.order_type).collect();
@@ -65,6 +73,8 @@ impl<S: StateStore> MaterializeExecutor<S> {
             input,
             state_table: StateTable::new(keyspace, column_descs, arrange_order_types),
             arrange_columns: arrange_columns.clone(),
+            handle_pk_conflict,
+            epoch: 0,
             info: ExecutorInfo {
                 schema,
                 pk_indices: arrange_columns,
@@ -80,55 +90,133 @@ impl<S: StateStore> MaterializeExecutor<S> {
         for msg in input {
             let msg = msg?;
             yield match msg {
+                Message::Chunk(chunk) if self.handle_pk_conflict => {
+                    match self.upsert_chunk(chunk).await? {
+                        Some(chunk) => Message::Chunk(chunk),
+                        None => continue,
+                    }
+                }
                 Message::Chunk(chunk) => {
                     for (idx, op) in chunk.ops().iter().enumerate() {
-                        // check visibility
-                        let visible = chunk
-                            .visibility()
-                            .as_ref()
-                            .map(|x| x.is_set(idx).unwrap())
-                            .unwrap_or(true);
-                        if !visible {
-                            continue;
-                        }
-
-                        // assemble pk row
-                        let arrange_row = Row(self
-                            .arrange_columns
-                            .iter()
-                            .map(|col_idx| chunk.column_at(*col_idx).array_ref().datum_at(idx))
-                            .collect_vec());
-
-                        // assemble row
-                        let row = Row(chunk
-                            .columns()
-                            .iter()
-                            .map(|x| x.array_ref().datum_at(idx))
-                            .collect_vec());
-
-                        match op {
-                            Insert | UpdateInsert => {
-                                self.state_table.insert(arrange_row, row)?;
-                            }
-                            Delete | UpdateDelete => {
-                                self.state_table.delete(arrange_row, row)?;
+                        if let Some((arrange_row, row)) = self.assemble_row(&chunk, idx) {
+                            match op {
+                                Insert | UpdateInsert => {
+                                    self.state_table.insert(arrange_row, row)?;
+                                }
+                                Delete | UpdateDelete => {
+                                    self.state_table.delete(arrange_row, row)?;
+                                }
                             }
                         }
                     }
 
                     Message::Chunk(chunk)
                 }
+                // Materializing doesn't change the rows, so downstream mviews can reuse it.
+                Message::Watermark(watermark) => Message::Watermark(watermark),
+                Message::WatermarkStatus(status) => Message::WatermarkStatus(status),
                 Message::Barrier(b) => {
                     // FIXME(ZBW): use a better error type
                     self.state_table
                         .commit_with_value_meta(b.epoch.prev)
                         .await
                         .map_err(StreamExecutorError::executor_v1)?;
+                    self.epoch = b.epoch.curr;
                     Message::Barrier(b)
                 }
             }
         }
     }
+
+    /// Returns the arrange key and the row at `idx` of the chunk, or `None` if it's invisible.
+    fn assemble_row(&self, chunk: &StreamChunk, idx: usize) -> Option<(Row, Row)> {
+        // check visibility
+        let visible = chunk
+            .visibility()
+            .as_ref()
+            .map(|x| x.is_set(idx).unwrap())
+            .unwrap_or(true);
+        if !visible {
+            return None;
+        }
+
+        // assemble pk row
+        let arrange_row = Row(self
+            .arrange_columns
+            .iter()
+            .map(|col_idx| chunk.column_at(*col_idx).array_ref().datum_at(idx))
+            .collect_vec());
+
+        // assemble row
+        let row = Row(chunk
+            .columns()
+            .iter()
+            .map(|x| x.array_ref().datum_at(idx))
+            .collect_vec());
+
+        Some((arrange_row, row))
+    }
+
+    /// Applies the chunk to the table with upsert semantics: an insert on an existing key
+    /// overwrites the existing row, and deletes remove whatever row is stored under the key.
+    /// Returns the changes actually made to the table, or `None` if there are none.
+    async fn upsert_chunk(
+        &mut self,
+        chunk: StreamChunk,
+    ) -> StreamExecutorResult<Option<StreamChunk>> {
+        let mut changes: Vec<(Op, Row)> = vec![];
+        for (idx, &op) in chunk.ops().iter().enumerate() {
+            let (arrange_row, row) = match self.assemble_row(&chunk, idx) {
+                Some(assembled) => assembled,
+                None => continue,
+            };
+            let old_row = self.state_table.get_row(&arrange_row, self.epoch).await?;
+
+            match (op, old_row) {
+                (Insert, Some(old_row)) => {
+                    self.state_table
+                        .delete(arrange_row.clone(), old_row.clone())?;
+                    self.state_table.insert(arrange_row, row.clone())?;
+                    changes.push((UpdateDelete, old_row));
+                    changes.push((UpdateInsert, row));
+                }
+                (UpdateInsert, Some(old_row)) => {
+                    // The update moves a row onto the key of another row, which is overwritten. As
+                    // the update can't be paired anymore, it's emitted as a delete and an insert.
+                    if let Some((last_op @ UpdateDelete, _)) = changes.last_mut() {
+                        *last_op = Delete;
+                    }
+                    self.state_table
+                        .delete(arrange_row.clone(), old_row.clone())?;
+                    self.state_table.insert(arrange_row, row.clone())?;
+                    changes.push((Delete, old_row));
+                    changes.push((Insert, row));
+                }
+                (Insert | UpdateInsert, None) => {
+                    let op = match changes.last() {
+                        Some((UpdateDelete, _)) => op,
+                        // The update deleted nothing, so only its insert remains.
+                        _ => Insert,
+                    };
+                    self.state_table.insert(arrange_row, row.clone())?;
+                    changes.push((op, row));
+                }
+                (Delete | UpdateDelete, Some(old_row)) => {
+                    self.state_table.delete(arrange_row, old_row.clone())?;
+                    changes.push((op, old_row));
+                }
+                // Deleting a key that doesn't exist is a no-op.
+                (Delete | UpdateDelete, None) => {}
+            }
+        }
+
+        if changes.is_empty() {
+            return Ok(None);
+        }
+        let chunk = StreamChunk::from_rows(&changes, &self.info.schema.data_types())
+            .map_err(StreamExecutorError::executor_v1)?;
+        Ok(Some(chunk))
+    }
 }
 
 impl<S: StateStore> Executor for MaterializeExecutor<S> {
@@ -224,6 +312,7 @@ mod tests {
             vec![OrderPair::new(0, OrderType::Ascending)],
             column_ids,
             1,
+            false,
         ))
         .execute();
 
@@ -253,4 +342,108 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[tokio::test]
+    async fn test_materialize_executor_upsert() {
+        let memory_state_store = MemoryStateStore::new();
+        let table_id = TableId::new(1);
+        // Two columns of int32 type, the first column is PK.
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let column_ids = vec![0.into(), 1.into()];
+
+        let chunk1 = StreamChunk::from_pretty(
+            " i i
+            + 1 4
+            + 2 5
+            + 1 6",
+        );
+        let chunk2 = StreamChunk::from_pretty(
+            " i i
+            + 2 7
+            - 3 9
+            - 1 0",
+        );
+
+        let source = MockSource::with_messages(
+            schema.clone(),
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk1),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+                Message::Chunk(chunk2),
+                Message::Barrier(Barrier::new_test_barrier(3)),
+            ],
+        );
+
+        let keyspace = Keyspace::table_root(memory_state_store.clone(), &table_id);
+        let order_types = vec![OrderType::Ascending];
+        let column_descs = vec![
+            ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+            ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+        ];
+        let table = CellBasedTable::new_for_test(keyspace.clone(), column_descs, order_types);
+        let mut materialize_executor = Box::new(MaterializeExecutor::new(
+            Box::new(source),
+            keyspace,
+            vec![OrderPair::new(0, OrderType::Ascending)],
+            column_ids,
+            1,
+            true,
+        ))
+        .execute();
+
+        materialize_executor.next().await.transpose().unwrap();
+
+        // The second insert on key 1 becomes an update.
+        let msg = materialize_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " i i
+                +  1 4
+                +  2 5
+                U- 1 4
+                U+ 1 6",
+            )
+        );
+        materialize_executor.next().await.transpose().unwrap();
+
+        // Deletes carry the stored rows, and deleting a missing key is a no-op.
+        let msg = materialize_executor
+            .next()
+            .await
+            .transpose()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " i i
+                U- 2 5
+                U+ 2 7
+                -  1 6",
+            )
+        );
+        materialize_executor.next().await.transpose().unwrap();
+
+        let row = table
+            .get_row(&Row(vec![Some(2_i32.into())]), u64::MAX)
+            .await
+            .unwrap();
+        assert_eq!(row, Some(Row(vec![Some(2_i32.into()), Some(7_i32.into())])));
+        let row = table
+            .get_row(&Row(vec![Some(1_i32.into())]), u64::MAX)
+            .await
+            .unwrap();
+        assert_eq!(row, None);
+    }
 }
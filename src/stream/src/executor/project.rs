@@ -12,17 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 
 use itertools::Itertools;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::{DataChunk, StreamChunk};
+use risingwave_common::array::{DataChunk, Row, StreamChunk};
 use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
 use risingwave_expr::expr::BoxedExpression;
 
 use super::{
     Executor, ExecutorInfo, PkIndices, PkIndicesRef, SimpleExecutor, SimpleExecutorWrapper,
-    StreamExecutorResult,
+    StreamExecutorResult, Watermark,
 };
 use crate::executor::error::StreamExecutorError;
 
@@ -34,6 +36,7 @@ impl ProjectExecutor {
         pk_indices: PkIndices,
         exprs: Vec<BoxedExpression>,
         execuotr_id: u64,
+        watermark_derivations: Vec<(usize, usize)>,
     ) -> Self {
         let info = ExecutorInfo {
             schema: input.schema().to_owned(),
@@ -42,7 +45,7 @@ impl ProjectExecutor {
         };
         SimpleExecutorWrapper {
             input,
-            inner: SimpleProjectExecutor::new(info, exprs, execuotr_id),
+            inner: SimpleProjectExecutor::new(info, exprs, execuotr_id, watermark_derivations),
         }
     }
 }
@@ -55,16 +58,33 @@ pub struct SimpleProjectExecutor {
 
     /// Expressions of the current projection.
     exprs: Vec<BoxedExpression>,
+
+    /// Data types of the input columns, used to evaluate the watermarks.
+    input_data_types: Vec<DataType>,
+
+    /// Maps an input column to the output columns whose watermarks are derived from it. The
+    /// planner guarantees that these expressions are monotonic on the input column.
+    watermark_derivations: HashMap<usize, Vec<usize>>,
 }
 
 impl SimpleProjectExecutor {
-    pub fn new(input_info: ExecutorInfo, exprs: Vec<BoxedExpression>, executor_id: u64) -> Self {
+    pub fn new(
+        input_info: ExecutorInfo,
+        exprs: Vec<BoxedExpression>,
+        executor_id: u64,
+        watermark_derivations: Vec<(usize, usize)>,
+    ) -> Self {
         let schema = Schema {
             fields: exprs
                 .iter()
                 .map(|e| Field::unnamed(e.return_type()))
                 .collect_vec(),
         };
+        let input_data_types = input_info.schema.data_types();
+        let mut derivations: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (input_idx, output_idx) in watermark_derivations {
+            derivations.entry(input_idx).or_default().push(output_idx);
+        }
         Self {
             info: ExecutorInfo {
                 schema,
@@ -72,6 +92,8 @@ impl SimpleProjectExecutor {
                 identity: format!("ProjectExecutor {:X}", executor_id),
             },
             exprs,
+            input_data_types,
+            watermark_derivations: derivations,
         }
     }
 }
@@ -115,6 +137,32 @@ impl SimpleExecutor for SimpleProjectExecutor {
         Ok(Some(new_chunk))
     }
 
+    fn handle_watermark(&mut self, watermark: Watermark) -> StreamExecutorResult<Vec<Watermark>> {
+        let output_indices = match self.watermark_derivations.get(&watermark.col_idx) {
+            Some(output_indices) => output_indices,
+            None => return Ok(vec![]),
+        };
+
+        // Evaluate the monotonic expressions on a single row holding the watermark value, in which
+        // the other columns are irrelevant.
+        let mut row = vec![None; self.input_data_types.len()];
+        row[watermark.col_idx] = Some(watermark.val);
+        let data_chunk = DataChunk::from_rows(&[Row(row)], &self.input_data_types)
+            .map_err(StreamExecutorError::eval_error)?;
+
+        let mut watermarks = vec![];
+        for &output_idx in output_indices {
+            let expr = &self.exprs[output_idx];
+            let array = expr
+                .eval(&data_chunk)
+                .map_err(StreamExecutorError::eval_error)?;
+            if let Some(val) = array.datum_at(0) {
+                watermarks.push(Watermark::new(output_idx, expr.return_type(), val));
+            }
+        }
+        Ok(watermarks)
+    }
+
     fn schema(&self) -> &Schema {
         &self.info.schema
     }
@@ -134,9 +182,9 @@ mod tests {
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::array::StreamChunk;
     use risingwave_common::catalog::{Field, Schema};
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_expr::expr::expr_binary_nonnull::new_binary_expr;
-    use risingwave_expr::expr::InputRefExpression;
+    use risingwave_expr::expr::{InputRefExpression, LiteralExpression};
     use risingwave_pb::expr::expr_node::Type;
 
     use super::super::test_utils::MockSource;
@@ -178,6 +226,7 @@ mod tests {
             vec![],
             vec![test_expr],
             1,
+            vec![],
         ));
         let mut project = project.execute();
 
@@ -204,4 +253,48 @@ mod tests {
 
         assert!(project.next().await.unwrap().unwrap().is_stop());
     }
+
+    #[tokio::test]
+    async fn test_watermark_projection() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let source = MockSource::with_messages(
+            schema,
+            PkIndices::new(),
+            vec![
+                Message::Watermark(Watermark::new(0, DataType::Int64, ScalarImpl::Int64(10))),
+                Message::Watermark(Watermark::new(1, DataType::Int64, ScalarImpl::Int64(20))),
+            ],
+        );
+
+        // `v0 + 1` derives the watermark of `v0`, while `v1` is projected out.
+        let add_expr = new_binary_expr(
+            Type::Add,
+            DataType::Int64,
+            Box::new(InputRefExpression::new(DataType::Int64, 0)),
+            Box::new(LiteralExpression::new(
+                DataType::Int64,
+                Some(ScalarImpl::Int64(1)),
+            )),
+        );
+        let project = Box::new(ProjectExecutor::new(
+            Box::new(source),
+            vec![],
+            vec![add_expr],
+            1,
+            vec![(0, 0)],
+        ));
+        let mut project = project.execute();
+
+        let msg = project.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_watermark().unwrap(),
+            Watermark::new(0, DataType::Int64, ScalarImpl::Int64(11))
+        );
+        assert!(project.next().await.unwrap().unwrap().is_stop());
+    }
 }
@@ -17,7 +17,7 @@ use std::sync::Arc;
 use either::Either;
 use futures::channel::{mpsc, oneshot};
 use futures::stream::select_with_strategy;
-use futures::{stream, FutureExt, StreamExt};
+use futures::{future, stream, FutureExt, StreamExt};
 use futures_async_stream::{for_await, try_stream};
 use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::Schema;
@@ -47,7 +47,7 @@ pub struct RearrangedChainExecutor {
     info: ExecutorInfo,
 }
 
-fn mapping(upstream_indices: &[usize], msg: Message) -> Message {
+fn mapping(upstream_indices: &[usize], msg: Message) -> Option<Message> {
     match msg {
         Message::Chunk(chunk) => {
             let (ops, columns, visibility) = chunk.into_inner();
@@ -55,9 +55,17 @@ fn mapping(upstream_indices: &[usize], msg: Message) -> Message {
                 .iter()
                 .map(|&i| columns[i].clone())
                 .collect();
-            Message::Chunk(StreamChunk::new(ops, mapped_columns, visibility))
+            Some(Message::Chunk(StreamChunk::new(
+                ops,
+                mapped_columns,
+                visibility,
+            )))
         }
-        _ => msg,
+        Message::Watermark(watermark) => upstream_indices
+            .iter()
+            .position(|&i| i == watermark.col_idx)
+            .map(|idx| Message::Watermark(watermark.with_idx(idx))),
+        _ => Some(msg),
     }
 }
 
@@ -83,6 +91,9 @@ impl From<Message> for RearrangedMessage {
         match msg {
             Message::Chunk(chunk) => RearrangedMessage::Chunk(chunk),
             Message::Barrier(barrier) => RearrangedMessage::RearrangedBarrier(barrier),
+            Message::Watermark(_) | Message::WatermarkStatus(_) => {
+                unreachable!("snapshot never yields watermarks")
+            }
         }
     }
 }
@@ -113,10 +124,13 @@ impl RearrangedChainExecutor {
     async fn execute_inner(self) {
         // 0. Project the upstream with `upstream_indices`.
         let upstream_indices = self.upstream_indices.clone();
-        let mut upstream = self
-            .upstream
-            .execute()
-            .map(move |result| result.map(|msg| mapping(&upstream_indices, msg)));
+        let mut upstream = self.upstream.execute().filter_map(move |result| {
+            future::ready(
+                result
+                    .map(|msg| mapping(&upstream_indices, msg))
+                    .transpose(),
+            )
+        });
 
         // 1. Poll the upstream to get the first barrier.
         let first_msg = upstream.next().await.unwrap()?;
@@ -291,6 +305,10 @@ impl RearrangedChainExecutor {
                             })?;
                     }
 
+                    // Watermarks are meaningless until we catch up, since the snapshot being
+                    // consumed contains older rows.
+                    Message::Watermark(_) | Message::WatermarkStatus(_) => {}
+
                     // If we polled a barrier, rearrange it to `rearranged_barrier_tx` and leave
                     // a phantom barrier in-place.
                     Message::Barrier(barrier) => {
@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use futures::channel::mpsc::Receiver;
 use futures::StreamExt;
 use risingwave_common::catalog::Schema;
 
+use crate::executor::monitor::StreamingMetrics;
 use crate::executor::{
     BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndices, PkIndicesRef,
 };
+use crate::task::ActorId;
 
 /// `ReceiverExecutor` is used along with a channel. After creating a mpsc channel,
 /// there should be a `ReceiverExecutor` running in the background, so as to push
@@ -27,6 +31,10 @@ pub struct ReceiverExecutor {
     receiver: Receiver<Message>,
     /// Logical Operator Info
     info: ExecutorInfo,
+    /// Belonged actor id.
+    actor_id: ActorId,
+
+    metrics: Arc<StreamingMetrics>,
 }
 
 impl std::fmt::Debug for ReceiverExecutor {
@@ -39,9 +47,17 @@ impl std::fmt::Debug for ReceiverExecutor {
 }
 
 impl ReceiverExecutor {
-    pub fn new(schema: Schema, pk_indices: PkIndices, receiver: Receiver<Message>) -> Self {
+    pub fn new(
+        schema: Schema,
+        pk_indices: PkIndices,
+        receiver: Receiver<Message>,
+        actor_id: ActorId,
+        metrics: Arc<StreamingMetrics>,
+    ) -> Self {
         Self {
             receiver,
+            actor_id,
+            metrics,
             info: ExecutorInfo {
                 schema,
                 pk_indices,
@@ -53,7 +69,18 @@ impl ReceiverExecutor {
 
 impl Executor for ReceiverExecutor {
     fn execute(self: Box<Self>) -> BoxedMessageStream {
-        self.receiver.map(Ok).boxed()
+        let in_record_cnt = self
+            .metrics
+            .actor_in_record_cnt
+            .with_label_values(&[&self.actor_id.to_string()]);
+        self.receiver
+            .map(move |msg| {
+                if let Message::Chunk(chunk) = &msg {
+                    in_record_cnt.inc_by(chunk.cardinality() as u64);
+                }
+                Ok(msg)
+            })
+            .boxed()
     }
 
     fn schema(&self) -> &Schema {
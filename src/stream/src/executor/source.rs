@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use either::Either;
-use futures::stream::{select_with_strategy, PollNext};
+use futures::stream::{select_with_strategy, PollNext, SelectAll};
 use futures::{Stream, StreamExt};
 use futures_async_stream::try_stream;
+use itertools::Itertools;
 use risingwave_common::array::column::Column;
 use risingwave_common::array::{ArrayBuilder, ArrayImpl, I64ArrayBuilder, Op, StreamChunk};
 use risingwave_common::catalog::{ColumnId, Schema, TableId};
@@ -26,17 +29,20 @@ use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_connector::state::SourceStateHandler;
 use risingwave_connector::{ConnectorState, ConnectorStateV2, SplitImpl};
+use risingwave_expr::expr::{build_from_prost, BoxedExpression};
 use risingwave_source::*;
 use risingwave_storage::{Keyspace, StateStore};
-use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
 
 use super::error::StreamExecutorError;
 use super::monitor::StreamingMetrics;
 use super::*;
+use crate::task::FinishSourceSplitsNotifier;
 
 /// [`SourceExecutor`] is a streaming source, from risingwave's batch table, or external systems
 /// such as Kafka.
 pub struct SourceExecutor<S: StateStore> {
+    actor_id: ActorId,
     source_id: TableId,
     source_desc: SourceDesc,
 
@@ -53,19 +59,86 @@ pub struct SourceExecutor<S: StateStore> {
     // monitor
     metrics: Arc<StreamingMetrics>,
 
-    /// Split info for stream source
+    /// Split info for stream source, of the splits being read
     stream_source_splits: Vec<SplitImpl>,
 
+    /// Assigned splits that are not read until their parents are read to their end
+    pending_splits: Vec<SplitImpl>,
+
+    /// Ids of the assigned splits that have been read to their end
+    finished_splits: HashSet<String>,
+
+    /// Reports the splits read to their end to the meta service, which holds back the splits
+    /// following them on other actors until then.
+    finished_splits_notifier: FinishSourceSplitsNotifier,
+
     source_identify: String,
 
     split_state_store: SourceStateHandler<S>,
     // store latest split to offset mapping
     state_cache: Option<Vec<ConnectorState>>,
+
+    /// Generators of the watermarks declared on the source columns.
+    watermark_generators: Vec<SourceWatermarkGenerator>,
+
+    /// See [`SourceExecutor::with_idle_timeout`].
+    idle_timeout: Duration,
+}
+
+/// See [`SourceExecutor::with_idle_timeout`].
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Derives the watermark of a source column from the rows read, as declared by `WATERMARK FOR`.
+struct SourceWatermarkGenerator {
+    /// Index of the watermark column in the output.
+    col_idx: usize,
+    data_type: DataType,
+    /// Evaluated on a chunk with only the watermark column.
+    expr: BoxedExpression,
+    /// The latest emitted watermark.
+    current: Option<ScalarImpl>,
+}
+
+impl SourceWatermarkGenerator {
+    /// Returns the new watermark if it advances after seeing `chunk`.
+    fn on_chunk(&mut self, chunk: &StreamChunk) -> Result<Option<Watermark>> {
+        let data_chunk = DataChunk::new(
+            vec![chunk.column_at(self.col_idx).clone()],
+            chunk.visibility().clone(),
+        );
+        let array = self.expr.eval(&data_chunk)?;
+        let max = chunk
+            .ops()
+            .iter()
+            .enumerate()
+            .filter(|(i, op)| {
+                matches!(op, Op::Insert | Op::UpdateInsert)
+                    && chunk
+                        .visibility()
+                        .as_ref()
+                        .map_or(true, |vis| vis.is_set(*i).unwrap())
+            })
+            .filter_map(|(i, _)| array.datum_at(i))
+            .max();
+
+        match max {
+            Some(max) if self.current.as_ref().map_or(true, |current| max > *current) => {
+                self.current = Some(max.clone());
+                Ok(Some(Watermark::new(
+                    self.col_idx,
+                    self.data_type.clone(),
+                    max,
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
 }
 
 impl<S: StateStore> SourceExecutor<S> {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
+        actor_id: ActorId,
         source_id: TableId,
         source_desc: SourceDesc,
         keyspace: Keyspace<S>,
@@ -78,8 +151,34 @@ impl<S: StateStore> SourceExecutor<S> {
         _op_info: String,
         streaming_metrics: Arc<StreamingMetrics>,
         stream_source_splits: Vec<SplitImpl>,
+        finished_splits_notifier: FinishSourceSplitsNotifier,
     ) -> Result<Self> {
+        let mut watermark_generators = vec![];
+        for desc in &source_desc.watermark_descs {
+            let column = &source_desc.columns[desc.watermark_idx as usize];
+            // The watermark column may be pruned from the output.
+            if let Some(col_idx) = column_ids.iter().position(|id| *id == column.column_id) {
+                watermark_generators.push(SourceWatermarkGenerator {
+                    col_idx,
+                    data_type: column.data_type.clone(),
+                    expr: build_from_prost(desc.get_expr()?)?,
+                    current: None,
+                });
+            }
+        }
+
+        // Splits whose parents are assigned too are read after them.
+        let assigned: HashSet<_> = stream_source_splits.iter().map(SplitImpl::id).collect();
+        let (pending_splits, stream_source_splits): (Vec<_>, Vec<_>) =
+            stream_source_splits.into_iter().partition(|split| {
+                split
+                    .parent_ids()
+                    .iter()
+                    .any(|parent_id| assigned.contains(parent_id))
+            });
+
         Ok(Self {
+            actor_id,
             source_id,
             source_desc,
             column_ids,
@@ -89,12 +188,32 @@ impl<S: StateStore> SourceExecutor<S> {
             identity: format!("SourceExecutor {:X}", executor_id),
             metrics: streaming_metrics,
             stream_source_splits,
+            pending_splits,
+            finished_splits: HashSet::new(),
+            finished_splits_notifier,
             source_identify: "Table_".to_string() + &source_id.table_id().to_string(),
             split_state_store: SourceStateHandler::new(keyspace),
             state_cache: None,
+            watermark_generators,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
         })
     }
 
+    /// Set how long the source may read no rows before it reports [`WatermarkStatus::Idle`], so
+    /// that it stops holding back the watermarks downstream until it reads rows again. A source
+    /// with no splits to read reports itself idle right away.
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Whether the source has no rows to emit, when it last emitted rows at `last_active`.
+    fn is_idle(&self, last_active: Instant) -> bool {
+        let no_splits = matches!(self.source_desc.source.as_ref(), SourceImpl::Connector(_))
+            && self.stream_source_splits.is_empty();
+        no_splits || last_active.elapsed() >= self.idle_timeout
+    }
+
     /// Generate a row ID column.
     fn gen_row_id_column(&mut self, len: usize) -> Column {
         let mut builder = I64ArrayBuilder::new(len).unwrap();
@@ -123,11 +242,107 @@ impl<S: StateStore> SourceExecutor<S> {
         }
         chunk
     }
+
+    /// Hold back the splits in `splits` that this executor is not reading yet, and create readers,
+    /// one per split, for those ready to be read. Pending splits that are no longer assigned are
+    /// dropped, while splits being read keep being read until the actor is rebuilt, and finished
+    /// splits are not read again.
+    async fn build_readers_for_new_splits(
+        &mut self,
+        splits: &[SplitImpl],
+    ) -> Result<Vec<Box<dyn StreamSourceReader>>> {
+        self.pending_splits
+            .retain(|pending| splits.iter().any(|split| split.id() == pending.id()));
+        self.finished_splits
+            .retain(|finished| splits.iter().any(|split| split.id() == *finished));
+        let new_splits = splits
+            .iter()
+            .filter(|split| {
+                !self.finished_splits.contains(&split.id())
+                    && !self
+                        .stream_source_splits
+                        .iter()
+                        .chain(&self.pending_splits)
+                        .any(|assigned| assigned.id() == split.id())
+            })
+            .cloned()
+            .collect_vec();
+        self.pending_splits.extend(new_splits);
+
+        self.build_readers_for_ready_splits().await
+    }
+
+    /// Create readers for the pending splits whose parents are no longer assigned to this executor
+    /// or have been read to their end, and remember them as being read.
+    async fn build_readers_for_ready_splits(&mut self) -> Result<Vec<Box<dyn StreamSourceReader>>> {
+        let unfinished: HashSet<_> = self
+            .stream_source_splits
+            .iter()
+            .chain(&self.pending_splits)
+            .map(SplitImpl::id)
+            .collect();
+        let (ready, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_splits)
+            .into_iter()
+            .partition(|split| {
+                split
+                    .parent_ids()
+                    .iter()
+                    .all(|parent_id| !unfinished.contains(parent_id))
+            });
+        self.pending_splits = pending;
+        if ready.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let SourceImpl::Connector(c) = self.source_desc.source.as_ref() else {
+            return Ok(vec![]);
+        };
+        info!(
+            "actor {} starts reading new splits {:?}",
+            self.actor_id, ready
+        );
+        let mut readers = Vec::with_capacity(ready.len());
+        for split in &ready {
+            let reader = c
+                .stream_reader(
+                    ConnectorStateV2::Splits(vec![split.clone()]),
+                    self.column_ids.clone(),
+                )
+                .await?;
+            readers
+                .push(Box::new(SourceStreamReaderImpl::Connector(reader))
+                    as Box<dyn StreamSourceReader>);
+        }
+        self.stream_source_splits.extend(ready);
+
+        Ok(readers)
+    }
+
+    /// Forget the splits that have been read to their end, report them to the meta service, and
+    /// create readers for the pending splits waiting for them.
+    async fn build_readers_after_finished_splits(
+        &mut self,
+        finished_splits: &[String],
+    ) -> Result<Vec<Box<dyn StreamSourceReader>>> {
+        info!(
+            "actor {} finished reading splits {:?}",
+            self.actor_id, finished_splits
+        );
+        self.stream_source_splits
+            .retain(|split| !finished_splits.contains(&split.id()));
+        self.finished_splits.extend(finished_splits.iter().cloned());
+        self.finished_splits_notifier
+            .notify(finished_splits.to_vec());
+
+        self.build_readers_for_ready_splits().await
+    }
 }
 
 struct SourceReader {
     /// The reader for stream source
     stream_reader: Box<dyn StreamSourceReader>,
+    /// The receiver of readers for splits assigned after the executor is started
+    added_reader_rx: UnboundedReceiver<Box<dyn StreamSourceReader>>,
     /// The reader for barrier
     barrier_receiver: UnboundedReceiver<Barrier>,
 }
@@ -137,7 +352,15 @@ impl SourceReader {
     async fn stream_reader(mut stream_reader: Box<dyn StreamSourceReader>) {
         loop {
             match stream_reader.next().await {
-                Ok(chunk) => yield chunk,
+                Ok(chunk) => {
+                    // Only readers of a single Kinesis shard finish, so nothing more comes from a
+                    // reader with finished splits.
+                    let finished = !chunk.finished_splits.is_empty();
+                    yield chunk;
+                    if finished {
+                        break;
+                    }
+                }
                 Err(e) => {
                     // TODO: report this error to meta service to mark the actors failed.
                     error!("hang up stream reader due to polling error: {}", e);
@@ -153,6 +376,29 @@ impl SourceReader {
         futures::future::pending().await
     }
 
+    /// Merge the initial reader with the readers of newly assigned splits. Each reader never ends,
+    /// so `readers` always has a pending item.
+    #[try_stream(ok = StreamChunkWithState, error = RwError)]
+    async fn merged_stream_reader(
+        stream_reader: Box<dyn StreamSourceReader>,
+        mut added_reader_rx: UnboundedReceiver<Box<dyn StreamSourceReader>>,
+    ) {
+        let mut readers = SelectAll::new();
+        readers.push(Self::stream_reader(stream_reader).boxed());
+
+        loop {
+            let chunk = tokio::select! {
+                biased;
+                Some(reader) = added_reader_rx.recv() => {
+                    readers.push(Self::stream_reader(reader).boxed());
+                    continue;
+                }
+                Some(chunk) = readers.next() => chunk?,
+            };
+            yield chunk;
+        }
+    }
+
     #[try_stream(ok = Message, error = RwError)]
     async fn barrier_receiver(mut rx: UnboundedReceiver<Barrier>) {
         while let Some(barrier) = rx.recv().await {
@@ -167,7 +413,7 @@ impl SourceReader {
         self,
     ) -> impl Stream<Item = Either<Result<Message>, Result<StreamChunkWithState>>> {
         let barrier_receiver = Self::barrier_receiver(self.barrier_receiver);
-        let stream_reader = Self::stream_reader(self.stream_reader);
+        let stream_reader = Self::merged_stream_reader(self.stream_reader, self.added_reader_rx);
         select_with_strategy(
             barrier_receiver.map(Either::Left),
             stream_reader.map(Either::Right),
@@ -208,12 +454,19 @@ impl<S: StateStore> SourceExecutor<S> {
         }
         .map_err(StreamExecutorError::source_error)?;
 
+        let (added_reader_tx, added_reader_rx) = unbounded_channel::<Box<dyn StreamSourceReader>>();
         let reader = SourceReader {
             stream_reader: Box::new(stream_reader),
+            added_reader_rx,
             barrier_receiver,
         };
         yield Message::Barrier(barrier);
 
+        // Watermark status is only reported when there are watermarks to hold back.
+        let report_status = !self.watermark_generators.is_empty();
+        let mut idle = false;
+        let mut last_active = Instant::now();
+
         #[for_await]
         for msg in reader.into_stream() {
             match msg {
@@ -232,6 +485,23 @@ impl<S: StateStore> SourceExecutor<S> {
                                         ))
                                     })?;
                             }
+                            if let Some(Mutation::SourceChangeSplit(changes)) =
+                                barrier.mutation.as_deref()
+                            {
+                                if let Some(splits) = changes.get(&self.actor_id) {
+                                    for reader in self
+                                        .build_readers_for_new_splits(splits)
+                                        .await
+                                        .map_err(StreamExecutorError::source_error)?
+                                    {
+                                        added_reader_tx.send(reader).unwrap();
+                                    }
+                                }
+                            }
+                            if report_status && !idle && self.is_idle(last_active) {
+                                idle = true;
+                                yield Message::WatermarkStatus(WatermarkStatus::Idle);
+                            }
                             yield Message::Barrier(barrier)
                         }
                         _ => unreachable!(),
@@ -240,6 +510,15 @@ impl<S: StateStore> SourceExecutor<S> {
                 Either::Right(chunk_with_state) => {
                     let chunk_with_state =
                         chunk_with_state.map_err(StreamExecutorError::source_error)?;
+                    if !chunk_with_state.finished_splits.is_empty() {
+                        for reader in self
+                            .build_readers_after_finished_splits(&chunk_with_state.finished_splits)
+                            .await
+                            .map_err(StreamExecutorError::source_error)?
+                        {
+                            added_reader_tx.send(reader).unwrap();
+                        }
+                    }
                     if chunk_with_state.split_offset_mapping.is_some() {
                         self.state_cache = Some(ConnectorState::from_hashmap(
                             chunk_with_state.split_offset_mapping.unwrap(),
@@ -294,7 +573,26 @@ impl<S: StateStore> SourceExecutor<S> {
                             .source_output_row_count
                             .with_label_values(&[self.source_identify.as_str()])
                             .inc_by(stream_chunk.cardinality() as u64);
+                        let mut watermarks = vec![];
+                        for generator in &mut self.watermark_generators {
+                            if let Some(watermark) = generator
+                                .on_chunk(&stream_chunk)
+                                .map_err(StreamExecutorError::eval_error)?
+                            {
+                                watermarks.push(watermark);
+                            }
+                        }
+                        if stream_chunk.cardinality() > 0 {
+                            last_active = Instant::now();
+                            if idle {
+                                idle = false;
+                                yield Message::WatermarkStatus(WatermarkStatus::Active);
+                            }
+                        }
                         yield Message::Chunk(stream_chunk);
+                        for watermark in watermarks {
+                            yield Message::Watermark(watermark);
+                        }
                     }
                 }
             }
@@ -410,6 +708,7 @@ mod tests {
         let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x2333);
 
         let executor = SourceExecutor::new(
+            0x3f3f3f,
             table_id,
             source_desc,
             keyspace,
@@ -422,6 +721,7 @@ mod tests {
             "SourceExecutor".to_string(),
             Arc::new(StreamingMetrics::new(prometheus::Registry::new())),
             vec![],
+            FinishSourceSplitsNotifier::unused(0x3f3f3f),
         )
         .unwrap();
         let mut executor = Box::new(executor).execute();
@@ -531,6 +831,7 @@ mod tests {
         let (barrier_sender, barrier_receiver) = unbounded_channel();
         let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x2333);
         let executor = SourceExecutor::new(
+            0x3f3f3f,
             table_id,
             source_desc,
             keyspace,
@@ -543,6 +844,7 @@ mod tests {
             "SourceExecutor".to_string(),
             Arc::new(StreamingMetrics::unused()),
             vec![],
+            FinishSourceSplitsNotifier::unused(0x3f3f3f),
         )
         .unwrap();
         let mut executor = Box::new(executor).execute();
@@ -20,7 +20,7 @@ use risingwave_storage::Keyspace;
 use tokio::sync::mpsc;
 
 use super::error::StreamExecutorError;
-use super::{Barrier, Executor, Message, PkIndices, StreamChunk};
+use super::{Barrier, Epoch, Executor, Message, PkIndices, StreamChunk, Watermark};
 
 pub struct MockSource {
     schema: Schema,
@@ -48,6 +48,22 @@ impl MessageSender {
         }
         self.0.send(Message::Barrier(barrier)).unwrap();
     }
+
+    /// Push a barrier of the given epochs, which are not necessarily adjacent, e.g. to advance the
+    /// processing time.
+    #[allow(dead_code)]
+    pub fn push_barrier_with_prev_epoch(&mut self, curr: u64, prev: u64) {
+        let barrier = Barrier {
+            epoch: Epoch::new(curr, prev),
+            ..Default::default()
+        };
+        self.0.send(Message::Barrier(barrier)).unwrap();
+    }
+
+    #[allow(dead_code)]
+    pub fn push_watermark(&mut self, watermark: Watermark) {
+        self.0.send(Message::Watermark(watermark)).unwrap();
+    }
 }
 
 impl std::fmt::Debug for MockSource {
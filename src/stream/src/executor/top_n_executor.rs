@@ -99,6 +99,8 @@ where
                 Message::Chunk(chunk) => {
                     yield Message::Chunk(self.inner.apply_chunk(chunk, epoch).await?)
                 }
+                // Top-n may retract and re-emit older rows, which breaks the watermark.
+                Message::Watermark(_) | Message::WatermarkStatus(_) => {}
                 Message::Barrier(barrier) => {
                     self.inner.flush_data(epoch).await?;
                     epoch = barrier.epoch.curr;
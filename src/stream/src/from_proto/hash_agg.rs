@@ -32,6 +32,9 @@ struct HashAggExecutorDispatcherArgs<S: StateStore> {
     keyspace: Vec<Keyspace<S>>,
     pk_indices: PkIndices,
     executor_id: u64,
+    emit_on_window_close: bool,
+    watermark_key_positions: Vec<usize>,
+    state_ttl_ms: Option<u64>,
 }
 
 impl<S: StateStore> HashKeyDispatcher for HashAggExecutorDispatcher<S> {
@@ -46,6 +49,9 @@ impl<S: StateStore> HashKeyDispatcher for HashAggExecutorDispatcher<S> {
             args.pk_indices,
             args.executor_id,
             args.key_indices,
+            args.emit_on_window_close,
+            args.watermark_key_positions,
+            args.state_ttl_ms,
         )?
         .boxed())
     }
@@ -91,6 +97,13 @@ impl ExecutorBuilder for HashAggExecutorBuilder {
             keyspace,
             pk_indices: params.pk_indices,
             executor_id: params.executor_id,
+            emit_on_window_close: node.emit_on_window_close,
+            watermark_key_positions: node
+                .watermark_key_positions
+                .iter()
+                .map(|pos| *pos as usize)
+                .collect(),
+            state_ttl_ms: (node.state_ttl_ms > 0).then(|| node.state_ttl_ms),
         };
         HashAggExecutorDispatcher::dispatch_by_kind(kind, args)
     }
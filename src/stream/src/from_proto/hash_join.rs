@@ -113,6 +113,7 @@ impl ExecutorBuilder for HashJoinExecutorBuilder {
             key_indices,
             keyspace_l: Keyspace::table_root(store.clone(), &left_table_id),
             keyspace_r: Keyspace::table_root(store, &right_table_id),
+            state_ttl_ms: (node.state_ttl_ms > 0).then(|| node.state_ttl_ms),
         };
 
         for_all_join_types! { impl_create_hash_join_executor };
@@ -135,6 +136,7 @@ struct HashJoinExecutorDispatcherArgs<S: StateStore> {
     key_indices: Vec<usize>,
     keyspace_l: Keyspace<S>,
     keyspace_r: Keyspace<S>,
+    state_ttl_ms: Option<u64>,
 }
 
 impl<S: StateStore, const T: JoinTypePrimitive> HashKeyDispatcher
@@ -156,6 +158,7 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashKeyDispatcher
             args.key_indices,
             args.keyspace_l,
             args.keyspace_r,
+            args.state_ttl_ms,
         )))
     }
 }
@@ -34,9 +34,23 @@ impl ExecutorBuilder for MergeExecutorBuilder {
         let mut rxs = stream.get_receive_message(params.actor_id, upstreams)?;
 
         if upstreams.len() == 1 {
-            Ok(ReceiverExecutor::new(schema, params.pk_indices, rxs.remove(0)).boxed())
+            Ok(ReceiverExecutor::new(
+                schema,
+                params.pk_indices,
+                rxs.remove(0),
+                params.actor_id,
+                params.executor_stats,
+            )
+            .boxed())
         } else {
-            Ok(MergeExecutor::new(schema, params.pk_indices, params.actor_id, rxs).boxed())
+            Ok(MergeExecutor::new(
+                schema,
+                params.pk_indices,
+                params.actor_id,
+                rxs,
+                params.executor_stats,
+            )
+            .boxed())
         }
     }
 }
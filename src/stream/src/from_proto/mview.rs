@@ -49,6 +49,7 @@ impl ExecutorBuilder for MaterializeExecutorBuilder {
             keys,
             column_ids,
             params.executor_id,
+            node.handle_pk_conflict,
         );
 
         Ok(executor.boxed())
@@ -88,6 +89,7 @@ impl ExecutorBuilder for ArrangeExecutorBuilder {
             keys,
             column_ids,
             params.executor_id,
+            false,
         );
 
         Ok(executor.boxed())
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use risingwave_common::catalog::{ColumnId, Field, Schema, TableId};
 use risingwave_common::error::ToRwResult;
 use risingwave_connector::SplitImpl;
@@ -65,20 +67,30 @@ impl ExecutorBuilder for SourceExecutorBuilder {
         }));
         let schema = Schema::new(fields);
         let keyspace = Keyspace::executor_root(store, params.executor_id);
+        let finished_splits_notifier = stream
+            .context
+            .register_finish_source_splits_notifier(params.actor_id);
 
-        Ok(Box::new(SourceExecutor::new(
-            source_id,
-            source_desc,
-            keyspace,
-            column_ids,
-            schema,
-            params.pk_indices,
-            barrier_receiver,
-            params.executor_id,
-            params.operator_id,
-            params.op_info,
-            params.executor_stats,
-            stream_source_splits,
-        )?))
+        Ok(Box::new(
+            SourceExecutor::new(
+                params.actor_id,
+                source_id,
+                source_desc,
+                keyspace,
+                column_ids,
+                schema,
+                params.pk_indices,
+                barrier_receiver,
+                params.executor_id,
+                params.operator_id,
+                params.op_info,
+                params.executor_stats,
+                stream_source_splits,
+                finished_splits_notifier,
+            )?
+            .with_idle_timeout(Duration::from_millis(
+                params.env.config().source_idle_timeout_ms,
+            )),
+        ))
     }
 }
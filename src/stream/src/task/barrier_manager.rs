@@ -14,9 +14,13 @@
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 
 use risingwave_common::error::Result;
-use risingwave_pb::stream_service::inject_barrier_response::FinishedCreateMview as ProstFinishedCreateMview;
+use risingwave_pb::stream_service::inject_barrier_response::{
+    FinishedCreateMview as ProstFinishedCreateMview,
+    FinishedSourceSplits as ProstFinishedSourceSplits,
+};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 
@@ -74,11 +78,66 @@ impl std::fmt::Debug for FinishCreateMviewNotifier {
     }
 }
 
+/// Represents that the source actor with `actor_id` has read the splits with `split_ids` to their
+/// end, e.g. closed Kinesis shards.
+#[derive(Debug)]
+pub struct FinishedSourceSplits {
+    pub actor_id: ActorId,
+
+    pub split_ids: Vec<String>,
+}
+
+impl From<FinishedSourceSplits> for ProstFinishedSourceSplits {
+    fn from(f: FinishedSourceSplits) -> Self {
+        Self {
+            actor_id: f.actor_id,
+            split_ids: f.split_ids,
+        }
+    }
+}
+
+/// To notify that a source actor has read some splits to their end, so that the meta service can
+/// hand the splits following them to any actor.
+pub struct FinishSourceSplitsNotifier {
+    pub barrier_manager: Arc<parking_lot::Mutex<LocalBarrierManager>>,
+    pub actor_id: ActorId,
+}
+
+impl FinishSourceSplitsNotifier {
+    /// Create a notifier whose notifications are not reported anywhere.
+    pub fn unused(actor_id: ActorId) -> Self {
+        Self {
+            barrier_manager: Arc::new(parking_lot::Mutex::new(LocalBarrierManager::new())),
+            actor_id,
+        }
+    }
+
+    pub fn notify(&self, split_ids: Vec<String>) {
+        self.barrier_manager
+            .lock()
+            .finish_source_splits(self.actor_id, split_ids);
+    }
+}
+
+impl std::fmt::Debug for FinishSourceSplitsNotifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FinishSourceSplitsNotifier")
+            .field("actor_id", &self.actor_id)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Collect result of some barrier on current compute node. Will be reported to the meta service.
 #[derive(Debug)]
 pub struct CollectResult {
     /// Finished Create MV DDLs in current epoch.
     pub finished_create_mviews: Vec<FinishedCreateMview>,
+
+    /// Splits read to their end by source actors in current epoch.
+    pub finished_source_splits: Vec<FinishedSourceSplits>,
+
+    /// Time taken by each actor to collect this barrier, since it's issued on this node.
+    pub actor_collect_times: Vec<(ActorId, Duration)>,
 }
 
 enum BarrierState {
@@ -233,6 +292,25 @@ impl LocalBarrierManager {
             }
         }
     }
+
+    /// Report that the source actor with `actor_id` has read the splits with `split_ids` to their
+    /// end. This will be piggybacked by the collection of current/next barrier and then be reported
+    /// to the meta service.
+    pub fn finish_source_splits(&mut self, actor_id: ActorId, split_ids: Vec<String>) {
+        match &mut self.state {
+            #[cfg(test)]
+            BarrierState::Local => {}
+
+            BarrierState::Managed(managed_state) => {
+                managed_state
+                    .finished_source_splits
+                    .push(FinishedSourceSplits {
+                        actor_id,
+                        split_ids,
+                    })
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -14,10 +14,11 @@
 
 use std::collections::HashSet;
 use std::iter::once;
+use std::time::{Duration, Instant};
 
 use tokio::sync::oneshot;
 
-use super::{CollectResult, FinishedCreateMview};
+use super::{CollectResult, FinishedCreateMview, FinishedSourceSplits};
 use crate::executor::Barrier;
 use crate::task::ActorId;
 
@@ -47,6 +48,13 @@ enum ManagedBarrierStateInner {
         /// Actor ids remaining to be collected.
         remaining_actors: HashSet<ActorId>,
 
+        /// When the `send_barrier` request is issued by the meta service.
+        issued_at: Instant,
+
+        /// Time taken by each collected actor since the request is issued. Actors collected before
+        /// that are recorded as zero.
+        actor_collect_times: Vec<(ActorId, Duration)>,
+
         /// Notify that the collection is finished.
         collect_notifier: oneshot::Sender<CollectResult>,
     },
@@ -57,6 +65,8 @@ pub(super) struct ManagedBarrierState {
     inner: ManagedBarrierStateInner,
 
     pub finished_create_mviews: Vec<FinishedCreateMview>,
+
+    pub finished_source_splits: Vec<FinishedSourceSplits>,
 }
 
 impl ManagedBarrierState {
@@ -68,6 +78,7 @@ impl ManagedBarrierState {
                 last_epoch: None,
             },
             finished_create_mviews: Default::default(),
+            finished_source_splits: Default::default(),
         }
     }
 
@@ -95,14 +106,19 @@ impl ManagedBarrierState {
                 },
             );
             let finished_create_mviews = std::mem::take(&mut self.finished_create_mviews);
+            let finished_source_splits = std::mem::take(&mut self.finished_source_splits);
 
             match state {
                 ManagedBarrierStateInner::Issued {
-                    collect_notifier, ..
+                    collect_notifier,
+                    actor_collect_times,
+                    ..
                 } => {
                     // Notify about barrier finishing.
                     let result = CollectResult {
                         finished_create_mviews,
+                        finished_source_splits,
+                        actor_collect_times,
                     };
                     if collect_notifier.send(result).is_err() {
                         warn!("failed to notify barrier collection with epoch {}", epoch)
@@ -149,12 +165,15 @@ impl ManagedBarrierState {
             ManagedBarrierStateInner::Issued {
                 epoch,
                 remaining_actors,
+                issued_at,
+                actor_collect_times,
                 ..
             } => {
                 assert_eq!(barrier.epoch.curr, *epoch);
 
                 let exist = remaining_actors.remove(&actor_id);
                 assert!(exist);
+                actor_collect_times.push((actor_id, issued_at.elapsed()));
                 self.may_notify();
             }
         }
@@ -175,6 +194,8 @@ impl ManagedBarrierState {
                 *self.inner_mut() = ManagedBarrierStateInner::Issued {
                     epoch: barrier.epoch.curr,
                     remaining_actors,
+                    issued_at: Instant::now(),
+                    actor_collect_times: vec![],
                     collect_notifier,
                 };
                 self.may_notify();
@@ -186,14 +207,17 @@ impl ManagedBarrierState {
             } => {
                 assert_eq!(barrier.epoch.curr, *epoch);
 
-                let remaining_actors = actor_ids_to_collect
+                let (collected, remaining_actors): (Vec<_>, _) = actor_ids_to_collect
                     .into_iter()
-                    .filter(|a| !collected_actors.contains(a))
-                    .collect();
+                    .partition(|a| collected_actors.contains(a));
+                let actor_collect_times =
+                    collected.into_iter().map(|a| (a, Duration::ZERO)).collect();
 
                 *self.inner_mut() = ManagedBarrierStateInner::Issued {
                     epoch: barrier.epoch.curr,
                     remaining_actors,
+                    issued_at: Instant::now(),
+                    actor_collect_times,
                     collect_notifier,
                 };
                 self.may_notify();
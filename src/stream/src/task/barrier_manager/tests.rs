@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::iter::once;
+use std::time::Duration;
 
 use itertools::Itertools;
 use tokio::sync::mpsc::unbounded_channel;
@@ -120,8 +122,15 @@ async fn test_managed_barrier_collection_before_send_request() -> Result<()> {
     // Report to local barrier manager
     for (i, (actor_id, barrier)) in collected_barriers.into_iter().enumerate() {
         manager.collect(actor_id, &barrier).unwrap();
-        let notified = collect_rx.try_recv().is_ok();
-        assert_eq!(notified, i == count - 1);
+        let result = collect_rx.try_recv().ok();
+        assert_eq!(result.is_some(), i == count - 1);
+
+        if let Some(result) = result {
+            let collect_times: HashMap<_, _> = result.actor_collect_times.into_iter().collect();
+            assert_eq!(collect_times.len(), count + 1);
+            // The extra actor is collected before the barrier is issued.
+            assert_eq!(collect_times[&extra_actor_id], Duration::ZERO);
+        }
     }
 
     Ok(())
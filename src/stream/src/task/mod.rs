@@ -35,6 +35,14 @@ pub use stream_manager::*;
 /// Default capacity of channel if two actors are on the same node
 pub const LOCAL_OUTPUT_CHANNEL_SIZE: usize = 16;
 
+/// Number of messages the upstream of a remote exchange can send before the downstream grants more
+/// permits.
+pub const REMOTE_INPUT_PERMITS: u32 = 32;
+
+/// The downstream of a remote exchange grants the permits of consumed messages in batches of this
+/// size.
+pub const REMOTE_INPUT_PERMITS_BATCH: u32 = 8;
+
 pub type ConsumableChannelPair = (Option<Sender<Message>>, Option<Receiver<Message>>);
 pub type ConsumableChannelVecPair = (Vec<Sender<Message>>, Vec<Receiver<Message>>);
 pub type ActorId = u32;
@@ -124,6 +132,18 @@ impl SharedContext {
         }
     }
 
+    /// Create a notifier for the splits that a source actor has read to their end, e.g. closed
+    /// Kinesis shards, which the meta service waits for before assigning the shards following them.
+    pub fn register_finish_source_splits_notifier(
+        &self,
+        actor_id: ActorId,
+    ) -> FinishSourceSplitsNotifier {
+        FinishSourceSplitsNotifier {
+            barrier_manager: self.barrier_manager.clone(),
+            actor_id,
+        }
+    }
+
     pub fn lock_barrier_manager(&self) -> MutexGuard<LocalBarrierManager> {
         self.barrier_manager.lock()
     }
@@ -15,6 +15,7 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use futures::channel::mpsc::{channel, Receiver};
 use itertools::Itertools;
@@ -48,6 +49,18 @@ lazy_static::lazy_static! {
 
 pub type ActorHandle = JoinHandle<()>;
 
+/// Result of a barrier collected from all actors and synced to the state store on this node.
+#[derive(Debug)]
+pub struct BarrierCompleteResult {
+    pub collect_result: CollectResult,
+
+    /// Time taken to collect the barrier from all actors.
+    pub collect_duration: Duration,
+
+    /// Time taken to sync the states of this epoch, including the shared buffer upload.
+    pub sync_duration: Duration,
+}
+
 pub struct LocalStreamManagerCore {
     /// Each processor runs in a future. Upon receiving a `Terminate` message, they will exit.
     /// `handles` store join handles of these futures, and therefore we could wait their
@@ -167,11 +180,13 @@ impl LocalStreamManager {
         barrier: &Barrier,
         actor_ids_to_send: impl IntoIterator<Item = ActorId>,
         actor_ids_to_collect: impl IntoIterator<Item = ActorId>,
-    ) -> Result<CollectResult> {
+    ) -> Result<BarrierCompleteResult> {
+        let start = Instant::now();
         let rx = self.send_barrier(barrier, actor_ids_to_send, actor_ids_to_collect)?;
 
         // Wait for all actors finishing this barrier.
         let collect_result = rx.await.unwrap();
+        let collect_duration = start.elapsed();
 
         // Sync states from shared buffer to S3 before telling meta service we've done.
         dispatch_state_store!(self.state_store(), store, {
@@ -185,8 +200,13 @@ impl LocalStreamManager {
                 ),
             }
         });
+        let sync_duration = start.elapsed() - collect_duration;
 
-        Ok(collect_result)
+        Ok(BarrierCompleteResult {
+            collect_result,
+            collect_duration,
+            sync_duration,
+        })
     }
 
     /// Broadcast a barrier to all senders. Returns immediately, and caller won't be notified when
@@ -299,6 +319,36 @@ impl LocalStreamManager {
     pub fn state_store(&self) -> StateStoreImpl {
         self.core.lock().state_store.clone()
     }
+
+    /// Collect the statistics of all running actors from the streaming metrics.
+    pub fn actor_stats(&self) -> HashMap<ActorId, stream_service::ActorStats> {
+        let core = self.core.lock();
+        let metrics = &core.streaming_metrics;
+        core.handles
+            .keys()
+            .map(|actor_id| {
+                let actor_id_string = actor_id.to_string();
+                let labels = [actor_id_string.as_str()];
+                let barrier_align = metrics
+                    .actor_barrier_align_duration
+                    .with_label_values(&labels);
+                let stats = stream_service::ActorStats {
+                    input_rows: metrics.actor_in_record_cnt.with_label_values(&labels).get(),
+                    output_rows: metrics
+                        .actor_out_record_cnt
+                        .with_label_values(&labels)
+                        .get(),
+                    output_blocked_ns: metrics
+                        .actor_output_buffer_blocking_duration
+                        .with_label_values(&labels)
+                        .get(),
+                    barrier_align_count: barrier_align.get_sample_count(),
+                    barrier_align_seconds: barrier_align.get_sample_sum(),
+                };
+                (*actor_id, stats)
+            })
+            .collect()
+    }
 }
 
 fn update_upstreams(context: &SharedContext, ids: &[UpDownActorIds]) {
@@ -422,6 +472,7 @@ impl LocalStreamManagerCore {
             dispatcher_impls,
             actor_id,
             self.context.clone(),
+            self.streaming_metrics.clone(),
         ))
     }
 
@@ -535,6 +586,7 @@ impl LocalStreamManagerCore {
                         let up_id = *up_id;
 
                         let pool = self.compute_client_pool.clone();
+                        let metrics = self.streaming_metrics.clone();
 
                         tokio::spawn(async move {
                             let init_client = async move {
@@ -542,6 +594,7 @@ impl LocalStreamManagerCore {
                                     pool.get_client_for_addr(upstream_addr).await?,
                                     (up_id, actor_id),
                                     sender,
+                                    metrics,
                                 )
                                 .await?;
                                 Ok::<_, RwError>(remote_input)
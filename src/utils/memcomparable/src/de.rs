@@ -102,7 +102,11 @@ impl<B: Buf> MaybeFlip<B> {
 }
 
 impl<B: Buf> Deserializer<B> {
-    fn read_bytes(&mut self) -> Result<Vec<u8>> {
+    /// Read a byte string written by `serialize_bytes`.
+    ///
+    /// `Vec<u8>` deserializes itself as a sequence, so callers holding raw bytes should use this
+    /// instead.
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>> {
         match self.input.get_u8() {
             0 => return Ok(vec![]), // empty slice
             1 => {}                 // non-empty slice
@@ -44,7 +44,12 @@ impl PgFieldDescriptor {
             | TypeOid::Time
             | TypeOid::Timestampz => 8,
             TypeOid::SmallInt => 2,
-            TypeOid::CharArray | TypeOid::Varchar | TypeOid::Decimal => -1,
+            TypeOid::Uuid => 16,
+            TypeOid::CharArray
+            | TypeOid::Varchar
+            | TypeOid::Decimal
+            | TypeOid::Jsonb
+            | TypeOid::Bytea => -1,
         };
 
         Self {
@@ -102,6 +107,9 @@ pub enum TypeOid {
     Timestamp,
     Timestampz,
     Decimal,
+    Jsonb,
+    Bytea,
+    Uuid,
 }
 
 impl TypeOid {
@@ -120,6 +128,9 @@ impl TypeOid {
             TypeOid::Timestamp => 1114,
             TypeOid::Timestampz => 1184,
             TypeOid::Decimal => 1231,
+            TypeOid::Jsonb => 3802,
+            TypeOid::Bytea => 17,
+            TypeOid::Uuid => 2950,
         }
     }
 }
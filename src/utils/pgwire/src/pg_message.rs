@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::io::{Error, ErrorKind, IoSlice, Result, Write};
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Result};
 
 use byteorder::{BigEndian, ByteOrder};
 /// Part of code learned from https://github.com/zenithdb/zenith/blob/main/zenith_utils/src/pq_proto.rs.
@@ -35,7 +36,11 @@ pub enum FeMessage {
     ReadError(PsqlError),
 }
 
-pub struct FeStartupMessage {}
+/// Startup message contains the connection parameters sent by the client, such as `user` and
+/// `database`.
+pub struct FeStartupMessage {
+    pub config: HashMap<String, String>,
+}
 
 /// Query message contains the string sql.
 pub struct FeQueryMessage {
@@ -88,7 +93,9 @@ impl FeStartupMessage {
         }
         match protocol_num {
             // code from: https://www.postgresql.org/docs/current/protocol-message-formats.html
-            196608 => Ok(FeMessage::Startup(FeStartupMessage {})),
+            196608 => Ok(FeMessage::Startup(FeStartupMessage {
+                config: parse_startup_params(&payload),
+            })),
             80877103 => Ok(FeMessage::Ssl),
             // Cancel request code.
             80877102 => Ok(FeMessage::CancelQuery),
@@ -100,6 +107,23 @@ impl FeStartupMessage {
     }
 }
 
+/// Parse the parameters of a startup message, which are pairs of null-terminated names and values
+/// ended by an extra null byte.
+fn parse_startup_params(payload: &[u8]) -> HashMap<String, String> {
+    let mut strs = payload
+        .split(|b| *b == 0)
+        .map(|s| String::from_utf8_lossy(s).into_owned());
+    let mut config = HashMap::new();
+    while let Some(name) = strs.next() {
+        if name.is_empty() {
+            break;
+        }
+        let value = strs.next().unwrap_or_default();
+        config.insert(name, value);
+    }
+    config
+}
+
 /// Message sent from server to psql client. Implement `write` (how to serialize it into psql
 /// buffer).
 #[derive(Debug)]
@@ -117,9 +141,9 @@ pub enum BeMessage<'a> {
 }
 
 #[derive(Debug)]
-pub enum BeParameterStatusMessage<'a> {
-    Encoding(&'a str),
-    StandardConformingString(&'a str),
+pub struct BeParameterStatusMessage<'a> {
+    pub name: &'a str,
+    pub value: &'a str,
 }
 
 #[derive(Debug)]
@@ -163,23 +187,12 @@ impl<'a> BeMessage<'a> {
             //  standard_conforming_string
             //
             // See: https://www.postgresql.org/docs/9.2/static/protocol-flow.html#PROTOCOL-ASYNC.
-            BeMessage::ParameterStatus(param) => {
-                use BeParameterStatusMessage::*;
-                let [name, value] = match param {
-                    Encoding(val) => [b"client_encoding", val.as_bytes()],
-                    StandardConformingString(val) => {
-                        [b"standard_conforming_strings", val.as_bytes()]
-                    }
-                };
-
-                // Parameter names and values are passed as null-terminated strings
-                let iov = &mut [name, b"\0", value, b"\0"].map(IoSlice::new);
-                let mut buffer = [0u8; 64]; // this should be enough
-                let cnt = buffer.as_mut().write_vectored(iov).unwrap();
-
+            BeMessage::ParameterStatus(BeParameterStatusMessage { name, value }) => {
                 buf.put_u8(b'S');
                 write_body(buf, |stream| {
-                    stream.put_slice(&buffer[..cnt]);
+                    // Parameter names and values are passed as null-terminated strings
+                    write_cstr(stream, name.as_bytes())?;
+                    write_cstr(stream, value.as_bytes())?;
                     Ok(())
                 })
                 .unwrap();
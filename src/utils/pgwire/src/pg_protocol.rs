@@ -111,20 +111,34 @@ where
         }
     }
 
-    fn process_startup_msg(&mut self, _msg: FeStartupMessage) -> Result<()> {
+    fn process_startup_msg(&mut self, msg: FeStartupMessage) -> Result<()> {
+        let user_name = msg
+            .config
+            .get("user")
+            .map(String::as_str)
+            .unwrap_or_default();
         // TODO: Replace `DEFAULT_DATABASE_NAME` with true database name in `FeStartupMessage`.
-        self.session = Some(self.session_mgr.connect("dev").map_err(IoError::other)?);
+        let session = self
+            .session_mgr
+            .connect("dev", user_name)
+            .map_err(IoError::other)?;
         self.write_message_no_flush(&BeMessage::AuthenticationOk)?;
-        self.write_message_no_flush(&BeMessage::ParameterStatus(
-            BeParameterStatusMessage::Encoding("utf8"),
-        ))?;
-        self.write_message_no_flush(&BeMessage::ParameterStatus(
-            BeParameterStatusMessage::StandardConformingString("on"),
-        ))?;
+        self.write_parameter_status(&session.parameter_status())?;
+        self.session = Some(session);
         self.write_message_no_flush(&BeMessage::ReadyForQuery)?;
         Ok(())
     }
 
+    fn write_parameter_status(&mut self, parameter_status: &[(String, String)]) -> Result<()> {
+        for (name, value) in parameter_status {
+            self.write_message_no_flush(&BeMessage::ParameterStatus(BeParameterStatusMessage {
+                name,
+                value,
+            }))?;
+        }
+        Ok(())
+    }
+
     fn process_terminate(&mut self) {
         self.is_terminate = true;
     }
@@ -137,6 +151,7 @@ where
         let process_res = session.run_statement(query.get_sql()).await;
         match process_res {
             Ok(res) => {
+                self.write_parameter_status(res.get_parameter_status())?;
                 if res.is_empty() {
                     self.write_message_no_flush(&BeMessage::EmptyQueryResponse)?;
                 } else if res.is_query() {
@@ -47,6 +47,7 @@ pub enum StatementType {
     SHOW_PARAMETERS,
     SHOW_COMMAND,
     FLUSH,
+    ANALYZE,
     OTHER,
     // EMPTY is used when query statement is empty (e.g. ";").
     EMPTY,
@@ -65,6 +66,8 @@ pub struct PgResponse {
     notice: Option<String>,
     values: Vec<Row>,
     row_desc: Vec<PgFieldDescriptor>,
+    /// Session variables changed by the statement, reported with `ParameterStatus` messages.
+    parameter_status: Vec<(String, String)>,
 }
 
 impl StatementType {
@@ -95,6 +98,7 @@ impl PgResponse {
             values,
             row_desc,
             notice: None,
+            parameter_status: vec![],
         }
     }
 
@@ -109,9 +113,17 @@ impl PgResponse {
             values: vec![],
             row_desc: vec![],
             notice: Some(notice),
+            parameter_status: vec![],
         }
     }
 
+    /// Report the changed session variables to the client.
+    #[must_use]
+    pub fn with_parameter_status(mut self, parameter_status: Vec<(String, String)>) -> Self {
+        self.parameter_status = parameter_status;
+        self
+    }
+
     pub fn get_stmt_type(&self) -> StatementType {
         self.stmt_type
     }
@@ -138,6 +150,10 @@ impl PgResponse {
         self.stmt_type == StatementType::EMPTY
     }
 
+    pub fn get_parameter_status(&self) -> &[(String, String)] {
+        &self.parameter_status
+    }
+
     pub fn get_row_desc(&self) -> Vec<PgFieldDescriptor> {
         self.row_desc.clone()
     }
@@ -26,7 +26,11 @@ use crate::pg_response::PgResponse;
 /// The interface for a database system behind pgwire protocol.
 /// We can mock it for testing purpose.
 pub trait SessionManager: Send + Sync {
-    fn connect(&self, database: &str) -> Result<Arc<dyn Session>, Box<dyn Error + Send + Sync>>;
+    fn connect(
+        &self,
+        database: &str,
+        user_name: &str,
+    ) -> Result<Arc<dyn Session>, Box<dyn Error + Send + Sync>>;
 }
 
 /// A psql connection. Each connection binds with a database. Switching database will need to
@@ -37,6 +41,9 @@ pub trait Session: Send + Sync {
         self: Arc<Self>,
         sql: &str,
     ) -> Result<PgResponse, Box<dyn Error + Send + Sync>>;
+
+    /// The session variables reported to the client with `ParameterStatus` messages on startup.
+    fn parameter_status(&self) -> Vec<(String, String)>;
 }
 
 /// Binds a Tcp listener at `addr`. Spawn a coroutine to serve every new connection.